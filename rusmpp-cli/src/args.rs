@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Debug, Parser)]
+#[command(name = "rusmpp", version, about = "Decode and encode SMPP PDUs")]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Decode commands, each prefixed with its command_length
+    Decode {
+        /// Format of the input bytes
+        #[arg(long, short, value_enum, default_value_t = BytesFormat::Hex)]
+        input: BytesFormat,
+        /// Format of the decoded commands
+        #[arg(long, short, value_enum, default_value_t = CommandFormat::Pretty)]
+        output: CommandFormat,
+        /// Print an annotated dissection of each command
+        #[arg(long)]
+        dissect: bool,
+        /// Input given on the command line instead of a file
+        #[arg(long, conflicts_with = "file")]
+        data: Option<String>,
+        /// Input file, `-` for stdin
+        #[arg(default_value = "-")]
+        file: PathBuf,
+    },
+    /// Encode commands into bytes, each prefixed with its command_length
+    Encode {
+        /// Format of the input commands. A single command or a list of commands
+        #[arg(long, short, value_enum, default_value_t = SerdeFormat::Json)]
+        input: SerdeFormat,
+        /// Format of the encoded bytes
+        #[arg(long, short, value_enum, default_value_t = BytesFormat::Hex)]
+        output: BytesFormat,
+        /// Input file, `-` for stdin
+        #[arg(default_value = "-")]
+        file: PathBuf,
+    },
+    /// Extract commands from a pcap or pcapng file by reassembling TCP streams
+    Pcap {
+        /// TCP port of the SMPP streams
+        #[arg(long, short, default_value_t = 2775)]
+        port: u16,
+        /// Format of the decoded commands
+        #[arg(long, short, value_enum, default_value_t = CommandFormat::Pretty)]
+        output: CommandFormat,
+        /// Print an annotated dissection of each command
+        #[arg(long)]
+        dissect: bool,
+        /// Capture file
+        file: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BytesFormat {
+    /// Hex string. Whitespace, commas, colons and `0x` prefixes are ignored
+    Hex,
+    /// Standard base64
+    Base64,
+    /// Raw bytes
+    Raw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CommandFormat {
+    /// Pretty-printed debug output
+    Pretty,
+    Json,
+    Yaml,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SerdeFormat {
+    Json,
+    Yaml,
+}
@@ -0,0 +1,88 @@
+use anyhow::Context;
+use base64::Engine;
+
+use crate::args::BytesFormat;
+
+/// Parses the input bytes according to `format`.
+pub fn parse(format: BytesFormat, input: &[u8]) -> anyhow::Result<Vec<u8>> {
+    match format {
+        BytesFormat::Hex => {
+            let input = std::str::from_utf8(input).context("Hex input is not valid UTF-8")?;
+
+            let hex: String = input
+                .split(|c: char| c.is_whitespace() || c == ',' || c == ':')
+                .map(|token| {
+                    token
+                        .strip_prefix("0x")
+                        .or_else(|| token.strip_prefix("0X"))
+                        .unwrap_or(token)
+                })
+                .collect();
+
+            hex::decode(hex).context("Invalid hex input")
+        }
+        BytesFormat::Base64 => {
+            let input: Vec<u8> = input
+                .iter()
+                .copied()
+                .filter(|byte| !byte.is_ascii_whitespace())
+                .collect();
+
+            base64::engine::general_purpose::STANDARD
+                .decode(input)
+                .context("Invalid base64 input")
+        }
+        BytesFormat::Raw => Ok(input.to_vec()),
+    }
+}
+
+/// Formats the bytes according to `format`.
+pub fn format(format: BytesFormat, bytes: &[u8]) -> Vec<u8> {
+    match format {
+        BytesFormat::Hex => format!("{}\n", hex::encode_upper(bytes)).into_bytes(),
+        BytesFormat::Base64 => format!(
+            "{}\n",
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        )
+        .into_bytes(),
+        BytesFormat::Raw => bytes.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hex() {
+        let expected = vec![0x00, 0x00, 0x00, 0x10, 0xAB];
+
+        for input in [
+            "00000010ab",
+            "00 00 00 10 AB\n",
+            "0x00, 0x00, 0x00, 0x10, 0xAB",
+            "00:00:00:10:ab",
+        ] {
+            assert_eq!(parse(BytesFormat::Hex, input.as_bytes()).unwrap(), expected);
+        }
+
+        assert!(parse(BytesFormat::Hex, b"0g").is_err());
+    }
+
+    #[test]
+    fn parse_base64() {
+        assert_eq!(
+            parse(BytesFormat::Base64, b"AAAAEKs=\n").unwrap(),
+            vec![0x00, 0x00, 0x00, 0x10, 0xAB]
+        );
+    }
+
+    #[test]
+    fn format_parse() {
+        let bytes = [0x00, 0x01, 0xFE, 0xFF];
+
+        for format_ in [BytesFormat::Hex, BytesFormat::Base64, BytesFormat::Raw] {
+            assert_eq!(parse(format_, &format(format_, &bytes)).unwrap(), bytes);
+        }
+    }
+}
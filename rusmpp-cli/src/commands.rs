@@ -0,0 +1,292 @@
+use std::io::Write;
+
+use rusmpp::{
+    Command,
+    decode::{DecodeError, DecodeWithLength},
+    dissect::dissect,
+    encode::{Encode, Length},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::args::{CommandFormat, SerdeFormat};
+
+/// Splits a stream of commands, each prefixed with its command_length, into frames.
+///
+/// Stops at the first invalid command_length. The remaining bytes are returned as the last frame.
+pub fn frames(mut bytes: &[u8]) -> Vec<&[u8]> {
+    let mut frames = Vec::new();
+
+    while !bytes.is_empty() {
+        let command_length = match bytes.get(..4) {
+            Some(length) => {
+                u32::from_be_bytes([length[0], length[1], length[2], length[3]]) as usize
+            }
+            None => bytes.len(),
+        };
+
+        if command_length < 16 || command_length > bytes.len() {
+            frames.push(bytes);
+
+            break;
+        }
+
+        let (frame, rest) = bytes.split_at(command_length);
+
+        frames.push(frame);
+
+        bytes = rest;
+    }
+
+    frames
+}
+
+/// Decodes a frame, starting with the command_length.
+pub fn decode(frame: &[u8]) -> Result<Command, DecodeError> {
+    let length = frame.len().saturating_sub(4);
+
+    Command::decode(frame.get(4..).unwrap_or_default(), length).map(|(command, _)| command)
+}
+
+/// Encodes a command, prefixed with its command_length.
+pub fn encode(command: &Command) -> Vec<u8> {
+    let command_length = 4 + command.length();
+
+    let mut bytes = vec![0; command_length];
+
+    bytes[..4].copy_from_slice(&(command_length as u32).to_be_bytes());
+    command.encode(&mut bytes[4..]);
+
+    bytes
+}
+
+/// Parses a stream of JSON values or YAML documents, each a command or a list of commands.
+pub fn parse(format: SerdeFormat, input: &str) -> anyhow::Result<Vec<Command>> {
+    let mut commands = Vec::new();
+
+    match format {
+        SerdeFormat::Json => {
+            for value in serde_json::Deserializer::from_str(input).into_iter() {
+                match value? {
+                    value @ serde_json::Value::Array(_) => {
+                        commands.extend(serde_json::from_value::<Vec<Command>>(value)?)
+                    }
+                    value => commands.push(serde_json::from_value(value)?),
+                }
+            }
+        }
+        SerdeFormat::Yaml => {
+            for document in serde_norway::Deserializer::from_str(input) {
+                match serde_norway::Value::deserialize(document)? {
+                    value @ serde_norway::Value::Sequence(_) => {
+                        commands.extend(serde_norway::from_value::<Vec<Command>>(value)?)
+                    }
+                    value => commands.push(serde_norway::from_value(value)?),
+                }
+            }
+        }
+    }
+
+    Ok(commands)
+}
+
+/// Where a command was captured.
+#[derive(Debug, Serialize)]
+pub struct Origin {
+    pub source: String,
+    pub destination: String,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    #[serde(flatten)]
+    origin: &'a Origin,
+    command: &'a Command,
+}
+
+/// Writes a command according to `format`.
+///
+/// JSON commands are written as a stream of values and YAML commands as a stream of documents,
+/// both of which can be read back with [`parse`] if no `origin` is given.
+pub fn write(
+    out: &mut impl Write,
+    format: CommandFormat,
+    command: &Command,
+    origin: Option<&Origin>,
+) -> anyhow::Result<()> {
+    match (format, origin) {
+        (CommandFormat::Pretty, None) => writeln!(out, "{command:#?}")?,
+        (CommandFormat::Pretty, Some(origin)) => writeln!(
+            out,
+            "# {} -> {}\n{command:#?}",
+            origin.source, origin.destination
+        )?,
+        (CommandFormat::Json, None) => {
+            serde_json::to_writer_pretty(&mut *out, command)?;
+            writeln!(out)?;
+        }
+        (CommandFormat::Json, Some(origin)) => {
+            serde_json::to_writer_pretty(&mut *out, &Record { origin, command })?;
+            writeln!(out)?;
+        }
+        (CommandFormat::Yaml, None) => {
+            writeln!(out, "---")?;
+            serde_norway::to_writer(&mut *out, command)?;
+        }
+        (CommandFormat::Yaml, Some(origin)) => {
+            writeln!(out, "---")?;
+            serde_norway::to_writer(&mut *out, &Record { origin, command })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the annotated dissection of a frame.
+pub fn write_dissection(out: &mut impl Write, frame: &[u8]) -> anyhow::Result<()> {
+    write!(out, "{}", dissect(frame))?;
+
+    Ok(())
+}
+
+/// Writes a decode error with the chain of fields that led to it, followed by the dissection of the frame.
+pub fn write_error(
+    out: &mut impl Write,
+    frame: &[u8],
+    offset: usize,
+    error: &DecodeError,
+) -> anyhow::Result<()> {
+    writeln!(
+        out,
+        "error: failed to decode the command at offset {offset}"
+    )?;
+    writeln!(out, "  kind: {}", error.kind())?;
+
+    let mut source = error.source();
+
+    while let Some(error_source) = source {
+        writeln!(
+            out,
+            "  at {:?}: {}",
+            error_source.field(),
+            error_source.error().kind()
+        )?;
+
+        source = error_source.error().source();
+    }
+
+    writeln!(out)?;
+
+    write_dissection(out, frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rusmpp::{
+        CommandStatus,
+        pdus::{BindTransmitter, SubmitSm},
+        types::{COctetString, OctetString},
+    };
+
+    use super::*;
+
+    fn commands() -> Vec<Command> {
+        vec![
+            Command::new(
+                CommandStatus::EsmeRok,
+                1,
+                BindTransmitter::builder()
+                    .system_id(COctetString::from_str("SMPP3TEST").unwrap())
+                    .password(COctetString::from_str("secret08").unwrap())
+                    .build(),
+            ),
+            Command::new(
+                CommandStatus::EsmeRok,
+                2,
+                SubmitSm::builder()
+                    .short_message(OctetString::new(b"Hello").unwrap())
+                    .build(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn frames_decode_encode() {
+        let bytes: Vec<u8> = commands().iter().flat_map(encode).collect();
+
+        let frames = frames(&bytes);
+
+        assert_eq!(frames.len(), 2);
+
+        let decoded: Vec<_> = frames.iter().map(|frame| decode(frame).unwrap()).collect();
+
+        assert_eq!(decoded, commands());
+    }
+
+    #[test]
+    fn frames_stop_at_invalid_length() {
+        let mut bytes = encode(&commands()[0]);
+
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x08, 0x00]);
+
+        let frames = frames(&bytes);
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1], &[0x00, 0x00, 0x00, 0x08, 0x00]);
+        assert!(decode(frames[1]).is_err());
+    }
+
+    #[test]
+    fn write_parse() {
+        for (output, input) in [
+            (CommandFormat::Json, SerdeFormat::Json),
+            (CommandFormat::Yaml, SerdeFormat::Yaml),
+        ] {
+            let mut out = Vec::new();
+
+            for command in commands() {
+                write(&mut out, output, &command, None).unwrap();
+            }
+
+            let parsed = parse(input, std::str::from_utf8(&out).unwrap()).unwrap();
+
+            assert_eq!(parsed, commands());
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_values() {
+        let mut out = Vec::new();
+
+        write(&mut out, CommandFormat::Json, &commands()[1], None).unwrap();
+
+        let json = std::str::from_utf8(&out).unwrap();
+
+        assert!(parse(SerdeFormat::Json, json).is_ok());
+
+        let json = json.replace("\"sm_length\": 5", "\"sm_length\": 7");
+
+        assert!(parse(SerdeFormat::Json, &json).is_err());
+    }
+
+    #[test]
+    fn write_error_chain() {
+        let mut frame = encode(&commands()[0]);
+
+        // Remove the null terminator of the password.
+        frame[34] = b'!';
+
+        let error = decode(&frame).unwrap_err();
+
+        let mut out = Vec::new();
+
+        write_error(&mut out, &frame, 0, &error).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains("at pdu"));
+        assert!(out.contains("at password"));
+        assert!(out.contains("system_id"));
+    }
+}
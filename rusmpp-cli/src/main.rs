@@ -0,0 +1,149 @@
+use std::{
+    io::{BufWriter, Read, Write},
+    path::Path,
+    process::ExitCode,
+};
+
+use anyhow::Context;
+use clap::Parser;
+
+use crate::{
+    args::{Args, Command, CommandFormat},
+    commands::Origin,
+};
+
+mod args;
+mod bytes;
+mod commands;
+mod pcap;
+
+fn main() -> anyhow::Result<ExitCode> {
+    let args = Args::parse();
+
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+
+    let ok = match args.command {
+        Command::Decode {
+            input,
+            output,
+            dissect,
+            data,
+            file,
+        } => {
+            let data = match data {
+                Some(data) => data.into_bytes(),
+                None => read(&file)?,
+            };
+
+            let bytes = bytes::parse(input, &data)?;
+
+            let mut ok = true;
+            let mut offset = 0;
+
+            for frame in commands::frames(&bytes) {
+                ok &= decode(&mut out, frame, offset, output, dissect, None)?;
+
+                offset += frame.len();
+            }
+
+            ok
+        }
+        Command::Encode {
+            input,
+            output,
+            file,
+        } => {
+            let data = String::from_utf8(read(&file)?).context("Input is not valid UTF-8")?;
+
+            for command in commands::parse(input, &data)? {
+                out.write_all(&bytes::format(output, &commands::encode(&command)))?;
+            }
+
+            true
+        }
+        Command::Pcap {
+            port,
+            output,
+            dissect,
+            file,
+        } => {
+            let capture = read(&file)?;
+
+            let mut ok = true;
+
+            for frame in pcap::frames(&capture, port)? {
+                let origin = Origin {
+                    source: frame.source.to_string(),
+                    destination: frame.destination.to_string(),
+                };
+
+                ok &= decode(&mut out, &frame.bytes, 0, output, dissect, Some(&origin))?;
+            }
+
+            ok
+        }
+    };
+
+    out.flush()?;
+
+    Ok(if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Reads a file, or stdin if the path is `-`.
+fn read(path: &Path) -> anyhow::Result<Vec<u8>> {
+    if path == Path::new("-") {
+        let mut data = Vec::new();
+
+        std::io::stdin()
+            .read_to_end(&mut data)
+            .context("Failed to read stdin")?;
+
+        return Ok(data);
+    }
+
+    std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// Decodes and writes a frame. Errors are written to stderr.
+///
+/// Returns `false` if the frame could not be decoded.
+fn decode(
+    out: &mut impl Write,
+    frame: &[u8],
+    offset: usize,
+    format: CommandFormat,
+    dissect: bool,
+    origin: Option<&Origin>,
+) -> anyhow::Result<bool> {
+    match commands::decode(frame) {
+        Ok(command) => {
+            commands::write(out, format, &command, origin)?;
+
+            if dissect {
+                commands::write_dissection(out, frame)?;
+            }
+
+            Ok(true)
+        }
+        Err(error) => {
+            // Keep the order of the outputs when both go to the terminal.
+            out.flush()?;
+
+            let stderr = std::io::stderr();
+            let mut err = stderr.lock();
+
+            if let Some(origin) = origin {
+                writeln!(err, "# {} -> {}", origin.source, origin.destination)?;
+            }
+
+            commands::write_error(&mut err, frame, offset, &error)?;
+
+            Ok(false)
+        }
+    }
+}
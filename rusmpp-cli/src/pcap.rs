@@ -0,0 +1,588 @@
+//! Extracts `SMPP` frames from pcap and pcapng captures.
+//!
+//! Supports Ethernet (with VLAN tags), raw IP, BSD loopback and Linux cooked captures of IPv4 and IPv6.
+//! TCP segments are reassembled per direction, in sequence number order. Retransmissions are dropped,
+//! out of order segments are buffered until the gap is filled.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+use anyhow::{Context, bail};
+
+/// A frame of a TCP stream, starting with the command_length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub bytes: Vec<u8>,
+}
+
+/// The largest accepted command_length. Larger lengths mean the stream is out of sync.
+const MAX_COMMAND_LENGTH: usize = 64 * 1024;
+
+/// Returns the frames of the TCP streams with `port` as source or destination port, in capture order.
+pub fn frames(capture: &[u8], port: u16) -> anyhow::Result<Vec<Frame>> {
+    let mut streams = Streams::default();
+
+    for packet in packets(capture)? {
+        if let Some(segment) = segment(packet.link_type, packet.data) {
+            if segment.source.port() == port || segment.destination.port() == port {
+                streams.push(segment);
+            }
+        }
+    }
+
+    Ok(streams.frames)
+}
+
+struct Packet<'a> {
+    link_type: u32,
+    data: &'a [u8],
+}
+
+const LINK_TYPE_NULL: u32 = 0;
+const LINK_TYPE_ETHERNET: u32 = 1;
+const LINK_TYPE_RAW: u32 = 101;
+const LINK_TYPE_RAW_LEGACY: u32 = 12;
+const LINK_TYPE_LOOP: u32 = 108;
+const LINK_TYPE_LINUX_SLL: u32 = 113;
+const LINK_TYPE_LINUX_SLL2: u32 = 276;
+
+fn packets(capture: &[u8]) -> anyhow::Result<Vec<Packet<'_>>> {
+    let magic = capture.get(..4).context("Capture is too short")?;
+
+    match magic {
+        [0xD4, 0xC3, 0xB2, 0xA1] | [0x4D, 0x3C, 0xB2, 0xA1] => pcap(capture, Endian::Little),
+        [0xA1, 0xB2, 0xC3, 0xD4] | [0xA1, 0xB2, 0x3C, 0x4D] => pcap(capture, Endian::Big),
+        [0x0A, 0x0D, 0x0D, 0x0A] => pcapng(capture),
+        _ => bail!("Unknown capture format"),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Endian {
+    Little,
+    Big,
+}
+
+impl Endian {
+    fn u16(self, bytes: &[u8], offset: usize) -> Option<u16> {
+        let bytes = bytes.get(offset..offset + 2)?.try_into().ok()?;
+
+        Some(match self {
+            Endian::Little => u16::from_le_bytes(bytes),
+            Endian::Big => u16::from_be_bytes(bytes),
+        })
+    }
+
+    fn u32(self, bytes: &[u8], offset: usize) -> Option<u32> {
+        let bytes = bytes.get(offset..offset + 4)?.try_into().ok()?;
+
+        Some(match self {
+            Endian::Little => u32::from_le_bytes(bytes),
+            Endian::Big => u32::from_be_bytes(bytes),
+        })
+    }
+}
+
+fn pcap(capture: &[u8], endian: Endian) -> anyhow::Result<Vec<Packet<'_>>> {
+    let link_type = endian
+        .u32(capture, 20)
+        .context("Truncated pcap header")?
+        // The upper bits may hold the FCS length.
+        & 0x0FFF_FFFF;
+
+    let mut packets = Vec::new();
+    let mut offset = 24;
+
+    while offset + 16 <= capture.len() {
+        let captured = endian
+            .u32(capture, offset + 8)
+            .context("Truncated record")? as usize;
+
+        let start = offset + 16;
+        let end = start + captured;
+
+        let data = capture
+            .get(start..end)
+            .context("Truncated pcap packet data")?;
+
+        packets.push(Packet { link_type, data });
+
+        offset = end;
+    }
+
+    Ok(packets)
+}
+
+fn pcapng(capture: &[u8]) -> anyhow::Result<Vec<Packet<'_>>> {
+    const SECTION_HEADER: u32 = 0x0A0D0D0A;
+    const INTERFACE_DESCRIPTION: u32 = 0x00000001;
+    const SIMPLE_PACKET: u32 = 0x00000003;
+    const ENHANCED_PACKET: u32 = 0x00000006;
+
+    let mut packets = Vec::new();
+    let mut endian = Endian::Little;
+    let mut interfaces: Vec<u32> = Vec::new();
+    let mut offset = 0;
+
+    while offset + 12 <= capture.len() {
+        // The block type of a section header is a palindrome, and its byte order magic sets the endianness.
+        if endian.u32(capture, offset) == Some(SECTION_HEADER) {
+            endian = match capture.get(offset + 8..offset + 12) {
+                Some([0x4D, 0x3C, 0x2B, 0x1A]) => Endian::Little,
+                Some([0x1A, 0x2B, 0x3C, 0x4D]) => Endian::Big,
+                _ => bail!("Invalid pcapng byte order magic"),
+            };
+
+            interfaces.clear();
+        }
+
+        let block_type = endian.u32(capture, offset).context("Truncated block")?;
+        let block_length = endian.u32(capture, offset + 4).context("Truncated block")? as usize;
+
+        if block_length < 12 || offset + block_length > capture.len() {
+            bail!("Invalid pcapng block length {block_length} at offset {offset}");
+        }
+
+        let body = &capture[offset + 8..offset + block_length - 4];
+
+        match block_type {
+            INTERFACE_DESCRIPTION => {
+                let link_type = endian.u16(body, 0).context("Truncated interface")?;
+
+                interfaces.push(link_type as u32);
+            }
+            ENHANCED_PACKET => {
+                let interface = endian.u32(body, 0).context("Truncated packet")? as usize;
+                let captured = endian.u32(body, 12).context("Truncated packet")? as usize;
+
+                let link_type = *interfaces
+                    .get(interface)
+                    .context("Packet of an unknown interface")?;
+
+                let data = body.get(20..20 + captured).context("Truncated packet")?;
+
+                packets.push(Packet { link_type, data });
+            }
+            SIMPLE_PACKET => {
+                let original = endian.u32(body, 0).context("Truncated packet")? as usize;
+
+                let link_type = *interfaces
+                    .first()
+                    .context("Packet of an unknown interface")?;
+
+                let data = &body[4..];
+                let data = &data[..original.min(data.len())];
+
+                packets.push(Packet { link_type, data });
+            }
+            _ => {}
+        }
+
+        offset += block_length;
+    }
+
+    Ok(packets)
+}
+
+struct Segment<'a> {
+    source: SocketAddr,
+    destination: SocketAddr,
+    sequence_number: u32,
+    syn: bool,
+    payload: &'a [u8],
+}
+
+/// Extracts the TCP segment of a packet.
+fn segment(link_type: u32, data: &[u8]) -> Option<Segment<'_>> {
+    let ip = match link_type {
+        LINK_TYPE_ETHERNET => {
+            let mut ether_type = u16::from_be_bytes(data.get(12..14)?.try_into().ok()?);
+            let mut offset = 14;
+
+            // 802.1Q and 802.1ad tags
+            while ether_type == 0x8100 || ether_type == 0x88A8 {
+                ether_type = u16::from_be_bytes(data.get(offset + 2..offset + 4)?.try_into().ok()?);
+                offset += 4;
+            }
+
+            if ether_type != 0x0800 && ether_type != 0x86DD {
+                return None;
+            }
+
+            data.get(offset..)?
+        }
+        LINK_TYPE_RAW | LINK_TYPE_RAW_LEGACY => data,
+        LINK_TYPE_NULL | LINK_TYPE_LOOP => data.get(4..)?,
+        LINK_TYPE_LINUX_SLL => data.get(16..)?,
+        LINK_TYPE_LINUX_SLL2 => data.get(20..)?,
+        _ => return None,
+    };
+
+    let (source, destination, tcp) = match ip.first()? >> 4 {
+        4 => {
+            let header_length = ((ip[0] & 0x0F) as usize) * 4;
+            let total_length = u16::from_be_bytes(ip.get(2..4)?.try_into().ok()?) as usize;
+
+            // Fragments are not reassembled
+            let fragment = u16::from_be_bytes(ip.get(6..8)?.try_into().ok()?);
+
+            if ip.get(9)? != &6 || fragment & 0x3FFF != 0 {
+                return None;
+            }
+
+            let source: [u8; 4] = ip.get(12..16)?.try_into().ok()?;
+            let destination: [u8; 4] = ip.get(16..20)?.try_into().ok()?;
+
+            (
+                IpAddr::V4(Ipv4Addr::from(source)),
+                IpAddr::V4(Ipv4Addr::from(destination)),
+                ip.get(header_length..total_length.min(ip.len()))?,
+            )
+        }
+        6 => {
+            let payload_length = u16::from_be_bytes(ip.get(4..6)?.try_into().ok()?) as usize;
+
+            let source: [u8; 16] = ip.get(8..24)?.try_into().ok()?;
+            let destination: [u8; 16] = ip.get(24..40)?.try_into().ok()?;
+
+            let mut next_header = *ip.get(6)?;
+            let mut offset = 40;
+
+            // Hop-by-hop, routing and destination options extension headers
+            while matches!(next_header, 0 | 43 | 60) {
+                next_header = *ip.get(offset)?;
+                offset += (*ip.get(offset + 1)? as usize + 1) * 8;
+            }
+
+            if next_header != 6 {
+                return None;
+            }
+
+            (
+                IpAddr::V6(Ipv6Addr::from(source)),
+                IpAddr::V6(Ipv6Addr::from(destination)),
+                ip.get(offset..(40 + payload_length).min(ip.len()))?,
+            )
+        }
+        _ => return None,
+    };
+
+    let source_port = u16::from_be_bytes(tcp.get(0..2)?.try_into().ok()?);
+    let destination_port = u16::from_be_bytes(tcp.get(2..4)?.try_into().ok()?);
+    let sequence_number = u32::from_be_bytes(tcp.get(4..8)?.try_into().ok()?);
+    let data_offset = ((tcp.get(12)? >> 4) as usize) * 4;
+    let syn = tcp.get(13)? & 0x02 != 0;
+
+    Some(Segment {
+        source: SocketAddr::new(source, source_port),
+        destination: SocketAddr::new(destination, destination_port),
+        sequence_number,
+        syn,
+        payload: tcp.get(data_offset..)?,
+    })
+}
+
+#[derive(Default)]
+struct Stream {
+    /// The sequence number of the next expected byte.
+    next_sequence_number: Option<u32>,
+    /// Out of order segments by sequence number.
+    pending: BTreeMap<u32, Vec<u8>>,
+    /// Reassembled bytes that do not form a complete frame yet.
+    buffer: Vec<u8>,
+}
+
+#[derive(Default)]
+struct Streams {
+    streams: HashMap<(SocketAddr, SocketAddr), Stream>,
+    frames: Vec<Frame>,
+}
+
+impl Streams {
+    fn push(&mut self, segment: Segment<'_>) {
+        let stream = self
+            .streams
+            .entry((segment.source, segment.destination))
+            .or_default();
+
+        let sequence_number = if segment.syn {
+            segment.sequence_number.wrapping_add(1)
+        } else {
+            segment.sequence_number
+        };
+
+        if segment.syn || stream.next_sequence_number.is_none() {
+            // A new connection, or the capture started mid-stream.
+            *stream = Stream {
+                next_sequence_number: Some(sequence_number),
+                ..Default::default()
+            };
+        }
+
+        if segment.payload.is_empty() {
+            return;
+        }
+
+        stream
+            .pending
+            .insert(sequence_number, segment.payload.to_vec());
+
+        // Append every pending segment that starts at or before the next expected byte.
+        while let Some(next) = stream.next_sequence_number {
+            let Some(sequence_number) = stream
+                .pending
+                .keys()
+                .copied()
+                .find(|sequence_number| (next.wrapping_sub(*sequence_number) as i32) >= 0)
+            else {
+                break;
+            };
+
+            let payload = stream.pending.remove(&sequence_number).expect("Key exists");
+
+            // Skip the bytes that were already received.
+            let overlap = next.wrapping_sub(sequence_number) as usize;
+
+            if overlap < payload.len() {
+                stream.buffer.extend_from_slice(&payload[overlap..]);
+                stream.next_sequence_number =
+                    Some(sequence_number.wrapping_add(payload.len() as u32));
+            }
+        }
+
+        while stream.buffer.len() >= 4 {
+            let command_length = u32::from_be_bytes(
+                stream.buffer[..4]
+                    .try_into()
+                    .expect("Slice is 4 bytes long"),
+            ) as usize;
+
+            if !(16..=MAX_COMMAND_LENGTH).contains(&command_length) {
+                // The stream is out of sync. Emit the garbage as a frame so it is reported.
+                self.frames.push(Frame {
+                    source: segment.source,
+                    destination: segment.destination,
+                    bytes: std::mem::take(&mut stream.buffer),
+                });
+
+                break;
+            }
+
+            if stream.buffer.len() < command_length {
+                break;
+            }
+
+            let rest = stream.buffer.split_off(command_length);
+
+            self.frames.push(Frame {
+                source: segment.source,
+                destination: segment.destination,
+                bytes: std::mem::replace(&mut stream.buffer, rest),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIENT: [u8; 4] = [10, 0, 0, 1];
+    const SERVER: [u8; 4] = [10, 0, 0, 2];
+
+    /// Builds an Ethernet/IPv4/TCP packet.
+    fn packet(
+        source: u16,
+        destination: u16,
+        sequence_number: u32,
+        syn: bool,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let (source_ip, destination_ip) = if destination == 2775 {
+            (CLIENT, SERVER)
+        } else {
+            (SERVER, CLIENT)
+        };
+
+        let mut packet = vec![0; 14];
+        packet[12..14].copy_from_slice(&0x0800u16.to_be_bytes());
+
+        let total_length = (20 + 20 + payload.len()) as u16;
+
+        packet.extend_from_slice(&[0x45, 0x00]);
+        packet.extend_from_slice(&total_length.to_be_bytes());
+        packet.extend_from_slice(&[0x00, 0x00, 0x40, 0x00, 0x40, 0x06, 0x00, 0x00]);
+        packet.extend_from_slice(&source_ip);
+        packet.extend_from_slice(&destination_ip);
+
+        packet.extend_from_slice(&source.to_be_bytes());
+        packet.extend_from_slice(&destination.to_be_bytes());
+        packet.extend_from_slice(&sequence_number.to_be_bytes());
+        packet.extend_from_slice(&[0x00; 4]);
+        packet.extend_from_slice(&[0x50, if syn { 0x02 } else { 0x18 }]);
+        packet.extend_from_slice(&[0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00]);
+        packet.extend_from_slice(payload);
+
+        // Ethernet padding
+        packet.extend_from_slice(&[0x00; 4]);
+
+        packet
+    }
+
+    fn pcap(packets: &[Vec<u8>]) -> Vec<u8> {
+        let mut capture = Vec::new();
+
+        capture.extend_from_slice(&0xA1B2C3D4u32.to_le_bytes());
+        capture.extend_from_slice(&2u16.to_le_bytes());
+        capture.extend_from_slice(&4u16.to_le_bytes());
+        capture.extend_from_slice(&[0x00; 8]);
+        capture.extend_from_slice(&65535u32.to_le_bytes());
+        capture.extend_from_slice(&LINK_TYPE_ETHERNET.to_le_bytes());
+
+        for packet in packets {
+            capture.extend_from_slice(&[0x00; 8]);
+            capture.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+            capture.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+            capture.extend_from_slice(packet);
+        }
+
+        capture
+    }
+
+    fn pcapng(packets: &[Vec<u8>]) -> Vec<u8> {
+        fn block(capture: &mut Vec<u8>, block_type: u32, body: &[u8]) {
+            let padding = (4 - body.len() % 4) % 4;
+            let length = (12 + body.len() + padding) as u32;
+
+            capture.extend_from_slice(&block_type.to_le_bytes());
+            capture.extend_from_slice(&length.to_le_bytes());
+            capture.extend_from_slice(body);
+            capture.extend_from_slice(&vec![0x00; padding]);
+            capture.extend_from_slice(&length.to_le_bytes());
+        }
+
+        let mut capture = Vec::new();
+
+        let mut section = Vec::new();
+        section.extend_from_slice(&0x1A2B3C4Du32.to_le_bytes());
+        section.extend_from_slice(&1u16.to_le_bytes());
+        section.extend_from_slice(&0u16.to_le_bytes());
+        section.extend_from_slice(&(-1i64).to_le_bytes());
+        block(&mut capture, 0x0A0D0D0A, &section);
+
+        let mut interface = Vec::new();
+        interface.extend_from_slice(&(LINK_TYPE_ETHERNET as u16).to_le_bytes());
+        interface.extend_from_slice(&[0x00; 2]);
+        interface.extend_from_slice(&65535u32.to_le_bytes());
+        block(&mut capture, 1, &interface);
+
+        for packet in packets {
+            let mut body = Vec::new();
+            body.extend_from_slice(&0u32.to_le_bytes());
+            body.extend_from_slice(&[0x00; 8]);
+            body.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+            body.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+            body.extend_from_slice(packet);
+            block(&mut capture, 6, &body);
+        }
+
+        capture
+    }
+
+    fn enquire_link(sequence_number: u8) -> Vec<u8> {
+        vec![
+            0x00,
+            0x00,
+            0x00,
+            0x10,
+            0x00,
+            0x00,
+            0x00,
+            0x15,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            sequence_number,
+        ]
+    }
+
+    #[test]
+    fn reassemble() {
+        let first = enquire_link(1);
+        let second = enquire_link(2);
+
+        let both: Vec<u8> = first.iter().chain(second.iter()).copied().collect();
+
+        let packets = vec![
+            packet(40000, 2775, 999, true, &[]),
+            // The first frame split in two segments
+            packet(40000, 2775, 1000, false, &first[..6]),
+            packet(40000, 2775, 1006, false, &first[6..]),
+            // Retransmission
+            packet(40000, 2775, 1006, false, &first[6..]),
+            // Out of order
+            packet(40000, 2775, 1024, false, &second[8..]),
+            packet(40000, 2775, 1016, false, &second[..8]),
+            // Response on another port, captured mid-stream
+            packet(2775, 40000, 5000, false, &both),
+            // Unrelated traffic
+            packet(40000, 8080, 1, false, &first),
+        ];
+
+        for capture in [pcap(&packets), pcapng(&packets)] {
+            let frames = frames(&capture, 2775).unwrap();
+
+            let client: SocketAddr = "10.0.0.1:40000".parse().unwrap();
+            let server: SocketAddr = "10.0.0.2:2775".parse().unwrap();
+
+            assert_eq!(
+                frames,
+                vec![
+                    Frame {
+                        source: client,
+                        destination: server,
+                        bytes: first.clone(),
+                    },
+                    Frame {
+                        source: client,
+                        destination: server,
+                        bytes: second.clone(),
+                    },
+                    Frame {
+                        source: server,
+                        destination: client,
+                        bytes: first.clone(),
+                    },
+                    Frame {
+                        source: server,
+                        destination: client,
+                        bytes: second.clone(),
+                    },
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_sync() {
+        let packets = vec![packet(40000, 2775, 1, false, &[0xFF; 8])];
+
+        let frames = frames(&pcap(&packets), 2775).unwrap();
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].bytes, vec![0xFF; 8]);
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(frames(&[0x00; 24], 2775).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{CommandId, CommandStatus, pdus::borrowed::Pdu};
+use crate::{CommandId, CommandStatus, pdus::borrowed::Pdu, values::InterfaceVersion};
 
 /// `SMPP` command.
 ///
@@ -41,7 +41,7 @@ use crate::{CommandId, CommandStatus, pdus::borrowed::Pdu};
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub struct Command<'a, const N: usize> {
     /// See [`CommandId`]
-    id: CommandId,
+    pub(crate) id: CommandId,
     /// See [`CommandStatus`]
     pub status: CommandStatus,
     /// The sequence_number represents a means of uniquely
@@ -52,7 +52,7 @@ pub struct Command<'a, const N: usize> {
     ///
     /// Optional because incoming commands may not have a PDU.
     #[rusmpp(key = id, length = "unchecked")]
-    pdu: Option<Pdu<'a, N>>,
+    pub(crate) pdu: Option<Pdu<'a, N>>,
 }
 
 impl<'a, const N: usize> Default for Command<'a, N> {
@@ -115,6 +115,22 @@ impl<'a, const N: usize> Command<'a, N> {
     pub fn builder() -> CommandStatusBuilder<'a, N> {
         Default::default()
     }
+
+    /// Returns true if any TLV of this [`Command`] is not supported by the given [`InterfaceVersion`].
+    pub fn has_unsupported_tlvs(&self, interface_version: InterfaceVersion) -> bool {
+        self.pdu.as_ref().is_some_and(|pdu| {
+            pdu.tlvs()
+                .iter()
+                .any(|tlv| !tlv.tag().is_supported_by(interface_version))
+        })
+    }
+
+    /// Removes the TLVs that are not supported by the given [`InterfaceVersion`].
+    pub fn retain_supported_tlvs(&mut self, interface_version: InterfaceVersion) {
+        if let Some(pdu) = self.pdu.as_mut() {
+            pdu.retain_tlvs(|tlv| tlv.tag().is_supported_by(interface_version));
+        }
+    }
 }
 
 #[derive(Debug, Default)]
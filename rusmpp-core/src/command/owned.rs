@@ -1,6 +1,11 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{CommandId, CommandStatus, pdus::owned::Pdu};
+use crate::{
+    CommandId, CommandStatus,
+    command::borrowed,
+    pdus::owned::{AsBorrowedError, Pdu},
+    values::InterfaceVersion,
+};
 
 /// `SMPP` command.
 ///
@@ -36,10 +41,16 @@ use crate::{CommandId, CommandStatus, pdus::owned::Pdu};
 /// | 01                            | addr_npi (0x01) |
 /// | 00                            | addr_range (NULL) |
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned)]
+#[rusmpp(decode = owned, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct Command {
     /// See [`CommandId`]
     id: CommandId,
@@ -117,6 +128,22 @@ impl Command {
         Default::default()
     }
 
+    /// Returns true if any TLV of this [`Command`] is not supported by the given [`InterfaceVersion`].
+    pub fn has_unsupported_tlvs(&self, interface_version: InterfaceVersion) -> bool {
+        self.pdu.as_ref().is_some_and(|pdu| {
+            pdu.tlvs()
+                .iter()
+                .any(|tlv| !tlv.tag().is_supported_by(interface_version))
+        })
+    }
+
+    /// Removes the TLVs that are not supported by the given [`InterfaceVersion`].
+    pub fn retain_supported_tlvs(&mut self, interface_version: InterfaceVersion) {
+        if let Some(pdu) = self.pdu.as_mut() {
+            pdu.retain_tlvs(|tlv| tlv.tag().is_supported_by(interface_version));
+        }
+    }
+
     /// Creates a new command from it's parts.
     ///
     /// # Note
@@ -133,6 +160,38 @@ impl Command {
     }
 }
 
+impl<const N: usize> From<borrowed::Command<'_, N>> for Command {
+    fn from(value: borrowed::Command<'_, N>) -> Self {
+        Self {
+            id: value.id,
+            status: value.status,
+            sequence_number: value.sequence_number,
+            pdu: value.pdu.map(Into::into),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::Command<'_, N> {
+    /// Converts this borrowed [`Command`](borrowed::Command) into an owned [`Command`].
+    pub fn into_owned(self) -> Command {
+        self.into()
+    }
+}
+
+impl Command {
+    /// Returns a borrowed [`Command`](borrowed::Command) view of this [`Command`].
+    ///
+    /// Fails if the TLVs, destination addresses or unsuccessful SMEs of the [`Pdu`] exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(&self) -> Result<borrowed::Command<'_, N>, AsBorrowedError> {
+        Ok(borrowed::Command {
+            id: self.id,
+            status: self.status,
+            sequence_number: self.sequence_number,
+            pdu: self.pdu.as_ref().map(Pdu::as_borrowed).transpose()?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CommandStatusBuilder {
     inner: Command,
@@ -182,4 +241,51 @@ mod tests {
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<Command>();
     }
+
+    #[test]
+    fn into_owned_as_borrowed() {
+        use crate::{decode::borrowed::DecodeWithLength, encode::Encode};
+
+        for original in crate::tests::owned::test_commands() {
+            let buf = &mut [0u8; 1024];
+            let size = original.encode(buf);
+
+            let (decoded, _) =
+                borrowed::Command::<'_, 16>::decode(&buf[..size], size).expect("Failed to decode");
+
+            assert_eq!(decoded.into_owned(), original);
+
+            let borrowed = original
+                .as_borrowed::<16>()
+                .expect("Failed to borrow command");
+
+            let borrowed_buf = &mut [0u8; 1024];
+            let borrowed_size = borrowed.encode(borrowed_buf);
+
+            assert_eq!(&borrowed_buf[..borrowed_size], &buf[..size]);
+        }
+    }
+
+    #[test]
+    fn as_borrowed_too_many_elements() {
+        use crate::{
+            fields::SmppField, pdus::owned::SubmitSm, tlvs::owned::MessageSubmissionRequestTlvValue,
+        };
+
+        let submit_sm = SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+            .push_tlv(MessageSubmissionRequestTlvValue::SarTotalSegments(2))
+            .build();
+
+        let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+
+        assert_eq!(
+            command.as_borrowed::<1>().unwrap_err(),
+            AsBorrowedError::TooManyElements {
+                field: SmppField::tlvs,
+                actual: 2,
+                max: 1,
+            }
+        );
+    }
 }
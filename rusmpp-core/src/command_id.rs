@@ -1,5 +1,7 @@
 use rusmpp_macros::Rusmpp;
 
+use crate::values::InterfaceVersion;
+
 /// Identifies the `SMPP` operation e.g. submit_sm, bind_transmitter etc.
 ///
 /// The [`CommandId`] is encoded as a 4-octet integer value.
@@ -21,7 +23,10 @@ use rusmpp_macros::Rusmpp;
 #[cfg_attr(test, derive(strum_macros::EnumIter))]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum CommandId {
     BindReceiver = 0x00000001,
     BindTransmitter = 0x00000002,
@@ -88,6 +93,45 @@ impl CommandId {
         let id: u32 = self.into();
         (id | 0x80000000).into()
     }
+
+    /// Returns true if this [`CommandId`] is defined by the given [`InterfaceVersion`].
+    ///
+    /// The broadcast operations were introduced in `SMPP` v5.0.
+    /// `data_sm`, `bind_transceiver`, `outbind` and `alert_notification` were introduced in `SMPP` v3.4.
+    /// Unknown interface versions and [`CommandId::Other`] are always supported.
+    pub fn is_supported_by(self, interface_version: InterfaceVersion) -> bool {
+        match interface_version {
+            InterfaceVersion::Smpp3_3OrEarlier(_) => {
+                !self.is_introduced_in_v3_4() && !self.is_introduced_in_v5_0()
+            }
+            InterfaceVersion::Smpp3_4 => !self.is_introduced_in_v5_0(),
+            InterfaceVersion::Smpp5_0 | InterfaceVersion::Other(_) => true,
+        }
+    }
+
+    const fn is_introduced_in_v3_4(self) -> bool {
+        matches!(
+            self,
+            CommandId::DataSm
+                | CommandId::DataSmResp
+                | CommandId::BindTransceiver
+                | CommandId::BindTransceiverResp
+                | CommandId::Outbind
+                | CommandId::AlertNotification
+        )
+    }
+
+    const fn is_introduced_in_v5_0(self) -> bool {
+        matches!(
+            self,
+            CommandId::BroadcastSm
+                | CommandId::BroadcastSmResp
+                | CommandId::QueryBroadcastSm
+                | CommandId::QueryBroadcastSmResp
+                | CommandId::CancelBroadcastSm
+                | CommandId::CancelBroadcastSmResp
+        )
+    }
 }
 
 #[cfg(test)]
@@ -159,6 +203,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_supported_by() {
+        assert!(CommandId::SubmitSm.is_supported_by(InterfaceVersion::Smpp3_4));
+        assert!(!CommandId::BroadcastSm.is_supported_by(InterfaceVersion::Smpp3_4));
+        assert!(
+            !CommandId::CancelBroadcastSmResp
+                .is_supported_by(InterfaceVersion::Smpp3_3OrEarlier(0x33))
+        );
+        assert!(CommandId::BroadcastSm.is_supported_by(InterfaceVersion::Smpp5_0));
+
+        for id in [
+            CommandId::DataSm,
+            CommandId::DataSmResp,
+            CommandId::BindTransceiver,
+            CommandId::BindTransceiverResp,
+            CommandId::Outbind,
+            CommandId::AlertNotification,
+        ] {
+            assert!(id.is_supported_by(InterfaceVersion::Smpp3_4));
+            assert!(!id.is_supported_by(InterfaceVersion::Smpp3_3OrEarlier(0x33)));
+        }
+
+        assert!(CommandId::SubmitSm.is_supported_by(InterfaceVersion::Smpp3_3OrEarlier(0x33)));
+        assert!(
+            CommandId::BindTransmitter.is_supported_by(InterfaceVersion::Smpp3_3OrEarlier(0x33))
+        );
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_test_instances::<CommandId>();
@@ -14,9 +14,13 @@ use rusmpp_macros::Rusmpp;
 /// `SMPP` message header and in the error_status_code field of a submit_multi_resp message.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
 #[repr(u32)]
+#[cfg_attr(test, derive(strum_macros::EnumIter))]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum CommandStatus {
     /// No Error.
     ///
@@ -418,8 +422,211 @@ pub enum CommandStatus {
     Other(u32),
 }
 
+/// The category of a [`CommandStatus`].
+///
+/// See [`CommandStatus::category`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CommandStatusCategory {
+    /// The request succeeded.
+    Ok,
+    /// The request is invalid or was rejected and will fail again if sent unchanged.
+    Permanent,
+    /// The request failed because of a temporary condition of the peer and may succeed if sent again later.
+    Transient,
+    /// The session is not in a state to process the request, or the bind failed.
+    Session,
+    /// Reserved, MC specific or otherwise unknown status.
+    Unknown,
+}
+
+/// How a request that failed with a [`CommandStatus`] should be retried.
+///
+/// See [`CommandStatus::retry_hint`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RetryHint {
+    /// Do not retry the request.
+    Never,
+    /// Retry the request later, preferably with an increasing delay.
+    Backoff,
+    /// Reduce the rate of requests, then retry the request.
+    Throttle,
+    /// Bind a new session, then retry the request.
+    Rebind,
+}
+
+impl CommandStatus {
+    /// Returns `true` if the status is [`CommandStatus::EsmeRok`].
+    #[inline]
+    pub const fn is_ok(self) -> bool {
+        matches!(self, Self::EsmeRok)
+    }
+
+    /// Returns the [`CommandStatusCategory`] of the status.
+    ///
+    /// Generic failures of an operation, e.g. [`CommandStatus::EsmeRsubmitfail`], are considered [`CommandStatusCategory::Transient`].
+    /// Bind failures, e.g. [`CommandStatus::EsmeRbindfail`], and [`CommandStatus::EsmeRinvbndsts`] are considered [`CommandStatusCategory::Session`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rusmpp_core::{CommandStatus, CommandStatusCategory};
+    ///
+    /// assert_eq!(CommandStatus::EsmeRinvdstadr.category(), CommandStatusCategory::Permanent);
+    /// assert_eq!(CommandStatus::EsmeRthrottled.category(), CommandStatusCategory::Transient);
+    /// assert_eq!(CommandStatus::EsmeRinvbndsts.category(), CommandStatusCategory::Session);
+    /// assert_eq!(CommandStatus::Other(0x00000400).category(), CommandStatusCategory::Unknown);
+    /// ```
+    pub const fn category(self) -> CommandStatusCategory {
+        match self {
+            Self::EsmeRok => CommandStatusCategory::Ok,
+            Self::EsmeRinvbndsts
+            | Self::EsmeRalybnd
+            | Self::EsmeRbindfail
+            | Self::EsmeRinvpaswd
+            | Self::EsmeRinvsysid
+            | Self::EsmeRinvsystyp => CommandStatusCategory::Session,
+            Self::EsmeRsyserr
+            | Self::EsmeRcancelfail
+            | Self::EsmeRreplacefail
+            | Self::EsmeRsubmitfail
+            | Self::EsmeRxTAppn
+            | Self::EsmeRqueryfail
+            | Self::EsmeRdeliveryfailure
+            | Self::EsmeRunknownerr
+            | Self::EsmeRsertypunavail
+            | Self::EsmeRbcastfail
+            | Self::EsmeRbcastqueryfail
+            | Self::EsmeRbcastcancelfail
+            | Self::EsmeRmsgqful
+            | Self::EsmeRthrottled => CommandStatusCategory::Transient,
+            Self::Other(_) => CommandStatusCategory::Unknown,
+            _ => CommandStatusCategory::Permanent,
+        }
+    }
+
+    /// Returns the [`RetryHint`] for a request that failed with the status.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rusmpp_core::{CommandStatus, RetryHint};
+    ///
+    /// assert_eq!(CommandStatus::EsmeRinvdstadr.retry_hint(), RetryHint::Never);
+    /// assert_eq!(CommandStatus::EsmeRsyserr.retry_hint(), RetryHint::Backoff);
+    /// assert_eq!(CommandStatus::EsmeRmsgqful.retry_hint(), RetryHint::Throttle);
+    /// assert_eq!(CommandStatus::EsmeRinvbndsts.retry_hint(), RetryHint::Rebind);
+    /// ```
+    pub const fn retry_hint(self) -> RetryHint {
+        match self {
+            Self::EsmeRmsgqful | Self::EsmeRthrottled => RetryHint::Throttle,
+            Self::EsmeRinvbndsts => RetryHint::Rebind,
+            _ => match self.category() {
+                CommandStatusCategory::Transient => RetryHint::Backoff,
+                _ => RetryHint::Never,
+            },
+        }
+    }
+
+    /// Returns `true` if a request that failed with the status may be retried.
+    ///
+    /// See [`CommandStatus::retry_hint`].
+    #[inline]
+    pub const fn is_retryable(self) -> bool {
+        !matches!(self.retry_hint(), RetryHint::Never)
+    }
+
+    /// Returns the description of the status from the `SMPP` 5.0 specification.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rusmpp_core::CommandStatus;
+    ///
+    /// assert_eq!(CommandStatus::EsmeRinvdstadr.description(), "Invalid Destination Address.");
+    /// assert_eq!(CommandStatus::Other(0x00000400).description(), "Reserved or MC specific error.");
+    /// ```
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::EsmeRok => "No Error.",
+            Self::EsmeRinvmsglen => "Message Length is invalid.",
+            Self::EsmeRinvcmdlen => "Command Length is invalid.",
+            Self::EsmeRinvcmdid => "Invalid Command ID.",
+            Self::EsmeRinvbndsts => "Incorrect BIND Status for given command.",
+            Self::EsmeRalybnd => "ESME Already in Bound State.",
+            Self::EsmeRinvprtflg => "Invalid Priority Flag.",
+            Self::EsmeRinvregdlvflg => "Invalid Registered Delivery Flag.",
+            Self::EsmeRsyserr => "System Error.",
+            Self::EsmeRinvsrcadr => "Invalid Source Address.",
+            Self::EsmeRinvdstadr => "Invalid Destination Address.",
+            Self::EsmeRinvmsgid => "Message ID is invalid.",
+            Self::EsmeRbindfail => "Bind Failed.",
+            Self::EsmeRinvpaswd => "Invalid Password.",
+            Self::EsmeRinvsysid => "Invalid System ID.",
+            Self::EsmeRcancelfail => "Cancel SM Failed.",
+            Self::EsmeRreplacefail => "Replace SM Failed.",
+            Self::EsmeRmsgqful => "Message Queue Full.",
+            Self::EsmeRinvsertyp => "Invalid Service Type.",
+            Self::EsmeRinvnumdests => "Invalid number of destinations.",
+            Self::EsmeRinvdlname => "Invalid Distribution List name.",
+            Self::EsmeRinvdestflag => "Destination flag is invalid (submit_multi).",
+            Self::EsmeRinvsubrep => {
+                "Submit w/replace functionality has been requested where it is either unsupported or inappropriate for the particular MC. This can typically occur with submit_multi where the context of “replace if present” is often a best effort operation and MCs may not support the feature in submit_multi."
+            }
+            Self::EsmeRinvesmclass => "Invalid esm_class field data.",
+            Self::EsmeRcntsubdl => "Cannot Submit to Distribution List.",
+            Self::EsmeRsubmitfail => "submit_sm, data_sm or submit_multi failed.",
+            Self::EsmeRinvsrcton => "Invalid Source address TON.",
+            Self::EsmeRinvsrcnpi => "Invalid Source address NPI.",
+            Self::EsmeRinvdstton => "Invalid Destination address TON.",
+            Self::EsmeRinvdstnpi => "Invalid Destination address NPI.",
+            Self::EsmeRinvsystyp => "Invalid system_type field.",
+            Self::EsmeRinvrepflag => "Invalid replace_if_present flag.",
+            Self::EsmeRinvnummsgs => "Invalid number of messages.",
+            Self::EsmeRthrottled => "Throttling error (ESME has exceeded allowed message limits).",
+            Self::EsmeRinvsched => "Invalid Scheduled Delivery Time.",
+            Self::EsmeRinvexpiry => "Invalid message validity period (Expiry time).",
+            Self::EsmeRinvdftmsgid => {
+                "Predefined Message ID is Invalid or specified predefined message was not found."
+            }
+            Self::EsmeRxTAppn => "ESME Receiver Temporary App Error Code.",
+            Self::EsmeRxPAppn => "ESME Receiver Permanent App Error Code.",
+            Self::EsmeRxRAppn => "ESME Receiver Reject Message Error Code.",
+            Self::EsmeRqueryfail => "query_sm request failed.",
+            Self::EsmeRinvtlvstream => "Error in the optional part of the PDU Body.",
+            Self::EsmeRtlvnotallwd => "TLV not allowed.",
+            Self::EsmeRinvtlvlen => "Invalid Parameter Length.",
+            Self::EsmeRmissingtlv => "Expected TLV missing.",
+            Self::EsmeRinvtlvval => "Invalid TLV Value.",
+            Self::EsmeRdeliveryfailure => "Transaction Delivery Failure.",
+            Self::EsmeRunknownerr => "Unknown Error.",
+            Self::EsmeRsertypunauth => "ESME Not authorized to use specified service_type.",
+            Self::EsmeRprohibited => "ESME Prohibited from using specified operation.",
+            Self::EsmeRsertypunavail => "Specified service_type is unavailable.",
+            Self::EsmeRsertypdenied => "Specified service_type is denied.",
+            Self::EsmeRinvdcs => "Invalid Data Coding Scheme.",
+            Self::EsmeRinvsrcaddrsubunit => "Source Address Sub unit is Invalid.",
+            Self::EsmeRinvdstaddrsubunit => "Destination Address Sub unit is Invalid.",
+            Self::EsmeRinvbcastfreqint => "Broadcast Frequency Interval is invalid.",
+            Self::EsmeRinvbcastaliasName => "Broadcast Alias Name is invalid.",
+            Self::EsmeRinvbcastareafmt => "Broadcast Area Format is invalid.",
+            Self::EsmeRinvnumbcastAreas => "Number of Broadcast Areas is invalid.",
+            Self::EsmeRinvbcastcnttype => "Broadcast Content Type is invalid.",
+            Self::EsmeRinvbcastmsgclass => "Broadcast Message Class is invalid.",
+            Self::EsmeRbcastfail => "broadcast_sm operation failed.",
+            Self::EsmeRbcastqueryfail => "query_broadcast_sm operation failed.",
+            Self::EsmeRbcastcancelfail => "cancel_broadcast_sm operation failed.",
+            Self::EsmeRinvbcastRep => "Number of Repeated Broadcasts is invalid.",
+            Self::EsmeRinvbcastsrvgrp => "Broadcast Service Group is invalid.",
+            Self::EsmeRinvbcastchanind => "Broadcast Channel Indicator is invalid.",
+            Self::Other(_) => "Reserved or MC specific error.",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use strum::IntoEnumIterator;
+
     use super::*;
 
     #[test]
@@ -440,6 +647,39 @@ mod tests {
         assert_eq!(status, CommandStatus::Other(0x00000115));
     }
 
+    #[test]
+    fn category() {
+        for status in CommandStatus::iter() {
+            let category = status.category();
+            let retry_hint = status.retry_hint();
+
+            match category {
+                CommandStatusCategory::Ok => assert_eq!(status, CommandStatus::EsmeRok),
+                CommandStatusCategory::Permanent | CommandStatusCategory::Unknown => {
+                    assert_eq!(retry_hint, RetryHint::Never)
+                }
+                CommandStatusCategory::Transient => assert!(matches!(
+                    retry_hint,
+                    RetryHint::Backoff | RetryHint::Throttle
+                )),
+                CommandStatusCategory::Session => {
+                    assert!(matches!(retry_hint, RetryHint::Never | RetryHint::Rebind))
+                }
+            }
+
+            assert!(!status.description().is_empty());
+        }
+
+        assert_eq!(
+            CommandStatus::EsmeRbindfail.category(),
+            CommandStatusCategory::Session
+        );
+        assert_eq!(CommandStatus::EsmeRbindfail.retry_hint(), RetryHint::Never);
+        assert!(CommandStatus::EsmeRxTAppn.is_retryable());
+        assert!(!CommandStatus::EsmeRxPAppn.is_retryable());
+        assert!(!CommandStatus::EsmeRok.is_retryable());
+    }
+
     #[test]
     fn encode_decode() {
         crate::tests::owned::encode_decode_test_instances::<CommandStatus>();
@@ -72,6 +72,11 @@ impl DecodeError {
         Self::new(DecodeErrorKind::TooManyElements { max })
     }
 
+    #[inline]
+    pub const fn trailing_bytes(decoded: usize, length: usize) -> Self {
+        Self::new(DecodeErrorKind::TrailingBytes { decoded, length })
+    }
+
     #[inline]
     pub const fn udh_decode_error(error: UdhDecodeError) -> Self {
         Self::new(DecodeErrorKind::UdhDecodeError(error))
@@ -139,6 +144,11 @@ pub enum DecodeErrorKind {
         max: usize,
     },
     UdhDecodeError(UdhDecodeError),
+    /// The value was decoded without consuming all of its `length` bytes.
+    TrailingBytes {
+        decoded: usize,
+        length: usize,
+    },
 }
 
 /// An error that can occur when decoding a `COctetString`.
@@ -163,6 +173,15 @@ pub enum OctetStringDecodeError {
 #[non_exhaustive]
 pub enum UdhDecodeError {
     ConcatenatedShortMessageDecodeError(ConcatenatedShortMessageDecodeError),
+    /// The length of the information element is invalid.
+    InvalidInformationElementLength {
+        actual: u8,
+        expected: u8,
+    },
+    TooFewBytes {
+        actual: usize,
+        min: usize,
+    },
 }
 
 /// An error that can occur when decoding a `ConcatenatedShortMessage` UDH.
@@ -250,6 +269,9 @@ impl core::fmt::Display for DecodeErrorKind {
                 write!(f, "Too many elements. max: {max}")
             }
             DecodeErrorKind::UdhDecodeError(e) => write!(f, "UDH decode error: {e}"),
+            DecodeErrorKind::TrailingBytes { decoded, length } => {
+                write!(f, "Trailing bytes. decoded: {decoded}, length: {length}")
+            }
         }
     }
 }
@@ -289,6 +311,15 @@ impl core::fmt::Display for UdhDecodeError {
             UdhDecodeError::ConcatenatedShortMessageDecodeError(e) => {
                 write!(f, "ConcatenatedShortMessage decode error: {e}")
             }
+            UdhDecodeError::InvalidInformationElementLength { actual, expected } => {
+                write!(
+                    f,
+                    "Invalid information element length. actual: {actual}, expected: {expected}"
+                )
+            }
+            UdhDecodeError::TooFewBytes { actual, min } => {
+                write!(f, "Too few bytes. actual: {actual}, min: {min}")
+            }
         }
     }
 }
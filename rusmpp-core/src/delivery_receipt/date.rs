@@ -0,0 +1,141 @@
+/// A `submit date` or `done date` of a delivery receipt text.
+///
+/// Formatted as `YYMMDDhhmm`, or `YYMMDDhhmmss` if the second is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReceiptDate {
+    year: u8,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: Option<u8>,
+}
+
+impl ReceiptDate {
+    /// Creates a new [`ReceiptDate`].
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Self)` if `year` is in `0..=99`, `month` in `1..=12`, `day` in `1..=31`,
+    ///   `hour` in `0..=23` and `minute` in `0..=59`.
+    /// - `None` otherwise.
+    pub const fn new(year: u8, month: u8, day: u8, hour: u8, minute: u8) -> Option<Self> {
+        if year > 99 || month == 0 || month > 12 || day == 0 || day > 31 || hour > 23 || minute > 59
+        {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second: None,
+        })
+    }
+
+    /// Sets the second.
+    ///
+    /// Returns `None` if `second` is not in `0..=59`.
+    pub const fn with_second(mut self, second: u8) -> Option<Self> {
+        if second > 59 {
+            return None;
+        }
+
+        self.second = Some(second);
+
+        Some(self)
+    }
+
+    /// Returns the last two digits of the year.
+    pub const fn year(&self) -> u8 {
+        self.year
+    }
+
+    /// Returns the month, starting at `1`.
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at `1`.
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour.
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute.
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, if present.
+    pub const fn second(&self) -> Option<u8> {
+        self.second
+    }
+
+    /// Parses `YYMMDDhhmm` or `YYMMDDhhmmss`.
+    ///
+    /// Returns `None` if `value` is malformed or out of range.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().as_bytes();
+
+        if !matches!(value.len(), 10 | 12) || !value.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        let field = |index: usize| (value[index] - b'0') * 10 + (value[index + 1] - b'0');
+
+        let date = Self::new(field(0), field(2), field(4), field(6), field(8))?;
+
+        match value.len() {
+            12 => date.with_second(field(10)),
+            _ => Some(date),
+        }
+    }
+}
+
+impl core::fmt::Display for ReceiptDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:02}{:02}{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )?;
+
+        if let Some(second) = self.second {
+            write!(f, "{second:02}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let date = ReceiptDate::parse("2510181342").unwrap();
+
+        assert_eq!(date, ReceiptDate::new(25, 10, 18, 13, 42).unwrap());
+        assert_eq!(date.to_string(), "2510181342");
+
+        let date = ReceiptDate::parse("251018134259").unwrap();
+
+        assert_eq!(date.second(), Some(59));
+        assert_eq!(date.to_string(), "251018134259");
+
+        assert!(ReceiptDate::parse("25101813").is_none());
+        assert!(ReceiptDate::parse("2513181342").is_none());
+        assert!(ReceiptDate::parse("25101813a2").is_none());
+        assert!(ReceiptDate::parse("251018134260").is_none());
+    }
+}
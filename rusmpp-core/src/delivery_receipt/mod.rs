@@ -0,0 +1,40 @@
+//! MC delivery receipts.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::{
+//!     delivery_receipt::DeliveryReceipt,
+//!     pdus::owned::DeliverSm,
+//!     values::MessageState,
+//! };
+//!
+//! let receipt = DeliveryReceipt::builder()
+//!     .id("0123456789")
+//!     .submitted(1)
+//!     .delivered(1)
+//!     .message_state(MessageState::Delivered)
+//!     .err("000")
+//!     .text("Hello")
+//!     .build();
+//!
+//! let deliver_sm = DeliverSm::builder()
+//!     .delivery_receipt(&receipt)
+//!     .build();
+//!
+//! assert_eq!(
+//!     deliver_sm.short_message().bytes(),
+//!     b"id:0123456789 sub:001 dlvrd:001 stat:DELIVRD err:000 text:Hello"
+//! );
+//!
+//! let parsed = deliver_sm.delivery_receipt().unwrap();
+//!
+//! assert_eq!(parsed.id(), Some("0123456789"));
+//! assert_eq!(parsed.message_state(), Some(MessageState::Delivered));
+//! ```
+
+mod date;
+pub use date::ReceiptDate;
+
+mod receipt;
+pub use receipt::{DeliveryReceipt, DeliveryReceiptBuilder};
@@ -0,0 +1,468 @@
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+};
+
+use crate::{
+    tlvs::owned::{Tlv, TlvValue},
+    values::{EsmClass, MessageState, MessageType, NetworkErrorCode},
+};
+
+use super::ReceiptDate;
+
+/// An MC delivery receipt.
+///
+/// Carried by a [`DeliverSm`](crate::pdus::owned::DeliverSm) or a [`DataSm`](crate::pdus::owned::DataSm)
+/// whose [`EsmClass::message_type`] is [`MessageType::ShortMessageContainsMCDeliveryReceipt`].
+///
+/// The de-facto receipt text format is defined in Appendix B of the `SMPP` specification:
+///
+/// ```text
+/// id:IIIIIIIIII sub:SSS dlvrd:DDD submit date:YYMMDDhhmm done date:YYMMDDhhmm stat:DDDDDDD err:E text:...
+/// ```
+///
+/// The text is parsed tolerantly: keys are case-insensitive, fields may be missing or appear in any order
+/// and malformed values are ignored. The `receipted_message_id`, `message_state` and `network_error_code`
+/// TLVs take precedence over the text.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DeliveryReceipt {
+    id: Option<String>,
+    submitted: Option<u32>,
+    delivered: Option<u32>,
+    submit_date: Option<ReceiptDate>,
+    done_date: Option<ReceiptDate>,
+    stat: Option<String>,
+    message_state: Option<MessageState>,
+    err: Option<String>,
+    network_error_code: Option<NetworkErrorCode>,
+    text: Option<String>,
+}
+
+impl DeliveryReceipt {
+    /// Parses a delivery receipt text.
+    ///
+    /// Never fails. Fields that are missing or malformed are `None`.
+    pub fn parse(text: &str) -> Self {
+        let mut receipt = Self::default();
+
+        let bytes = text.as_bytes();
+
+        // The field being read and the start of its value.
+        let mut current: Option<(Field, usize)> = None;
+
+        let mut index = 0;
+
+        while index < bytes.len() {
+            let boundary = index == 0 || bytes[index - 1].is_ascii_whitespace();
+
+            let Some((field, value_start)) = boundary.then(|| Field::at(bytes, index)).flatten()
+            else {
+                index += 1;
+
+                continue;
+            };
+
+            if let Some((field, start)) = current {
+                receipt.set(field, &text[start..index]);
+            }
+
+            current = Some((field, value_start));
+
+            // The text is the last field and may contain anything.
+            if field == Field::Text {
+                break;
+            }
+
+            index = value_start;
+        }
+
+        if let Some((field, start)) = current {
+            receipt.set(field, &text[start..]);
+        }
+
+        receipt
+    }
+
+    /// Parses the delivery receipt carried by a PDU with the given `esm_class`, `body` and `tlvs`.
+    ///
+    /// Returns `None` if the `esm_class` does not mark an MC delivery receipt.
+    pub(crate) fn from_pdu(esm_class: EsmClass, body: &[u8], tlvs: &[Tlv]) -> Option<Self> {
+        if esm_class.message_type != MessageType::ShortMessageContainsMCDeliveryReceipt {
+            return None;
+        }
+
+        Some(Self::from_body(body, tlvs))
+    }
+
+    /// Parses the delivery receipt `body` and merges the `tlvs`, regardless of the `esm_class`.
+    pub(crate) fn from_body(body: &[u8], tlvs: &[Tlv]) -> Self {
+        let mut receipt = Self::parse(&String::from_utf8_lossy(body));
+
+        receipt.merge_tlvs(tlvs);
+
+        receipt
+    }
+
+    /// Overrides the fields with the values of the `receipted_message_id`, `message_state`
+    /// and `network_error_code` TLVs found in `tlvs`.
+    pub fn merge_tlvs(&mut self, tlvs: &[Tlv]) {
+        for tlv in tlvs {
+            match tlv.value() {
+                Some(TlvValue::ReceiptedMessageId(id)) => self.id = Some(id.as_str().to_owned()),
+                Some(TlvValue::MessageState(state)) => self.message_state = Some(*state),
+                Some(TlvValue::NetworkErrorCode(code)) => {
+                    self.network_error_code = Some(code.clone())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the id of the receipted message.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Returns the number of short messages originally submitted.
+    pub const fn submitted(&self) -> Option<u32> {
+        self.submitted
+    }
+
+    /// Returns the number of short messages delivered.
+    pub const fn delivered(&self) -> Option<u32> {
+        self.delivered
+    }
+
+    /// Returns the time and date at which the short message was submitted.
+    pub const fn submit_date(&self) -> Option<ReceiptDate> {
+        self.submit_date
+    }
+
+    /// Returns the time and date at which the short message reached its final state.
+    pub const fn done_date(&self) -> Option<ReceiptDate> {
+        self.done_date
+    }
+
+    /// Returns the raw `stat` field of the receipt text.
+    pub fn stat(&self) -> Option<&str> {
+        self.stat.as_deref()
+    }
+
+    /// Returns the final state of the message.
+    ///
+    /// Read from the `message_state` TLV, or mapped from the `stat` field. See [`MessageState::from_receipt_stat`].
+    pub const fn message_state(&self) -> Option<MessageState> {
+        self.message_state
+    }
+
+    /// Returns the raw `err` field of the receipt text.
+    pub fn err(&self) -> Option<&str> {
+        self.err.as_deref()
+    }
+
+    /// Returns the network error code read from the `network_error_code` TLV.
+    pub const fn network_error_code(&self) -> Option<&NetworkErrorCode> {
+        self.network_error_code.as_ref()
+    }
+
+    /// Returns the `text` field, usually the first characters of the original message.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn builder() -> DeliveryReceiptBuilder {
+        DeliveryReceiptBuilder::new()
+    }
+
+    fn set(&mut self, field: Field, value: &str) {
+        let value = value.trim();
+
+        if value.is_empty() {
+            return;
+        }
+
+        match field {
+            Field::Id => self.id = Some(value.to_owned()),
+            Field::Submitted => self.submitted = value.parse().ok(),
+            Field::Delivered => self.delivered = value.parse().ok(),
+            Field::SubmitDate => self.submit_date = ReceiptDate::parse(value),
+            Field::DoneDate => self.done_date = ReceiptDate::parse(value),
+            Field::Stat => {
+                self.message_state = MessageState::from_receipt_stat(value);
+                self.stat = Some(value.to_owned());
+            }
+            Field::Err => self.err = Some(value.to_owned()),
+            Field::Text => self.text = Some(value.to_owned()),
+        }
+    }
+}
+
+/// Formats the receipt text. Missing fields are omitted.
+impl core::fmt::Display for DeliveryReceipt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut separator = "";
+
+        let mut field = |f: &mut core::fmt::Formatter<'_>,
+                         key: &str,
+                         value: &dyn core::fmt::Display|
+         -> core::fmt::Result {
+            write!(f, "{separator}{key}:{value}")?;
+
+            separator = " ";
+
+            Ok(())
+        };
+
+        if let Some(id) = &self.id {
+            field(f, "id", id)?;
+        }
+
+        if let Some(submitted) = self.submitted {
+            field(f, "sub", &format_args!("{submitted:03}"))?;
+        }
+
+        if let Some(delivered) = self.delivered {
+            field(f, "dlvrd", &format_args!("{delivered:03}"))?;
+        }
+
+        if let Some(submit_date) = &self.submit_date {
+            field(f, "submit date", submit_date)?;
+        }
+
+        if let Some(done_date) = &self.done_date {
+            field(f, "done date", done_date)?;
+        }
+
+        let stat = self
+            .stat
+            .as_deref()
+            .or_else(|| self.message_state.and_then(|state| state.receipt_stat()));
+
+        if let Some(stat) = stat {
+            field(f, "stat", &stat)?;
+        }
+
+        if let Some(err) = &self.err {
+            field(f, "err", err)?;
+        }
+
+        if let Some(text) = &self.text {
+            field(f, "text", text)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DeliveryReceiptBuilder {
+    inner: DeliveryReceipt,
+}
+
+impl DeliveryReceiptBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.inner.id = Some(id.into());
+        self
+    }
+
+    pub fn submitted(mut self, submitted: u32) -> Self {
+        self.inner.submitted = Some(submitted);
+        self
+    }
+
+    pub fn delivered(mut self, delivered: u32) -> Self {
+        self.inner.delivered = Some(delivered);
+        self
+    }
+
+    pub fn submit_date(mut self, submit_date: ReceiptDate) -> Self {
+        self.inner.submit_date = Some(submit_date);
+        self
+    }
+
+    pub fn done_date(mut self, done_date: ReceiptDate) -> Self {
+        self.inner.done_date = Some(done_date);
+        self
+    }
+
+    /// Sets the message state and the matching `stat` abbreviation.
+    pub fn message_state(mut self, message_state: MessageState) -> Self {
+        self.inner.message_state = Some(message_state);
+        self.inner.stat = message_state.receipt_stat().map(ToString::to_string);
+        self
+    }
+
+    /// Overrides the raw `stat` field of the receipt text.
+    pub fn stat(mut self, stat: impl Into<String>) -> Self {
+        self.inner.stat = Some(stat.into());
+        self
+    }
+
+    pub fn err(mut self, err: impl Into<String>) -> Self {
+        self.inner.err = Some(err.into());
+        self
+    }
+
+    pub fn network_error_code(mut self, network_error_code: NetworkErrorCode) -> Self {
+        self.inner.network_error_code = Some(network_error_code);
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.inner.text = Some(text.into());
+        self
+    }
+
+    pub fn build(self) -> DeliveryReceipt {
+        self.inner
+    }
+}
+
+/// A field of the receipt text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Id,
+    Submitted,
+    Delivered,
+    SubmitDate,
+    DoneDate,
+    Stat,
+    Err,
+    Text,
+}
+
+impl Field {
+    const KEYS: [(&'static str, Field); 8] = [
+        ("id", Field::Id),
+        ("sub", Field::Submitted),
+        ("dlvrd", Field::Delivered),
+        ("submit date", Field::SubmitDate),
+        ("done date", Field::DoneDate),
+        ("stat", Field::Stat),
+        ("err", Field::Err),
+        ("text", Field::Text),
+    ];
+
+    /// Matches a `key:` at `index`.
+    ///
+    /// Keys are case-insensitive and the space in `submit date` and `done date` may also be an underscore.
+    ///
+    /// Returns the field and the start of its value.
+    fn at(bytes: &[u8], index: usize) -> Option<(Self, usize)> {
+        Self::KEYS.iter().find_map(|(key, field)| {
+            let end = index + key.len();
+
+            let key_matches =
+                bytes
+                    .get(index..end)?
+                    .iter()
+                    .zip(key.bytes())
+                    .all(|(&a, b)| match b {
+                        b' ' => a == b' ' || a == b'_',
+                        _ => a.eq_ignore_ascii_case(&b),
+                    });
+
+            (key_matches && bytes.get(end) == Some(&b':')).then_some((*field, end + 1))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::values::ErrorCodeNetworkType;
+
+    use super::*;
+
+    const TEXT: &str = "id:0123456789 sub:001 dlvrd:001 submit date:2510181342 done date:2510181343 stat:DELIVRD err:000 text:Hello world";
+
+    #[test]
+    fn parse() {
+        let receipt = DeliveryReceipt::parse(TEXT);
+
+        assert_eq!(receipt.id(), Some("0123456789"));
+        assert_eq!(receipt.submitted(), Some(1));
+        assert_eq!(receipt.delivered(), Some(1));
+        assert_eq!(receipt.submit_date(), ReceiptDate::new(25, 10, 18, 13, 42));
+        assert_eq!(receipt.done_date(), ReceiptDate::new(25, 10, 18, 13, 43));
+        assert_eq!(receipt.stat(), Some("DELIVRD"));
+        assert_eq!(receipt.message_state(), Some(MessageState::Delivered));
+        assert_eq!(receipt.err(), Some("000"));
+        assert_eq!(receipt.network_error_code(), None);
+        assert_eq!(receipt.text(), Some("Hello world"));
+    }
+
+    #[test]
+    fn parse_tolerant() {
+        let receipt = DeliveryReceipt::parse(
+            "ID:abc-def  Stat:undeliv  submit_date:garbage Done Date:251018134301 sub:x text: id:1 stat:DELIVRD",
+        );
+
+        assert_eq!(receipt.id(), Some("abc-def"));
+        assert_eq!(receipt.stat(), Some("undeliv"));
+        assert_eq!(receipt.message_state(), Some(MessageState::Undeliverable));
+        assert_eq!(receipt.submit_date(), None);
+        assert_eq!(
+            receipt.done_date(),
+            ReceiptDate::new(25, 10, 18, 13, 43).and_then(|date| date.with_second(1))
+        );
+        assert_eq!(receipt.submitted(), None);
+        assert_eq!(receipt.delivered(), None);
+        // Keys after `text:` are part of the text.
+        assert_eq!(receipt.text(), Some("id:1 stat:DELIVRD"));
+
+        // Keys inside values are not matched.
+        let receipt = DeliveryReceipt::parse("id:xstat:DELIVRD err:1");
+
+        assert_eq!(receipt.id(), Some("xstat:DELIVRD"));
+        assert_eq!(receipt.stat(), None);
+
+        assert_eq!(DeliveryReceipt::parse(""), DeliveryReceipt::default());
+        assert_eq!(
+            DeliveryReceipt::parse("not a receipt"),
+            DeliveryReceipt::default()
+        );
+    }
+
+    #[test]
+    fn parse_unknown_stat() {
+        let receipt = DeliveryReceipt::parse("id:1 stat:FOO");
+
+        assert_eq!(receipt.stat(), Some("FOO"));
+        assert_eq!(receipt.message_state(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(DeliveryReceipt::parse(TEXT).to_string(), TEXT);
+
+        let receipt = DeliveryReceipt::builder()
+            .id("42")
+            .message_state(MessageState::Expired)
+            .network_error_code(NetworkErrorCode::new(ErrorCodeNetworkType::Gsm, 1))
+            .build();
+
+        assert_eq!(receipt.to_string(), "id:42 stat:EXPIRED");
+
+        assert_eq!(DeliveryReceipt::default().to_string(), "");
+    }
+
+    #[test]
+    fn builder_round_trip() {
+        let receipt = DeliveryReceipt::builder()
+            .id("abc")
+            .submitted(2)
+            .delivered(1)
+            .submit_date(ReceiptDate::new(25, 1, 2, 3, 4).unwrap())
+            .done_date(ReceiptDate::new(25, 1, 2, 3, 5).unwrap())
+            .message_state(MessageState::Undeliverable)
+            .err("012")
+            .text("Hi")
+            .build();
+
+        assert_eq!(DeliveryReceipt::parse(&receipt.to_string()), receipt);
+    }
+}
@@ -0,0 +1,686 @@
+//! Checked `serde` deserialization of owned `SMPP` types.
+//!
+//! With the `serde-deserialize` feature, deserialized values are subject to the same invariants as decoded values:
+//!
+//! - Octet strings are created with their checked constructors, e.g. [`COctetString::new`](crate::types::owned::COctetString::new).
+//! - Length and count fields must match the fields they describe, e.g. `sm_length` and `short_message`.
+//! - The tag and value_length of a [`Tlv`] must match its value.
+//! - The TLVs of a PDU must be allowed in that PDU, e.g. [`MessageSubmissionRequestTlvValue`] for [`SubmitSm`].
+//! - The [`CommandId`] of a [`Command`] must match its [`Pdu`].
+//! - An [`SmppTime`] must be valid, see [`SmppTime::validate`].
+//! - A [`DataCodingScheme`] must be the one its octet decodes to, e.g. `0x00` is not [`DataCodingScheme::Reserved`].
+//!
+//! Violations are reported as a descriptive [`DeserializeError`] through the deserializer's error type.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::pdus::owned::SubmitSm;
+//!
+//! // `sm_length` does not match the length of `short_message`.
+//! let json = r#"{
+//!     "service_type": { "value": [0] },
+//!     "source_addr_ton": "Unknown",
+//!     "source_addr_npi": "Unknown",
+//!     "source_addr": [0],
+//!     "dest_addr_ton": "Unknown",
+//!     "dest_addr_npi": "Unknown",
+//!     "destination_addr": [0],
+//!     "esm_class": {
+//!         "messaging_mode": "Default",
+//!         "message_type": "Default",
+//!         "ansi41_specific": "ShortMessageContainsDeliveryAcknowledgement",
+//!         "gsm_features": "NotSelected"
+//!     },
+//!     "protocol_id": 0,
+//!     "priority_flag": { "value": 0 },
+//!     "schedule_delivery_time": [0],
+//!     "validity_period": [0],
+//!     "registered_delivery": {
+//!         "mc_delivery_receipt": "NoMcDeliveryReceiptRequested",
+//!         "sme_originated_acknowledgement": "NoReceiptSmeAcknowledgementRequested",
+//!         "intermediate_notification": "NoIntermediaryNotificationRequested",
+//!         "other": 0
+//!     },
+//!     "replace_if_present_flag": "DoNotReplace",
+//!     "data_coding": "McSpecific",
+//!     "sm_default_msg_id": 0,
+//!     "sm_length": 5,
+//!     "short_message": [104, 105],
+//!     "tlvs": []
+//! }"#;
+//!
+//! let error = serde_json::from_str::<SubmitSm>(json).unwrap_err();
+//!
+//! assert!(error.to_string().starts_with("sm_length 5 does not match the short_message length 2"));
+//!
+//! // With a matching `sm_length`, the value is deserialized.
+//! let submit_sm = serde_json::from_str::<SubmitSm>(&json.replace(r#""sm_length": 5"#, r#""sm_length": 2"#)).unwrap();
+//!
+//! assert_eq!(submit_sm.short_message().bytes(), b"hi");
+//! ```
+
+use crate::{
+    CommandId,
+    command::owned::Command,
+    encode::Length,
+    pdus::owned::*,
+    tlvs::{
+        TlvTag,
+        owned::{
+            BroadcastRequestTlvValue, BroadcastResponseTlvValue, CancelBroadcastTlvValue,
+            MessageDeliveryRequestTlvValue, MessageDeliveryResponseTlvValue,
+            MessageSubmissionRequestTlvValue, MessageSubmissionResponseTlvValue,
+            QueryBroadcastResponseTlvValue, Tlv,
+        },
+    },
+    values::{
+        DataCoding, DataCodingScheme, DestFlag, SmppDateTime, SmppTime, SmppTimeError,
+        owned::{DistributionListName, SmeAddress},
+    },
+};
+
+/// An error that can occur when deserializing an owned `SMPP` type with the `serde-deserialize` feature.
+///
+/// See the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeserializeError {
+    /// The `sm_length` does not match the length of the `short_message`.
+    SmLength {
+        sm_length: u8,
+        short_message_length: usize,
+    },
+    /// The `number_of_dests` does not match the number of destination addresses.
+    NumberOfDests {
+        number_of_dests: u8,
+        dest_addresses: usize,
+    },
+    /// The `no_unsuccess` does not match the number of unsuccessful SMEs.
+    NoUnsuccess {
+        no_unsuccess: u8,
+        unsuccess_smes: usize,
+    },
+    /// The tag of a [`Tlv`] does not match the tag of its value.
+    TlvTag { tag: TlvTag, value_tag: TlvTag },
+    /// The value_length of a [`Tlv`] does not match the length of its value.
+    TlvValueLength {
+        tag: TlvTag,
+        value_length: u16,
+        length: usize,
+    },
+    /// The [`Tlv`] is not allowed in the PDU.
+    TlvNotAllowed { tag: TlvTag },
+    /// The [`DestFlag`] does not match the destination address format.
+    DestFlag {
+        expected: DestFlag,
+        actual: DestFlag,
+    },
+    /// The [`CommandId`] does not match the [`Pdu`].
+    CommandId { id: CommandId, pdu_id: CommandId },
+    /// The [`SmppTime`] or [`SmppDateTime`] is invalid.
+    SmppTime(SmppTimeError),
+    /// The [`DataCodingScheme`] is not the one its octet decodes to.
+    DataCodingScheme {
+        value: DataCodingScheme,
+        decoded: DataCodingScheme,
+    },
+}
+
+impl core::fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeserializeError::SmLength {
+                sm_length,
+                short_message_length,
+            } => write!(
+                f,
+                "sm_length {sm_length} does not match the short_message length {short_message_length}"
+            ),
+            DeserializeError::NumberOfDests {
+                number_of_dests,
+                dest_addresses,
+            } => write!(
+                f,
+                "number_of_dests {number_of_dests} does not match the number of dest_address {dest_addresses}"
+            ),
+            DeserializeError::NoUnsuccess {
+                no_unsuccess,
+                unsuccess_smes,
+            } => write!(
+                f,
+                "no_unsuccess {no_unsuccess} does not match the number of unsuccess_sme {unsuccess_smes}"
+            ),
+            DeserializeError::TlvTag { tag, value_tag } => {
+                write!(
+                    f,
+                    "TLV tag {tag:?} does not match the value tag {value_tag:?}"
+                )
+            }
+            DeserializeError::TlvValueLength {
+                tag,
+                value_length,
+                length,
+            } => write!(
+                f,
+                "TLV {tag:?} value_length {value_length} does not match the value length {length}"
+            ),
+            DeserializeError::TlvNotAllowed { tag } => {
+                write!(f, "TLV {tag:?} is not allowed in this PDU")
+            }
+            DeserializeError::DestFlag { expected, actual } => {
+                write!(f, "dest_flag {actual:?} does not match {expected:?}")
+            }
+            DeserializeError::CommandId { id, pdu_id } => {
+                write!(f, "command_id {id:?} does not match the PDU {pdu_id:?}")
+            }
+            DeserializeError::SmppTime(error) => write!(f, "Invalid SMPP time: {error}"),
+            DeserializeError::DataCodingScheme { value, decoded } => write!(
+                f,
+                "data coding scheme {value:?} does not match its decoded octet {decoded:?}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DeserializeError {}
+
+/// Checks the invariants of a deserialized value.
+///
+/// Implemented for types with `#[rusmpp(deserialize = checked)]`.
+pub(crate) trait Check {
+    fn check(&self) -> Result<(), DeserializeError>;
+}
+
+fn check_sm_length(sm_length: u8, short_message: &[u8]) -> Result<(), DeserializeError> {
+    match sm_length as usize == short_message.len() {
+        true => Ok(()),
+        false => Err(DeserializeError::SmLength {
+            sm_length,
+            short_message_length: short_message.len(),
+        }),
+    }
+}
+
+fn check_tlvs<'a>(
+    tlvs: impl IntoIterator<Item = &'a Tlv>,
+    allows: impl Fn(TlvTag) -> bool,
+) -> Result<(), DeserializeError> {
+    match tlvs.into_iter().find(|tlv| !allows(tlv.tag())) {
+        Some(tlv) => Err(DeserializeError::TlvNotAllowed { tag: tlv.tag() }),
+        None => Ok(()),
+    }
+}
+
+fn check_tlv(tlv: Option<&Tlv>, tag: TlvTag) -> Result<(), DeserializeError> {
+    check_tlvs(tlv, |tlv_tag| tlv_tag == tag)
+}
+
+fn check_dest_flag(expected: DestFlag, actual: DestFlag) -> Result<(), DeserializeError> {
+    match expected == actual {
+        true => Ok(()),
+        false => Err(DeserializeError::DestFlag { expected, actual }),
+    }
+}
+
+impl Check for Tlv {
+    fn check(&self) -> Result<(), DeserializeError> {
+        let tag = self.tag();
+        let value_length = self.value_length();
+
+        let length = match self.value() {
+            Some(value) => {
+                if value.tag() != tag {
+                    return Err(DeserializeError::TlvTag {
+                        tag,
+                        value_tag: value.tag(),
+                    });
+                }
+
+                value.length()
+            }
+            None => 0,
+        };
+
+        match value_length as usize == length {
+            true => Ok(()),
+            false => Err(DeserializeError::TlvValueLength {
+                tag,
+                value_length,
+                length,
+            }),
+        }
+    }
+}
+
+impl Check for SmppDateTime {
+    fn check(&self) -> Result<(), DeserializeError> {
+        SmppDateTime::new(
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.tenths(),
+        )
+        .map(|_| ())
+        .map_err(DeserializeError::SmppTime)
+    }
+}
+
+impl Check for SmppTime {
+    fn check(&self) -> Result<(), DeserializeError> {
+        self.validate().map_err(DeserializeError::SmppTime)
+    }
+}
+
+impl Check for DataCodingScheme {
+    fn check(&self) -> Result<(), DeserializeError> {
+        let decoded = DataCodingScheme::from(DataCoding::from(*self));
+
+        match decoded == *self {
+            true => Ok(()),
+            false => Err(DeserializeError::DataCodingScheme {
+                value: *self,
+                decoded,
+            }),
+        }
+    }
+}
+
+impl Check for SmeAddress {
+    fn check(&self) -> Result<(), DeserializeError> {
+        check_dest_flag(DestFlag::SmeAddress, self.dest_flag())
+    }
+}
+
+impl Check for DistributionListName {
+    fn check(&self) -> Result<(), DeserializeError> {
+        check_dest_flag(DestFlag::DistributionListName, self.dest_flag())
+    }
+}
+
+macro_rules! impl_check_bind_resp {
+    ($($name:ident),*) => {
+        $(
+            impl Check for $name {
+                fn check(&self) -> Result<(), DeserializeError> {
+                    check_tlv(self.sc_interface_version_tlv(), TlvTag::ScInterfaceVersion)
+                }
+            }
+        )*
+    };
+}
+
+impl_check_bind_resp!(BindTransmitterResp, BindReceiverResp, BindTransceiverResp);
+
+macro_rules! impl_check_tlvs {
+    ($($name:ident => $allows:expr),* $(,)?) => {
+        $(
+            impl Check for $name {
+                fn check(&self) -> Result<(), DeserializeError> {
+                    check_tlvs(self.tlvs(), $allows)
+                }
+            }
+        )*
+    };
+}
+
+impl_check_tlvs!(
+    SubmitSmResp => MessageSubmissionResponseTlvValue::allows,
+    DeliverSmResp => MessageDeliveryResponseTlvValue::allows,
+    DataSmResp => MessageDeliveryResponseTlvValue::allows,
+    // data_sm is used in both directions.
+    DataSm => |tag| {
+        MessageSubmissionRequestTlvValue::allows(tag) || MessageDeliveryRequestTlvValue::allows(tag)
+    },
+    BroadcastSm => BroadcastRequestTlvValue::allows,
+    BroadcastSmResp => BroadcastResponseTlvValue::allows,
+    QueryBroadcastSmResp => QueryBroadcastResponseTlvValue::allows,
+    CancelBroadcastSm => CancelBroadcastTlvValue::allows,
+);
+
+impl Check for AlertNotification {
+    fn check(&self) -> Result<(), DeserializeError> {
+        check_tlv(
+            self.ms_availability_status_tlv(),
+            TlvTag::MsAvailabilityStatus,
+        )
+    }
+}
+
+impl Check for QueryBroadcastSm {
+    fn check(&self) -> Result<(), DeserializeError> {
+        check_tlv(
+            self.user_message_reference_tlv(),
+            TlvTag::UserMessageReference,
+        )
+    }
+}
+
+impl Check for SubmitSm {
+    fn check(&self) -> Result<(), DeserializeError> {
+        check_sm_length(self.sm_length(), self.short_message().bytes())?;
+        check_tlvs(self.tlvs(), MessageSubmissionRequestTlvValue::allows)
+    }
+}
+
+impl Check for DeliverSm {
+    fn check(&self) -> Result<(), DeserializeError> {
+        check_sm_length(self.sm_length(), self.short_message().bytes())?;
+        check_tlvs(self.tlvs(), MessageDeliveryRequestTlvValue::allows)
+    }
+}
+
+impl Check for ReplaceSm {
+    fn check(&self) -> Result<(), DeserializeError> {
+        check_sm_length(self.sm_length(), self.short_message().bytes())?;
+        check_tlv(self.message_payload_tlv(), TlvTag::MessagePayload)
+    }
+}
+
+impl Check for SubmitMulti {
+    fn check(&self) -> Result<(), DeserializeError> {
+        if self.number_of_dests() as usize != self.dest_address().len() {
+            return Err(DeserializeError::NumberOfDests {
+                number_of_dests: self.number_of_dests(),
+                dest_addresses: self.dest_address().len(),
+            });
+        }
+
+        check_sm_length(self.sm_length(), self.short_message().bytes())?;
+        check_tlvs(self.tlvs(), MessageSubmissionRequestTlvValue::allows)
+    }
+}
+
+impl Check for SubmitMultiResp {
+    fn check(&self) -> Result<(), DeserializeError> {
+        if self.no_unsuccess() as usize != self.unsuccess_sme().len() {
+            return Err(DeserializeError::NoUnsuccess {
+                no_unsuccess: self.no_unsuccess(),
+                unsuccess_smes: self.unsuccess_sme().len(),
+            });
+        }
+
+        check_tlvs(self.tlvs(), MessageSubmissionResponseTlvValue::allows)
+    }
+}
+
+impl Check for Command {
+    fn check(&self) -> Result<(), DeserializeError> {
+        let Some(pdu) = self.pdu() else {
+            return Ok(());
+        };
+
+        let pdu_id = pdu.command_id();
+
+        // `Pdu::Other` is only decoded for unknown command ids.
+        let other_known =
+            matches!(pdu, Pdu::Other { .. }) && !matches!(pdu_id, CommandId::Other(_));
+
+        match self.id() == pdu_id && !other_known {
+            true => Ok(()),
+            false => Err(DeserializeError::CommandId {
+                id: self.id(),
+                pdu_id,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        CommandStatus,
+        tlvs::owned::{MessageDeliveryResponseTlvValue, TlvValue},
+        types::owned::{AnyOctetString, COctetString, OctetString},
+        values::{owned::*, *},
+    };
+
+    use super::*;
+
+    /// Serializes `value`, applies `f` to the JSON and deserializes it back.
+    fn modified<T>(
+        value: &T,
+        f: impl FnOnce(&mut serde_json::Value),
+    ) -> Result<T, serde_json::Error>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let mut json = serde_json::to_value(value).unwrap();
+
+        f(&mut json);
+
+        serde_json::from_value(json)
+    }
+
+    fn submit_sm() -> SubmitSm {
+        SubmitSm::builder()
+            .source_addr(COctetString::new(b"12345\0").unwrap())
+            .destination_addr(COctetString::new(b"67890\0").unwrap())
+            .short_message(OctetString::new(b"Hello").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(1),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn round_trip() {
+        let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm());
+
+        assert_eq!(modified(&command, |_| {}).unwrap(), command);
+    }
+
+    #[test]
+    fn c_octet_string_bounds() {
+        let error = modified(&submit_sm(), |json| {
+            json["source_addr"] = serde_json::json!(alloc::vec![0x31; 25]);
+        })
+        .unwrap_err();
+
+        assert!(error.to_string().starts_with("Too many bytes"));
+
+        let error = modified(&submit_sm(), |json| {
+            json["source_addr"] = serde_json::json!([0x31, 0x32]);
+        })
+        .unwrap_err();
+
+        assert!(error.to_string().starts_with("Not null terminated"));
+    }
+
+    #[test]
+    fn sm_length() {
+        let error = modified(&submit_sm(), |json| {
+            json["sm_length"] = serde_json::json!(3);
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("sm_length 3 does not match the short_message length 5")
+        );
+    }
+
+    #[test]
+    fn tlv_value_length() {
+        let error = modified(&submit_sm(), |json| {
+            json["tlvs"][0]["value_length"] = serde_json::json!(4);
+        })
+        .unwrap_err();
+
+        assert!(error.to_string().starts_with(
+            "TLV UserMessageReference value_length 4 does not match the value length 2"
+        ));
+    }
+
+    #[test]
+    fn tlv_tag() {
+        let error = modified(&submit_sm(), |json| {
+            json["tlvs"][0]["tag"] = serde_json::json!("SarMsgRefNum");
+        })
+        .unwrap_err();
+
+        assert!(
+            error.to_string().starts_with(
+                "TLV tag SarMsgRefNum does not match the value tag UserMessageReference"
+            )
+        );
+    }
+
+    #[test]
+    fn tlv_not_allowed() {
+        let tlv = Tlv::new(TlvValue::ReceiptedMessageId(
+            COctetString::new(b"id\0").unwrap(),
+        ));
+
+        let error = modified(&submit_sm(), |json| {
+            json["tlvs"] = serde_json::json!([tlv]);
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("TLV ReceiptedMessageId is not allowed in this PDU")
+        );
+
+        // Allowed in deliver_sm_resp.
+        let deliver_sm_resp = DeliverSmResp::new(
+            COctetString::new(b"id\0").unwrap(),
+            alloc::vec![MessageDeliveryResponseTlvValue::AdditionalStatusInfoText(
+                COctetString::new(b"info\0").unwrap()
+            )],
+        );
+
+        assert_eq!(modified(&deliver_sm_resp, |_| {}).unwrap(), deliver_sm_resp);
+
+        let tlv = Tlv::new(TlvValue::MessagePayload(MessagePayload::new(
+            AnyOctetString::new(b"Hello"),
+        )));
+
+        let error = modified(&deliver_sm_resp, |json| {
+            json["tlvs"] = serde_json::json!([tlv]);
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("TLV MessagePayload is not allowed in this PDU")
+        );
+    }
+
+    #[test]
+    fn number_of_dests() {
+        let submit_multi = SubmitMulti::builder()
+            .push_dest_address(DestAddress::DistributionListName(
+                DistributionListName::new(COctetString::new(b"list\0").unwrap()),
+            ))
+            .build();
+
+        assert_eq!(modified(&submit_multi, |_| {}).unwrap(), submit_multi);
+
+        let error = modified(&submit_multi, |json| {
+            json["number_of_dests"] = serde_json::json!(2);
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("number_of_dests 2 does not match the number of dest_address 1")
+        );
+
+        let error = modified(&submit_multi, |json| {
+            json["dest_address"][0]["DistributionListName"]["dest_flag"] =
+                serde_json::json!("SmeAddress");
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("dest_flag SmeAddress does not match DistributionListName")
+        );
+    }
+
+    #[test]
+    fn command_id() {
+        let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm());
+
+        let error = modified(&command, |json| {
+            json["id"] = serde_json::json!("DeliverSm");
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("command_id DeliverSm does not match the PDU SubmitSm")
+        );
+    }
+
+    #[test]
+    fn smpp_time() {
+        let time =
+            SmppTime::absolute(SmppDateTime::new(25, 10, 18, 13, 42, 5, 0).unwrap(), -14).unwrap();
+
+        assert_eq!(modified(&time, |_| {}).unwrap(), time);
+
+        let error = modified(&time, |json| {
+            json["Absolute"]["datetime"]["month"] = serde_json::json!(13);
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("Invalid SMPP time: Field month is out of range: 13")
+        );
+
+        let error = modified(&time, |json| {
+            json["Absolute"]["utc_offset_quarter_hours"] = serde_json::json!(49);
+        })
+        .unwrap_err();
+
+        assert!(
+            error.to_string().starts_with(
+                "Invalid SMPP time: Field utc_offset_quarter_hours is out of range: 49"
+            )
+        );
+
+        let time = SmppTime::relative(0, 0, 1, 2, 0, 0).unwrap();
+
+        let error = modified(&time, |json| {
+            json["Relative"]["days"] = serde_json::json!(100);
+        })
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("Invalid SMPP time: Field days is out of range: 100")
+        );
+    }
+
+    #[test]
+    fn data_coding_scheme() {
+        let scheme = DataCodingScheme::from(DataCoding::from(0x18));
+
+        assert_eq!(modified(&scheme, |_| {}).unwrap(), scheme);
+
+        let error = serde_json::from_value::<DataCodingScheme>(serde_json::json!({
+            "Reserved": 0
+        }))
+        .unwrap_err();
+
+        assert!(error.to_string().starts_with(
+            "data coding scheme Reserved(0) does not match its decoded octet Smpp(McSpecific)"
+        ));
+    }
+}
@@ -0,0 +1,674 @@
+//! Annotated dissection of `SMPP` commands.
+//!
+//! [`dissect`] walks the raw bytes of a command and annotates every field of the header,
+//! the mandatory fields of the PDU and each TLV with its [`SmppField`], byte range, raw bytes and decoded value.
+//! Decoding stops at the first invalid field, which is reported in the [`Dissection`] along with all the fields decoded before it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::{dissect::dissect, fields::SmppField};
+//!
+//! let bytes: [u8; 46] = [
+//!     0x00, 0x00, 0x00, 0x2E, // command_length
+//!     0x00, 0x00, 0x00, 0x02, // id (bind_transmitter)
+//!     0x00, 0x00, 0x00, 0x00, // status
+//!     0x00, 0x00, 0x00, 0x01, // sequence_number
+//!     // system_id: "SMPP3TEST\0"
+//!     0x53, 0x4D, 0x50, 0x50, 0x33, 0x54, 0x45, 0x53, 0x54, 0x00,
+//!     // password: "secret08" not null terminated!
+//!     0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x30, 0x38,
+//!     // system_type: "SUBMIT1"
+//!     0x53, 0x55, 0x42, 0x4D, 0x49, 0x54, 0x31, 0x00,
+//!     0x50, 0x01, 0x01, 0x00,
+//! ];
+//!
+//! let dissection = dissect(&bytes);
+//!
+//! assert!(dissection.error().is_some());
+//!
+//! let fields: Vec<_> = dissection.nodes().iter().map(|node| node.field()).collect();
+//!
+//! assert_eq!(
+//!     fields,
+//!     [
+//!         SmppField::command_length,
+//!         SmppField::id,
+//!         SmppField::status,
+//!         SmppField::sequence_number,
+//!         SmppField::system_id,
+//!         SmppField::password,
+//!     ]
+//! );
+//!
+//! let password = &dissection.nodes()[5];
+//!
+//! assert_eq!(password.range(), 26..46);
+//! assert!(password.value().is_err());
+//!
+//! // Renders as an aligned table:
+//! //
+//! // Offset  Field            Bytes                                               Value
+//! //      0  command_length   00 00 00 2E                                         46
+//! //      4  id               00 00 00 02                                         BindTransmitter
+//! // ...
+//! println!("{dissection}");
+//!
+//! // Or with the bytes as ASCII characters.
+//! println!("{dissection:#}");
+//! ```
+
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt::Debug, ops::Range};
+
+use crate::{
+    CommandId,
+    decode::{DecodeError, DecodeErrorKind, owned::Decode},
+    fields::SmppField,
+    pdus::owned::*,
+    tlvs::owned::Tlv,
+    types::owned::AnyOctetString,
+};
+
+/// The maximum number of bytes rendered per node.
+const MAX_RENDERED_BYTES: usize = 16;
+
+/// Dissects the raw bytes of a command, starting with the `command_length`.
+///
+/// Never fails: if decoding fails, the [`Dissection`] contains the fields decoded so far and the [`DecodeError`].
+pub fn dissect(bytes: &[u8]) -> Dissection<'_> {
+    let mut dissector = Dissector::new(bytes, 0, bytes.len());
+
+    let error = dissect_command(bytes, &mut dissector).err();
+
+    Dissection {
+        bytes,
+        nodes: dissector.nodes,
+        error,
+    }
+}
+
+/// The result of [`dissect`].
+#[derive(Debug)]
+pub struct Dissection<'a> {
+    bytes: &'a [u8],
+    nodes: Vec<Node<'a>>,
+    error: Option<DecodeError>,
+}
+
+impl<'a> Dissection<'a> {
+    /// Returns the dissected bytes.
+    #[inline]
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the top-level nodes: the header fields, the mandatory fields of the PDU and the TLVs.
+    #[inline]
+    pub fn nodes(&self) -> &[Node<'a>] {
+        &self.nodes
+    }
+
+    /// Returns the error that stopped the dissection, if any.
+    #[inline]
+    pub const fn error(&self) -> Option<&DecodeError> {
+        self.error.as_ref()
+    }
+
+    /// Returns `true` if the whole command was decoded.
+    #[inline]
+    pub const fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// A dissected field.
+#[derive(Debug)]
+pub struct Node<'a> {
+    field: SmppField,
+    range: Range<usize>,
+    bytes: &'a [u8],
+    value: Result<String, DecodeErrorKind>,
+    children: Vec<Node<'a>>,
+}
+
+impl<'a> Node<'a> {
+    #[inline]
+    pub const fn field(&self) -> SmppField {
+        self.field
+    }
+
+    /// Returns the byte range of the field in the dissected bytes.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the raw bytes of the field.
+    #[inline]
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the decoded value formatted with [`Debug`], or the reason the field could not be decoded.
+    ///
+    /// The range of an invalid field extends to the end of the undecoded bytes.
+    #[inline]
+    pub fn value(&self) -> Result<&str, DecodeErrorKind> {
+        self.value.as_deref().map_err(|kind| *kind)
+    }
+
+    /// Returns the nested fields, e.g. the `tag`, `value_length` and `value` of a TLV.
+    #[inline]
+    pub fn children(&self) -> &[Node<'a>] {
+        &self.children
+    }
+}
+
+/// Dissects the fields of a type with the same logic as its owned decode implementation.
+///
+/// Implemented by `#[derive(Rusmpp)]` for structs.
+pub(crate) trait Dissect {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut Dissector<'_>,
+    ) -> Result<usize, DecodeError>;
+}
+
+/// Collects the [`Node`]s of a type starting at `offset` in `bytes`.
+#[derive(Debug)]
+pub(crate) struct Dissector<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    end: usize,
+    nodes: Vec<Node<'a>>,
+}
+
+impl<'a> Dissector<'a> {
+    fn new(bytes: &'a [u8], offset: usize, end: usize) -> Self {
+        Self {
+            bytes,
+            offset,
+            end,
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Adds a decoded field spanning `start..end` relative to the offset of the dissector.
+    pub(crate) fn field<T: Debug>(
+        &mut self,
+        field: SmppField,
+        start: usize,
+        end: usize,
+        value: &T,
+    ) {
+        let range = self.offset + start..self.offset + end;
+
+        if field == SmppField::tlvs {
+            self.tlvs(range);
+
+            return;
+        }
+
+        self.nodes.push(Node {
+            field,
+            bytes: &self.bytes[range.clone()],
+            range,
+            value: Ok(format!("{value:?}")),
+            children: Vec::new(),
+        });
+    }
+
+    /// Adds a field that failed to decode at `start` relative to the offset of the dissector and returns the `error`.
+    pub(crate) fn error(
+        &mut self,
+        field: SmppField,
+        start: usize,
+        error: DecodeError,
+    ) -> DecodeError {
+        let range = (self.offset + start).min(self.end)..self.end;
+
+        if field == SmppField::tlvs {
+            self.tlvs(range);
+
+            return error;
+        }
+
+        self.nodes.push(Node {
+            field,
+            bytes: &self.bytes[range.clone()],
+            range,
+            value: Err(error.kind()),
+            children: Vec::new(),
+        });
+
+        error
+    }
+
+    /// Adds a node for each TLV in `range`.
+    fn tlvs(&mut self, range: Range<usize>) {
+        let mut start = range.start;
+
+        while start < range.end {
+            let mut dissector = Dissector::new(self.bytes, start, range.end);
+
+            let result = Tlv::dissect(
+                &self.bytes[start..range.end],
+                range.end - start,
+                &mut dissector,
+            );
+
+            let end = match result {
+                Ok(size) => start + size,
+                Err(_) => range.end,
+            };
+
+            // The value of a TLV node is its tag.
+            let value = match result {
+                Ok(_) => Ok(dissector
+                    .nodes
+                    .first()
+                    .and_then(|tag| tag.value.clone().ok())
+                    .unwrap_or_default()),
+                Err(error) => Err(error.kind()),
+            };
+
+            self.nodes.push(Node {
+                field: SmppField::tlvs,
+                range: start..end,
+                bytes: &self.bytes[start..end],
+                value,
+                children: dissector.nodes,
+            });
+
+            if end == start || end == range.end {
+                break;
+            }
+
+            start = end;
+        }
+    }
+
+    /// Decodes a header field at `start` relative to the offset of the dissector.
+    fn header<T: Decode + Debug>(
+        &mut self,
+        field: SmppField,
+        src: &[u8],
+        start: usize,
+    ) -> Result<T, DecodeError> {
+        match T::decode(src.get(start..).unwrap_or_default()) {
+            Ok((value, size)) => {
+                self.field(field, start, start + size, &value);
+
+                Ok(value)
+            }
+            Err(error) => Err(self.error(field, start, error)),
+        }
+    }
+}
+
+fn dissect_command<'a>(bytes: &'a [u8], dissector: &mut Dissector<'a>) -> Result<(), DecodeError> {
+    let command_length = dissector.header::<u32>(SmppField::command_length, bytes, 0)? as usize;
+
+    // The body ends at the command length, unless the bytes are truncated.
+    dissector.end = command_length.clamp(4, bytes.len());
+
+    let src = &bytes[..dissector.end];
+
+    let id = dissector.header::<CommandId>(SmppField::id, src, 4)?;
+    let _ = dissector.header::<crate::CommandStatus>(SmppField::status, src, 8)?;
+    let _ = dissector.header::<u32>(SmppField::sequence_number, src, 12)?;
+
+    let mut body = Dissector::new(bytes, 16, dissector.end);
+
+    let result = dissect_pdu(id, &src[16..], src.len() - 16, &mut body);
+
+    dissector.nodes.append(&mut body.nodes);
+
+    result.map(|_| ())
+}
+
+fn dissect_pdu(
+    id: CommandId,
+    src: &[u8],
+    length: usize,
+    dissector: &mut Dissector<'_>,
+) -> Result<usize, DecodeError> {
+    if length == 0 {
+        return Ok(0);
+    }
+
+    match id {
+        CommandId::BindTransmitter => BindTransmitter::dissect(src, length, dissector),
+        CommandId::BindTransmitterResp => BindTransmitterResp::dissect(src, length, dissector),
+        CommandId::BindReceiver => BindReceiver::dissect(src, length, dissector),
+        CommandId::BindReceiverResp => BindReceiverResp::dissect(src, length, dissector),
+        CommandId::BindTransceiver => BindTransceiver::dissect(src, length, dissector),
+        CommandId::BindTransceiverResp => BindTransceiverResp::dissect(src, length, dissector),
+        CommandId::Outbind => Outbind::dissect(src, length, dissector),
+        CommandId::AlertNotification => AlertNotification::dissect(src, length, dissector),
+        CommandId::SubmitSm => SubmitSm::dissect(src, length, dissector),
+        CommandId::SubmitSmResp => SubmitSmResp::dissect(src, length, dissector),
+        CommandId::QuerySm => QuerySm::dissect(src, length, dissector),
+        CommandId::QuerySmResp => QuerySmResp::dissect(src, length, dissector),
+        CommandId::DeliverSm => DeliverSm::dissect(src, length, dissector),
+        CommandId::DeliverSmResp => DeliverSmResp::dissect(src, length, dissector),
+        CommandId::DataSm => DataSm::dissect(src, length, dissector),
+        CommandId::DataSmResp => DataSmResp::dissect(src, length, dissector),
+        CommandId::CancelSm => CancelSm::dissect(src, length, dissector),
+        CommandId::ReplaceSm => ReplaceSm::dissect(src, length, dissector),
+        CommandId::SubmitMulti => SubmitMulti::dissect(src, length, dissector),
+        CommandId::SubmitMultiResp => SubmitMultiResp::dissect(src, length, dissector),
+        CommandId::BroadcastSm => BroadcastSm::dissect(src, length, dissector),
+        CommandId::BroadcastSmResp => BroadcastSmResp::dissect(src, length, dissector),
+        CommandId::QueryBroadcastSm => QueryBroadcastSm::dissect(src, length, dissector),
+        CommandId::QueryBroadcastSmResp => QueryBroadcastSmResp::dissect(src, length, dissector),
+        CommandId::CancelBroadcastSm => CancelBroadcastSm::dissect(src, length, dissector),
+        // Unknown bodies and bodies of commands that should not have one are shown as raw bytes.
+        CommandId::Other(_)
+        | CommandId::Unbind
+        | CommandId::UnbindResp
+        | CommandId::EnquireLink
+        | CommandId::EnquireLinkResp
+        | CommandId::GenericNack
+        | CommandId::CancelSmResp
+        | CommandId::ReplaceSmResp
+        | CommandId::CancelBroadcastSmResp => {
+            dissector.field(SmppField::pdu, 0, length, &AnyOctetString::new(src));
+
+            Ok(length)
+        }
+    }
+}
+
+/// Renders an aligned table of the nodes.
+///
+/// The bytes are rendered as hex, or as ASCII characters with the alternate flag (`{:#}`).
+/// At most 16 bytes are rendered per node.
+impl core::fmt::Display for Dissection<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fn field_width(nodes: &[Node<'_>], depth: usize) -> usize {
+            nodes
+                .iter()
+                .map(|node| {
+                    (depth * 2 + format!("{:?}", node.field).len())
+                        .max(field_width(&node.children, depth + 1))
+                })
+                .max()
+                .unwrap_or(0)
+        }
+
+        fn render_bytes(bytes: &[u8], ascii: bool) -> String {
+            let mut rendered = String::new();
+
+            for (i, byte) in bytes.iter().take(MAX_RENDERED_BYTES).enumerate() {
+                if ascii {
+                    rendered.push(if byte.is_ascii_graphic() || *byte == b' ' {
+                        *byte as char
+                    } else {
+                        '.'
+                    });
+                } else {
+                    if i > 0 {
+                        rendered.push(' ');
+                    }
+
+                    rendered.push_str(&format!("{byte:02X}"));
+                }
+            }
+
+            if bytes.len() > MAX_RENDERED_BYTES {
+                rendered.push_str(" ..");
+            }
+
+            rendered
+        }
+
+        fn render(
+            f: &mut core::fmt::Formatter<'_>,
+            nodes: &[Node<'_>],
+            depth: usize,
+            field_width: usize,
+            bytes_width: usize,
+        ) -> core::fmt::Result {
+            let ascii = f.alternate();
+
+            for node in nodes {
+                let field = format!("{:indent$}{:?}", "", node.field, indent = depth * 2);
+                let bytes = render_bytes(node.bytes, ascii);
+
+                let value = match &node.value {
+                    Ok(value) => value.clone(),
+                    Err(kind) => format!("<error: {kind}>"),
+                };
+
+                writeln!(
+                    f,
+                    "{:>6}  {field:field_width$}  {bytes:bytes_width$}  {value}",
+                    node.range.start
+                )?;
+
+                render(f, &node.children, depth + 1, field_width, bytes_width)?;
+            }
+
+            Ok(())
+        }
+
+        let field_width = field_width(&self.nodes, 0).max("Field".len());
+
+        // Hex: 3 characters per byte, ASCII: 1 character per byte, plus the " .." suffix.
+        let bytes_width = if f.alternate() {
+            MAX_RENDERED_BYTES + 3
+        } else {
+            MAX_RENDERED_BYTES * 3 + 2
+        };
+
+        writeln!(
+            f,
+            "{:>6}  {:field_width$}  {:bytes_width$}  Value",
+            "Offset", "Field", "Bytes"
+        )?;
+
+        render(f, &self.nodes, 0, field_width, bytes_width)?;
+
+        if let Some(error) = &self.error {
+            writeln!(f, "Error: {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use crate::{
+        CommandStatus,
+        command::owned::Command,
+        encode::{Encode, Length},
+        tlvs::owned::MessageSubmissionRequestTlvValue,
+        types::owned::{COctetString, OctetString},
+    };
+
+    use super::*;
+
+    fn encode(command: &Command) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; 4 + command.length()];
+
+        bytes[..4].copy_from_slice(&((4 + command.length()) as u32).to_be_bytes());
+        command.encode(&mut bytes[4..]);
+
+        bytes
+    }
+
+    fn fields(nodes: &[Node<'_>]) -> Vec<SmppField> {
+        nodes.iter().map(Node::field).collect()
+    }
+
+    #[test]
+    fn submit_sm() {
+        let command = Command::new(
+            CommandStatus::EsmeRok,
+            7,
+            SubmitSm::builder()
+                .short_message(OctetString::new(b"Hi").unwrap())
+                .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+                .build(),
+        );
+
+        let bytes = encode(&command);
+        let dissection = dissect(&bytes);
+
+        assert!(dissection.is_complete());
+
+        let nodes = dissection.nodes();
+
+        assert_eq!(
+            fields(nodes),
+            [
+                SmppField::command_length,
+                SmppField::id,
+                SmppField::status,
+                SmppField::sequence_number,
+                SmppField::service_type,
+                SmppField::source_addr_ton,
+                SmppField::source_addr_npi,
+                SmppField::source_addr,
+                SmppField::dest_addr_ton,
+                SmppField::dest_addr_npi,
+                SmppField::destination_addr,
+                SmppField::esm_class,
+                SmppField::protocol_id,
+                SmppField::priority_flag,
+                SmppField::schedule_delivery_time,
+                SmppField::validity_period,
+                SmppField::registered_delivery,
+                SmppField::replace_if_present_flag,
+                SmppField::data_coding,
+                SmppField::sm_default_msg_id,
+                SmppField::sm_length,
+                SmppField::short_message,
+                SmppField::tlvs,
+            ]
+        );
+
+        assert_eq!(nodes[1].value().ok(), Some("SubmitSm"));
+        assert_eq!(nodes[3].value().ok(), Some("7"));
+
+        // The nodes cover all the bytes in order.
+        let mut end = 0;
+
+        for node in nodes {
+            assert_eq!(node.range().start, end);
+            assert_eq!(node.bytes(), &bytes[node.range()]);
+
+            end = node.range().end;
+        }
+
+        assert_eq!(end, bytes.len());
+
+        let tlv = &nodes[22];
+
+        assert_eq!(tlv.value().ok(), Some("SarMsgRefNum"));
+        assert_eq!(
+            fields(tlv.children()),
+            [SmppField::tag, SmppField::value_length, SmppField::value]
+        );
+        assert_eq!(tlv.children()[2].value().ok(), Some("SarMsgRefNum(1)"));
+    }
+
+    #[test]
+    fn partial() {
+        let command = Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            BindTransmitter::builder()
+                .system_id(COctetString::from_str("SMPP3TEST").unwrap())
+                .password(COctetString::from_str("secret08").unwrap())
+                .build(),
+        );
+
+        let mut bytes = encode(&command);
+
+        // Remove the null terminator of the password.
+        bytes[34] = b'!';
+
+        let dissection = dissect(&bytes);
+
+        assert!(!dissection.is_complete());
+        assert_eq!(
+            fields(dissection.nodes()),
+            [
+                SmppField::command_length,
+                SmppField::id,
+                SmppField::status,
+                SmppField::sequence_number,
+                SmppField::system_id,
+                SmppField::password,
+            ]
+        );
+
+        let password = &dissection.nodes()[5];
+
+        assert!(password.value().is_err());
+        assert_eq!(password.range(), 26..bytes.len());
+    }
+
+    #[test]
+    fn truncated_header() {
+        let dissection = dissect(&[0x00, 0x00, 0x00, 0x10, 0x00, 0x00]);
+
+        assert!(!dissection.is_complete());
+        assert_eq!(
+            fields(dissection.nodes()),
+            [SmppField::command_length, SmppField::id]
+        );
+        assert!(dissection.nodes()[1].value().is_err());
+    }
+
+    #[test]
+    fn invalid_tlv() {
+        let command = Command::new(CommandStatus::EsmeRok, 1, SubmitSm::default());
+
+        let mut bytes = encode(&command);
+
+        // sar_msg_ref_num with a value_length of 2 and only 1 byte of value.
+        bytes.extend_from_slice(&[0x02, 0x0C, 0x00, 0x02, 0x00]);
+
+        let length = bytes.len() as u32;
+        bytes[..4].copy_from_slice(&length.to_be_bytes());
+
+        let dissection = dissect(&bytes);
+
+        assert!(!dissection.is_complete());
+
+        let tlv = dissection.nodes().last().unwrap();
+
+        assert_eq!(tlv.field(), SmppField::tlvs);
+        assert!(tlv.value().is_err());
+        assert_eq!(
+            fields(tlv.children()),
+            [SmppField::tag, SmppField::value_length, SmppField::value]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let command = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+
+        let bytes = encode(&command);
+        let dissection = dissect(&bytes);
+
+        let expected = "\
+Offset  Field            Bytes                                               Value
+     0  command_length   00 00 00 10                                         16
+     4  id               00 00 00 15                                         EnquireLink
+     8  status           00 00 00 00                                         EsmeRok
+    12  sequence_number  00 00 00 01                                         1
+";
+
+        assert_eq!(format!("{dissection}"), expected);
+    }
+}
@@ -96,6 +96,40 @@ pub trait EncodeExt: Encode {
 
 impl<T: Encode> EncodeExt for T {}
 
+/// Trait for encoding `SMPP` values directly into a [`BytesMut`](tokio_util::bytes::BytesMut).
+///
+/// Values are written through [`BufMut`](tokio_util::bytes::BufMut) at the end of the buffer,
+/// so the buffer is never zero-filled before encoding.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::encode::EncodeBytesMut;
+/// use tokio_util::bytes::BytesMut;
+///
+/// let mut dst = BytesMut::from(&[0xFF][..]);
+///
+/// let size = 0x01020304u32.encode_bytes_mut(&mut dst);
+///
+/// assert_eq!(size, 4);
+/// assert_eq!(&dst[..], &[0xFF, 0x01, 0x02, 0x03, 0x04]);
+/// ```
+#[cfg(feature = "tokio-codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-codec")))]
+pub trait EncodeBytesMut: Encode {
+    /// Writes the encoded value to the end of `dst` and returns the number of bytes written.
+    ///
+    /// `dst` grows as needed, see [`EncodeBytesMut::encode_bytes_mut`] to reserve the space upfront.
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize;
+
+    /// Reserves [`Length::length`] bytes in `dst`, appends the encoded value and returns the number of bytes written.
+    fn encode_bytes_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        dst.reserve(self.length());
+
+        self.encode_buf_mut(dst)
+    }
+}
+
 impl<T: Length> Length for Option<T> {
     fn length(&self) -> usize {
         self.as_ref().map(Length::length).unwrap_or(0)
@@ -149,6 +183,36 @@ impl<T: Encode, const N: usize> Encode for heapless::vec::Vec<T, N> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<T: EncodeBytesMut> EncodeBytesMut for Option<T> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        self.as_ref()
+            .map(|item| item.encode_buf_mut(dst))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl<T: EncodeBytesMut> EncodeBytesMut for &[T] {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        self.iter().map(|item| item.encode_buf_mut(dst)).sum()
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl<T: EncodeBytesMut> EncodeBytesMut for alloc::vec::Vec<T> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        self.as_slice().encode_buf_mut(dst)
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl<T: EncodeBytesMut, const N: usize> EncodeBytesMut for heapless::vec::Vec<T, N> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        self.as_slice().encode_buf_mut(dst)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,114 @@
+//! Bengali national language shift tables.
+
+use super::NONE;
+
+/// Bengali national language locking shift table (3GPP TS 23.038 A.3.4), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    '\u{0981}', '\u{0982}', '\u{0983}', '\u{0985}', '\u{0986}', '\u{0987}', '\u{0988}',
+    '\u{0989}', //
+    '\u{098A}', '\u{098B}', '\n', '\u{098C}', NONE, '\r', NONE, '\u{098F}', //
+    '\u{0990}', NONE, NONE, '\u{0993}', '\u{0994}', '\u{0995}', '\u{0996}', '\u{0997}', //
+    '\u{0998}', '\u{0999}', '\u{099A}', NONE, '\u{099B}', '\u{099C}', '\u{099D}',
+    '\u{099E}', //
+    ' ', '!', '\u{099F}', '\u{09A0}', '\u{09A1}', '\u{09A2}', '\u{09A3}', '\u{09A4}', //
+    ')', '(', '\u{09A5}', '\u{09A6}', ',', '\u{09A7}', '.', '\u{09A8}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', NONE, '\u{09AA}', '\u{09AB}', '?', //
+    '\u{09AC}', '\u{09AD}', '\u{09AE}', '\u{09AF}', '\u{09B0}', NONE, '\u{09B2}', NONE, //
+    NONE, NONE, '\u{09B6}', '\u{09B7}', '\u{09B8}', '\u{09B9}', '\u{09BC}', '\u{09BD}', //
+    '\u{09BE}', '\u{09BF}', '\u{09C0}', '\u{09C1}', '\u{09C2}', '\u{09C3}', '\u{09C4}',
+    NONE, //
+    NONE, '\u{09C7}', '\u{09C8}', NONE, NONE, '\u{09CB}', '\u{09CC}', '\u{09CD}', //
+    '\u{09CE}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{09D7}', '\u{09DC}', '\u{09DD}', '\u{09F0}', '\u{09F1}', //
+];
+
+/// Bengali national language single shift table (3GPP TS 23.038 A.2.4) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 84] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{000C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{09E6}'),
+    (0x1D, '\u{09E7}'),
+    (0x1E, '\u{09E8}'),
+    (0x1F, '\u{09E9}'),
+    (0x20, '\u{09EA}'),
+    (0x21, '\u{09EB}'),
+    (0x22, '\u{09EC}'),
+    (0x23, '\u{09ED}'),
+    (0x24, '\u{09EE}'),
+    (0x25, '\u{09EF}'),
+    (0x26, '\u{09DF}'),
+    (0x27, '\u{09E0}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{09E1}'),
+    (0x2B, '\u{09E2}'),
+    (0x2C, '\u{09E3}'),
+    (0x2D, '\u{09F2}'),
+    (0x2E, '\u{09F3}'),
+    (0x2F, '\\'),
+    (0x30, '\u{09F4}'),
+    (0x31, '\u{09F5}'),
+    (0x32, '\u{09F6}'),
+    (0x33, '\u{09F7}'),
+    (0x34, '\u{09F8}'),
+    (0x35, '\u{09F9}'),
+    (0x36, '\u{09FA}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
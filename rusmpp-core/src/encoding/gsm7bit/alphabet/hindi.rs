@@ -0,0 +1,122 @@
+//! Hindi national language shift tables.
+
+use super::NONE;
+
+/// Hindi national language locking shift table (3GPP TS 23.038 A.3.6), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    '\u{0901}', '\u{0902}', '\u{0903}', '\u{0905}', '\u{0906}', '\u{0907}', '\u{0908}',
+    '\u{0909}', //
+    '\u{090A}', '\u{090B}', '\n', '\u{090C}', '\u{090D}', '\r', '\u{090E}', '\u{090F}', //
+    '\u{0910}', '\u{0911}', '\u{0912}', '\u{0913}', '\u{0914}', '\u{0915}', '\u{0916}',
+    '\u{0917}', //
+    '\u{0918}', '\u{0919}', '\u{091A}', NONE, '\u{091B}', '\u{091C}', '\u{091D}',
+    '\u{091E}', //
+    ' ', '!', '\u{091F}', '\u{0920}', '\u{0921}', '\u{0922}', '\u{0923}', '\u{0924}', //
+    ')', '(', '\u{0925}', '\u{0926}', ',', '\u{0927}', '.', '\u{0928}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '\u{0929}', '\u{092A}', '\u{092B}', '?', //
+    '\u{092C}', '\u{092D}', '\u{092E}', '\u{092F}', '\u{0930}', '\u{0931}', '\u{0932}',
+    '\u{0933}', //
+    '\u{0934}', '\u{0935}', '\u{0936}', '\u{0937}', '\u{0938}', '\u{0939}', '\u{093C}',
+    '\u{093D}', //
+    '\u{093E}', '\u{093F}', '\u{0940}', '\u{0941}', '\u{0942}', '\u{0943}', '\u{0944}',
+    '\u{0945}', //
+    '\u{0946}', '\u{0947}', '\u{0948}', '\u{0949}', '\u{094A}', '\u{094B}', '\u{094C}',
+    '\u{094D}', //
+    '\u{0950}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0972}', '\u{097B}', '\u{097C}', '\u{097E}', '\u{097F}', //
+];
+
+/// Hindi national language single shift table (3GPP TS 23.038 A.2.6) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 88] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{000C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0966}'),
+    (0x1D, '\u{0967}'),
+    (0x1E, '\u{0968}'),
+    (0x1F, '\u{0969}'),
+    (0x20, '\u{096A}'),
+    (0x21, '\u{096B}'),
+    (0x22, '\u{096C}'),
+    (0x23, '\u{096D}'),
+    (0x24, '\u{096E}'),
+    (0x25, '\u{096F}'),
+    (0x26, '\u{0951}'),
+    (0x27, '\u{0952}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0953}'),
+    (0x2B, '\u{0954}'),
+    (0x2C, '\u{0958}'),
+    (0x2D, '\u{0959}'),
+    (0x2E, '\u{095A}'),
+    (0x2F, '\\'),
+    (0x30, '\u{095B}'),
+    (0x31, '\u{095C}'),
+    (0x32, '\u{095D}'),
+    (0x33, '\u{095E}'),
+    (0x34, '\u{095F}'),
+    (0x35, '\u{0960}'),
+    (0x36, '\u{0961}'),
+    (0x37, '\u{0962}'),
+    (0x38, '\u{0963}'),
+    (0x39, '\u{0970}'),
+    (0x3A, '\u{0971}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
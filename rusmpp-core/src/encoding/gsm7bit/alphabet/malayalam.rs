@@ -0,0 +1,116 @@
+//! Malayalam national language shift tables.
+
+use super::NONE;
+
+/// Malayalam national language locking shift table (3GPP TS 23.038 A.3.8), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    NONE, '\u{0D02}', '\u{0D03}', '\u{0D05}', '\u{0D06}', '\u{0D07}', '\u{0D08}',
+    '\u{0D09}', //
+    '\u{0D0A}', '\u{0D0B}', '\n', '\u{0D0C}', NONE, '\r', '\u{0D0E}', '\u{0D0F}', //
+    '\u{0D10}', NONE, '\u{0D12}', '\u{0D13}', '\u{0D14}', '\u{0D15}', '\u{0D16}',
+    '\u{0D17}', //
+    '\u{0D18}', '\u{0D19}', '\u{0D1A}', NONE, '\u{0D1B}', '\u{0D1C}', '\u{0D1D}',
+    '\u{0D1E}', //
+    ' ', '!', '\u{0D1F}', '\u{0D20}', '\u{0D21}', '\u{0D22}', '\u{0D23}', '\u{0D24}', //
+    ')', '(', '\u{0D25}', '\u{0D26}', ',', '\u{0D27}', '.', '\u{0D28}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', NONE, '\u{0D2A}', '\u{0D2B}', '?', //
+    '\u{0D2C}', '\u{0D2D}', '\u{0D2E}', '\u{0D2F}', '\u{0D30}', '\u{0D31}', '\u{0D32}',
+    '\u{0D33}', //
+    '\u{0D34}', '\u{0D35}', '\u{0D36}', '\u{0D37}', '\u{0D38}', '\u{0D39}', NONE,
+    '\u{0D3D}', //
+    '\u{0D3E}', '\u{0D3F}', '\u{0D40}', '\u{0D41}', '\u{0D42}', '\u{0D43}', '\u{0D44}',
+    NONE, //
+    '\u{0D46}', '\u{0D47}', '\u{0D48}', NONE, '\u{0D4A}', '\u{0D4B}', '\u{0D4C}',
+    '\u{0D4D}', //
+    '\u{0D57}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0D60}', '\u{0D61}', '\u{0D62}', '\u{0D63}', '\u{0D79}', //
+];
+
+/// Malayalam national language single shift table (3GPP TS 23.038 A.2.8) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 82] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{000C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0D66}'),
+    (0x1D, '\u{0D67}'),
+    (0x1E, '\u{0D68}'),
+    (0x1F, '\u{0D69}'),
+    (0x20, '\u{0D6A}'),
+    (0x21, '\u{0D6B}'),
+    (0x22, '\u{0D6C}'),
+    (0x23, '\u{0D6D}'),
+    (0x24, '\u{0D6E}'),
+    (0x25, '\u{0D6F}'),
+    (0x26, '\u{0D70}'),
+    (0x27, '\u{0D71}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0D72}'),
+    (0x2B, '\u{0D73}'),
+    (0x2C, '\u{0D74}'),
+    (0x2D, '\u{0D75}'),
+    (0x2E, '\u{0D7A}'),
+    (0x2F, '\\'),
+    (0x30, '\u{0D7B}'),
+    (0x31, '\u{0D7C}'),
+    (0x32, '\u{0D7D}'),
+    (0x33, '\u{0D7E}'),
+    (0x34, '\u{0D7F}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
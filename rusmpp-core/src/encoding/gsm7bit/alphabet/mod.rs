@@ -0,0 +1,189 @@
+//! GSM 03.38 default alphabet, extension table and national language shift tables.
+
+use super::NationalLanguage;
+
+pub mod bengali;
+pub mod gujarati;
+pub mod hindi;
+pub mod kannada;
+pub mod malayalam;
+pub mod oriya;
+pub mod portuguese;
+pub mod punjabi;
+pub mod spanish;
+pub mod tamil;
+pub mod telugu;
+pub mod turkish;
+pub mod urdu;
+
+/// The escape septet used to switch to the extension table for the next septet.
+pub const ESCAPE: u8 = 0x1B;
+
+/// The carriage return septet used to fill the spare bits of a packed message.
+pub const CR: u8 = 0x0D;
+
+/// Placeholder for the escape and unused positions of a table. Never produced or matched.
+const NONE: char = '\u{FFFF}';
+
+/// GSM 03.38 default alphabet, indexed by septet.
+pub const DEFAULT_ALPHABET: [char; 128] = [
+    '@', '£', '$', '¥', 'è', 'é', 'ù', 'ì', 'ò', 'Ç', '\n', 'Ø', 'ø', '\r', 'Å', 'å', //
+    'Δ', '_', 'Φ', 'Γ', 'Λ', 'Ω', 'Π', 'Ψ', 'Σ', 'Θ', 'Ξ', NONE, 'Æ', 'æ', 'ß', 'É', //
+    ' ', '!', '"', '#', '¤', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', //
+    '¡', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ä', 'Ö', 'Ñ', 'Ü', '§', //
+    '¿', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ñ', 'ü', 'à', //
+];
+
+/// GSM 03.38 default alphabet extension table as `(septet, char)` pairs.
+///
+/// Each character is encoded as [`ESCAPE`] followed by its septet.
+pub const EXTENSION_TABLE: [(u8, char); 10] = [
+    (0x0A, '\u{0C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x65, '€'),
+];
+
+/// A GSM 7-bit alphabet made of a locking shift table and a single shift table.
+///
+/// The locking shift table replaces the [default alphabet](DEFAULT_ALPHABET) and
+/// the single shift table replaces the [extension table](EXTENSION_TABLE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    locking_shift: &'static [char; 128],
+    single_shift: &'static [(u8, char)],
+}
+
+impl Alphabet {
+    /// The default alphabet with its extension table.
+    pub const DEFAULT: Self = Self {
+        locking_shift: &DEFAULT_ALPHABET,
+        single_shift: &EXTENSION_TABLE,
+    };
+
+    /// Creates a new [`Alphabet`] from the given national language shift tables.
+    ///
+    /// `None` selects the default alphabet or the extension table respectively.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` if the shift tables are available.
+    /// - `Err(NationalLanguage)` with the language whose shift table is not available.
+    pub const fn new(
+        locking_shift: Option<NationalLanguage>,
+        single_shift: Option<NationalLanguage>,
+    ) -> Result<Self, NationalLanguage> {
+        let locking_shift = match locking_shift {
+            None => &DEFAULT_ALPHABET,
+            Some(language) => match locking_shift_table(language) {
+                Some(table) => table,
+                None => return Err(language),
+            },
+        };
+
+        let single_shift = match single_shift {
+            None => EXTENSION_TABLE.as_slice(),
+            Some(language) => match single_shift_table(language) {
+                Some(table) => table,
+                None => return Err(language),
+            },
+        };
+
+        Ok(Self {
+            locking_shift,
+            single_shift,
+        })
+    }
+
+    /// Returns the septet of `character` in the locking shift table.
+    pub fn locking_shift_septet(&self, character: char) -> Option<u8> {
+        if character == NONE {
+            return None;
+        }
+
+        self.locking_shift
+            .iter()
+            .position(|&c| c == character)
+            .map(|position| position as u8)
+    }
+
+    /// Returns the septet of `character` in the single shift table.
+    pub fn single_shift_septet(&self, character: char) -> Option<u8> {
+        self.single_shift
+            .iter()
+            .find(|(_, c)| *c == character)
+            .map(|(septet, _)| *septet)
+    }
+
+    /// Returns the character of `septet` in the locking shift table.
+    ///
+    /// The [`ESCAPE`] septet and unused septets have no character and are displayed as a space.
+    pub const fn locking_shift_char(&self, septet: u8) -> char {
+        match self.locking_shift[(septet & 0x7F) as usize] {
+            NONE => ' ',
+            character => character,
+        }
+    }
+
+    /// Returns the character of `septet` in the single shift table.
+    pub fn single_shift_char(&self, septet: u8) -> Option<char> {
+        self.single_shift
+            .iter()
+            .find(|(s, _)| *s == septet)
+            .map(|(_, character)| *character)
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Returns the national language locking shift table of `language`, if defined.
+pub const fn locking_shift_table(language: NationalLanguage) -> Option<&'static [char; 128]> {
+    match language {
+        NationalLanguage::Turkish => Some(&turkish::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Portuguese => Some(&portuguese::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Bengali => Some(&bengali::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Gujarati => Some(&gujarati::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Hindi => Some(&hindi::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Kannada => Some(&kannada::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Malayalam => Some(&malayalam::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Oriya => Some(&oriya::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Punjabi => Some(&punjabi::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Tamil => Some(&tamil::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Telugu => Some(&telugu::LOCKING_SHIFT_TABLE),
+        NationalLanguage::Urdu => Some(&urdu::LOCKING_SHIFT_TABLE),
+        _ => None,
+    }
+}
+
+/// Returns the national language single shift table of `language`, if defined.
+pub const fn single_shift_table(language: NationalLanguage) -> Option<&'static [(u8, char)]> {
+    match language {
+        NationalLanguage::Turkish => Some(turkish::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Spanish => Some(spanish::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Portuguese => Some(portuguese::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Bengali => Some(bengali::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Gujarati => Some(gujarati::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Hindi => Some(hindi::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Kannada => Some(kannada::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Malayalam => Some(malayalam::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Oriya => Some(oriya::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Punjabi => Some(punjabi::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Tamil => Some(tamil::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Telugu => Some(telugu::SINGLE_SHIFT_TABLE.as_slice()),
+        NationalLanguage::Urdu => Some(urdu::SINGLE_SHIFT_TABLE.as_slice()),
+        _ => None,
+    }
+}
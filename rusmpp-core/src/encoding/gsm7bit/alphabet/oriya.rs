@@ -0,0 +1,107 @@
+//! Oriya national language shift tables.
+
+use super::NONE;
+
+/// Oriya national language locking shift table (3GPP TS 23.038 A.3.9), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    '\u{0B01}', '\u{0B02}', '\u{0B03}', '\u{0B05}', '\u{0B06}', '\u{0B07}', '\u{0B08}',
+    '\u{0B09}', //
+    '\u{0B0A}', '\u{0B0B}', '\n', '\u{0B0C}', NONE, '\r', NONE, '\u{0B0F}', //
+    '\u{0B10}', NONE, NONE, '\u{0B13}', '\u{0B14}', '\u{0B15}', '\u{0B16}', '\u{0B17}', //
+    '\u{0B18}', '\u{0B19}', '\u{0B1A}', NONE, '\u{0B1B}', '\u{0B1C}', '\u{0B1D}',
+    '\u{0B1E}', //
+    ' ', '!', '\u{0B1F}', '\u{0B20}', '\u{0B21}', '\u{0B22}', '\u{0B23}', '\u{0B24}', //
+    ')', '(', '\u{0B25}', '\u{0B26}', ',', '\u{0B27}', '.', '\u{0B28}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', NONE, '\u{0B2A}', '\u{0B2B}', '?', //
+    '\u{0B2C}', '\u{0B2D}', '\u{0B2E}', '\u{0B2F}', '\u{0B30}', NONE, '\u{0B32}',
+    '\u{0B33}', //
+    NONE, '\u{0B35}', '\u{0B36}', '\u{0B37}', '\u{0B38}', '\u{0B39}', '\u{0B3C}',
+    '\u{0B3D}', //
+    '\u{0B3E}', '\u{0B3F}', '\u{0B40}', '\u{0B41}', '\u{0B42}', '\u{0B43}', '\u{0B44}',
+    NONE, //
+    NONE, '\u{0B47}', '\u{0B48}', NONE, NONE, '\u{0B4B}', '\u{0B4C}', '\u{0B4D}', //
+    '\u{0B56}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0B57}', '\u{0B60}', '\u{0B61}', '\u{0B62}', '\u{0B63}', //
+];
+
+/// Oriya national language single shift table (3GPP TS 23.038 A.2.9) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 75] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{000C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0B66}'),
+    (0x1D, '\u{0B67}'),
+    (0x1E, '\u{0B68}'),
+    (0x1F, '\u{0B69}'),
+    (0x20, '\u{0B6A}'),
+    (0x21, '\u{0B6B}'),
+    (0x22, '\u{0B6C}'),
+    (0x23, '\u{0B6D}'),
+    (0x24, '\u{0B6E}'),
+    (0x25, '\u{0B6F}'),
+    (0x26, '\u{0B5C}'),
+    (0x27, '\u{0B5D}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0B5F}'),
+    (0x2B, '\u{0B70}'),
+    (0x2C, '\u{0B71}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
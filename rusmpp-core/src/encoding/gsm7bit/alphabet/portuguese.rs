@@ -0,0 +1,64 @@
+//! Portuguese national language shift tables.
+
+use super::NONE;
+
+/// Portuguese national language locking shift table (3GPP TS 23.038 A.3.3), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    '@', '\u{00A3}', '$', '\u{00A5}', '\u{00EA}', '\u{00E9}', '\u{00FA}', '\u{00ED}', //
+    '\u{00F3}', '\u{00E7}', '\n', '\u{00D4}', '\u{00F4}', '\r', '\u{00C1}', '\u{00E1}', //
+    '\u{0394}', '_', '\u{00AA}', '\u{00C7}', '\u{00C0}', '\u{221E}', '^', '\\', //
+    '\u{20AC}', '\u{00D3}', '|', NONE, '\u{00C2}', '\u{00E2}', '\u{00CA}', '\u{00C9}', //
+    ' ', '!', '"', '#', '\u{00BA}', '%', '&', '\'', //
+    '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '<', '=', '>', '?', //
+    '\u{00CD}', 'A', 'B', 'C', 'D', 'E', 'F', 'G', //
+    'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', //
+    'X', 'Y', 'Z', '\u{00C3}', '\u{00D5}', '\u{00DA}', '\u{00DC}', '\u{00A7}', //
+    '~', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{00E3}', '\u{00F5}', '`', '\u{00FC}', '\u{00E0}', //
+];
+
+/// Portuguese national language single shift table (3GPP TS 23.038 A.2.3) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 37] = [
+    (0x05, '\u{00EA}'),
+    (0x09, '\u{00E7}'),
+    (0x0A, '\u{000C}'),
+    (0x0B, '\u{00D4}'),
+    (0x0C, '\u{00F4}'),
+    (0x0E, '\u{00C1}'),
+    (0x0F, '\u{00E1}'),
+    (0x12, '\u{03A6}'),
+    (0x13, '\u{0393}'),
+    (0x14, '^'),
+    (0x15, '\u{03A9}'),
+    (0x16, '\u{03A0}'),
+    (0x17, '\u{03A8}'),
+    (0x18, '\u{03A3}'),
+    (0x19, '\u{0398}'),
+    (0x1F, '\u{00CA}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, '\u{00C0}'),
+    (0x49, '\u{00CD}'),
+    (0x4F, '\u{00D3}'),
+    (0x55, '\u{00DA}'),
+    (0x5B, '\u{00C3}'),
+    (0x5C, '\u{00D5}'),
+    (0x61, '\u{00C2}'),
+    (0x65, '\u{20AC}'),
+    (0x69, '\u{00ED}'),
+    (0x6F, '\u{00F3}'),
+    (0x75, '\u{00FA}'),
+    (0x7B, '\u{00E3}'),
+    (0x7C, '\u{00F5}'),
+    (0x7F, '\u{00E2}'),
+];
@@ -0,0 +1,106 @@
+//! Punjabi national language shift tables.
+
+use super::NONE;
+
+/// Punjabi national language locking shift table (3GPP TS 23.038 A.3.10), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    '\u{0A01}', '\u{0A02}', '\u{0A03}', '\u{0A05}', '\u{0A06}', '\u{0A07}', '\u{0A08}',
+    '\u{0A09}', //
+    '\u{0A0A}', NONE, '\n', NONE, NONE, '\r', NONE, '\u{0A0F}', //
+    '\u{0A10}', NONE, NONE, '\u{0A13}', '\u{0A14}', '\u{0A15}', '\u{0A16}', '\u{0A17}', //
+    '\u{0A18}', '\u{0A19}', '\u{0A1A}', NONE, '\u{0A1B}', '\u{0A1C}', '\u{0A1D}',
+    '\u{0A1E}', //
+    ' ', '!', '\u{0A1F}', '\u{0A20}', '\u{0A21}', '\u{0A22}', '\u{0A23}', '\u{0A24}', //
+    ')', '(', '\u{0A25}', '\u{0A26}', ',', '\u{0A27}', '.', '\u{0A28}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', NONE, '\u{0A2A}', '\u{0A2B}', '?', //
+    '\u{0A2C}', '\u{0A2D}', '\u{0A2E}', '\u{0A2F}', '\u{0A30}', NONE, '\u{0A32}',
+    '\u{0A33}', //
+    NONE, '\u{0A35}', '\u{0A36}', NONE, '\u{0A38}', '\u{0A39}', '\u{0A3C}', NONE, //
+    '\u{0A3E}', '\u{0A3F}', '\u{0A40}', '\u{0A41}', '\u{0A42}', NONE, NONE, NONE, //
+    NONE, '\u{0A47}', '\u{0A48}', NONE, NONE, '\u{0A4B}', '\u{0A4C}', '\u{0A4D}', //
+    '\u{0A51}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0A70}', '\u{0A71}', '\u{0A72}', '\u{0A73}', '\u{0A74}', //
+];
+
+/// Punjabi national language single shift table (3GPP TS 23.038 A.2.10) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 76] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{000C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0964}'),
+    (0x1A, '\u{0965}'),
+    (0x1C, '\u{0A66}'),
+    (0x1D, '\u{0A67}'),
+    (0x1E, '\u{0A68}'),
+    (0x1F, '\u{0A69}'),
+    (0x20, '\u{0A6A}'),
+    (0x21, '\u{0A6B}'),
+    (0x22, '\u{0A6C}'),
+    (0x23, '\u{0A6D}'),
+    (0x24, '\u{0A6E}'),
+    (0x25, '\u{0A6F}'),
+    (0x26, '\u{0A59}'),
+    (0x27, '\u{0A5A}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{0A5B}'),
+    (0x2B, '\u{0A5C}'),
+    (0x2C, '\u{0A5E}'),
+    (0x2D, '\u{0A75}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
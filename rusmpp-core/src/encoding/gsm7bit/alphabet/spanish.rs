@@ -0,0 +1,26 @@
+//! Spanish national language shift tables.
+//!
+//! 3GPP TS 23.038 defines no Spanish locking shift table, the default alphabet is used instead.
+
+/// Spanish national language single shift table (3GPP TS 23.038 A.2.2) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 19] = [
+    (0x09, '\u{00E7}'),
+    (0x0A, '\u{000C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, '\u{00C1}'),
+    (0x49, '\u{00CD}'),
+    (0x4F, '\u{00D3}'),
+    (0x55, '\u{00DA}'),
+    (0x61, '\u{00E1}'),
+    (0x65, '\u{20AC}'),
+    (0x69, '\u{00ED}'),
+    (0x6F, '\u{00F3}'),
+    (0x75, '\u{00FA}'),
+];
@@ -0,0 +1,46 @@
+//! Turkish national language shift tables.
+
+use super::NONE;
+
+/// Turkish national language locking shift table (3GPP TS 23.038 A.3.1), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    '@', '\u{00A3}', '$', '\u{00A5}', '\u{20AC}', '\u{00E9}', '\u{00F9}', '\u{0131}', //
+    '\u{00F2}', '\u{00C7}', '\n', '\u{011E}', '\u{011F}', '\r', '\u{00C5}', '\u{00E5}', //
+    '\u{0394}', '_', '\u{03A6}', '\u{0393}', '\u{039B}', '\u{03A9}', '\u{03A0}',
+    '\u{03A8}', //
+    '\u{03A3}', '\u{0398}', '\u{039E}', NONE, '\u{015E}', '\u{015F}', '\u{00DF}',
+    '\u{00C9}', //
+    ' ', '!', '"', '#', '\u{00A4}', '%', '&', '\'', //
+    '(', ')', '*', '+', ',', '-', '.', '/', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '<', '=', '>', '?', //
+    '\u{0130}', 'A', 'B', 'C', 'D', 'E', 'F', 'G', //
+    'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', //
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', //
+    'X', 'Y', 'Z', '\u{00C4}', '\u{00D6}', '\u{00D1}', '\u{00DC}', '\u{00A7}', //
+    '\u{00E7}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{00E4}', '\u{00F6}', '\u{00F1}', '\u{00FC}', '\u{00E0}', //
+];
+
+/// Turkish national language single shift table (3GPP TS 23.038 A.2.1) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 17] = [
+    (0x0A, '\u{000C}'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x47, '\u{011E}'),
+    (0x49, '\u{0130}'),
+    (0x53, '\u{015E}'),
+    (0x63, '\u{00E7}'),
+    (0x65, '\u{20AC}'),
+    (0x67, '\u{011F}'),
+    (0x69, '\u{0131}'),
+    (0x73, '\u{015F}'),
+];
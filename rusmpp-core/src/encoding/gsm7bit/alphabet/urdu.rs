@@ -0,0 +1,124 @@
+//! Urdu national language shift tables.
+
+use super::NONE;
+
+/// Urdu national language locking shift table (3GPP TS 23.038 A.3.13), indexed by septet.
+pub const LOCKING_SHIFT_TABLE: [char; 128] = [
+    '\u{0627}', '\u{0622}', '\u{0628}', '\u{067B}', '\u{0680}', '\u{067E}', '\u{06A6}',
+    '\u{062A}', //
+    '\u{06C2}', '\u{067F}', '\n', '\u{0679}', '\u{067D}', '\r', '\u{067A}', '\u{067C}', //
+    '\u{062B}', '\u{062C}', '\u{0681}', '\u{0684}', '\u{0683}', '\u{0685}', '\u{0686}',
+    '\u{0687}', //
+    '\u{062D}', '\u{062E}', '\u{062F}', NONE, '\u{068C}', '\u{0688}', '\u{0689}',
+    '\u{068A}', //
+    ' ', '!', '\u{068F}', '\u{068D}', '\u{0630}', '\u{0631}', '\u{0691}', '\u{0693}', //
+    ')', '(', '\u{0699}', '\u{0632}', ',', '\u{0696}', '.', '\u{0698}', //
+    '0', '1', '2', '3', '4', '5', '6', '7', //
+    '8', '9', ':', ';', '\u{069A}', '\u{0633}', '\u{0634}', '?', //
+    '\u{0635}', '\u{0636}', '\u{0637}', '\u{0638}', '\u{0639}', '\u{0641}', '\u{0642}',
+    '\u{06A9}', //
+    '\u{06AA}', '\u{06AB}', '\u{06AF}', '\u{06B3}', '\u{06B1}', '\u{0644}', '\u{0645}',
+    '\u{0646}', //
+    '\u{06BA}', '\u{06BB}', '\u{06BC}', '\u{0648}', '\u{06C4}', '\u{06D5}', '\u{06C1}',
+    '\u{06BE}', //
+    '\u{0621}', '\u{06CC}', '\u{06D0}', '\u{06D2}', '\u{064D}', '\u{0650}', '\u{064F}',
+    '\u{0657}', //
+    '\u{0654}', 'a', 'b', 'c', 'd', 'e', 'f', 'g', //
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', //
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', //
+    'x', 'y', 'z', '\u{0655}', '\u{0651}', '\u{0653}', '\u{0656}', '\u{0670}', //
+];
+
+/// Urdu national language single shift table (3GPP TS 23.038 A.2.13) as `(septet, char)` pairs.
+pub const SINGLE_SHIFT_TABLE: [(u8, char); 90] = [
+    (0x00, '@'),
+    (0x01, '\u{00A3}'),
+    (0x02, '$'),
+    (0x03, '\u{00A5}'),
+    (0x04, '\u{00BF}'),
+    (0x05, '"'),
+    (0x06, '\u{00A4}'),
+    (0x07, '%'),
+    (0x08, '&'),
+    (0x09, '\''),
+    (0x0A, '\u{000C}'),
+    (0x0B, '*'),
+    (0x0C, '+'),
+    (0x0E, '-'),
+    (0x0F, '/'),
+    (0x10, '<'),
+    (0x11, '='),
+    (0x12, '>'),
+    (0x13, '\u{00A1}'),
+    (0x14, '^'),
+    (0x15, '\u{00A1}'),
+    (0x16, '_'),
+    (0x17, '#'),
+    (0x18, '*'),
+    (0x19, '\u{0600}'),
+    (0x1A, '\u{0601}'),
+    (0x1C, '\u{06F0}'),
+    (0x1D, '\u{06F1}'),
+    (0x1E, '\u{06F2}'),
+    (0x1F, '\u{06F3}'),
+    (0x20, '\u{06F4}'),
+    (0x21, '\u{06F5}'),
+    (0x22, '\u{06F6}'),
+    (0x23, '\u{06F7}'),
+    (0x24, '\u{06F8}'),
+    (0x25, '\u{06F9}'),
+    (0x26, '\u{060C}'),
+    (0x27, '\u{060D}'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2A, '\u{060E}'),
+    (0x2B, '\u{060F}'),
+    (0x2C, '\u{0610}'),
+    (0x2D, '\u{0611}'),
+    (0x2E, '\u{0612}'),
+    (0x2F, '\\'),
+    (0x30, '\u{0613}'),
+    (0x31, '\u{0614}'),
+    (0x32, '\u{061B}'),
+    (0x33, '\u{061F}'),
+    (0x34, '\u{0640}'),
+    (0x35, '\u{0652}'),
+    (0x36, '\u{0658}'),
+    (0x37, '\u{066B}'),
+    (0x38, '\u{066C}'),
+    (0x39, '\u{0672}'),
+    (0x3A, '\u{0673}'),
+    (0x3B, '\u{06CD}'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x3F, '\u{06D4}'),
+    (0x40, '|'),
+    (0x41, 'A'),
+    (0x42, 'B'),
+    (0x43, 'C'),
+    (0x44, 'D'),
+    (0x45, 'E'),
+    (0x46, 'F'),
+    (0x47, 'G'),
+    (0x48, 'H'),
+    (0x49, 'I'),
+    (0x4A, 'J'),
+    (0x4B, 'K'),
+    (0x4C, 'L'),
+    (0x4D, 'M'),
+    (0x4E, 'N'),
+    (0x4F, 'O'),
+    (0x50, 'P'),
+    (0x51, 'Q'),
+    (0x52, 'R'),
+    (0x53, 'S'),
+    (0x54, 'T'),
+    (0x55, 'U'),
+    (0x56, 'V'),
+    (0x57, 'W'),
+    (0x58, 'X'),
+    (0x59, 'Y'),
+    (0x5A, 'Z'),
+    (0x65, '\u{20AC}'),
+];
@@ -0,0 +1,89 @@
+use super::NationalLanguage;
+
+/// Errors that can occur when encoding text using the GSM 7-bit default alphabet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Gsm7BitEncodeError {
+    /// The character can not be represented in the GSM 7-bit default alphabet or its extension table.
+    UnencodableCharacter {
+        /// The unencodable character.
+        character: char,
+        /// The byte index of the character in the input text.
+        index: usize,
+    },
+}
+
+impl core::fmt::Display for Gsm7BitEncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnencodableCharacter { character, index } => {
+                write!(
+                    f,
+                    "Character {character:?} at index {index} can not be encoded using the GSM 7-bit alphabet"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for Gsm7BitEncodeError {}
+
+/// Errors that can occur when decoding GSM 7-bit encoded text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Gsm7BitDecodeError {
+    /// An unpacked octet has its most significant bit set.
+    InvalidSeptet {
+        /// The invalid octet.
+        value: u8,
+        /// The index of the octet in the input.
+        index: usize,
+    },
+    /// The input ends with an escape septet that is not followed by an extension septet.
+    DanglingEscape,
+}
+
+impl core::fmt::Display for Gsm7BitDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidSeptet { value, index } => {
+                write!(f, "Invalid septet 0x{value:02X} at index {index}")
+            }
+            Self::DanglingEscape => {
+                write!(f, "Escape septet is not followed by an extension septet")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Gsm7BitDecodeError {}
+
+/// The national language shift table is not defined in 3GPP TS 23.038 or not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsupportedNationalLanguageError {
+    /// No locking shift table is available for the language.
+    LockingShift(NationalLanguage),
+    /// No single shift table is available for the language.
+    SingleShift(NationalLanguage),
+}
+
+impl core::fmt::Display for UnsupportedNationalLanguageError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::LockingShift(language) => {
+                write!(
+                    f,
+                    "No locking shift table for national language {language:?}"
+                )
+            }
+            Self::SingleShift(language) => {
+                write!(
+                    f,
+                    "No single shift table for national language {language:?}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for UnsupportedNationalLanguageError {}
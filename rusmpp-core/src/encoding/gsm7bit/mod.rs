@@ -0,0 +1,1193 @@
+//! GSM 03.38 7-bit default alphabet.
+//!
+//! Text is mapped to septets using the [default alphabet](alphabet::DEFAULT_ALPHABET).
+//! Characters of the [extension table](alphabet::EXTENSION_TABLE) take two septets,
+//! the [escape](alphabet::ESCAPE) septet followed by the character's septet.
+//!
+//! The default alphabet and the extension table can be replaced by national language
+//! locking shift and single shift tables (3GPP TS 23.038). The receiver learns about
+//! the shift tables through the national language information elements of the UDH,
+//! see [`Gsm7Bit::udh_bytes`].
+//!
+//! Septets are either [packed](Gsm7BitPacking::Packed), `8` septets in `7` octets,
+//! or [unpacked](Gsm7BitPacking::Unpacked), one septet per octet.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::encoding::gsm7bit::Gsm7Bit;
+//!
+//! let codec = Gsm7Bit::packed();
+//!
+//! let mut buf = [0u8; 16];
+//! let size = codec.encode("hellohello", &mut buf).unwrap();
+//!
+//! assert_eq!(&buf[..size], &[0xE8, 0x32, 0x9B, 0xFD, 0x46, 0x97, 0xD9, 0xEC, 0x37]);
+//!
+//! let decoded = codec
+//!     .decode(&buf[..size])
+//!     .collect::<Result<heapless::String<16>, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(decoded, "hellohello");
+//! ```
+//!
+//! # National language shift tables
+//!
+//! ```rust
+//! use rusmpp_core::encoding::gsm7bit::{Gsm7Bit, NationalLanguage};
+//!
+//! let text = "Günaydın, Şule!";
+//!
+//! let codec = Gsm7Bit::unpacked()
+//!     .select_national_language(text, &[NationalLanguage::Turkish])
+//!     .unwrap();
+//!
+//! assert_eq!(codec.locking_shift(), Some(NationalLanguage::Turkish));
+//! assert_eq!(codec.single_shift(), None);
+//!
+//! let mut buf = [0u8; 32];
+//! let size = codec.encode_with_udh(text, &mut buf).unwrap();
+//!
+//! // UDH length, locking shift IE, followed by the text
+//! assert_eq!(&buf[..4], &[0x03, 0x25, 0x01, 0x01]);
+//! assert_eq!(size, 4 + text.chars().count());
+//! ```
+
+use crate::values::DataCoding;
+
+pub mod alphabet;
+use alphabet::Alphabet;
+
+mod errors;
+pub use errors::{Gsm7BitDecodeError, Gsm7BitEncodeError, UnsupportedNationalLanguageError};
+
+mod national_language;
+pub use national_language::NationalLanguage;
+
+mod transliteration;
+pub use transliteration::transliterate;
+
+/// Packing of GSM 7-bit septets into octets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Gsm7BitPacking {
+    /// `8` septets are packed into `7` octets.
+    ///
+    /// `160` characters fit in `140` octets.
+    Packed,
+    /// Each septet occupies one octet.
+    ///
+    /// `140` characters fit in `140` octets.
+    #[default]
+    Unpacked,
+}
+
+/// GSM 03.38 7-bit default alphabet codec.
+///
+/// Used with [`DataCoding::McSpecific`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Gsm7Bit {
+    packing: Gsm7BitPacking,
+    locking_shift: Option<NationalLanguage>,
+    single_shift: Option<NationalLanguage>,
+}
+
+impl Gsm7Bit {
+    /// UDH information element identifier of the national language single shift.
+    const SINGLE_SHIFT_IEI: u8 = 0x24;
+
+    /// UDH information element identifier of the national language locking shift.
+    const LOCKING_SHIFT_IEI: u8 = 0x25;
+
+    /// Creates a new [`Gsm7Bit`] codec with the given [`Gsm7BitPacking`].
+    pub const fn new(packing: Gsm7BitPacking) -> Self {
+        Self {
+            packing,
+            locking_shift: None,
+            single_shift: None,
+        }
+    }
+
+    /// Creates a new [`Gsm7Bit`] codec with [`Gsm7BitPacking::Packed`].
+    pub const fn packed() -> Self {
+        Self::new(Gsm7BitPacking::Packed)
+    }
+
+    /// Creates a new [`Gsm7Bit`] codec with [`Gsm7BitPacking::Unpacked`].
+    pub const fn unpacked() -> Self {
+        Self::new(Gsm7BitPacking::Unpacked)
+    }
+
+    /// Returns the [`Gsm7BitPacking`] of the codec.
+    pub const fn packing(&self) -> Gsm7BitPacking {
+        self.packing
+    }
+
+    /// Returns the national language of the locking shift table, if any.
+    pub const fn locking_shift(&self) -> Option<NationalLanguage> {
+        self.locking_shift
+    }
+
+    /// Returns the national language of the single shift table, if any.
+    pub const fn single_shift(&self) -> Option<NationalLanguage> {
+        self.single_shift
+    }
+
+    /// Sets the national language locking shift table, replacing the default alphabet.
+    ///
+    /// `None` selects the default alphabet.
+    pub const fn with_locking_shift(
+        self,
+        language: Option<NationalLanguage>,
+    ) -> Result<Self, UnsupportedNationalLanguageError> {
+        if let Some(language) = language {
+            if alphabet::locking_shift_table(language).is_none() {
+                return Err(UnsupportedNationalLanguageError::LockingShift(language));
+            }
+        }
+
+        Ok(Self {
+            locking_shift: language,
+            ..self
+        })
+    }
+
+    /// Sets the national language single shift table, replacing the extension table.
+    ///
+    /// `None` selects the extension table.
+    pub const fn with_single_shift(
+        self,
+        language: Option<NationalLanguage>,
+    ) -> Result<Self, UnsupportedNationalLanguageError> {
+        if let Some(language) = language {
+            if alphabet::single_shift_table(language).is_none() {
+                return Err(UnsupportedNationalLanguageError::SingleShift(language));
+            }
+        }
+
+        Ok(Self {
+            single_shift: language,
+            ..self
+        })
+    }
+
+    /// Returns the [`Alphabet`] of the codec.
+    pub const fn alphabet(&self) -> Alphabet {
+        match Alphabet::new(self.locking_shift, self.single_shift) {
+            Ok(alphabet) => alphabet,
+            // The languages are checked by `with_locking_shift` and `with_single_shift`.
+            Err(_) => Alphabet::DEFAULT,
+        }
+    }
+
+    /// Returns the [`DataCoding`] of GSM 7-bit encoded short messages.
+    pub const fn data_coding(&self) -> DataCoding {
+        DataCoding::McSpecific
+    }
+
+    /// Selects the national language shift tables that encode `text` in the fewest octets,
+    /// including the UDH required to announce them.
+    ///
+    /// The default alphabet and extension table are preferred if they can encode `text`.
+    /// Languages without shift tables are ignored.
+    pub fn select_national_language(
+        self,
+        text: &str,
+        languages: &[NationalLanguage],
+    ) -> Result<Self, Gsm7BitEncodeError> {
+        let default = Self::new(self.packing);
+
+        let candidates = core::iter::once(default).chain(languages.iter().flat_map(|&language| {
+            [
+                default.with_single_shift(Some(language)),
+                default.with_locking_shift(Some(language)),
+                default
+                    .with_locking_shift(Some(language))
+                    .and_then(|codec| codec.with_single_shift(Some(language))),
+            ]
+            .into_iter()
+            .flatten()
+        }));
+
+        let mut selected: Option<(Self, usize)> = None;
+
+        for codec in candidates {
+            if let Ok(length) = codec.encoded_length_with_udh(text) {
+                if selected.is_none_or(|(_, selected_length)| length < selected_length) {
+                    selected = Some((codec, length));
+                }
+            }
+        }
+
+        match selected {
+            Some((codec, _)) => Ok(codec),
+            // Reports the first character the default alphabet can not encode.
+            None => default.septet_count(text).map(|_| default),
+        }
+    }
+
+    /// Returns `true` if every character of `text` can be encoded.
+    pub fn is_encodable(&self, text: &str) -> bool {
+        let alphabet = self.alphabet();
+
+        text.chars()
+            .all(|character| septets(&alphabet, character).is_some())
+    }
+
+    /// Returns the number of septets required to encode `text`.
+    ///
+    /// Characters of the single shift table count as two septets.
+    pub fn septet_count(&self, text: &str) -> Result<usize, Gsm7BitEncodeError> {
+        let alphabet = self.alphabet();
+
+        text.char_indices()
+            .try_fold(0, |count, (index, character)| {
+                septets(&alphabet, character)
+                    .map(|(_, size)| count + size)
+                    .ok_or(Gsm7BitEncodeError::UnencodableCharacter { character, index })
+            })
+    }
+
+    /// Returns the number of octets required to encode `text`.
+    pub fn encoded_length(&self, text: &str) -> Result<usize, Gsm7BitEncodeError> {
+        self.encoded_length_after_udh(0, text)
+    }
+
+    /// Returns the number of octets required to encode `text` after a UDH of `udh_length` octets.
+    ///
+    /// The UDH itself is not included.
+    pub fn encoded_length_after_udh(
+        &self,
+        udh_length: usize,
+        text: &str,
+    ) -> Result<usize, Gsm7BitEncodeError> {
+        let septets = self.septet_count(text)?;
+
+        match self.packing {
+            Gsm7BitPacking::Unpacked => Ok(septets),
+            Gsm7BitPacking::Packed => {
+                let fill_bits = fill_bits(udh_length);
+
+                let extra_cr = needs_extra_cr(text, fill_bits, septets) as usize;
+
+                Ok(packed_length(fill_bits, septets + extra_cr))
+            }
+        }
+    }
+
+    /// Returns the number of octets required to encode `text` including the national language UDH.
+    ///
+    /// See [`Gsm7Bit::encode_with_udh`].
+    pub fn encoded_length_with_udh(&self, text: &str) -> Result<usize, Gsm7BitEncodeError> {
+        let udh_length = self.udh_length();
+
+        Ok(udh_length + self.encoded_length_after_udh(udh_length, text)?)
+    }
+
+    /// Encodes `text` into `dst`.
+    ///
+    /// Returns the number of octets written.
+    ///
+    /// # Note
+    ///
+    /// When packing, the spare bits of the last octet are filled with a `<CR>` if they can hold a whole septet.
+    /// A text ending with `<CR>` on an octet boundary gets an additional `<CR>`, as required by GSM 03.38,
+    /// so that the receiver does not mistake it for padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than [`Gsm7Bit::encoded_length`].
+    pub fn encode(&self, text: &str, dst: &mut [u8]) -> Result<usize, Gsm7BitEncodeError> {
+        self.encode_after_udh(0, text, dst)
+    }
+
+    /// Encodes `text` into `dst`, which directly follows a UDH of `udh_length` octets.
+    ///
+    /// When packing, the septets are aligned to the septet boundary following the UDH
+    /// by leading fill bits.
+    ///
+    /// Returns the number of octets written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than [`Gsm7Bit::encoded_length_after_udh`].
+    pub fn encode_after_udh(
+        &self,
+        udh_length: usize,
+        text: &str,
+        dst: &mut [u8],
+    ) -> Result<usize, Gsm7BitEncodeError> {
+        let alphabet = self.alphabet();
+        let septet_count = self.septet_count(text)?;
+        let length = self.encoded_length_after_udh(udh_length, text)?;
+        let fill_bits = fill_bits(udh_length);
+
+        let mut writer = SeptetWriter::new(self.packing, fill_bits, &mut dst[..length]);
+
+        for character in text.chars() {
+            // Every character is encodable, checked by `septet_count`.
+            if let Some((septets, size)) = septets(&alphabet, character) {
+                for septet in &septets[..size] {
+                    writer.write(*septet);
+                }
+            }
+        }
+
+        if let Gsm7BitPacking::Packed = self.packing {
+            if needs_extra_cr(text, fill_bits, septet_count) {
+                writer.write(alphabet::CR);
+            }
+        }
+
+        Ok(writer.finish())
+    }
+
+    /// Encodes the national language UDH followed by `text` into `dst`.
+    ///
+    /// If the codec uses national language shift tables, the UDH Indicator must be set
+    /// in the `esm_class` of the PDU carrying the encoded bytes. See [`Gsm7Bit::udh_bytes`].
+    ///
+    /// Returns the number of octets written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than [`Gsm7Bit::encoded_length_with_udh`].
+    pub fn encode_with_udh(&self, text: &str, dst: &mut [u8]) -> Result<usize, Gsm7BitEncodeError> {
+        let udh = self.udh_bytes();
+
+        // Validate before writing anything.
+        self.septet_count(text)?;
+
+        dst[..udh.len()].copy_from_slice(&udh);
+
+        let size = self.encode_after_udh(udh.len(), text, &mut dst[udh.len()..])?;
+
+        Ok(udh.len() + size)
+    }
+
+    /// Decodes `src` into an iterator of characters.
+    ///
+    /// Escape septets followed by a septet not found in the single shift table
+    /// are decoded using the locking shift table, as required by GSM 03.38.
+    pub fn decode<'a>(&self, src: &'a [u8]) -> Gsm7BitChars<'a> {
+        self.decode_after_udh(0, src)
+    }
+
+    /// Decodes `src`, which directly follows a UDH of `udh_length` octets, into an iterator of characters.
+    ///
+    /// See [`Gsm7Bit::encode_after_udh`].
+    pub fn decode_after_udh<'a>(&self, udh_length: usize, src: &'a [u8]) -> Gsm7BitChars<'a> {
+        Gsm7BitChars::new(self.alphabet(), self.packing, fill_bits(udh_length), src)
+    }
+
+    /// Returns `true` if the codec uses national language shift tables.
+    ///
+    /// The encoded text must then be preceded by the national language UDH.
+    pub const fn has_udh(&self) -> bool {
+        self.locking_shift.is_some() || self.single_shift.is_some()
+    }
+
+    /// Returns the length of the national language UDH in octets, including the UDH length octet.
+    ///
+    /// `0` if the codec does not use national language shift tables.
+    pub const fn udh_length(&self) -> usize {
+        let information_elements =
+            self.locking_shift.is_some() as usize + self.single_shift.is_some() as usize;
+
+        match information_elements {
+            0 => 0,
+            n => 1 + n * 3,
+        }
+    }
+
+    /// Returns the national language UDH, including the UDH length octet.
+    ///
+    /// Empty if the codec does not use national language shift tables.
+    ///
+    /// # Format
+    ///
+    /// ```txt
+    /// 06 24 01 LL 25 01 LL
+    /// │  │  │  │  │  │  └─ National language identifier
+    /// │  │  │  │  │  └──── IE Data Length = 1
+    /// │  │  │  │  └─────── IEI = 25 (locking shift)
+    /// │  │  │  └────────── National language identifier
+    /// │  │  └───────────── IE Data Length = 1
+    /// │  └──────────────── IEI = 24 (single shift)
+    /// └─────────────────── UDH length
+    /// ```
+    pub fn udh_bytes(&self) -> heapless::Vec<u8, 7> {
+        let mut udh = heapless::Vec::new();
+
+        if !self.has_udh() {
+            return udh;
+        }
+
+        // The capacity fits the UDH length octet and both information elements.
+        let _ = udh.push((self.udh_length() - 1) as u8);
+
+        if let Some(language) = self.single_shift {
+            let _ = udh.extend_from_slice(&[Self::SINGLE_SHIFT_IEI, 0x01, language.into()]);
+        }
+
+        if let Some(language) = self.locking_shift {
+            let _ = udh.extend_from_slice(&[Self::LOCKING_SHIFT_IEI, 0x01, language.into()]);
+        }
+
+        udh
+    }
+
+    /// Encodes `text` into a newly allocated [`Vec`](alloc::vec::Vec).
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode_to_vec(&self, text: &str) -> Result<alloc::vec::Vec<u8>, Gsm7BitEncodeError> {
+        let mut buf = alloc::vec![0; self.encoded_length(text)?];
+
+        let size = self.encode(text, &mut buf)?;
+
+        buf.truncate(size);
+
+        Ok(buf)
+    }
+
+    /// Encodes the national language UDH followed by `text` into a newly allocated [`Vec`](alloc::vec::Vec).
+    ///
+    /// See [`Gsm7Bit::encode_with_udh`].
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode_to_vec_with_udh(
+        &self,
+        text: &str,
+    ) -> Result<alloc::vec::Vec<u8>, Gsm7BitEncodeError> {
+        let mut buf = alloc::vec![0; self.encoded_length_with_udh(text)?];
+
+        let size = self.encode_with_udh(text, &mut buf)?;
+
+        buf.truncate(size);
+
+        Ok(buf)
+    }
+
+    /// Decodes `src` into a newly allocated [`String`](alloc::string::String).
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_string(
+        &self,
+        src: &[u8],
+    ) -> Result<alloc::string::String, Gsm7BitDecodeError> {
+        self.decode(src).collect()
+    }
+
+    /// Replaces every character of `text` that can not be encoded by a [transliteration](transliterate),
+    /// or by `?` if there is none.
+    ///
+    /// The result is always encodable.
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn transliterate(&self, text: &str) -> alloc::string::String {
+        let alphabet = self.alphabet();
+
+        let encodable = |character: &char| septets(&alphabet, *character).is_some();
+
+        let mut transliterated = alloc::string::String::with_capacity(text.len());
+
+        for character in text.chars() {
+            if encodable(&character) {
+                transliterated.push(character);
+
+                continue;
+            }
+
+            match transliterate(character) {
+                Some(replacement) if replacement.chars().all(|c| encodable(&c)) => {
+                    transliterated.push_str(replacement)
+                }
+                _ if encodable(&'?') => transliterated.push('?'),
+                _ => {}
+            }
+        }
+
+        transliterated
+    }
+}
+
+/// Returns the septets of `character` and their count.
+fn septets(alphabet: &Alphabet, character: char) -> Option<([u8; 2], usize)> {
+    if let Some(septet) = alphabet.locking_shift_septet(character) {
+        return Some(([septet, 0], 1));
+    }
+
+    alphabet
+        .single_shift_septet(character)
+        .map(|septet| ([alphabet::ESCAPE, septet], 2))
+}
+
+/// Returns the number of fill bits aligning the first septet after a UDH of `udh_length` octets.
+const fn fill_bits(udh_length: usize) -> usize {
+    (7 - (udh_length * 8) % 7) % 7
+}
+
+/// Returns the number of octets required to pack `septets` septets after `fill_bits` fill bits.
+const fn packed_length(fill_bits: usize, septets: usize) -> usize {
+    (fill_bits + septets * 7).div_ceil(8)
+}
+
+/// A text ending with `<CR>` on an octet boundary needs an additional `<CR>`.
+fn needs_extra_cr(text: &str, fill_bits: usize, septets: usize) -> bool {
+    septets > 0 && (fill_bits + septets * 7) % 8 == 0 && text.ends_with('\r')
+}
+
+/// Writes septets into octets.
+struct SeptetWriter<'a> {
+    packing: Gsm7BitPacking,
+    fill_bits: usize,
+    dst: &'a mut [u8],
+    /// Number of septets written.
+    septets: usize,
+}
+
+impl<'a> SeptetWriter<'a> {
+    fn new(packing: Gsm7BitPacking, fill_bits: usize, dst: &'a mut [u8]) -> Self {
+        dst.fill(0);
+
+        Self {
+            packing,
+            fill_bits,
+            dst,
+            septets: 0,
+        }
+    }
+
+    fn write(&mut self, septet: u8) {
+        match self.packing {
+            Gsm7BitPacking::Unpacked => {
+                self.dst[self.septets] = septet;
+            }
+            Gsm7BitPacking::Packed => {
+                let bit = self.fill_bits + self.septets * 7;
+                let index = bit / 8;
+                let shift = bit % 8;
+
+                self.dst[index] |= septet << shift;
+
+                if shift > 1 {
+                    self.dst[index + 1] |= septet >> (8 - shift);
+                }
+            }
+        }
+
+        self.septets += 1;
+    }
+
+    /// Fills the spare bits and returns the number of octets written.
+    fn finish(self) -> usize {
+        match self.packing {
+            Gsm7BitPacking::Unpacked => self.septets,
+            Gsm7BitPacking::Packed => {
+                let size = packed_length(self.fill_bits, self.septets);
+
+                // The spare bits can hold a whole septet.
+                if size * 8 - self.fill_bits - self.septets * 7 == 7 {
+                    self.dst[size - 1] |= alphabet::CR << 1;
+                }
+
+                size
+            }
+        }
+    }
+}
+
+/// Reads septets from octets.
+#[derive(Debug, Clone)]
+struct SeptetReader<'a> {
+    packing: Gsm7BitPacking,
+    fill_bits: usize,
+    src: &'a [u8],
+    /// Index of the next septet.
+    index: usize,
+    /// Number of septets in `src`.
+    count: usize,
+}
+
+impl<'a> SeptetReader<'a> {
+    fn new(packing: Gsm7BitPacking, fill_bits: usize, src: &'a [u8]) -> Self {
+        let count = match packing {
+            Gsm7BitPacking::Unpacked => src.len(),
+            Gsm7BitPacking::Packed => {
+                let bits = (src.len() * 8).saturating_sub(fill_bits);
+                let count = bits / 7;
+
+                // The spare bits of the last octet were filled with a `<CR>`.
+                if bits % 7 == 0
+                    && count > 0
+                    && Self::packed_septet(src, fill_bits, count - 1) == alphabet::CR
+                {
+                    count - 1
+                } else {
+                    count
+                }
+            }
+        };
+
+        Self {
+            packing,
+            fill_bits,
+            src,
+            index: 0,
+            count,
+        }
+    }
+
+    fn packed_septet(src: &[u8], fill_bits: usize, index: usize) -> u8 {
+        let bit = fill_bits + index * 7;
+        let octet = bit / 8;
+        let shift = bit % 8;
+
+        let mut septet = src[octet] >> shift;
+
+        if shift > 1 {
+            septet |= src[octet + 1] << (8 - shift);
+        }
+
+        septet & 0x7F
+    }
+}
+
+impl Iterator for SeptetReader<'_> {
+    type Item = Result<u8, Gsm7BitDecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let index = self.index;
+
+        self.index += 1;
+
+        match self.packing {
+            Gsm7BitPacking::Packed => {
+                Some(Ok(Self::packed_septet(self.src, self.fill_bits, index)))
+            }
+            Gsm7BitPacking::Unpacked => match self.src[index] {
+                value if value > 0x7F => {
+                    Some(Err(Gsm7BitDecodeError::InvalidSeptet { value, index }))
+                }
+                value => Some(Ok(value)),
+            },
+        }
+    }
+}
+
+/// Iterator over the characters of GSM 7-bit encoded text.
+///
+/// Created by [`Gsm7Bit::decode`].
+#[derive(Debug, Clone)]
+pub struct Gsm7BitChars<'a> {
+    alphabet: Alphabet,
+    septets: SeptetReader<'a>,
+    done: bool,
+}
+
+impl<'a> Gsm7BitChars<'a> {
+    fn new(alphabet: Alphabet, packing: Gsm7BitPacking, fill_bits: usize, src: &'a [u8]) -> Self {
+        Self {
+            alphabet,
+            septets: SeptetReader::new(packing, fill_bits, src),
+            done: false,
+        }
+    }
+
+    fn next_char(&mut self) -> Option<Result<char, Gsm7BitDecodeError>> {
+        let septet = match self.septets.next()? {
+            Ok(septet) => septet,
+            Err(err) => return Some(Err(err)),
+        };
+
+        if septet != alphabet::ESCAPE {
+            return Some(Ok(self.alphabet.locking_shift_char(septet)));
+        }
+
+        match self.septets.next() {
+            None => Some(Err(Gsm7BitDecodeError::DanglingEscape)),
+            Some(Err(err)) => Some(Err(err)),
+            Some(Ok(septet)) => Some(Ok(self
+                .alphabet
+                .single_shift_char(septet)
+                .unwrap_or(self.alphabet.locking_shift_char(septet)))),
+        }
+    }
+}
+
+impl Iterator for Gsm7BitChars<'_> {
+    type Item = Result<char, Gsm7BitDecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let next = self.next_char();
+
+        if let Some(Err(_)) = next {
+            self.done = true;
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::*;
+
+    const TEXT: &str = "Hello @£$¥ {€} [~] |^\\ ÆØÅ æøå ΔΦΓΛΩΠΨΣΘΞ";
+
+    #[test]
+    fn septet_count() {
+        let codec = Gsm7Bit::unpacked();
+
+        assert_eq!(codec.septet_count("").unwrap(), 0);
+        assert_eq!(codec.septet_count("Hello").unwrap(), 5);
+        assert_eq!(codec.septet_count("{€}").unwrap(), 6);
+        assert_eq!(
+            codec.septet_count("Hi 😀").unwrap_err(),
+            Gsm7BitEncodeError::UnencodableCharacter {
+                character: '😀',
+                index: 3
+            }
+        );
+    }
+
+    #[test]
+    fn is_encodable() {
+        assert!(Gsm7Bit::unpacked().is_encodable(TEXT));
+        assert!(!Gsm7Bit::unpacked().is_encodable("Привет"));
+    }
+
+    mod unpacked {
+        use super::*;
+
+        #[test]
+        fn encode() {
+            let encoded = Gsm7Bit::unpacked().encode_to_vec("@a€").unwrap();
+
+            assert_eq!(encoded, [0x00, 0x61, 0x1B, 0x65]);
+        }
+
+        #[test]
+        fn decode() {
+            let decoded = Gsm7Bit::unpacked()
+                .decode_to_string(&[0x00, 0x61, 0x1B, 0x65])
+                .unwrap();
+
+            assert_eq!(decoded, "@a€");
+        }
+
+        #[test]
+        fn decode_invalid_septet() {
+            let err = Gsm7Bit::unpacked()
+                .decode_to_string(&[0x61, 0x80])
+                .unwrap_err();
+
+            assert_eq!(
+                err,
+                Gsm7BitDecodeError::InvalidSeptet {
+                    value: 0x80,
+                    index: 1
+                }
+            );
+        }
+
+        #[test]
+        fn decode_dangling_escape() {
+            let err = Gsm7Bit::unpacked()
+                .decode_to_string(&[0x61, 0x1B])
+                .unwrap_err();
+
+            assert_eq!(err, Gsm7BitDecodeError::DanglingEscape);
+        }
+
+        #[test]
+        fn decode_unknown_extension_falls_back_to_default_alphabet() {
+            let decoded = Gsm7Bit::unpacked().decode_to_string(&[0x1B, 0x61]).unwrap();
+
+            assert_eq!(decoded, "a");
+        }
+
+        #[test]
+        fn round_trip() {
+            let codec = Gsm7Bit::unpacked();
+
+            let encoded = codec.encode_to_vec(TEXT).unwrap();
+            let decoded = codec.decode_to_string(&encoded).unwrap();
+
+            assert_eq!(decoded, TEXT);
+        }
+    }
+
+    mod packed {
+        use super::*;
+
+        #[test]
+        fn encode() {
+            let encoded = Gsm7Bit::packed().encode_to_vec("hellohello").unwrap();
+
+            assert_eq!(
+                encoded,
+                [0xE8, 0x32, 0x9B, 0xFD, 0x46, 0x97, 0xD9, 0xEC, 0x37]
+            );
+        }
+
+        #[test]
+        fn encode_fills_spare_bits_with_cr() {
+            // 7 septets leave 7 spare bits in the last octet.
+            let encoded = Gsm7Bit::packed().encode_to_vec("1234567").unwrap();
+
+            assert_eq!(encoded, [0x31, 0xD9, 0x8C, 0x56, 0xB3, 0xDD, 0x1A]);
+        }
+
+        #[test]
+        fn encode_160_characters_in_140_octets() {
+            let text = "a".repeat(160);
+
+            let encoded = Gsm7Bit::packed().encode_to_vec(&text).unwrap();
+
+            assert_eq!(encoded.len(), 140);
+        }
+
+        #[test]
+        fn decode() {
+            let decoded = Gsm7Bit::packed()
+                .decode_to_string(&[0xE8, 0x32, 0x9B, 0xFD, 0x46, 0x97, 0xD9, 0xEC, 0x37])
+                .unwrap();
+
+            assert_eq!(decoded, "hellohello");
+        }
+
+        #[test]
+        fn decode_strips_cr_padding() {
+            let decoded = Gsm7Bit::packed()
+                .decode_to_string(&[0x31, 0xD9, 0x8C, 0x56, 0xB3, 0xDD, 0x1A])
+                .unwrap();
+
+            assert_eq!(decoded, "1234567");
+        }
+
+        #[test]
+        fn trailing_cr_on_octet_boundary() {
+            let codec = Gsm7Bit::packed();
+
+            let encoded = codec.encode_to_vec("1234567\r").unwrap();
+
+            assert_eq!(encoded.len(), 8);
+
+            let decoded = codec.decode_to_string(&encoded).unwrap();
+
+            // <CR><CR> is equivalent to <CR> as defined in GSM 03.38.
+            assert_eq!(decoded, "1234567\r\r");
+        }
+
+        #[test]
+        fn round_trip() {
+            let codec = Gsm7Bit::packed();
+
+            for length in 0..=alphabet::DEFAULT_ALPHABET.len() {
+                let text = alphabet::DEFAULT_ALPHABET
+                    .iter()
+                    .copied()
+                    .filter(|&c| codec.alphabet().locking_shift_septet(c).is_some())
+                    .chain(alphabet::EXTENSION_TABLE.iter().map(|(_, c)| *c))
+                    .filter(|&c| c != '\r')
+                    .take(length)
+                    .collect::<String>();
+
+                let encoded = codec.encode_to_vec(&text).unwrap();
+
+                assert_eq!(encoded.len(), codec.encoded_length(&text).unwrap());
+
+                let decoded = codec.decode_to_string(&encoded).unwrap();
+
+                assert_eq!(decoded, text);
+            }
+        }
+
+        #[test]
+        fn round_trip_after_udh() {
+            let codec = Gsm7Bit::packed();
+
+            for udh_length in 0..8 {
+                for length in 0..20 {
+                    let text = "abcdefghijklmnopqrstuvwxyz"[..length].replace('e', "€");
+
+                    let mut buf = [0u8; 32];
+                    let size = codec.encode_after_udh(udh_length, &text, &mut buf).unwrap();
+
+                    assert_eq!(
+                        size,
+                        codec.encoded_length_after_udh(udh_length, &text).unwrap()
+                    );
+
+                    let decoded = codec
+                        .decode_after_udh(udh_length, &buf[..size])
+                        .collect::<Result<String, _>>()
+                        .unwrap();
+
+                    assert_eq!(decoded, text);
+                }
+            }
+        }
+
+        #[test]
+        fn encode_after_udh_aligns_to_septet_boundary() {
+            // A 6 octet UDH (48 bits) requires 1 fill bit.
+            let mut buf = [0u8; 8];
+            let size = Gsm7Bit::packed()
+                .encode_after_udh(6, "a", &mut buf)
+                .unwrap();
+
+            assert_eq!(&buf[..size], &[0x61 << 1]);
+        }
+    }
+
+    mod national_language {
+        use super::*;
+
+        #[test]
+        fn unsupported() {
+            assert_eq!(
+                Gsm7Bit::unpacked()
+                    .with_locking_shift(Some(NationalLanguage::Spanish))
+                    .unwrap_err(),
+                UnsupportedNationalLanguageError::LockingShift(NationalLanguage::Spanish)
+            );
+
+            assert_eq!(
+                Gsm7Bit::unpacked()
+                    .with_single_shift(Some(NationalLanguage::Other(0x20)))
+                    .unwrap_err(),
+                UnsupportedNationalLanguageError::SingleShift(NationalLanguage::Other(0x20))
+            );
+        }
+
+        #[test]
+        fn turkish_single_shift() {
+            let codec = Gsm7Bit::unpacked()
+                .with_single_shift(Some(NationalLanguage::Turkish))
+                .unwrap();
+
+            let encoded = codec.encode_to_vec("Şç").unwrap();
+
+            assert_eq!(encoded, [0x1B, 0x53, 0x1B, 0x63]);
+            assert_eq!(codec.decode_to_string(&encoded).unwrap(), "Şç");
+        }
+
+        #[test]
+        fn turkish_locking_shift() {
+            let codec = Gsm7Bit::unpacked()
+                .with_locking_shift(Some(NationalLanguage::Turkish))
+                .unwrap();
+
+            let encoded = codec.encode_to_vec("İğ€").unwrap();
+
+            assert_eq!(encoded, [0x40, 0x0C, 0x04]);
+            assert_eq!(codec.decode_to_string(&encoded).unwrap(), "İğ€");
+        }
+
+        #[test]
+        fn hindi_round_trip() {
+            let codec = Gsm7Bit::packed()
+                .with_locking_shift(Some(NationalLanguage::Hindi))
+                .unwrap()
+                .with_single_shift(Some(NationalLanguage::Hindi))
+                .unwrap();
+
+            let text = "नमस्ते दुनिया! ०१२ @ ABC";
+
+            let encoded = codec.encode_to_vec(text).unwrap();
+
+            assert_eq!(codec.decode_to_string(&encoded).unwrap(), text);
+        }
+
+        #[test]
+        fn indian_languages_round_trip() {
+            for language in [
+                NationalLanguage::Bengali,
+                NationalLanguage::Gujarati,
+                NationalLanguage::Hindi,
+                NationalLanguage::Kannada,
+                NationalLanguage::Malayalam,
+                NationalLanguage::Oriya,
+                NationalLanguage::Punjabi,
+                NationalLanguage::Tamil,
+                NationalLanguage::Telugu,
+                NationalLanguage::Urdu,
+            ] {
+                let locking_shift = alphabet::locking_shift_table(language).unwrap();
+                let single_shift = alphabet::single_shift_table(language).unwrap();
+
+                let alphabet = Alphabet::new(Some(language), Some(language)).unwrap();
+
+                for (septet, &character) in locking_shift.iter().enumerate() {
+                    if septet as u8 == alphabet::ESCAPE || character == '\u{FFFF}' {
+                        continue;
+                    }
+
+                    assert_eq!(
+                        alphabet.locking_shift_septet(character),
+                        Some(septet as u8),
+                        "{language:?} {character:?}"
+                    );
+                }
+
+                let text: String = locking_shift
+                    .iter()
+                    .chain(single_shift.iter().map(|(_, character)| character))
+                    .filter(|&&character| character != '\u{FFFF}')
+                    .collect();
+
+                let codec = Gsm7Bit::unpacked()
+                    .with_locking_shift(Some(language))
+                    .unwrap()
+                    .with_single_shift(Some(language))
+                    .unwrap();
+
+                let encoded = codec.encode_to_vec(&text).unwrap();
+
+                assert_eq!(
+                    codec.decode_to_string(&encoded).unwrap(),
+                    text,
+                    "{language:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn unknown_single_shift_septet_falls_back_to_locking_shift() {
+            let codec = Gsm7Bit::unpacked()
+                .with_locking_shift(Some(NationalLanguage::Portuguese))
+                .unwrap();
+
+            // 0x1B 0x04 is not defined in the extension table.
+            let decoded = codec.decode_to_string(&[0x1B, 0x04]).unwrap();
+
+            assert_eq!(decoded, "ê");
+        }
+
+        #[test]
+        fn select_prefers_default_alphabet() {
+            let codec = Gsm7Bit::unpacked()
+                .select_national_language("Hello", &[NationalLanguage::Turkish])
+                .unwrap();
+
+            assert_eq!(codec, Gsm7Bit::unpacked());
+            assert!(codec.udh_bytes().is_empty());
+        }
+
+        #[test]
+        fn select_single_shift() {
+            // Spanish has no locking shift table.
+            let codec = Gsm7Bit::packed()
+                .select_national_language("Canción", &[NationalLanguage::Spanish])
+                .unwrap();
+
+            assert_eq!(codec.single_shift(), Some(NationalLanguage::Spanish));
+            assert_eq!(codec.locking_shift(), None);
+            assert_eq!(codec.udh_bytes().as_slice(), &[0x03, 0x24, 0x01, 0x02]);
+        }
+
+        #[test]
+        fn select_locking_shift() {
+            let codec = Gsm7Bit::packed()
+                .select_national_language(
+                    "Canción",
+                    &[NationalLanguage::Spanish, NationalLanguage::Portuguese],
+                )
+                .unwrap();
+
+            assert_eq!(codec.locking_shift(), Some(NationalLanguage::Portuguese));
+            assert_eq!(codec.single_shift(), None);
+        }
+
+        #[test]
+        fn select_locking_and_single_shift() {
+            let codec = Gsm7Bit::unpacked()
+                .select_national_language("नमस्ते @", &[NationalLanguage::Hindi])
+                .unwrap();
+
+            assert_eq!(codec.locking_shift(), Some(NationalLanguage::Hindi));
+            assert_eq!(codec.single_shift(), Some(NationalLanguage::Hindi));
+            assert_eq!(
+                codec.udh_bytes().as_slice(),
+                &[0x06, 0x24, 0x01, 0x06, 0x25, 0x01, 0x06]
+            );
+        }
+
+        #[test]
+        fn select_unencodable() {
+            let err = Gsm7Bit::unpacked()
+                .select_national_language("Привет", &[NationalLanguage::Turkish])
+                .unwrap_err();
+
+            assert_eq!(
+                err,
+                Gsm7BitEncodeError::UnencodableCharacter {
+                    character: 'П',
+                    index: 0
+                }
+            );
+        }
+
+        #[test]
+        fn encode_with_udh_round_trip() {
+            let codec = Gsm7Bit::packed()
+                .with_locking_shift(Some(NationalLanguage::Turkish))
+                .unwrap()
+                .with_single_shift(Some(NationalLanguage::Turkish))
+                .unwrap();
+
+            let text = "Günaydın ğüşiöç {€}";
+
+            let encoded = codec.encode_to_vec_with_udh(text).unwrap();
+
+            assert_eq!(&encoded[..7], &[0x06, 0x24, 0x01, 0x01, 0x25, 0x01, 0x01]);
+
+            let decoded = codec
+                .decode_after_udh(7, &encoded[7..])
+                .collect::<Result<String, _>>()
+                .unwrap();
+
+            assert_eq!(decoded, text);
+        }
+    }
+
+    #[test]
+    fn encode_unencodable_character() {
+        let mut buf = [0u8; 16];
+
+        let err = Gsm7Bit::packed().encode("abc€ш", &mut buf).unwrap_err();
+
+        assert_eq!(
+            err,
+            Gsm7BitEncodeError::UnencodableCharacter {
+                character: 'ш',
+                index: 6
+            }
+        );
+    }
+
+    #[test]
+    fn transliterate() {
+        let codec = Gsm7Bit::unpacked();
+
+        assert_eq!(
+            codec.transliterate("“Olá” – façade… ш"),
+            "\"Ola\" - fa\u{00C7}ade... ?"
+        );
+
+        // Unchanged if encodable
+        assert_eq!(codec.transliterate(TEXT), TEXT);
+    }
+}
@@ -0,0 +1,31 @@
+use rusmpp_macros::Rusmpp;
+
+/// National language identifier of the GSM 7-bit national language shift tables.
+///
+/// See 3GPP TS 23.038 6.2.1.2.4.
+#[non_exhaustive]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[rusmpp(test = skip)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
+pub enum NationalLanguage {
+    Turkish = 0x01,
+    Spanish = 0x02,
+    Portuguese = 0x03,
+    Bengali = 0x04,
+    Gujarati = 0x05,
+    Hindi = 0x06,
+    Kannada = 0x07,
+    Malayalam = 0x08,
+    Oriya = 0x09,
+    Punjabi = 0x0A,
+    Tamil = 0x0B,
+    Telugu = 0x0C,
+    Urdu = 0x0D,
+    Other(u8),
+}
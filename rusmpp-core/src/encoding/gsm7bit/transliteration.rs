@@ -0,0 +1,121 @@
+/// Returns a replacement for `character` made of characters of the GSM 7-bit default alphabet.
+///
+/// Covers typographic punctuation, special spaces and the accented Latin letters
+/// missing from the default alphabet. Returns [`None`] if no sensible replacement exists.
+///
+/// Characters already found in the default alphabet are not transliterated.
+pub const fn transliterate(character: char) -> Option<&'static str> {
+    let replacement = match character {
+        // Quotes and apostrophes
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' | '\u{00B4}' | '`' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' | '\u{00AB}'
+        | '\u{00BB}' => "\"",
+        '\u{2039}' => "<",
+        '\u{203A}' => ">",
+        // Dashes
+        '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2015}'
+        | '\u{2212}' => "-",
+        // Spaces
+        '\u{00A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => " ",
+        // Punctuation and symbols
+        '\u{2026}' => "...",
+        '\u{2022}' | '\u{00B7}' => "*",
+        '\u{00A2}' => "c",
+        '\u{00A9}' => "(C)",
+        '\u{00AE}' => "(R)",
+        '\u{2122}' => "TM",
+        '\u{00B0}' => "o",
+        '\u{00D7}' => "x",
+        '\u{00F7}' => "/",
+        '\u{00B9}' => "1",
+        '\u{00B2}' => "2",
+        '\u{00B3}' => "3",
+        '\u{00BD}' => "1/2",
+        '\u{00BC}' => "1/4",
+        '\u{00BE}' => "3/4",
+        // Latin-1 Supplement
+        '\u{00C0}' | '\u{00C1}' | '\u{00C2}' | '\u{00C3}' => "A",
+        '\u{00C8}' | '\u{00CA}' | '\u{00CB}' => "E",
+        '\u{00CC}' | '\u{00CD}' | '\u{00CE}' | '\u{00CF}' => "I",
+        '\u{00D0}' => "D",
+        '\u{00D2}' | '\u{00D3}' | '\u{00D4}' | '\u{00D5}' => "O",
+        '\u{00D9}' | '\u{00DA}' | '\u{00DB}' => "U",
+        '\u{00DD}' => "Y",
+        '\u{00DE}' => "Th",
+        '\u{00E1}' | '\u{00E2}' | '\u{00E3}' => "a",
+        '\u{00E7}' => "\u{00C7}",
+        '\u{00EA}' | '\u{00EB}' => "e",
+        '\u{00ED}' | '\u{00EE}' | '\u{00EF}' => "i",
+        '\u{00F0}' => "d",
+        '\u{00F3}' | '\u{00F4}' | '\u{00F5}' => "o",
+        '\u{00FA}' | '\u{00FB}' => "u",
+        '\u{00FD}' | '\u{00FF}' => "y",
+        '\u{00FE}' => "th",
+        // Latin Extended-A
+        '\u{0100}' | '\u{0102}' | '\u{0104}' => "A",
+        '\u{0101}' | '\u{0103}' | '\u{0105}' => "a",
+        '\u{0106}' | '\u{0108}' | '\u{010A}' | '\u{010C}' => "C",
+        '\u{0107}' | '\u{0109}' | '\u{010B}' | '\u{010D}' => "c",
+        '\u{010E}' | '\u{0110}' => "D",
+        '\u{010F}' | '\u{0111}' => "d",
+        '\u{0112}' | '\u{0114}' | '\u{0116}' | '\u{0118}' | '\u{011A}' => "E",
+        '\u{0113}' | '\u{0115}' | '\u{0117}' | '\u{0119}' | '\u{011B}' => "e",
+        '\u{011C}' | '\u{011E}' | '\u{0120}' | '\u{0122}' => "G",
+        '\u{011D}' | '\u{011F}' | '\u{0121}' | '\u{0123}' => "g",
+        '\u{0124}' | '\u{0126}' => "H",
+        '\u{0125}' | '\u{0127}' => "h",
+        '\u{0128}' | '\u{012A}' | '\u{012C}' | '\u{012E}' | '\u{0130}' => "I",
+        '\u{0129}' | '\u{012B}' | '\u{012D}' | '\u{012F}' | '\u{0131}' => "i",
+        '\u{0134}' => "J",
+        '\u{0135}' => "j",
+        '\u{0136}' => "K",
+        '\u{0137}' => "k",
+        '\u{0139}' | '\u{013B}' | '\u{013D}' | '\u{013F}' | '\u{0141}' => "L",
+        '\u{013A}' | '\u{013C}' | '\u{013E}' | '\u{0140}' | '\u{0142}' => "l",
+        '\u{0143}' | '\u{0145}' | '\u{0147}' => "N",
+        '\u{0144}' | '\u{0146}' | '\u{0148}' => "n",
+        '\u{014C}' | '\u{014E}' | '\u{0150}' => "O",
+        '\u{014D}' | '\u{014F}' | '\u{0151}' => "o",
+        '\u{0152}' => "OE",
+        '\u{0153}' => "oe",
+        '\u{0154}' | '\u{0156}' | '\u{0158}' => "R",
+        '\u{0155}' | '\u{0157}' | '\u{0159}' => "r",
+        '\u{015A}' | '\u{015C}' | '\u{015E}' | '\u{0160}' => "S",
+        '\u{015B}' | '\u{015D}' | '\u{015F}' | '\u{0161}' => "s",
+        '\u{0162}' | '\u{0164}' | '\u{0166}' => "T",
+        '\u{0163}' | '\u{0165}' | '\u{0167}' => "t",
+        '\u{0168}' | '\u{016A}' | '\u{016C}' | '\u{016E}' | '\u{0170}' | '\u{0172}' => "U",
+        '\u{0169}' | '\u{016B}' | '\u{016D}' | '\u{016F}' | '\u{0171}' | '\u{0173}' => "u",
+        '\u{0174}' => "W",
+        '\u{0175}' => "w",
+        '\u{0176}' | '\u{0178}' => "Y",
+        '\u{0177}' => "y",
+        '\u{0179}' | '\u{017B}' | '\u{017D}' => "Z",
+        '\u{017A}' | '\u{017C}' | '\u{017E}' => "z",
+        _ => return None,
+    };
+
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::gsm7bit::Gsm7Bit;
+
+    #[test]
+    fn replacements_are_encodable() {
+        let codec = Gsm7Bit::unpacked();
+
+        for character in (0..=0x3000).filter_map(char::from_u32) {
+            if let Some(replacement) = transliterate(character) {
+                assert!(
+                    codec.is_encodable(replacement),
+                    "{character:?} -> {replacement:?}"
+                );
+
+                assert!(!codec.is_encodable(character.encode_utf8(&mut [0; 4])));
+            }
+        }
+    }
+}
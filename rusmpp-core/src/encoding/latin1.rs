@@ -0,0 +1,161 @@
+//! ISO-8859-1 (Latin-1).
+//!
+//! Each character maps to exactly one octet. Only characters up to `U+00FF` can be encoded.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::encoding::latin1::Latin1;
+//!
+//! let codec = Latin1::new();
+//!
+//! let mut buf = [0u8; 16];
+//! let size = codec.encode("Señor", &mut buf).unwrap();
+//!
+//! assert_eq!(&buf[..size], b"Se\xF1or");
+//!
+//! let decoded = codec.decode(&buf[..size]).collect::<heapless::String<16>>();
+//!
+//! assert_eq!(decoded, "Señor");
+//! ```
+
+use crate::values::DataCoding;
+
+/// Errors that can occur when encoding text using Latin-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Latin1EncodeError {
+    /// The character is outside of the Latin-1 range.
+    UnencodableCharacter {
+        /// The unencodable character.
+        character: char,
+        /// The byte index of the character in the input text.
+        index: usize,
+    },
+}
+
+impl core::fmt::Display for Latin1EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnencodableCharacter { character, index } => {
+                write!(
+                    f,
+                    "Character {character:?} at index {index} can not be encoded using Latin-1"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for Latin1EncodeError {}
+
+/// ISO-8859-1 (Latin-1) codec.
+///
+/// Used with [`DataCoding::Latin1`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Latin1;
+
+impl Latin1 {
+    /// Creates a new [`Latin1`] codec.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the [`DataCoding`] of Latin-1 encoded short messages.
+    pub const fn data_coding(&self) -> DataCoding {
+        DataCoding::Latin1
+    }
+
+    /// Returns `true` if every character of `text` can be encoded.
+    pub fn is_encodable(&self, text: &str) -> bool {
+        text.chars()
+            .all(|character| u8::try_from(character).is_ok())
+    }
+
+    /// Returns the number of octets required to encode `text`.
+    pub fn encoded_length(&self, text: &str) -> Result<usize, Latin1EncodeError> {
+        text.char_indices()
+            .try_fold(0, |count, (index, character)| {
+                u8::try_from(character)
+                    .map(|_| count + 1)
+                    .map_err(|_| Latin1EncodeError::UnencodableCharacter { character, index })
+            })
+    }
+
+    /// Encodes `text` into `dst`.
+    ///
+    /// Returns the number of octets written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than [`Latin1::encoded_length`].
+    pub fn encode(&self, text: &str, dst: &mut [u8]) -> Result<usize, Latin1EncodeError> {
+        let length = self.encoded_length(text)?;
+
+        for (octet, character) in dst[..length].iter_mut().zip(text.chars()) {
+            // Every character is encodable, checked by `encoded_length`.
+            *octet = u8::try_from(character).unwrap_or_default();
+        }
+
+        Ok(length)
+    }
+
+    /// Decodes `src` into an iterator of characters.
+    ///
+    /// Every octet is a valid Latin-1 character.
+    pub fn decode<'a>(&self, src: &'a [u8]) -> impl Iterator<Item = char> + 'a {
+        src.iter().copied().map(char::from)
+    }
+
+    /// Encodes `text` into a newly allocated [`Vec`](alloc::vec::Vec).
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode_to_vec(&self, text: &str) -> Result<alloc::vec::Vec<u8>, Latin1EncodeError> {
+        let mut buf = alloc::vec![0; self.encoded_length(text)?];
+
+        self.encode(text, &mut buf)?;
+
+        Ok(buf)
+    }
+
+    /// Decodes `src` into a newly allocated [`String`](alloc::string::String).
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_string(&self, src: &[u8]) -> alloc::string::String {
+        self.decode(src).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let codec = Latin1::new();
+
+        let text = "Hello ¡Señor! ÀÿÞß © ½";
+
+        let encoded = codec.encode_to_vec(text).unwrap();
+
+        assert_eq!(encoded.len(), text.chars().count());
+        assert_eq!(codec.decode_to_string(&encoded), text);
+    }
+
+    #[test]
+    fn encode_unencodable_character() {
+        let codec = Latin1::new();
+
+        assert!(!codec.is_encodable("€"));
+
+        let err = codec.encode_to_vec("ab€").unwrap_err();
+
+        assert_eq!(
+            err,
+            Latin1EncodeError::UnencodableCharacter {
+                character: '€',
+                index: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,6 @@
+//! Short message text encodings.
+
+pub mod gsm7bit;
+pub mod latin1;
+pub mod text;
+pub mod ucs2;
@@ -0,0 +1,518 @@
+//! Automatic selection of the short message text encoding.
+//!
+//! [`TextEncoder`] picks the encoding that represents the text in the fewest octets:
+//!
+//! - [GSM 7-bit](Gsm7Bit), optionally with national language shift tables.
+//! - [Latin-1](Latin1), can be disabled.
+//! - [UCS2](Ucs2), encodes every text.
+//!
+//! On ties, GSM 7-bit is preferred over Latin-1, and Latin-1 over UCS2.
+//!
+//! In lossy mode, the text is always encoded using GSM 7-bit.
+//! Unencodable characters are [transliterated](crate::encoding::gsm7bit::transliterate).
+//!
+//! [`TextEncoding::from_data_coding`] picks the encoding of received text from its [`DataCoding`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::{encoding::text::TextEncoder, values::DataCoding};
+//!
+//! let encoder = TextEncoder::new();
+//!
+//! assert_eq!(encoder.select("Hello").data_coding(), DataCoding::McSpecific);
+//! assert_eq!(encoder.select("Olá").data_coding(), DataCoding::Latin1);
+//! assert_eq!(encoder.select("Привет").data_coding(), DataCoding::Ucs2);
+//!
+//! let encoder = TextEncoder::new().with_lossy(true);
+//!
+//! assert_eq!(encoder.select("“Olá”").data_coding(), DataCoding::McSpecific);
+//! ```
+
+use crate::values::{DataCoding, DataCodingAlphabet};
+
+use super::{
+    gsm7bit::{Gsm7Bit, Gsm7BitDecodeError, Gsm7BitPacking, NationalLanguage},
+    latin1::Latin1,
+    ucs2::{Ucs2, Ucs2DecodeError},
+};
+
+/// Errors that can occur when decoding text using a [`TextEncoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TextDecodeError {
+    /// GSM 7-bit decoding failed.
+    Gsm7Bit(Gsm7BitDecodeError),
+    /// UCS2 decoding failed.
+    Ucs2(Ucs2DecodeError),
+}
+
+impl core::fmt::Display for TextDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Gsm7Bit(err) => write!(f, "GSM 7-bit decode error: {err}"),
+            Self::Ucs2(err) => write!(f, "UCS2 decode error: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for TextDecodeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Gsm7Bit(err) => Some(err),
+            Self::Ucs2(err) => Some(err),
+        }
+    }
+}
+
+impl From<Gsm7BitDecodeError> for TextDecodeError {
+    fn from(value: Gsm7BitDecodeError) -> Self {
+        Self::Gsm7Bit(value)
+    }
+}
+
+impl From<Ucs2DecodeError> for TextDecodeError {
+    fn from(value: Ucs2DecodeError) -> Self {
+        Self::Ucs2(value)
+    }
+}
+
+/// Text encoding selected by the [`TextEncoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextEncoding {
+    /// GSM 7-bit default alphabet or national language shift tables.
+    Gsm7Bit(Gsm7Bit),
+    /// ISO-8859-1.
+    Latin1(Latin1),
+    /// UCS2.
+    Ucs2(Ucs2),
+}
+
+impl TextEncoding {
+    /// Returns the encoding of the [alphabet](crate::values::DataCodingScheme::alphabet) of `data_coding`.
+    ///
+    /// - GSM 7-bit uses the default alphabet and `packing`.
+    /// - Latin-1 and IA5, a subset of Latin-1, use [`Latin1`].
+    /// - UCS2 uses [`Ucs2`].
+    ///
+    /// Returns `None` for 8-bit data, compressed text and other alphabets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rusmpp_core::{
+    ///     encoding::{gsm7bit::Gsm7BitPacking, text::TextEncoding},
+    ///     values::DataCoding,
+    /// };
+    ///
+    /// // UCS2 flash SMS.
+    /// let encoding = TextEncoding::from_data_coding(DataCoding::from(0x18), Gsm7BitPacking::Unpacked);
+    ///
+    /// assert!(matches!(encoding, Some(TextEncoding::Ucs2(_))));
+    ///
+    /// // 8-bit data.
+    /// let encoding = TextEncoding::from_data_coding(DataCoding::from(0xF4), Gsm7BitPacking::Unpacked);
+    ///
+    /// assert!(encoding.is_none());
+    /// ```
+    pub fn from_data_coding(data_coding: DataCoding, packing: Gsm7BitPacking) -> Option<Self> {
+        let scheme = data_coding.scheme();
+
+        if scheme.is_compressed() {
+            return None;
+        }
+
+        match scheme.alphabet() {
+            DataCodingAlphabet::Gsm7Bit => Some(Self::Gsm7Bit(Gsm7Bit::new(packing))),
+            DataCodingAlphabet::Latin1 | DataCodingAlphabet::Ia5 => {
+                Some(Self::Latin1(Latin1::new()))
+            }
+            DataCodingAlphabet::Ucs2 => Some(Self::Ucs2(Ucs2::new())),
+            DataCodingAlphabet::Octet | DataCodingAlphabet::Other => None,
+        }
+    }
+
+    /// Decodes `src` into a newly allocated [`String`](alloc::string::String).
+    ///
+    /// `src` must not start with a UDH.
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_string(&self, src: &[u8]) -> Result<alloc::string::String, TextDecodeError> {
+        self.decode_to_string_after_udh(0, src)
+    }
+
+    /// Decodes `src`, which directly follows a UDH of `udh_length` octets, into a newly allocated [`String`](alloc::string::String).
+    ///
+    /// See [`Gsm7Bit::decode_after_udh`].
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_string_after_udh(
+        &self,
+        udh_length: usize,
+        src: &[u8],
+    ) -> Result<alloc::string::String, TextDecodeError> {
+        match self {
+            Self::Gsm7Bit(codec) => Ok(codec
+                .decode_after_udh(udh_length, src)
+                .collect::<Result<_, _>>()?),
+            Self::Latin1(codec) => Ok(codec.decode_to_string(src)),
+            Self::Ucs2(codec) => Ok(codec.decode_to_string(src)?),
+        }
+    }
+
+    /// Returns the [`DataCoding`] of the encoding.
+    pub const fn data_coding(&self) -> DataCoding {
+        match self {
+            Self::Gsm7Bit(codec) => codec.data_coding(),
+            Self::Latin1(codec) => codec.data_coding(),
+            Self::Ucs2(codec) => codec.data_coding(),
+        }
+    }
+
+    /// Returns `true` if the encoded text starts with a UDH.
+    ///
+    /// This is the case for GSM 7-bit using national language shift tables.
+    pub const fn has_udh(&self) -> bool {
+        match self {
+            Self::Gsm7Bit(codec) => codec.has_udh(),
+            Self::Latin1(_) | Self::Ucs2(_) => false,
+        }
+    }
+}
+
+/// Selects the cheapest encoding of a text.
+///
+/// See the [module level documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextEncoder<'a> {
+    packing: Gsm7BitPacking,
+    national_languages: &'a [NationalLanguage],
+    latin1: bool,
+    lossy: bool,
+}
+
+impl Default for TextEncoder<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextEncoder<'static> {
+    /// Creates a new [`TextEncoder`].
+    ///
+    /// GSM 7-bit is unpacked, national languages are disabled, Latin-1 is enabled and lossy mode is disabled.
+    pub const fn new() -> Self {
+        Self {
+            packing: Gsm7BitPacking::Unpacked,
+            national_languages: &[],
+            latin1: true,
+            lossy: false,
+        }
+    }
+}
+
+impl<'a> TextEncoder<'a> {
+    /// Sets the [`Gsm7BitPacking`].
+    pub const fn with_gsm7bit_packing(mut self, packing: Gsm7BitPacking) -> Self {
+        self.packing = packing;
+        self
+    }
+
+    /// Sets the national languages whose shift tables may be used for GSM 7-bit.
+    ///
+    /// See [`Gsm7Bit::select_national_language`].
+    pub const fn with_national_languages<'b>(
+        self,
+        national_languages: &'b [NationalLanguage],
+    ) -> TextEncoder<'b> {
+        TextEncoder {
+            packing: self.packing,
+            national_languages,
+            latin1: self.latin1,
+            lossy: self.lossy,
+        }
+    }
+
+    /// Enables or disables Latin-1.
+    ///
+    /// Many handsets do not support Latin-1, [`DataCoding::Latin1`] is not part of GSM 03.38.
+    pub const fn with_latin1(mut self, latin1: bool) -> Self {
+        self.latin1 = latin1;
+        self
+    }
+
+    /// Enables or disables lossy mode.
+    pub const fn with_lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Returns the [`Gsm7BitPacking`].
+    pub const fn gsm7bit_packing(&self) -> Gsm7BitPacking {
+        self.packing
+    }
+
+    /// Returns the national languages whose shift tables may be used for GSM 7-bit.
+    pub const fn national_languages(&self) -> &'a [NationalLanguage] {
+        self.national_languages
+    }
+
+    /// Returns `true` if Latin-1 is enabled.
+    pub const fn latin1(&self) -> bool {
+        self.latin1
+    }
+
+    /// Returns `true` if lossy mode is enabled.
+    pub const fn lossy(&self) -> bool {
+        self.lossy
+    }
+
+    /// Selects the encoding of `text`.
+    pub fn select(&self, text: &str) -> TextEncoding {
+        let default = Gsm7Bit::new(self.packing);
+
+        let gsm7bit = default
+            .select_national_language(text, self.national_languages)
+            .ok();
+
+        if self.lossy {
+            return TextEncoding::Gsm7Bit(gsm7bit.unwrap_or(default));
+        }
+
+        let gsm7bit = gsm7bit.and_then(|codec| {
+            codec
+                .encoded_length_with_udh(text)
+                .ok()
+                .map(|length| (TextEncoding::Gsm7Bit(codec), length))
+        });
+
+        let latin1 = self
+            .latin1
+            .then(|| Latin1::new().encoded_length(text).ok())
+            .flatten()
+            .map(|length| (TextEncoding::Latin1(Latin1::new()), length));
+
+        let ucs2 = Some((
+            TextEncoding::Ucs2(Ucs2::new()),
+            Ucs2::new().encoded_length(text),
+        ));
+
+        let mut selected = (TextEncoding::Ucs2(Ucs2::new()), usize::MAX);
+
+        // Candidates in order of preference, only strictly cheaper ones replace the selected one.
+        for (encoding, length) in [gsm7bit, latin1, ucs2].into_iter().flatten() {
+            if length < selected.1 {
+                selected = (encoding, length);
+            }
+        }
+
+        selected.0
+    }
+
+    /// Encodes `text` using the [selected](TextEncoder::select) encoding.
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode(&self, text: &str) -> EncodedText {
+        let encoding = self.select(text);
+
+        let bytes = match encoding {
+            TextEncoding::Gsm7Bit(codec) => {
+                let text = if self.lossy {
+                    alloc::borrow::Cow::Owned(codec.transliterate(text))
+                } else {
+                    alloc::borrow::Cow::Borrowed(text)
+                };
+
+                codec
+                    .encode_to_vec_with_udh(&text)
+                    .expect("Selected GSM 7-bit codec encodes the text")
+            }
+            TextEncoding::Latin1(codec) => codec
+                .encode_to_vec(text)
+                .expect("Selected Latin-1 codec encodes the text"),
+            TextEncoding::Ucs2(codec) => codec.encode_to_vec(text),
+        };
+
+        EncodedText { encoding, bytes }
+    }
+}
+
+/// Text encoded by the [`TextEncoder`].
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EncodedText {
+    encoding: TextEncoding,
+    bytes: alloc::vec::Vec<u8>,
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl EncodedText {
+    /// Returns the selected [`TextEncoding`].
+    pub const fn encoding(&self) -> TextEncoding {
+        self.encoding
+    }
+
+    /// Returns the [`DataCoding`] of the encoded text.
+    pub const fn data_coding(&self) -> DataCoding {
+        self.encoding.data_coding()
+    }
+
+    /// Returns `true` if the encoded text starts with a UDH.
+    ///
+    /// The UDH Indicator must be set in the `esm_class` of the PDU carrying the encoded text.
+    pub const fn has_udh(&self) -> bool {
+        self.encoding.has_udh()
+    }
+
+    /// Returns the encoded bytes, including the UDH if any.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Converts the [`EncodedText`] into its encoded bytes.
+    pub fn into_bytes(self) -> alloc::vec::Vec<u8> {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_cheapest() {
+        let encoder = TextEncoder::new();
+
+        assert!(matches!(encoder.select(""), TextEncoding::Gsm7Bit(_)));
+        assert!(matches!(encoder.select("Hello"), TextEncoding::Gsm7Bit(_)));
+        assert!(matches!(encoder.select("Olá"), TextEncoding::Latin1(_)));
+        assert!(matches!(encoder.select("€ á"), TextEncoding::Ucs2(_)));
+        assert!(matches!(encoder.select("😀"), TextEncoding::Ucs2(_)));
+
+        // Extension table characters take two septets, unpacked Latin-1 takes one octet.
+        assert!(matches!(encoder.select("{}"), TextEncoding::Latin1(_)));
+
+        let packed = encoder.with_gsm7bit_packing(Gsm7BitPacking::Packed);
+
+        assert!(matches!(
+            packed.select("Hello world, how are you {}"),
+            TextEncoding::Gsm7Bit(_)
+        ));
+    }
+
+    #[test]
+    fn select_without_latin1() {
+        let encoder = TextEncoder::new().with_latin1(false);
+
+        assert!(matches!(encoder.select("Olá"), TextEncoding::Ucs2(_)));
+    }
+
+    #[test]
+    fn select_national_language() {
+        let text = "Günaydın, Şule! Nasılsın? Çok güzel bir gün, değil mi?";
+
+        let encoder = TextEncoder::new().with_latin1(false);
+
+        assert!(matches!(encoder.select(text), TextEncoding::Ucs2(_)));
+
+        let encoder = encoder.with_national_languages(&[NationalLanguage::Turkish]);
+
+        let TextEncoding::Gsm7Bit(codec) = encoder.select(text) else {
+            panic!("Expected GSM 7-bit");
+        };
+
+        assert_eq!(codec.locking_shift(), Some(NationalLanguage::Turkish));
+
+        let encoded = encoder.encode(text);
+
+        assert!(encoded.has_udh());
+        assert_eq!(encoded.data_coding(), DataCoding::McSpecific);
+        assert_eq!(&encoded.bytes()[..4], &[0x03, 0x25, 0x01, 0x01]);
+    }
+
+    #[test]
+    fn encode() {
+        let encoder = TextEncoder::new();
+
+        let encoded = encoder.encode("Hello");
+
+        assert_eq!(encoded.data_coding(), DataCoding::McSpecific);
+        assert!(!encoded.has_udh());
+        assert_eq!(encoded.bytes(), b"Hello");
+
+        let encoded = encoder.encode("Olá");
+
+        assert_eq!(encoded.data_coding(), DataCoding::Latin1);
+        assert_eq!(encoded.bytes(), b"Ol\xE1");
+
+        let encoded = encoder.encode("€ á");
+
+        assert_eq!(encoded.data_coding(), DataCoding::Ucs2);
+        assert_eq!(encoded.bytes(), &[0x20, 0xAC, 0x00, 0x20, 0x00, 0xE1]);
+    }
+
+    #[test]
+    fn from_data_coding() {
+        let unpacked = Gsm7BitPacking::Unpacked;
+
+        let cases = [
+            (0x00, Some(TextEncoding::Gsm7Bit(Gsm7Bit::new(unpacked)))),
+            (0x01, Some(TextEncoding::Latin1(Latin1::new()))),
+            (0x03, Some(TextEncoding::Latin1(Latin1::new()))),
+            (0x08, Some(TextEncoding::Ucs2(Ucs2::new()))),
+            (0x04, None),
+            (0x05, None),
+            // Flash SMS.
+            (0x10, Some(TextEncoding::Gsm7Bit(Gsm7Bit::new(unpacked)))),
+            (0xF0, Some(TextEncoding::Gsm7Bit(Gsm7Bit::new(unpacked)))),
+            (0x18, Some(TextEncoding::Ucs2(Ucs2::new()))),
+            // Compressed.
+            (0x20, None),
+            // Message waiting indication.
+            (0xC8, Some(TextEncoding::Gsm7Bit(Gsm7Bit::new(unpacked)))),
+            (0xE8, Some(TextEncoding::Ucs2(Ucs2::new()))),
+            (0xF4, None),
+        ];
+
+        for (value, encoding) in cases {
+            assert_eq!(
+                TextEncoding::from_data_coding(DataCoding::from(value), unpacked),
+                encoding,
+                "0x{value:02X}"
+            );
+        }
+    }
+
+    #[test]
+    fn decode_to_string() {
+        let encoder = TextEncoder::new();
+
+        for text in ["Hello", "Olá", "€ á"] {
+            let encoded = encoder.encode(text);
+
+            let encoding =
+                TextEncoding::from_data_coding(encoded.data_coding(), Gsm7BitPacking::Unpacked)
+                    .unwrap();
+
+            assert_eq!(encoding.decode_to_string(encoded.bytes()).unwrap(), text);
+        }
+
+        assert_eq!(
+            TextEncoding::Ucs2(Ucs2::new()).decode_to_string(&[0x00]),
+            Err(TextDecodeError::Ucs2(Ucs2DecodeError::OddLength {
+                length: 1
+            }))
+        );
+    }
+
+    #[test]
+    fn encode_lossy() {
+        let encoder = TextEncoder::new().with_lossy(true);
+
+        let encoded = encoder.encode("“Olá” – ш");
+
+        assert_eq!(encoded.data_coding(), DataCoding::McSpecific);
+        assert_eq!(encoded.bytes(), b"\"Ola\" - ?");
+    }
+}
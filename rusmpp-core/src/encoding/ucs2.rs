@@ -0,0 +1,188 @@
+//! UCS2, encoded as big-endian UTF-16.
+//!
+//! Characters outside of the Basic Multilingual Plane are encoded as surrogate pairs,
+//! taking four octets.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::encoding::ucs2::Ucs2;
+//!
+//! let codec = Ucs2::new();
+//!
+//! let mut buf = [0u8; 16];
+//! let size = codec.encode("Hi 😀", &mut buf);
+//!
+//! assert_eq!(&buf[..size], &[0x00, 0x48, 0x00, 0x69, 0x00, 0x20, 0xD8, 0x3D, 0xDE, 0x00]);
+//!
+//! let decoded = codec
+//!     .decode(&buf[..size])
+//!     .collect::<Result<heapless::String<16>, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(decoded, "Hi 😀");
+//! ```
+
+use crate::values::DataCoding;
+
+/// Errors that can occur when decoding UCS2 encoded text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Ucs2DecodeError {
+    /// The input has an odd number of octets.
+    OddLength {
+        /// The length of the input.
+        length: usize,
+    },
+    /// A surrogate code unit is not part of a surrogate pair.
+    UnpairedSurrogate {
+        /// The unpaired surrogate.
+        value: u16,
+    },
+}
+
+impl core::fmt::Display for Ucs2DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OddLength { length } => {
+                write!(f, "Odd number of octets: {length}")
+            }
+            Self::UnpairedSurrogate { value } => {
+                write!(f, "Unpaired surrogate 0x{value:04X}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Ucs2DecodeError {}
+
+/// UCS2 codec.
+///
+/// Used with [`DataCoding::Ucs2`]. Every character can be encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Ucs2;
+
+impl Ucs2 {
+    /// Creates a new [`Ucs2`] codec.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the [`DataCoding`] of UCS2 encoded short messages.
+    pub const fn data_coding(&self) -> DataCoding {
+        DataCoding::Ucs2
+    }
+
+    /// Returns the number of octets required to encode `text`.
+    pub fn encoded_length(&self, text: &str) -> usize {
+        text.chars().map(char::len_utf16).sum::<usize>() * 2
+    }
+
+    /// Encodes `text` into `dst`.
+    ///
+    /// Returns the number of octets written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is shorter than [`Ucs2::encoded_length`].
+    pub fn encode(&self, text: &str, dst: &mut [u8]) -> usize {
+        let mut size = 0;
+
+        for unit in text.encode_utf16() {
+            dst[size..size + 2].copy_from_slice(&unit.to_be_bytes());
+
+            size += 2;
+        }
+
+        size
+    }
+
+    /// Decodes `src` into an iterator of characters.
+    ///
+    /// The iterator stops after the first error.
+    pub fn decode<'a>(
+        &self,
+        src: &'a [u8],
+    ) -> impl Iterator<Item = Result<char, Ucs2DecodeError>> + 'a {
+        let units = src
+            .chunks_exact(2)
+            .map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+
+        let chars = char::decode_utf16(units).map(|result| {
+            result.map_err(|err| Ucs2DecodeError::UnpairedSurrogate {
+                value: err.unpaired_surrogate(),
+            })
+        });
+
+        let trailing =
+            (src.len() % 2 != 0).then_some(Err(Ucs2DecodeError::OddLength { length: src.len() }));
+
+        let mut failed = false;
+
+        chars.chain(trailing).take_while(move |result| {
+            let take = !failed;
+
+            failed |= result.is_err();
+
+            take
+        })
+    }
+
+    /// Encodes `text` into a newly allocated [`Vec`](alloc::vec::Vec).
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode_to_vec(&self, text: &str) -> alloc::vec::Vec<u8> {
+        let mut buf = alloc::vec![0; self.encoded_length(text)];
+
+        self.encode(text, &mut buf);
+
+        buf
+    }
+
+    /// Decodes `src` into a newly allocated [`String`](alloc::string::String).
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_to_string(&self, src: &[u8]) -> Result<alloc::string::String, Ucs2DecodeError> {
+        self.decode(src).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let codec = Ucs2::new();
+
+        let text = "Hello Привет مرحبا 你好 😀👍";
+
+        let encoded = codec.encode_to_vec(text);
+
+        assert_eq!(encoded.len(), codec.encoded_length(text));
+        assert_eq!(encoded.len(), text.encode_utf16().count() * 2);
+        assert_eq!(codec.decode_to_string(&encoded).unwrap(), text);
+    }
+
+    #[test]
+    fn decode_odd_length() {
+        let codec = Ucs2::new();
+
+        let err = codec.decode_to_string(&[0x00, 0x48, 0x00]).unwrap_err();
+
+        assert_eq!(err, Ucs2DecodeError::OddLength { length: 3 });
+    }
+
+    #[test]
+    fn decode_unpaired_surrogate() {
+        let codec = Ucs2::new();
+
+        let mut decoded = codec.decode(&[0xD8, 0x3D, 0x00, 0x48, 0x00, 0x69]);
+
+        assert_eq!(
+            decoded.next(),
+            Some(Err(Ucs2DecodeError::UnpairedSurrogate { value: 0xD83D }))
+        );
+        assert_eq!(decoded.next(), None);
+    }
+}
@@ -54,6 +54,8 @@ pub enum SmppField {
     addr_ton,
     address_range,
     area,
+    callback_num,
+    command_length,
     data_coding,
     dest_addr_npi,
     dest_addr_ton,
@@ -83,6 +85,7 @@ pub enum SmppField {
     pdu,
     priority_flag,
     protocol_id,
+    receipted_message_id,
     registered_delivery,
     replace_if_present_flag,
     sc_interface_version,
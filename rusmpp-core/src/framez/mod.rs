@@ -5,23 +5,52 @@ use core::num::TryFromIntError;
 use framez::{decode::Decoder, encode::Encoder};
 
 use crate::{
+    CommandId,
     command::borrowed::Command,
     decode::borrowed::DecodeWithLength,
     encode::{Encode, Length},
     logging::{debug, error, trace},
+    values::InterfaceVersion,
 };
 
 #[cfg(test)]
 mod tests;
 
 /// Codec for encoding and decoding `SMPP` PDUs using [`Encoder`] and [`Decoder`] traits.
+///
+/// # Interface version
+///
+/// By default, the codec speaks `SMPP` v5.0. Use [`CommandCodec::with_interface_version`] to talk to older peers:
+///
+/// - [`InterfaceVersion::Smpp3_4`]: v5.0 only PDUs are rejected and v5.0 only TLVs are removed.
+/// - [`InterfaceVersion::Smpp3_3OrEarlier`]: v3.4 and v5.0 only PDUs are rejected and all TLVs are removed.
+///
+/// Unsupported PDUs are rejected when encoding. Decoded unsupported PDUs are still returned,
+/// so that the caller can answer them with a `generic_nack` instead of ending the stream.
+///
+/// See [`CommandId::is_supported_by`] and [`TlvTag::is_supported_by`](crate::tlvs::TlvTag::is_supported_by).
 #[derive(Debug)]
 #[non_exhaustive]
-pub struct CommandCodec<const N: usize> {}
+pub struct CommandCodec<const N: usize> {
+    interface_version: InterfaceVersion,
+}
 
 impl<const N: usize> CommandCodec<N> {
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            interface_version: InterfaceVersion::Smpp5_0,
+        }
+    }
+
+    #[inline]
+    pub const fn interface_version(&self) -> InterfaceVersion {
+        self.interface_version
+    }
+
+    #[inline]
+    pub const fn with_interface_version(mut self, interface_version: InterfaceVersion) -> Self {
+        self.interface_version = interface_version;
+        self
     }
 }
 
@@ -37,12 +66,24 @@ impl<const N: usize> Default for CommandCodec<N> {
 pub enum EncodeError {
     /// The input buffer is too small to fit the encoded [`Command`].
     BufferTooSmall,
+    /// The [`Command`] is not supported by the interface version of the codec.
+    UnsupportedCommand {
+        id: CommandId,
+        interface_version: InterfaceVersion,
+    },
 }
 
 impl core::fmt::Display for EncodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::BufferTooSmall => write!(f, "Buffer too small"),
+            Self::UnsupportedCommand {
+                id,
+                interface_version,
+            } => write!(
+                f,
+                "Unsupported command. id: {id:?}, interface_version: {interface_version:?}"
+            ),
         }
     }
 }
@@ -52,7 +93,18 @@ impl core::error::Error for EncodeError {}
 impl<'buf, const N: usize> Encoder<Command<'buf, N>> for CommandCodec<N> {
     type Error = EncodeError;
 
-    fn encode(&mut self, item: Command<'buf, N>, dst: &mut [u8]) -> Result<usize, Self::Error> {
+    fn encode(&mut self, mut item: Command<'buf, N>, dst: &mut [u8]) -> Result<usize, Self::Error> {
+        if !item.id().is_supported_by(self.interface_version) {
+            error!(target: "rusmpp::codec::encode", id=?item.id(), interface_version=?self.interface_version, "Unsupported command");
+
+            return Err(EncodeError::UnsupportedCommand {
+                id: item.id(),
+                interface_version: self.interface_version,
+            });
+        }
+
+        item.retain_supported_tlvs(self.interface_version);
+
         let command_length = 4 + item.length();
 
         if dst.len() < command_length {
@@ -146,7 +198,7 @@ impl<'buf, const N: usize> Decoder<'buf> for CommandCodec<N> {
 
         debug!(target: "rusmpp::codec::decode", decoding=?crate::formatter::Formatter(&src[..command_length]), "Decoding");
 
-        let (command, _size) = match Command::decode(&src[4..command_length], pdu_len) {
+        let (mut command, _size) = match Command::decode(&src[4..command_length], pdu_len) {
             Ok((command, size)) => {
                 debug!(target: "rusmpp::codec::decode", command=?command, command_length, decoded_length=size, "Decoded");
 
@@ -159,6 +211,16 @@ impl<'buf, const N: usize> Decoder<'buf> for CommandCodec<N> {
             }
         };
 
+        if !command.id().is_supported_by(self.interface_version) {
+            error!(target: "rusmpp::codec::decode", id=?command.id(), interface_version=?self.interface_version, "Unsupported command");
+        }
+
+        if command.has_unsupported_tlvs(self.interface_version) {
+            command.retain_supported_tlvs(self.interface_version);
+
+            debug!(target: "rusmpp::codec::decode", command=?command, interface_version=?self.interface_version, "Removed unsupported TLVs");
+        }
+
         Ok(Some((command, command_length)))
     }
 }
@@ -1,14 +1,18 @@
 use embedded_io_adapters::tokio_1::FromTokio;
-use framez::{FramedRead, FramedWrite, ReadError, next};
+use framez::{FramedRead, FramedWrite, ReadError, decode::Decoder, encode::Encoder, next};
 
 use tokio::io::AsyncWriteExt;
 
 use crate::{
+    CommandId, CommandStatus,
     command::borrowed::Command,
     encode::Encode,
-    framez::{CommandCodec, DecodeError},
+    framez::{CommandCodec, DecodeError, EncodeError},
     pdus::borrowed::*,
     tests::borrowed::test_commands,
+    tlvs::borrowed::MessageSubmissionRequestTlvValue,
+    types::borrowed::OctetString,
+    values::InterfaceVersion,
 };
 
 /// Encode and decode every possible test command created using [`TestInstance`](crate::tests::TestInstance).
@@ -104,3 +108,55 @@ async fn min_length() {
         }
     }
 }
+
+#[test]
+fn interface_version_3_4() {
+    let mut v5 = CommandCodec::<16>::new();
+    let mut v3_4 = CommandCodec::<16>::new().with_interface_version(InterfaceVersion::Smpp3_4);
+
+    let buf = &mut [0u8; 1024];
+
+    let command = Command::new(CommandStatus::EsmeRok, 1, BroadcastSm::<'_, 16>::default());
+
+    match v3_4.encode(command.clone(), buf).unwrap_err() {
+        EncodeError::UnsupportedCommand { id, .. } => assert_eq!(id, CommandId::BroadcastSm),
+        _ => panic!("Encode must fail with `EncodeError::UnsupportedCommand`"),
+    }
+
+    let size = v5.encode(command, buf).unwrap();
+
+    // Unsupported commands are still decoded, so that the caller can answer them with a generic_nack.
+    {
+        let (decoded, decoded_size) = v3_4.decode(buf).unwrap().unwrap();
+
+        assert_eq!(decoded.id(), CommandId::BroadcastSm);
+        assert_eq!(decoded.sequence_number(), 1);
+        assert_eq!(decoded_size, size);
+    }
+
+    let submit_sm = SubmitSm::<'_, 16>::builder()
+        .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+        .unwrap()
+        .push_tlv(MessageSubmissionRequestTlvValue::BillingIdentification(
+            OctetString::new(b"billing").unwrap(),
+        ))
+        .unwrap()
+        .build();
+
+    let expected = Command::new(
+        CommandStatus::EsmeRok,
+        1,
+        SubmitSm::<'_, 16>::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+            .unwrap()
+            .build(),
+    );
+
+    let _ = v5
+        .encode(Command::new(CommandStatus::EsmeRok, 1, submit_sm), buf)
+        .unwrap();
+
+    let (decoded, _) = v3_4.decode(buf).unwrap().unwrap();
+
+    assert_eq!(decoded, expected);
+}
@@ -10,8 +10,11 @@
 //! - `arbitrary`: Implements [`Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) trait for all SMPP types.
 //! - `serde`: Implements [`Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html) trait for all SMPP types.
 //! - `serde-deserialize-unchecked`: Implements [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) trait for owned SMPP types, but does not check the validity of the data. Use with caution.
+//! - `serde-deserialize`: Implements [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) trait for owned SMPP types and checks the deserialized values like decoded values. Takes precedence over `serde-deserialize-unchecked`. See [`deserialize`].
 //! - `tokio-codec`: Implements [`tokio-util`](https://docs.rs/tokio-util/latest/tokio_util/index.html) [`Encoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Encoder.html) and [`Decoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Decoder.html) traits.
 //! - `framez`: Implements [`framez`](https://docs.rs/framez/latest/framez/index.html) [`Encoder`](https://docs.rs/framez/latest/framez/encode/trait.Encoder.html) and [`Decoder`](https://docs.rs/framez/latest/framez/decode/trait.Decoder.html) traits.
+//! - `chrono`: Implements conversions between [`SmppTime`](values::SmppTime) and [`chrono`](https://docs.rs/chrono/latest/chrono/)'s `DateTime`.
+//! - `time`: Implements conversions between [`SmppTime`](values::SmppTime) and [`time`](https://docs.rs/time/latest/time/)'s `OffsetDateTime`.
 //! - `tracing`: Enables logging using [`tracing`](https://docs.rs/tracing/latest/tracing/).
 //! - `pretty-hex-fmt`: Logs byte slices like `[0x00, 0x00, 0x00, 0x6F]` instead of `[00, 00, 00, 6F]`, if `tracing` feature is enabled.
 //! - `char-fmt`: Logs byte slices as characters, if `tracing` feature is enabled.
@@ -30,7 +33,7 @@ mod command_id;
 pub use command_id::CommandId;
 
 mod command_status;
-pub use command_status::CommandStatus;
+pub use command_status::{CommandStatus, CommandStatusCategory, RetryHint};
 
 pub mod command;
 
@@ -63,3 +66,25 @@ pub mod framez;
 pub mod tokio_codec;
 
 pub mod udhs;
+
+pub mod encoding;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod multipart;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod delivery_receipt;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod validate;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod dissect;
+
+#[cfg(feature = "serde-deserialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-deserialize")))]
+pub mod deserialize;
@@ -0,0 +1,136 @@
+//! Errors related to multipart messages.
+
+use crate::{
+    encoding::{gsm7bit::Gsm7BitEncodeError, latin1::Latin1EncodeError},
+    udhs::errors::ConcatenatedShortMessageError,
+};
+
+/// Errors that can occur when splitting a message into parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SplitError {
+    /// The text can not be encoded using GSM 7-bit.
+    Gsm7Bit(Gsm7BitEncodeError),
+    /// The text can not be encoded using Latin-1.
+    Latin1(Latin1EncodeError),
+    /// The message requires more than `255` parts.
+    TooManyParts {
+        /// The number of parts required.
+        parts: usize,
+    },
+    /// A part can not hold a single character after the UDH.
+    MaxShortMessageSizeTooSmall {
+        /// The configured maximum size of the short message.
+        max_short_message_size: usize,
+    },
+}
+
+impl core::fmt::Display for SplitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Gsm7Bit(err) => write!(f, "{err}"),
+            Self::Latin1(err) => write!(f, "{err}"),
+            Self::TooManyParts { parts } => {
+                write!(f, "Too many parts. actual: {parts}, max: 255")
+            }
+            Self::MaxShortMessageSizeTooSmall {
+                max_short_message_size,
+            } => {
+                write!(
+                    f,
+                    "Max short message size {max_short_message_size} is too small to hold a single character"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for SplitError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Gsm7Bit(err) => Some(err),
+            Self::Latin1(err) => Some(err),
+            Self::TooManyParts { .. } | Self::MaxShortMessageSizeTooSmall { .. } => None,
+        }
+    }
+}
+
+impl From<Gsm7BitEncodeError> for SplitError {
+    fn from(err: Gsm7BitEncodeError) -> Self {
+        Self::Gsm7Bit(err)
+    }
+}
+
+impl From<Latin1EncodeError> for SplitError {
+    fn from(err: Latin1EncodeError) -> Self {
+        Self::Latin1(err)
+    }
+}
+
+/// Errors that can occur when reassembling a multipart message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReassemblyError {
+    /// The UDH Indicator is set but the UDH is malformed.
+    InvalidUdh,
+    /// The concatenation UDH or the SAR TLVs are inconsistent.
+    Concatenation(ConcatenatedShortMessageError),
+    /// The total number of parts differs from the parts already received for the same message.
+    InconsistentTotalParts {
+        /// The total number of parts of the buffered parts.
+        expected: u8,
+        /// The total number of parts of the rejected part.
+        actual: u8,
+    },
+    /// Buffering the part would exceed the memory cap.
+    MemoryCapExceeded {
+        /// The number of buffered payload bytes.
+        buffered_bytes: usize,
+        /// The size of the rejected part.
+        part_size: usize,
+        /// The maximum number of buffered payload bytes.
+        max_buffered_bytes: usize,
+    },
+}
+
+impl core::fmt::Display for ReassemblyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidUdh => write!(f, "Invalid UDH"),
+            Self::Concatenation(err) => write!(f, "{err}"),
+            Self::InconsistentTotalParts { expected, actual } => {
+                write!(
+                    f,
+                    "Inconsistent total parts. expected: {expected}, actual: {actual}"
+                )
+            }
+            Self::MemoryCapExceeded {
+                buffered_bytes,
+                part_size,
+                max_buffered_bytes,
+            } => {
+                write!(
+                    f,
+                    "Memory cap exceeded. buffered: {buffered_bytes}, part: {part_size}, max: {max_buffered_bytes}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for ReassemblyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Concatenation(err) => Some(err),
+            Self::InvalidUdh
+            | Self::InconsistentTotalParts { .. }
+            | Self::MemoryCapExceeded { .. } => None,
+        }
+    }
+}
+
+impl From<ConcatenatedShortMessageError> for ReassemblyError {
+    fn from(err: ConcatenatedShortMessageError) -> Self {
+        Self::Concatenation(err)
+    }
+}
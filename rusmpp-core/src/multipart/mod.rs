@@ -0,0 +1,42 @@
+//! Multipart (concatenated) short messages.
+//!
+//! The [`Splitter`] splits messages exceeding the maximum short message size into parts
+//! linked by a concatenation UDH or by [SAR](Sar) TLVs, see [`Concatenation`].
+//! The [`Reassembler`] joins the parts of received multipart messages.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::{
+//!     multipart::Splitter, pdus::owned::SubmitSm,
+//!     udhs::owned::concatenation::ConcatenatedShortMessageType, values::GsmFeatures,
+//! };
+//!
+//! let splitter = Splitter::new(ConcatenatedShortMessageType::u8(42));
+//!
+//! let parts = SubmitSm::builder()
+//!     .build()
+//!     .split_text(&"Hello, World! ".repeat(20), &splitter)
+//!     .unwrap();
+//!
+//! assert_eq!(parts.len(), 3);
+//!
+//! for (index, part) in parts.iter().enumerate() {
+//!     assert_eq!(part.esm_class.gsm_features, GsmFeatures::UdhiIndicator);
+//!     assert_eq!(&part.short_message().bytes()[..6], &[0x05, 0x00, 0x03, 42, 3, index as u8 + 1]);
+//! }
+//! ```
+
+pub mod errors;
+pub use errors::{ReassemblyError, SplitError};
+
+mod reassembler;
+pub use reassembler::{
+    GroupKey, IncompleteGroup, MessagePart, Reassembled, ReassembledMessage, Reassembler, Reference,
+};
+
+mod sar;
+pub use sar::Sar;
+
+mod splitter;
+pub use splitter::{Concatenation, SplitMessage, Splitter};
@@ -0,0 +1,936 @@
+use core::time::Duration;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    pdus::owned::{DataSm, DeliverSm},
+    tlvs::owned::{Tlv, message_payload},
+    types::owned::COctetString,
+    udhs::owned::concatenation::{ConcatenatedShortMessage8Bit, ConcatenatedShortMessage16Bit},
+    values::{DataCoding, EsmClass},
+};
+
+use super::{ReassemblyError, Sar};
+
+/// Reassembles multipart mobile originated messages received as separate [`DeliverSm`] or [`DataSm`] PDUs.
+///
+/// Parts are grouped by reference number, source address and destination address.
+/// Parts are linked by an 8-bit or 16-bit concatenation UDH or by [SAR](Sar) TLVs.
+/// Messages that are not split are complete on arrival.
+///
+/// The reassembler does not read a clock. Every call takes `now`, the time elapsed since
+/// an arbitrary fixed point, e.g. the start of the application.
+///
+/// # Expiry
+///
+/// A group expires [`max_age`](Reassembler::with_max_age) after its first part arrived.
+/// Expired groups are removed by [`Reassembler::expire`], which should be called periodically.
+/// A part that arrives for an expired group, e.g. a reused reference number, starts a new group.
+/// The parts of the expired group are discarded.
+///
+/// # Memory cap
+///
+/// Parts exceeding the [`max_buffered_bytes`](Reassembler::with_max_buffered_bytes)
+/// are rejected with [`ReassemblyError::MemoryCapExceeded`].
+///
+/// # Duplicates
+///
+/// A part whose sequence number was already received is ignored and reported as [`Reassembled::Duplicate`].
+/// The first received part is kept.
+#[derive(Debug, Clone)]
+pub struct Reassembler {
+    max_age: Duration,
+    max_buffered_bytes: usize,
+    buffered_bytes: usize,
+    groups: BTreeMap<GroupKey, Group>,
+}
+
+impl Default for Reassembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reassembler {
+    /// The default time after which a group expires.
+    const DEFAULT_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+    /// The default maximum number of buffered payload bytes.
+    const DEFAULT_MAX_BUFFERED_BYTES: usize = 1024 * 1024;
+
+    /// Creates a new [`Reassembler`].
+    ///
+    /// Groups expire after `5` minutes and at most `1 MiB` of payload is buffered.
+    pub const fn new() -> Self {
+        Self {
+            max_age: Self::DEFAULT_MAX_AGE,
+            max_buffered_bytes: Self::DEFAULT_MAX_BUFFERED_BYTES,
+            buffered_bytes: 0,
+            groups: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the time after which a group expires.
+    pub const fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Sets the maximum number of buffered payload bytes.
+    pub const fn with_max_buffered_bytes(mut self, max_buffered_bytes: usize) -> Self {
+        self.max_buffered_bytes = max_buffered_bytes;
+        self
+    }
+
+    /// Returns the time after which a group expires.
+    pub const fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Returns the maximum number of buffered payload bytes.
+    pub const fn max_buffered_bytes(&self) -> usize {
+        self.max_buffered_bytes
+    }
+
+    /// Returns the number of buffered payload bytes.
+    pub const fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    /// Pushes a [`DeliverSm`].
+    ///
+    /// The payload is read from the short_message, or from the message_payload TLV if the short_message is empty.
+    pub fn push_deliver_sm(
+        &mut self,
+        deliver_sm: &DeliverSm,
+        now: Duration,
+    ) -> Result<Reassembled, ReassemblyError> {
+        let payload = match deliver_sm.short_message().bytes() {
+            [] => message_payload(deliver_sm.tlvs()),
+            short_message => short_message,
+        };
+
+        self.push(
+            Incoming {
+                source_addr: &deliver_sm.source_addr,
+                destination_addr: &deliver_sm.destination_addr,
+                esm_class: deliver_sm.esm_class,
+                data_coding: deliver_sm.data_coding,
+                tlvs: deliver_sm.tlvs(),
+                payload,
+            },
+            now,
+        )
+    }
+
+    /// Pushes a [`DataSm`].
+    ///
+    /// The payload is read from the message_payload TLV.
+    pub fn push_data_sm(
+        &mut self,
+        data_sm: &DataSm,
+        now: Duration,
+    ) -> Result<Reassembled, ReassemblyError> {
+        self.push(
+            Incoming {
+                source_addr: &data_sm.source_addr,
+                destination_addr: &data_sm.destination_addr,
+                esm_class: data_sm.esm_class,
+                data_coding: data_sm.data_coding,
+                tlvs: data_sm.tlvs(),
+                payload: message_payload(data_sm.tlvs()),
+            },
+            now,
+        )
+    }
+
+    /// Removes and returns the groups that expired at `now`.
+    pub fn expire(&mut self, now: Duration) -> Vec<IncompleteGroup> {
+        let mut expired = Vec::new();
+
+        self.groups.retain(|key, group| {
+            if !group.is_expired(now, self.max_age) {
+                return true;
+            }
+
+            self.buffered_bytes -= group.buffered_bytes;
+
+            expired.push(group.report(key.clone()));
+
+            false
+        });
+
+        expired
+    }
+
+    /// Returns a report of the groups still waiting for parts.
+    pub fn incomplete(&self) -> impl Iterator<Item = IncompleteGroup> + '_ {
+        self.groups
+            .iter()
+            .map(|(key, group)| group.report(key.clone()))
+    }
+
+    /// Removes all groups.
+    pub fn clear(&mut self) {
+        self.groups.clear();
+        self.buffered_bytes = 0;
+    }
+
+    fn push(
+        &mut self,
+        incoming: Incoming<'_>,
+        now: Duration,
+    ) -> Result<Reassembled, ReassemblyError> {
+        let Some((reference, total_parts, part_number)) = concatenation(&incoming)? else {
+            let part = MessagePart::new(incoming.payload, incoming.udh_length()?);
+
+            return Ok(Reassembled::Complete(ReassembledMessage {
+                source_addr: incoming.source_addr.clone(),
+                destination_addr: incoming.destination_addr.clone(),
+                data_coding: incoming.data_coding,
+                parts: alloc::vec![part],
+            }));
+        };
+
+        let key = GroupKey {
+            source_addr: incoming.source_addr.clone(),
+            destination_addr: incoming.destination_addr.clone(),
+            reference,
+        };
+
+        // A reused reference starts a new group once the previous one expired.
+        if self
+            .groups
+            .get(&key)
+            .is_some_and(|group| group.is_expired(now, self.max_age))
+        {
+            let group = self.groups.remove(&key).expect("Group exists");
+
+            self.buffered_bytes -= group.buffered_bytes;
+        }
+
+        if let Some(group) = self.groups.get(&key) {
+            if group.parts.len() != total_parts as usize {
+                return Err(ReassemblyError::InconsistentTotalParts {
+                    expected: group.parts.len() as u8,
+                    actual: total_parts,
+                });
+            }
+
+            if group.parts[part_number as usize - 1].is_some() {
+                return Ok(Reassembled::Duplicate);
+            }
+        }
+
+        let size = incoming.payload.len();
+
+        if self.buffered_bytes + size > self.max_buffered_bytes {
+            return Err(ReassemblyError::MemoryCapExceeded {
+                buffered_bytes: self.buffered_bytes,
+                part_size: size,
+                max_buffered_bytes: self.max_buffered_bytes,
+            });
+        }
+
+        let part = MessagePart::new(incoming.payload, incoming.udh_length()?);
+
+        let group = self.groups.entry(key.clone()).or_insert_with(|| Group {
+            data_coding: incoming.data_coding,
+            first_received: now,
+            buffered_bytes: 0,
+            parts: alloc::vec![None; total_parts as usize],
+        });
+
+        group.parts[part_number as usize - 1] = Some(part);
+        group.buffered_bytes += size;
+
+        self.buffered_bytes += size;
+
+        let received_parts = group.received_parts();
+
+        if received_parts < total_parts {
+            return Ok(Reassembled::Pending {
+                received_parts,
+                total_parts,
+            });
+        }
+
+        let group = self.groups.remove(&key).expect("Group exists");
+
+        self.buffered_bytes -= group.buffered_bytes;
+
+        Ok(Reassembled::Complete(ReassembledMessage {
+            source_addr: key.source_addr,
+            destination_addr: key.destination_addr,
+            data_coding: group.data_coding,
+            parts: group.parts.into_iter().flatten().collect(),
+        }))
+    }
+}
+
+/// The result of pushing a part to the [`Reassembler`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reassembled {
+    /// Every part of the message has arrived.
+    Complete(ReassembledMessage),
+    /// The part was buffered, the message is waiting for more parts.
+    Pending {
+        /// The number of received parts.
+        received_parts: u8,
+        /// The total number of parts.
+        total_parts: u8,
+    },
+    /// The part was already received and is ignored.
+    Duplicate,
+}
+
+/// Reference of a multipart message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Reference {
+    /// 8-bit concatenation UDH reference number.
+    EightBit(u8),
+    /// 16-bit concatenation UDH reference number.
+    SixteenBit(u16),
+    /// SAR message reference number.
+    Sar(u16),
+}
+
+/// Key grouping the parts of a multipart message.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GroupKey {
+    /// Address of the SME which originated the message.
+    pub source_addr: COctetString<1, 21>,
+    /// Destination address of the message.
+    pub destination_addr: COctetString<1, 21>,
+    /// Reference of the message.
+    pub reference: Reference,
+}
+
+/// Report of a group waiting for parts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IncompleteGroup {
+    /// Key of the group.
+    pub key: GroupKey,
+    /// The total number of parts.
+    pub total_parts: u8,
+    /// The part numbers received so far, in ascending order.
+    pub received_parts: Vec<u8>,
+    /// The time the first part arrived.
+    pub first_received: Duration,
+}
+
+impl IncompleteGroup {
+    /// Returns the part numbers that did not arrive, in ascending order.
+    pub fn missing_parts(&self) -> impl Iterator<Item = u8> + '_ {
+        (1..=self.total_parts).filter(|part_number| !self.received_parts.contains(part_number))
+    }
+}
+
+/// A message whose parts have all arrived.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReassembledMessage {
+    source_addr: COctetString<1, 21>,
+    destination_addr: COctetString<1, 21>,
+    data_coding: DataCoding,
+    parts: Vec<MessagePart>,
+}
+
+impl ReassembledMessage {
+    /// Returns the address of the SME which originated the message.
+    pub fn source_addr(&self) -> &COctetString<1, 21> {
+        &self.source_addr
+    }
+
+    /// Returns the destination address of the message.
+    pub fn destination_addr(&self) -> &COctetString<1, 21> {
+        &self.destination_addr
+    }
+
+    /// Returns the [`DataCoding`] of the first received part.
+    pub const fn data_coding(&self) -> DataCoding {
+        self.data_coding
+    }
+
+    /// Returns the parts, ordered by part number.
+    pub fn parts(&self) -> &[MessagePart] {
+        &self.parts
+    }
+
+    /// Returns the user data of all parts, without their UDH, concatenated.
+    ///
+    /// # Note
+    ///
+    /// Packed GSM 7-bit parts can not be concatenated, as every part is aligned to its own UDH.
+    /// Decode every part on its own using [`MessagePart::udh_length`] instead.
+    pub fn payload(&self) -> Vec<u8> {
+        self.parts
+            .iter()
+            .flat_map(|part| part.user_data())
+            .copied()
+            .collect()
+    }
+}
+
+/// A part of a [`ReassembledMessage`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MessagePart {
+    bytes: Vec<u8>,
+    udh_length: usize,
+}
+
+impl MessagePart {
+    fn new(bytes: &[u8], udh_length: usize) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+            udh_length,
+        }
+    }
+
+    /// Returns the payload of the part, including the UDH.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the length of the UDH, including the UDH length octet. `0` if there is no UDH.
+    pub const fn udh_length(&self) -> usize {
+        self.udh_length
+    }
+
+    /// Returns the UDH, including the UDH length octet.
+    pub fn udh(&self) -> &[u8] {
+        &self.bytes[..self.udh_length]
+    }
+
+    /// Returns the user data following the UDH.
+    pub fn user_data(&self) -> &[u8] {
+        &self.bytes[self.udh_length..]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Group {
+    data_coding: DataCoding,
+    first_received: Duration,
+    buffered_bytes: usize,
+    parts: Vec<Option<MessagePart>>,
+}
+
+impl Group {
+    fn is_expired(&self, now: Duration, max_age: Duration) -> bool {
+        now.saturating_sub(self.first_received) >= max_age
+    }
+
+    fn received_parts(&self) -> u8 {
+        self.parts.iter().filter(|part| part.is_some()).count() as u8
+    }
+
+    fn report(&self, key: GroupKey) -> IncompleteGroup {
+        IncompleteGroup {
+            key,
+            total_parts: self.parts.len() as u8,
+            received_parts: (1..=self.parts.len() as u8)
+                .zip(&self.parts)
+                .filter(|(_, part)| part.is_some())
+                .map(|(part_number, _)| part_number)
+                .collect(),
+            first_received: self.first_received,
+        }
+    }
+}
+
+struct Incoming<'a> {
+    source_addr: &'a COctetString<1, 21>,
+    destination_addr: &'a COctetString<1, 21>,
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+    tlvs: &'a [Tlv],
+    payload: &'a [u8],
+}
+
+impl Incoming<'_> {
+    fn has_udh(&self) -> bool {
+        self.esm_class.has_udhi_indicator()
+    }
+
+    /// Returns the information elements of the UDH, or an empty slice if there is no UDH.
+    fn udh_information_elements(&self) -> Result<&[u8], ReassemblyError> {
+        if !self.has_udh() {
+            return Ok(&[]);
+        }
+
+        let udh_length = match self.payload.first() {
+            Some(&udh_length) if self.payload.len() > udh_length as usize => udh_length as usize,
+            _ => return Err(ReassemblyError::InvalidUdh),
+        };
+
+        Ok(&self.payload[1..=udh_length])
+    }
+
+    fn udh_length(&self) -> Result<usize, ReassemblyError> {
+        if !self.has_udh() {
+            return Ok(0);
+        }
+
+        Ok(self.udh_information_elements()?.len() + 1)
+    }
+}
+
+/// Returns the reference, total parts and part number of a multipart message.
+fn concatenation(incoming: &Incoming<'_>) -> Result<Option<(Reference, u8, u8)>, ReassemblyError> {
+    let mut information_elements = incoming.udh_information_elements()?;
+
+    while let [iei, length, rest @ ..] = information_elements {
+        let length = *length as usize;
+
+        if rest.len() < length {
+            return Err(ReassemblyError::InvalidUdh);
+        }
+
+        let (data, remaining) = rest.split_at(length);
+
+        match (*iei, data) {
+            (0x00, &[reference, total_parts, part_number]) => {
+                let udh = ConcatenatedShortMessage8Bit::new(reference, total_parts, part_number)?;
+
+                return Ok(Some((
+                    Reference::EightBit(udh.reference()),
+                    udh.total_parts(),
+                    udh.part_number(),
+                )));
+            }
+            (0x08, &[high, low, total_parts, part_number]) => {
+                let udh = ConcatenatedShortMessage16Bit::new(
+                    u16::from_be_bytes([high, low]),
+                    total_parts,
+                    part_number,
+                )?;
+
+                return Ok(Some((
+                    Reference::SixteenBit(udh.reference()),
+                    udh.total_parts(),
+                    udh.part_number(),
+                )));
+            }
+            (0x00 | 0x08, _) => return Err(ReassemblyError::InvalidUdh),
+            _ => information_elements = remaining,
+        }
+    }
+
+    if !information_elements.is_empty() {
+        return Err(ReassemblyError::InvalidUdh);
+    }
+
+    match Sar::from_tlvs(incoming.tlvs) {
+        Some(sar) => {
+            let sar = sar?;
+
+            Ok(Some((
+                Reference::Sar(sar.msg_ref_num()),
+                sar.total_segments(),
+                sar.segment_seqnum(),
+            )))
+        }
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use crate::{
+        multipart::{Concatenation, Splitter},
+        pdus::owned::SubmitSm,
+        tlvs::owned::MessageDeliveryRequestTlvValue,
+        types::owned::OctetString,
+        udhs::owned::concatenation::ConcatenatedShortMessageType,
+        values::GsmFeatures,
+    };
+
+    use super::*;
+
+    const NOW: Duration = Duration::from_secs(0);
+
+    /// Splits `text` and converts the parts into [`DeliverSm`]s.
+    fn deliver_sms(text: &str, concatenation: Concatenation, source_addr: &str) -> Vec<DeliverSm> {
+        SubmitSm::default()
+            .split_text(text, &Splitter::new(concatenation))
+            .unwrap()
+            .into_iter()
+            .map(|submit_sm| {
+                let tlvs = submit_sm
+                    .sar()
+                    .map(|sar| sar.unwrap().message_delivery_request_tlvs().to_vec())
+                    .unwrap_or_default();
+
+                DeliverSm::builder()
+                    .source_addr(COctetString::from_str(source_addr).unwrap())
+                    .destination_addr(COctetString::from_str("Destination").unwrap())
+                    .esm_class(submit_sm.esm_class)
+                    .data_coding(submit_sm.data_coding)
+                    .short_message(submit_sm.short_message().clone())
+                    .tlvs(tlvs)
+                    .build()
+            })
+            .collect()
+    }
+
+    fn text() -> alloc::string::String {
+        (0..300u16)
+            .map(|index| (b'a' + (index % 26) as u8) as char)
+            .collect()
+    }
+
+    #[test]
+    fn not_concatenated() {
+        let mut reassembler = Reassembler::new();
+
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::new(b"Hello").unwrap())
+            .build();
+
+        let Reassembled::Complete(message) = reassembler.push_deliver_sm(&deliver_sm, NOW).unwrap()
+        else {
+            panic!("Expected complete message");
+        };
+
+        assert_eq!(message.payload(), b"Hello");
+        assert_eq!(reassembler.buffered_bytes(), 0);
+    }
+
+    #[test]
+    fn udh_8_bit() {
+        let text = text();
+
+        let mut parts = deliver_sms(&text, ConcatenatedShortMessageType::u8(42).into(), "Source");
+
+        assert_eq!(parts.len(), 3);
+
+        // Out of order
+        parts.swap(0, 2);
+
+        let mut reassembler = Reassembler::new();
+
+        assert_eq!(
+            reassembler.push_deliver_sm(&parts[0], NOW).unwrap(),
+            Reassembled::Pending {
+                received_parts: 1,
+                total_parts: 3
+            }
+        );
+        assert_eq!(
+            reassembler.push_deliver_sm(&parts[1], NOW).unwrap(),
+            Reassembled::Pending {
+                received_parts: 2,
+                total_parts: 3
+            }
+        );
+
+        let Reassembled::Complete(message) = reassembler.push_deliver_sm(&parts[2], NOW).unwrap()
+        else {
+            panic!("Expected complete message");
+        };
+
+        assert_eq!(message.payload(), text.as_bytes());
+        assert_eq!(message.parts()[0].udh_length(), 6);
+        assert_eq!(
+            message.source_addr(),
+            &COctetString::from_str("Source").unwrap()
+        );
+        assert_eq!(reassembler.buffered_bytes(), 0);
+        assert_eq!(reassembler.incomplete().count(), 0);
+    }
+
+    #[test]
+    fn udh_16_bit() {
+        let text = text();
+
+        let parts = deliver_sms(
+            &text,
+            ConcatenatedShortMessageType::u16(0x1234).into(),
+            "Source",
+        );
+
+        let mut reassembler = Reassembler::new();
+
+        let results = parts
+            .iter()
+            .map(|part| reassembler.push_deliver_sm(part, NOW).unwrap())
+            .collect::<Vec<_>>();
+
+        let Some(Reassembled::Complete(message)) = results.last() else {
+            panic!("Expected complete message");
+        };
+
+        assert_eq!(message.payload(), text.as_bytes());
+        assert_eq!(message.parts()[0].udh_length(), 7);
+    }
+
+    #[test]
+    fn sar() {
+        let text = text();
+
+        let parts = deliver_sms(&text, Concatenation::sar(7), "Source");
+
+        assert_eq!(parts[0].esm_class.gsm_features, GsmFeatures::NotSelected);
+
+        let mut reassembler = Reassembler::new();
+
+        let results = parts
+            .iter()
+            .map(|part| reassembler.push_deliver_sm(part, NOW).unwrap())
+            .collect::<Vec<_>>();
+
+        let Some(Reassembled::Complete(message)) = results.last() else {
+            panic!("Expected complete message");
+        };
+
+        assert_eq!(message.payload(), text.as_bytes());
+        assert_eq!(message.parts()[0].udh_length(), 0);
+    }
+
+    #[test]
+    fn data_sm() {
+        let text = text();
+
+        let parts = DataSm::default()
+            .split_text(&text, &Splitter::new(ConcatenatedShortMessageType::u8(1)))
+            .unwrap();
+
+        let mut reassembler = Reassembler::new();
+
+        let results = parts
+            .iter()
+            .map(|part| reassembler.push_data_sm(part, NOW).unwrap())
+            .collect::<Vec<_>>();
+
+        let Some(Reassembled::Complete(message)) = results.last() else {
+            panic!("Expected complete message");
+        };
+
+        assert_eq!(message.payload(), text.as_bytes());
+    }
+
+    #[test]
+    fn grouped_by_address() {
+        let text = text();
+
+        let concatenation = ConcatenatedShortMessageType::u8(1).into();
+
+        let first = deliver_sms(&text, concatenation, "First");
+        let second = deliver_sms(&text, concatenation, "Second");
+
+        let mut reassembler = Reassembler::new();
+
+        assert_eq!(first.len(), 3);
+
+        for part in [&first[0], &second[0], &first[1], &second[1]] {
+            assert!(matches!(
+                reassembler.push_deliver_sm(part, NOW).unwrap(),
+                Reassembled::Pending { .. }
+            ));
+        }
+
+        assert_eq!(reassembler.incomplete().count(), 2);
+
+        let Reassembled::Complete(message) = reassembler.push_deliver_sm(&second[2], NOW).unwrap()
+        else {
+            panic!("Expected complete message");
+        };
+
+        assert_eq!(
+            message.source_addr(),
+            &COctetString::from_str("Second").unwrap()
+        );
+        assert_eq!(reassembler.incomplete().count(), 1);
+    }
+
+    #[test]
+    fn duplicate() {
+        let parts = deliver_sms(
+            &text(),
+            ConcatenatedShortMessageType::u8(1).into(),
+            "Source",
+        );
+
+        let mut reassembler = Reassembler::new();
+
+        reassembler.push_deliver_sm(&parts[0], NOW).unwrap();
+
+        let buffered_bytes = reassembler.buffered_bytes();
+
+        assert_eq!(
+            reassembler.push_deliver_sm(&parts[0], NOW).unwrap(),
+            Reassembled::Duplicate
+        );
+        assert_eq!(reassembler.buffered_bytes(), buffered_bytes);
+    }
+
+    #[test]
+    fn expire() {
+        let parts = deliver_sms(
+            &text(),
+            ConcatenatedShortMessageType::u8(1).into(),
+            "Source",
+        );
+
+        let mut reassembler = Reassembler::new().with_max_age(Duration::from_secs(60));
+
+        reassembler
+            .push_deliver_sm(&parts[0], Duration::from_secs(10))
+            .unwrap();
+        reassembler
+            .push_deliver_sm(&parts[2], Duration::from_secs(20))
+            .unwrap();
+
+        assert!(reassembler.expire(Duration::from_secs(69)).is_empty());
+
+        let expired = reassembler.expire(Duration::from_secs(70));
+
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].total_parts, 3);
+        assert_eq!(expired[0].received_parts, [1, 3]);
+        assert_eq!(expired[0].missing_parts().collect::<Vec<_>>(), [2]);
+        assert_eq!(expired[0].first_received, Duration::from_secs(10));
+        assert_eq!(expired[0].key.reference, Reference::EightBit(1));
+
+        assert_eq!(reassembler.buffered_bytes(), 0);
+        assert_eq!(reassembler.incomplete().count(), 0);
+    }
+
+    #[test]
+    fn expired_group_is_replaced() {
+        let three = deliver_sms(
+            &text(),
+            ConcatenatedShortMessageType::u8(1).into(),
+            "Source",
+        );
+        let two = deliver_sms(
+            &text()[..200],
+            ConcatenatedShortMessageType::u8(1).into(),
+            "Source",
+        );
+
+        let mut reassembler = Reassembler::new().with_max_age(Duration::from_secs(60));
+
+        reassembler
+            .push_deliver_sm(&three[0], Duration::from_secs(10))
+            .unwrap();
+
+        assert_eq!(
+            reassembler
+                .push_deliver_sm(&three[0], Duration::from_secs(70))
+                .unwrap(),
+            Reassembled::Pending {
+                received_parts: 1,
+                total_parts: 3
+            }
+        );
+        assert_eq!(reassembler.buffered_bytes(), 140);
+
+        assert_eq!(
+            reassembler
+                .push_deliver_sm(&two[1], Duration::from_secs(130))
+                .unwrap(),
+            Reassembled::Pending {
+                received_parts: 1,
+                total_parts: 2
+            }
+        );
+        assert_eq!(
+            reassembler.buffered_bytes(),
+            two[1].short_message().as_ref().len()
+        );
+        assert_eq!(reassembler.incomplete().count(), 1);
+    }
+
+    #[test]
+    fn memory_cap() {
+        let parts = deliver_sms(
+            &text(),
+            ConcatenatedShortMessageType::u8(1).into(),
+            "Source",
+        );
+
+        let mut reassembler = Reassembler::new().with_max_buffered_bytes(200);
+
+        reassembler.push_deliver_sm(&parts[0], NOW).unwrap();
+
+        let err = reassembler.push_deliver_sm(&parts[1], NOW).unwrap_err();
+
+        assert_eq!(
+            err,
+            ReassemblyError::MemoryCapExceeded {
+                buffered_bytes: 140,
+                part_size: 140,
+                max_buffered_bytes: 200
+            }
+        );
+    }
+
+    #[test]
+    fn inconsistent_total_parts() {
+        let three = deliver_sms(
+            &text(),
+            ConcatenatedShortMessageType::u8(1).into(),
+            "Source",
+        );
+        let two = deliver_sms(
+            &text()[..200],
+            ConcatenatedShortMessageType::u8(1).into(),
+            "Source",
+        );
+
+        let mut reassembler = Reassembler::new();
+
+        reassembler.push_deliver_sm(&three[0], NOW).unwrap();
+
+        let err = reassembler.push_deliver_sm(&two[1], NOW).unwrap_err();
+
+        assert_eq!(
+            err,
+            ReassemblyError::InconsistentTotalParts {
+                expected: 3,
+                actual: 2
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_udh() {
+        let mut reassembler = Reassembler::new();
+
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .short_message(OctetString::new([0x05, 0x00, 0x03, 0x01]).unwrap())
+            .build();
+
+        assert_eq!(
+            reassembler.push_deliver_sm(&deliver_sm, NOW).unwrap_err(),
+            ReassemblyError::InvalidUdh
+        );
+
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .short_message(OctetString::new([0x05, 0x00, 0x03, 0x01, 0x02, 0x03, 0x41]).unwrap())
+            .build();
+
+        assert!(matches!(
+            reassembler.push_deliver_sm(&deliver_sm, NOW).unwrap_err(),
+            ReassemblyError::Concatenation(_)
+        ));
+
+        let deliver_sm = DeliverSm::builder()
+            .tlvs(alloc::vec![
+                MessageDeliveryRequestTlvValue::SarMsgRefNum(1),
+                MessageDeliveryRequestTlvValue::SarTotalSegments(0),
+                MessageDeliveryRequestTlvValue::SarSegmentSeqnum(1),
+            ])
+            .build();
+
+        assert!(matches!(
+            reassembler.push_deliver_sm(&deliver_sm, NOW).unwrap_err(),
+            ReassemblyError::Concatenation(_)
+        ));
+    }
+}
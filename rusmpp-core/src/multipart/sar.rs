@@ -0,0 +1,170 @@
+use crate::{
+    tlvs::owned::{
+        MessageDeliveryRequestTlvValue, MessageSubmissionRequestTlvValue, Tlv, TlvValue,
+    },
+    udhs::errors::ConcatenatedShortMessageError,
+};
+
+/// Segmentation and reassembly (SAR) of a multipart message.
+///
+/// The set of `sar_msg_ref_num`, `sar_total_segments` and `sar_segment_seqnum` TLVs,
+/// an alternative to the concatenation UDH.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Sar {
+    msg_ref_num: u16,
+    total_segments: u8,
+    segment_seqnum: u8,
+}
+
+impl Sar {
+    /// Creates a new [`Sar`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` if `total_segments` is not zero and `segment_seqnum` is in `1..=total_segments`.
+    /// - `Err(ConcatenatedShortMessageError)` otherwise.
+    pub const fn new(
+        msg_ref_num: u16,
+        total_segments: u8,
+        segment_seqnum: u8,
+    ) -> Result<Self, ConcatenatedShortMessageError> {
+        if total_segments == 0 {
+            return Err(ConcatenatedShortMessageError::TotalPartsZero);
+        }
+
+        if segment_seqnum == 0 {
+            return Err(ConcatenatedShortMessageError::PartNumberZero);
+        }
+
+        if segment_seqnum > total_segments {
+            return Err(ConcatenatedShortMessageError::PartNumberExceedsTotalParts {
+                part_number: segment_seqnum,
+                total_parts: total_segments,
+            });
+        }
+
+        Ok(Self::new_unchecked(
+            msg_ref_num,
+            total_segments,
+            segment_seqnum,
+        ))
+    }
+
+    /// Creates a new [`Sar`] without checking invariants.
+    pub const fn new_unchecked(msg_ref_num: u16, total_segments: u8, segment_seqnum: u8) -> Self {
+        Self {
+            msg_ref_num,
+            total_segments,
+            segment_seqnum,
+        }
+    }
+
+    /// Returns the reference number of the multipart message.
+    pub const fn msg_ref_num(&self) -> u16 {
+        self.msg_ref_num
+    }
+
+    /// Returns the total number of segments.
+    pub const fn total_segments(&self) -> u8 {
+        self.total_segments
+    }
+
+    /// Returns the sequence number of this segment, starting at `1`.
+    pub const fn segment_seqnum(&self) -> u8 {
+        self.segment_seqnum
+    }
+
+    /// Reads the SAR TLVs from `tlvs`.
+    ///
+    /// # Returns
+    ///
+    /// - `None` if any of the SAR TLVs is missing.
+    /// - `Some(Err(ConcatenatedShortMessageError))` if the TLVs are inconsistent. See [`Sar::new`].
+    pub fn from_tlvs(tlvs: &[Tlv]) -> Option<Result<Self, ConcatenatedShortMessageError>> {
+        let mut msg_ref_num = None;
+        let mut total_segments = None;
+        let mut segment_seqnum = None;
+
+        for tlv in tlvs {
+            match tlv.value() {
+                Some(TlvValue::SarMsgRefNum(value)) => msg_ref_num = Some(*value),
+                Some(TlvValue::SarTotalSegments(value)) => total_segments = Some(*value),
+                Some(TlvValue::SarSegmentSeqnum(value)) => segment_seqnum = Some(*value),
+                _ => {}
+            }
+        }
+
+        Some(Self::new(msg_ref_num?, total_segments?, segment_seqnum?))
+    }
+
+    /// Returns the SAR TLVs of a message submission request.
+    pub const fn message_submission_request_tlvs(&self) -> [MessageSubmissionRequestTlvValue; 3] {
+        [
+            MessageSubmissionRequestTlvValue::SarMsgRefNum(self.msg_ref_num),
+            MessageSubmissionRequestTlvValue::SarTotalSegments(self.total_segments),
+            MessageSubmissionRequestTlvValue::SarSegmentSeqnum(self.segment_seqnum),
+        ]
+    }
+
+    /// Returns the SAR TLVs of a message delivery request.
+    pub const fn message_delivery_request_tlvs(&self) -> [MessageDeliveryRequestTlvValue; 3] {
+        [
+            MessageDeliveryRequestTlvValue::SarMsgRefNum(self.msg_ref_num),
+            MessageDeliveryRequestTlvValue::SarTotalSegments(self.total_segments),
+            MessageDeliveryRequestTlvValue::SarSegmentSeqnum(self.segment_seqnum),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert!(Sar::new(1, 3, 2).is_ok());
+
+        assert!(matches!(
+            Sar::new(1, 0, 1),
+            Err(ConcatenatedShortMessageError::TotalPartsZero)
+        ));
+        assert!(matches!(
+            Sar::new(1, 3, 0),
+            Err(ConcatenatedShortMessageError::PartNumberZero)
+        ));
+        assert!(matches!(
+            Sar::new(1, 2, 3),
+            Err(ConcatenatedShortMessageError::PartNumberExceedsTotalParts {
+                part_number: 3,
+                total_parts: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn from_tlvs() {
+        let sar = Sar::new(0x1234, 3, 2).unwrap();
+
+        let tlvs = sar
+            .message_delivery_request_tlvs()
+            .into_iter()
+            .map(Tlv::from)
+            .collect::<Vec<_>>();
+
+        assert_eq!(Sar::from_tlvs(&tlvs).unwrap().unwrap(), sar);
+
+        // Missing TLV
+        assert!(Sar::from_tlvs(&tlvs[1..]).is_none());
+
+        // Inconsistent TLVs
+        let tlvs = [
+            Tlv::from(MessageDeliveryRequestTlvValue::SarMsgRefNum(1)),
+            Tlv::from(MessageDeliveryRequestTlvValue::SarTotalSegments(2)),
+            Tlv::from(MessageDeliveryRequestTlvValue::SarSegmentSeqnum(3)),
+        ];
+
+        assert!(Sar::from_tlvs(&tlvs).unwrap().is_err());
+    }
+}
@@ -0,0 +1,732 @@
+use core::ops::Range;
+
+use alloc::vec::Vec;
+
+use crate::{
+    encoding::{
+        gsm7bit::alphabet::ESCAPE,
+        text::{TextEncoder, TextEncoding},
+    },
+    udhs::owned::concatenation::ConcatenatedShortMessageType,
+    values::{DataCoding, DataCodingAlphabet},
+};
+
+use super::{Sar, SplitError};
+
+/// How the parts of a multipart message are linked together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Concatenation {
+    /// Every part starts with a concatenation UDH.
+    Udh(ConcatenatedShortMessageType),
+    /// Every part carries the `sar_msg_ref_num`, `sar_total_segments` and `sar_segment_seqnum` TLVs.
+    ///
+    /// See [`Sar`].
+    Sar {
+        /// The reference number of the multipart message.
+        msg_ref_num: u16,
+    },
+}
+
+impl Concatenation {
+    /// Creates a new [`Concatenation::Sar`].
+    pub const fn sar(msg_ref_num: u16) -> Self {
+        Self::Sar { msg_ref_num }
+    }
+
+    /// Returns the length of the concatenation UDH, `0` for [`Concatenation::Sar`].
+    pub const fn udh_length(&self) -> usize {
+        match self {
+            Self::Udh(concatenation) => concatenation.udh_length(),
+            Self::Sar { .. } => 0,
+        }
+    }
+}
+
+impl From<ConcatenatedShortMessageType> for Concatenation {
+    fn from(concatenation: ConcatenatedShortMessageType) -> Self {
+        Self::Udh(concatenation)
+    }
+}
+
+/// Splits messages exceeding the maximum short message size into concatenated parts.
+///
+/// The parts are linked either by a concatenation UDH or by SAR TLVs, see [`Concatenation`].
+/// National language information elements of GSM 7-bit encoded texts are repeated in every part.
+///
+/// Characters are never split across parts: GSM 7-bit escape sequences and UCS2
+/// surrogate pairs always stay in the same part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Splitter<'a> {
+    concatenation: Concatenation,
+    encoder: TextEncoder<'a>,
+    max_short_message_size: usize,
+}
+
+impl Splitter<'static> {
+    /// Creates a new [`Splitter`] linking the parts using `concatenation`.
+    ///
+    /// Texts are encoded using the default [`TextEncoder`] and parts are at most `140` octets.
+    pub fn new(concatenation: impl Into<Concatenation>) -> Self {
+        Self {
+            concatenation: concatenation.into(),
+            encoder: TextEncoder::new(),
+            max_short_message_size: Self::DEFAULT_MAX_SHORT_MESSAGE_SIZE,
+        }
+    }
+}
+
+impl<'a> Splitter<'a> {
+    /// The default maximum size of a part in octets, including the UDH.
+    const DEFAULT_MAX_SHORT_MESSAGE_SIZE: usize = 140;
+
+    /// The maximum size of the short_message field in octets.
+    const MAX_SHORT_MESSAGE_SIZE: usize = 255;
+
+    /// Sets the [`TextEncoder`] used by [`Splitter::split_text`].
+    pub const fn with_encoder<'b>(self, encoder: TextEncoder<'b>) -> Splitter<'b> {
+        Splitter {
+            concatenation: self.concatenation,
+            encoder,
+            max_short_message_size: self.max_short_message_size,
+        }
+    }
+
+    /// Sets the maximum size of a part in octets, including the UDH.
+    ///
+    /// Values above `255` are clamped to `255`, the maximum size of the short_message field.
+    pub const fn with_max_short_message_size(mut self, max_short_message_size: usize) -> Self {
+        self.max_short_message_size = if max_short_message_size > Self::MAX_SHORT_MESSAGE_SIZE {
+            Self::MAX_SHORT_MESSAGE_SIZE
+        } else {
+            max_short_message_size
+        };
+
+        self
+    }
+
+    /// Returns the [`Concatenation`].
+    pub const fn concatenation(&self) -> Concatenation {
+        self.concatenation
+    }
+
+    /// Returns the [`TextEncoder`].
+    pub const fn encoder(&self) -> TextEncoder<'a> {
+        self.encoder
+    }
+
+    /// Returns the maximum size of a part in octets, including the UDH.
+    pub const fn max_short_message_size(&self) -> usize {
+        self.max_short_message_size
+    }
+
+    /// Encodes `text` using the [selected](TextEncoder::select) encoding and splits it into parts.
+    ///
+    /// A text fitting into a single part is not split and has no concatenation UDH.
+    pub fn split_text(&self, text: &str) -> Result<SplitMessage, SplitError> {
+        let encoding = self.encoder.select(text);
+
+        match encoding {
+            TextEncoding::Gsm7Bit(codec) if self.encoder.lossy() => {
+                self.split_text_as(&codec.transliterate(text), encoding)
+            }
+            _ => self.split_text_as(text, encoding),
+        }
+    }
+
+    /// Encodes `text` using `encoding` and splits it into parts.
+    ///
+    /// A text fitting into a single part is not split and has no concatenation UDH.
+    pub fn split_text_as(
+        &self,
+        text: &str,
+        encoding: TextEncoding,
+    ) -> Result<SplitMessage, SplitError> {
+        let national_udh = match encoding {
+            TextEncoding::Gsm7Bit(codec) => codec.udh_bytes(),
+            TextEncoding::Latin1(_) | TextEncoding::Ucs2(_) => heapless::Vec::new(),
+        };
+
+        let single_length =
+            national_udh.len() + encoded_length_after_udh(encoding, national_udh.len(), text)?;
+
+        if single_length <= self.max_short_message_size {
+            let mut part = alloc::vec![0; single_length];
+
+            part[..national_udh.len()].copy_from_slice(&national_udh);
+
+            encode_after_udh(
+                encoding,
+                national_udh.len(),
+                text,
+                &mut part[national_udh.len()..],
+            )?;
+
+            return Ok(SplitMessage {
+                data_coding: encoding.data_coding(),
+                has_udh: !national_udh.is_empty(),
+                sar_msg_ref_num: None,
+                parts: alloc::vec![part],
+            });
+        }
+
+        let udh_length = self.udh(1, 1, &national_udh).len();
+        let capacity = self.max_short_message_size.saturating_sub(udh_length);
+
+        let fits = |range: Range<usize>| {
+            encoded_length_after_udh(encoding, udh_length, &text[range])
+                .map(|length| length <= capacity)
+        };
+
+        let mut ranges = Vec::new();
+        let mut start = 0;
+
+        for (index, character) in text.char_indices() {
+            let end = index + character.len_utf8();
+
+            if fits(start..end)? {
+                continue;
+            }
+
+            if index == start || !fits(index..end)? {
+                return Err(self.too_small());
+            }
+
+            ranges.push(start..index);
+            start = index;
+        }
+
+        ranges.push(start..text.len());
+
+        let total_parts = Self::total_parts(ranges.len())?;
+
+        let parts = ranges
+            .into_iter()
+            .zip(1..=total_parts)
+            .map(|(range, part_number)| {
+                let udh = self.udh(total_parts, part_number, &national_udh);
+                let length = encoded_length_after_udh(encoding, udh.len(), &text[range.clone()])?;
+
+                let mut part = alloc::vec![0; udh.len() + length];
+
+                part[..udh.len()].copy_from_slice(&udh);
+
+                encode_after_udh(encoding, udh.len(), &text[range], &mut part[udh.len()..])?;
+
+                Ok(part)
+            })
+            .collect::<Result<Vec<_>, SplitError>>()?;
+
+        Ok(SplitMessage {
+            data_coding: encoding.data_coding(),
+            has_udh: udh_length > 0,
+            sar_msg_ref_num: self.sar_msg_ref_num(),
+            parts,
+        })
+    }
+
+    /// Splits already encoded `bytes` into parts.
+    ///
+    /// The [alphabet](crate::values::DataCodingScheme::alphabet) of `data_coding` determines the split points:
+    ///
+    /// - [`DataCodingAlphabet::Gsm7Bit`]: unpacked GSM 7-bit septets, one per octet.
+    ///   Escape sequences are not split.
+    /// - [`DataCodingAlphabet::Ucs2`]: UCS2 code units. Surrogate pairs are not split.
+    /// - Otherwise: any octet.
+    ///
+    /// A payload fitting into a single part is not split and has no concatenation UDH.
+    pub fn split_bytes(
+        &self,
+        bytes: &[u8],
+        data_coding: DataCoding,
+    ) -> Result<SplitMessage, SplitError> {
+        if bytes.len() <= self.max_short_message_size {
+            return Ok(SplitMessage {
+                data_coding,
+                has_udh: false,
+                sar_msg_ref_num: None,
+                parts: alloc::vec![bytes.to_vec()],
+            });
+        }
+
+        let capacity = self
+            .max_short_message_size
+            .saturating_sub(self.concatenation.udh_length());
+
+        let mut ranges = Vec::new();
+        let mut start = 0;
+
+        while start < bytes.len() {
+            let remaining = &bytes[start..];
+
+            let size = if remaining.len() <= capacity {
+                remaining.len()
+            } else {
+                split_point(data_coding, remaining, capacity)
+            };
+
+            if size == 0 {
+                return Err(self.too_small());
+            }
+
+            ranges.push(start..start + size);
+            start += size;
+        }
+
+        let total_parts = Self::total_parts(ranges.len())?;
+
+        let parts = ranges
+            .into_iter()
+            .zip(1..=total_parts)
+            .map(|(range, part_number)| {
+                let mut part = self.udh(total_parts, part_number, &[]).to_vec();
+
+                part.extend_from_slice(&bytes[range]);
+
+                part
+            })
+            .collect();
+
+        Ok(SplitMessage {
+            data_coding,
+            has_udh: matches!(self.concatenation, Concatenation::Udh(_)),
+            sar_msg_ref_num: self.sar_msg_ref_num(),
+            parts,
+        })
+    }
+
+    /// Builds the UDH of a part: the concatenation information element, if any,
+    /// followed by the information elements of `national_udh`.
+    fn udh(&self, total_parts: u8, part_number: u8, national_udh: &[u8]) -> heapless::Vec<u8, 16> {
+        let mut udh = heapless::Vec::new();
+
+        let Concatenation::Udh(concatenation) = self.concatenation else {
+            // At most 7 octets of national language UDH.
+            let _ = udh.extend_from_slice(national_udh);
+
+            return udh;
+        };
+
+        let concatenation = concatenation
+            .concatenated_short_message_unchecked(total_parts, part_number)
+            .udh_bytes();
+
+        let concatenation = concatenation.as_bytes();
+
+        // National language information elements without the UDH length.
+        let national_ies = national_udh.get(1..).unwrap_or_default();
+
+        // Both fit, at most 7 octets of concatenation UDH and 6 octets of national language IEs.
+        let _ = udh.push(concatenation[0] + national_ies.len() as u8);
+        let _ = udh.extend_from_slice(&concatenation[1..]);
+        let _ = udh.extend_from_slice(national_ies);
+
+        udh
+    }
+
+    const fn sar_msg_ref_num(&self) -> Option<u16> {
+        match self.concatenation {
+            Concatenation::Udh(_) => None,
+            Concatenation::Sar { msg_ref_num } => Some(msg_ref_num),
+        }
+    }
+
+    fn total_parts(parts: usize) -> Result<u8, SplitError> {
+        u8::try_from(parts).map_err(|_| SplitError::TooManyParts { parts })
+    }
+
+    const fn too_small(&self) -> SplitError {
+        SplitError::MaxShortMessageSizeTooSmall {
+            max_short_message_size: self.max_short_message_size,
+        }
+    }
+}
+
+/// A message split into parts by the [`Splitter`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SplitMessage {
+    data_coding: DataCoding,
+    has_udh: bool,
+    sar_msg_ref_num: Option<u16>,
+    parts: Vec<Vec<u8>>,
+}
+
+impl SplitMessage {
+    /// Returns the [`DataCoding`] of the parts.
+    pub const fn data_coding(&self) -> DataCoding {
+        self.data_coding
+    }
+
+    /// Returns `true` if the parts start with a UDH.
+    ///
+    /// The UDH Indicator must be set in the `esm_class` of the PDUs carrying the parts.
+    pub const fn has_udh(&self) -> bool {
+        self.has_udh
+    }
+
+    /// Returns the short messages of the parts, including their UDH.
+    pub fn parts(&self) -> &[Vec<u8>] {
+        &self.parts
+    }
+
+    /// Returns the [`Sar`] of the part at `index`.
+    ///
+    /// Returns [`None`] if the parts are not linked by SAR TLVs, the message was not split,
+    /// or `index` is out of bounds.
+    pub fn sar(&self, index: usize) -> Option<Sar> {
+        let msg_ref_num = self.sar_msg_ref_num?;
+
+        if index >= self.parts.len() {
+            return None;
+        }
+
+        // The number of parts is checked by the splitter.
+        Some(Sar::new_unchecked(
+            msg_ref_num,
+            self.parts.len() as u8,
+            index as u8 + 1,
+        ))
+    }
+
+    /// Converts the [`SplitMessage`] into the short messages of the parts.
+    pub fn into_short_messages(self) -> Vec<Vec<u8>> {
+        self.parts
+    }
+}
+
+fn encoded_length_after_udh(
+    encoding: TextEncoding,
+    udh_length: usize,
+    text: &str,
+) -> Result<usize, SplitError> {
+    match encoding {
+        TextEncoding::Gsm7Bit(codec) => Ok(codec.encoded_length_after_udh(udh_length, text)?),
+        TextEncoding::Latin1(codec) => Ok(codec.encoded_length(text)?),
+        TextEncoding::Ucs2(codec) => Ok(codec.encoded_length(text)),
+    }
+}
+
+fn encode_after_udh(
+    encoding: TextEncoding,
+    udh_length: usize,
+    text: &str,
+    dst: &mut [u8],
+) -> Result<usize, SplitError> {
+    match encoding {
+        TextEncoding::Gsm7Bit(codec) => Ok(codec.encode_after_udh(udh_length, text, dst)?),
+        TextEncoding::Latin1(codec) => Ok(codec.encode(text, dst)?),
+        TextEncoding::Ucs2(codec) => Ok(codec.encode(text, dst)),
+    }
+}
+
+/// Returns the largest size not exceeding `max` at which `bytes` can be split without breaking a character.
+fn split_point(data_coding: DataCoding, bytes: &[u8], max: usize) -> usize {
+    match data_coding.scheme().alphabet() {
+        DataCodingAlphabet::Gsm7Bit => match max.checked_sub(1).map(|index| bytes[index]) {
+            // The escape septet must stay with the following septet.
+            Some(ESCAPE) => max - 1,
+            _ => max,
+        },
+        DataCodingAlphabet::Ucs2 => {
+            let max = max & !1;
+
+            match max
+                .checked_sub(2)
+                .map(|index| u16::from_be_bytes([bytes[index], bytes[index + 1]]))
+            {
+                // The high surrogate must stay with the low surrogate.
+                Some(0xD800..=0xDBFF) => max - 2,
+                _ => max,
+            }
+        }
+        _ => max,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use crate::encoding::{
+        gsm7bit::{Gsm7Bit, Gsm7BitPacking, NationalLanguage},
+        latin1::Latin1,
+        ucs2::Ucs2,
+    };
+
+    use super::*;
+
+    fn splitter() -> Splitter<'static> {
+        Splitter::new(ConcatenatedShortMessageType::u8(0x2A))
+    }
+
+    #[test]
+    fn single_part() {
+        let message = splitter().split_text("Hello").unwrap();
+
+        assert_eq!(message.data_coding(), DataCoding::McSpecific);
+        assert!(!message.has_udh());
+        assert_eq!(message.parts(), &[b"Hello".to_vec()]);
+    }
+
+    #[test]
+    fn gsm7bit_unpacked() {
+        let text = "a".repeat(300);
+
+        let message = splitter().split_text(&text).unwrap();
+
+        assert!(message.has_udh());
+        assert_eq!(message.parts().len(), 3);
+
+        for (index, part) in message.parts().iter().enumerate() {
+            assert_eq!(&part[..6], &[0x05, 0x00, 0x03, 0x2A, 0x03, index as u8 + 1]);
+            assert!(part.len() <= 140);
+        }
+
+        assert_eq!(message.parts()[0].len(), 140);
+        assert_eq!(message.parts()[2].len(), 6 + 300 - 2 * 134);
+    }
+
+    #[test]
+    fn gsm7bit_packed() {
+        let splitter = splitter().with_encoder(
+            TextEncoder::new()
+                .with_gsm7bit_packing(Gsm7BitPacking::Packed)
+                .with_latin1(false),
+        );
+
+        let text = "a".repeat(161);
+
+        let message = splitter.split_text(&text).unwrap();
+
+        assert_eq!(message.parts().len(), 2);
+
+        // 153 septets after a 6 octet UDH and 1 fill bit.
+        let codec = Gsm7Bit::packed();
+
+        let first = codec.decode_after_udh(6, &message.parts()[0][6..]);
+        let second = codec.decode_after_udh(6, &message.parts()[1][6..]);
+
+        assert_eq!(first.collect::<Result<String, _>>().unwrap().len(), 153);
+        assert_eq!(second.collect::<Result<String, _>>().unwrap().len(), 8);
+    }
+
+    #[test]
+    fn gsm7bit_escape_sequence_not_split() {
+        let codec = Gsm7Bit::unpacked();
+
+        // 133 septets, the escape sequence would start at the last octet of the first part.
+        let text = alloc::format!("{}€{}", "a".repeat(133), "b".repeat(10));
+
+        let message = splitter()
+            .split_text_as(&text, TextEncoding::Gsm7Bit(codec))
+            .unwrap();
+
+        assert_eq!(message.parts().len(), 2);
+        assert_eq!(message.parts()[0].len(), 6 + 133);
+
+        let decoded = message
+            .parts()
+            .iter()
+            .map(|part| codec.decode_to_string(&part[6..]).unwrap())
+            .collect::<String>();
+
+        assert_eq!(decoded, text);
+    }
+
+    #[test]
+    fn gsm7bit_national_language() {
+        let languages = [NationalLanguage::Turkish];
+
+        let splitter = splitter().with_encoder(
+            TextEncoder::new()
+                .with_latin1(false)
+                .with_national_languages(&languages),
+        );
+
+        let text = "ş".repeat(200);
+
+        let message = splitter.split_text(&text).unwrap();
+
+        assert_eq!(message.parts().len(), 2);
+
+        for part in message.parts() {
+            assert_eq!(&part[..1], &[0x08]);
+            assert_eq!(&part[6..9], &[0x25, 0x01, 0x01]);
+        }
+    }
+
+    #[test]
+    fn latin1() {
+        let text = "á".repeat(150);
+
+        let message = splitter()
+            .split_text_as(&text, TextEncoding::Latin1(Latin1::new()))
+            .unwrap();
+
+        assert_eq!(message.data_coding(), DataCoding::Latin1);
+        assert_eq!(message.parts().len(), 2);
+        assert_eq!(message.parts()[0].len(), 140);
+        assert_eq!(message.parts()[1].len(), 6 + 16);
+    }
+
+    #[test]
+    fn ucs2_surrogate_pair_not_split() {
+        // 133 octets of text, the surrogate pair would be split at the 134 octet boundary.
+        let text = alloc::format!("{}😀{}", "ш".repeat(66), "ш".repeat(10));
+
+        let message = splitter()
+            .split_text_as(&text, TextEncoding::Ucs2(Ucs2::new()))
+            .unwrap();
+
+        assert_eq!(message.parts().len(), 2);
+        assert_eq!(message.parts()[0].len(), 6 + 132);
+
+        let decoded = message
+            .parts()
+            .iter()
+            .map(|part| Ucs2::new().decode_to_string(&part[6..]).unwrap())
+            .collect::<String>();
+
+        assert_eq!(decoded, text);
+    }
+
+    #[test]
+    fn sixteen_bit_reference() {
+        let splitter = Splitter::new(ConcatenatedShortMessageType::u16(0x1234));
+
+        let message = splitter.split_text(&"a".repeat(200)).unwrap();
+
+        assert_eq!(message.parts().len(), 2);
+        assert_eq!(
+            &message.parts()[1][..7],
+            &[0x06, 0x08, 0x04, 0x12, 0x34, 0x02, 0x02]
+        );
+        assert_eq!(message.parts()[0].len(), 140);
+    }
+
+    #[test]
+    fn sar() {
+        let splitter = Splitter::new(Concatenation::sar(0x1234));
+
+        let text = "a".repeat(300);
+
+        let message = splitter.split_text(&text).unwrap();
+
+        assert!(!message.has_udh());
+        assert_eq!(message.parts().len(), 3);
+        assert_eq!(message.parts()[0], "a".repeat(140).into_bytes());
+        assert_eq!(message.parts()[2].len(), 20);
+
+        assert_eq!(message.sar(0), Some(Sar::new(0x1234, 3, 1).unwrap()));
+        assert_eq!(message.sar(2), Some(Sar::new(0x1234, 3, 3).unwrap()));
+        assert_eq!(message.sar(3), None);
+
+        // Not split
+        let message = splitter.split_text("Hello").unwrap();
+
+        assert_eq!(message.sar(0), None);
+    }
+
+    #[test]
+    fn sar_national_language() {
+        let languages = [NationalLanguage::Turkish];
+
+        let splitter = Splitter::new(Concatenation::sar(1)).with_encoder(
+            TextEncoder::new()
+                .with_latin1(false)
+                .with_national_languages(&languages),
+        );
+
+        let message = splitter.split_text(&"ş".repeat(200)).unwrap();
+
+        assert!(message.has_udh());
+        assert_eq!(message.parts().len(), 2);
+
+        for part in message.parts() {
+            assert_eq!(&part[..4], &[0x03, 0x25, 0x01, 0x01]);
+        }
+
+        assert_eq!(message.parts()[0].len(), 140);
+    }
+
+    #[test]
+    fn sar_split_bytes() {
+        let splitter = Splitter::new(Concatenation::sar(1));
+
+        let message = splitter
+            .split_bytes(&[0xFF; 300], DataCoding::OctetUnspecified)
+            .unwrap();
+
+        assert!(!message.has_udh());
+        assert_eq!(message.parts().len(), 3);
+        assert_eq!(message.parts()[0].len(), 140);
+        assert_eq!(message.parts()[2].len(), 20);
+    }
+
+    #[test]
+    fn split_bytes_escape_sequence_not_split() {
+        let mut bytes = alloc::vec![0x61; 133];
+        bytes.extend_from_slice(&[ESCAPE, 0x65]);
+        bytes.extend_from_slice(&[0x62; 10]);
+
+        let message = splitter()
+            .split_bytes(&bytes, DataCoding::McSpecific)
+            .unwrap();
+
+        assert_eq!(message.parts().len(), 2);
+        assert_eq!(message.parts()[0].len(), 6 + 133);
+        assert_eq!(&message.parts()[1][6..8], &[ESCAPE, 0x65]);
+    }
+
+    #[test]
+    fn split_bytes_surrogate_pair_not_split() {
+        let text = alloc::format!("{}😀{}", "ш".repeat(66), "ш".repeat(10));
+        let bytes = Ucs2::new().encode_to_vec(&text);
+
+        // UCS2 and UCS2 flash SMS.
+        for data_coding in [DataCoding::Ucs2, DataCoding::from(0x18)] {
+            let message = splitter().split_bytes(&bytes, data_coding).unwrap();
+
+            assert_eq!(message.parts().len(), 2);
+            assert_eq!(message.parts()[0].len(), 6 + 132);
+            assert_eq!(&message.parts()[1][6..8], &[0xD8, 0x3D]);
+        }
+    }
+
+    #[test]
+    fn split_bytes_binary() {
+        let bytes = alloc::vec![0xFF; 300];
+
+        let message = splitter()
+            .split_bytes(&bytes, DataCoding::OctetUnspecified)
+            .unwrap();
+
+        assert_eq!(message.parts().len(), 3);
+        assert_eq!(message.parts()[0].len(), 140);
+        assert_eq!(message.parts()[2].len(), 6 + 300 - 2 * 134);
+    }
+
+    #[test]
+    fn too_many_parts() {
+        let text = "a".repeat(134 * 256);
+
+        let err = splitter().split_text(&text).unwrap_err();
+
+        assert_eq!(err, SplitError::TooManyParts { parts: 256 });
+    }
+
+    #[test]
+    fn max_short_message_size_too_small() {
+        let splitter = splitter().with_max_short_message_size(7);
+
+        let err = splitter
+            .split_text_as("шшшш", TextEncoding::Ucs2(Ucs2::new()))
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            SplitError::MaxShortMessageSizeTooSmall {
+                max_short_message_size: 7
+            }
+        );
+    }
+}
@@ -37,7 +37,7 @@ pub struct AlertNotification<'a> {
     pub esme_addr: COctetString<'a, 1, 65>,
     /// The status of the mobile station [`MsAvailabilityStatus`].
     #[rusmpp(length = "checked")]
-    ms_availability_status: Option<Tlv<'a>>,
+    pub(crate) ms_availability_status: Option<Tlv<'a>>,
 }
 
 impl<'a> AlertNotification<'a> {
@@ -21,7 +21,7 @@ macro_rules! bind_resp {
             pub system_id: COctetString<'a, 1, 16>,
             /// `SMPP` version supported by MC. [`ScInterfaceVersion`].
             #[rusmpp(length = "checked")]
-            sc_interface_version: Option<Tlv<'a>>,
+            pub(crate) sc_interface_version: Option<Tlv<'a>>,
         }
 
         impl<'a> $name<'a> {
@@ -18,7 +18,7 @@ pub struct BroadcastSmResp<'a, const N: usize> {
     /// Broadcast response TLVs ([`BroadcastResponseTlvValue`]).
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> BroadcastSmResp<'a, N> {
@@ -63,7 +63,7 @@ pub struct CancelBroadcastSm<'a, const N: usize> {
     /// Cancel broadcast  TLVs ([`CancelBroadcastTlvValue`]).
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> CancelBroadcastSm<'a, N> {
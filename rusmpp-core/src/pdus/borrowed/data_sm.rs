@@ -64,7 +64,7 @@ pub struct DataSm<'a, const N: usize> {
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> DataSm<'a, N> {
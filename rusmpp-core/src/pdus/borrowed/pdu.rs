@@ -5,9 +5,13 @@ use crate::{
         borrowed::{Decode, DecodeWithKeyOptional, DecodeWithLength},
     },
     encode::{Encode, Length},
+    tlvs::borrowed::Tlv,
     types::borrowed::AnyOctetString,
 };
 
+#[cfg(feature = "tokio-codec")]
+use crate::encode::EncodeBytesMut;
+
 use super::*;
 
 /// `SMPP` PDU.
@@ -223,6 +227,60 @@ impl<'a, const N: usize> Pdu<'a, N> {
             Pdu::CancelBroadcastSmResp => CommandId::CancelBroadcastSmResp,
         }
     }
+
+    /// Returns the TLVs of this [`Pdu`].
+    pub(crate) fn tlvs(&self) -> &[Tlv<'a>] {
+        match self {
+            Pdu::BindTransmitterResp(pdu) => pdu.sc_interface_version.as_slice(),
+            Pdu::BindReceiverResp(pdu) => pdu.sc_interface_version.as_slice(),
+            Pdu::BindTransceiverResp(pdu) => pdu.sc_interface_version.as_slice(),
+            Pdu::AlertNotification(pdu) => pdu.ms_availability_status.as_slice(),
+            Pdu::SubmitSm(pdu) => &pdu.tlvs,
+            Pdu::SubmitSmResp(pdu) => &pdu.tlvs,
+            Pdu::DeliverSm(pdu) => &pdu.tlvs,
+            Pdu::DeliverSmResp(pdu) => &pdu.tlvs,
+            Pdu::DataSm(pdu) => &pdu.tlvs,
+            Pdu::DataSmResp(pdu) => &pdu.tlvs,
+            Pdu::SubmitMulti(pdu) => &pdu.tlvs,
+            Pdu::SubmitMultiResp(pdu) => &pdu.tlvs,
+            Pdu::BroadcastSm(pdu) => &pdu.tlvs,
+            Pdu::BroadcastSmResp(pdu) => &pdu.tlvs,
+            Pdu::QueryBroadcastSmResp(pdu) => &pdu.tlvs,
+            Pdu::CancelBroadcastSm(pdu) => &pdu.tlvs,
+            _ => &[],
+        }
+    }
+
+    /// Retains only the TLVs specified by the predicate.
+    pub(crate) fn retain_tlvs(&mut self, mut f: impl FnMut(&Tlv<'a>) -> bool) {
+        match self {
+            Pdu::BindTransmitterResp(pdu) => {
+                pdu.sc_interface_version = pdu.sc_interface_version.take().filter(|tlv| f(tlv));
+            }
+            Pdu::BindReceiverResp(pdu) => {
+                pdu.sc_interface_version = pdu.sc_interface_version.take().filter(|tlv| f(tlv));
+            }
+            Pdu::BindTransceiverResp(pdu) => {
+                pdu.sc_interface_version = pdu.sc_interface_version.take().filter(|tlv| f(tlv));
+            }
+            Pdu::AlertNotification(pdu) => {
+                pdu.ms_availability_status = pdu.ms_availability_status.take().filter(|tlv| f(tlv));
+            }
+            Pdu::SubmitSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::SubmitSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DeliverSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DeliverSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DataSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DataSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::SubmitMulti(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::SubmitMultiResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::BroadcastSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::BroadcastSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::QueryBroadcastSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::CancelBroadcastSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            _ => {}
+        }
+    }
 }
 
 impl<const N: usize> Length for Pdu<'_, N> {
@@ -307,6 +365,48 @@ impl<const N: usize> Encode for Pdu<'_, N> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<const N: usize> EncodeBytesMut for Pdu<'_, N> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        match self {
+            Pdu::BindTransmitter(body) => body.encode_buf_mut(dst),
+            Pdu::BindTransmitterResp(body) => body.encode_buf_mut(dst),
+            Pdu::BindReceiver(body) => body.encode_buf_mut(dst),
+            Pdu::BindReceiverResp(body) => body.encode_buf_mut(dst),
+            Pdu::BindTransceiver(body) => body.encode_buf_mut(dst),
+            Pdu::BindTransceiverResp(body) => body.encode_buf_mut(dst),
+            Pdu::Outbind(body) => body.encode_buf_mut(dst),
+            Pdu::AlertNotification(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitSm(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::QuerySm(body) => body.encode_buf_mut(dst),
+            Pdu::QuerySmResp(body) => body.encode_buf_mut(dst),
+            Pdu::DeliverSm(body) => body.encode_buf_mut(dst),
+            Pdu::DeliverSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::DataSm(body) => body.encode_buf_mut(dst),
+            Pdu::DataSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::CancelSm(body) => body.encode_buf_mut(dst),
+            Pdu::ReplaceSm(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitMulti(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitMultiResp(body) => body.encode_buf_mut(dst),
+            Pdu::BroadcastSm(body) => body.encode_buf_mut(dst),
+            Pdu::BroadcastSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::QueryBroadcastSm(body) => body.encode_buf_mut(dst),
+            Pdu::QueryBroadcastSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::CancelBroadcastSm(body) => body.encode_buf_mut(dst),
+            Pdu::Unbind
+            | Pdu::UnbindResp
+            | Pdu::EnquireLink
+            | Pdu::EnquireLinkResp
+            | Pdu::GenericNack
+            | Pdu::CancelSmResp
+            | Pdu::ReplaceSmResp
+            | Pdu::CancelBroadcastSmResp => 0,
+            Pdu::Other { body, .. } => body.encode_buf_mut(dst),
+        }
+    }
+}
+
 impl<'a, const N: usize> DecodeWithKeyOptional<'a> for Pdu<'a, N> {
     type Key = CommandId;
 
@@ -47,7 +47,7 @@ pub struct QueryBroadcastSm<'a> {
     pub source_addr: COctetString<'a, 1, 21>,
     /// ESME assigned message reference number. [`UserMessageReference`].
     #[rusmpp(length = "checked")]
-    user_message_reference: Option<Tlv<'a>>,
+    pub(crate) user_message_reference: Option<Tlv<'a>>,
 }
 
 impl<'a> QueryBroadcastSm<'a> {
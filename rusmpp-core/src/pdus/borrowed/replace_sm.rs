@@ -61,7 +61,7 @@ pub struct ReplaceSm<'a> {
     /// If not using a MC canned message, set to NULL.
     pub sm_default_msg_id: u8,
     /// Length in octets of the short_message user data.
-    sm_length: u8,
+    pub(crate) sm_length: u8,
     /// Up to 255 octets of short message user data.
     /// The exact physical limit for short_message size may
     /// vary according to the underlying network
@@ -72,10 +72,10 @@ pub struct ReplaceSm<'a> {
     /// 255 octets should use the message_payload TLV. In
     /// this case the sm_length field should be set to zero.
     #[rusmpp(length = sm_length)]
-    short_message: OctetString<'a, 0, 255>,
+    pub(crate) short_message: OctetString<'a, 0, 255>,
     /// Message replacement request TLVs. [`MessagePayload`].
     #[rusmpp(length = "checked")]
-    message_payload: Option<Tlv<'a>>,
+    pub(crate) message_payload: Option<Tlv<'a>>,
 }
 
 impl<'a> ReplaceSm<'a> {
@@ -17,11 +17,11 @@ macro_rules! sm_resp {
             /// This field contains the MC message ID of the submitted message.
             /// It may be used at a later stage to query the status of a message,
             /// cancel or replace the message.
-            message_id: COctetString<'a, 1, 65>,
+            pub(crate) message_id: COctetString<'a, 1, 65>,
             /// Message delivery response TLVs ([`MessageDeliveryResponseTlvValue`])
             #[rusmpp(length = "unchecked")]
             #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-            tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+            pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
         }
 
         impl<'a, const N: usize> $name<'a, N> {
@@ -44,11 +44,11 @@ pub struct SubmitMulti<'a, const N: usize> {
     ///
     /// Note: Set to 1 when submitting to one SME Address or when
     /// submitting to one Distribution List.
-    number_of_dests: u8,
+    pub(crate) number_of_dests: u8,
     /// Composite field.
     #[rusmpp(count = number_of_dests)]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    dest_address: heapless::vec::Vec<DestAddress<'a>, N>,
+    pub(crate) dest_address: heapless::vec::Vec<DestAddress<'a>, N>,
     /// Indicates Message Mode and Message Type.
     pub esm_class: EsmClass,
     /// Protocol Identifier.
@@ -81,7 +81,7 @@ pub struct SubmitMulti<'a, const N: usize> {
     /// If not using a MC canned message, set to NULL.
     pub sm_default_msg_id: u8,
     /// Length in octets of the short_message user data.
-    sm_length: u8,
+    pub(crate) sm_length: u8,
     /// Up to 255 octets of short message user data.
     ///
     /// The exact physical limit for short_message size may
@@ -94,11 +94,11 @@ pub struct SubmitMulti<'a, const N: usize> {
     /// 255 octets should use the message_payload TLV. In
     /// this case the sm_length field should be set to zero.
     #[rusmpp(length = sm_length)]
-    short_message: OctetString<'a, 0, 255>,
+    pub(crate) short_message: OctetString<'a, 0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> SubmitMulti<'a, N> {
@@ -20,17 +20,17 @@ pub struct SubmitMultiResp<'a, const N: usize> {
     /// were unsuccessfully submitted to the MC. This is followed by
     /// the specified number of unsuccessful SMEs, each
     /// specified in a unsuccess_sme field.
-    no_unsuccess: u8,
+    pub(crate) no_unsuccess: u8,
     /// Unsuccessful SME.
     ///
     /// (Composite Field).
     #[rusmpp(count = no_unsuccess)]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    unsuccess_sme: heapless::vec::Vec<UnsuccessSme<'a>, N>,
+    pub(crate) unsuccess_sme: heapless::vec::Vec<UnsuccessSme<'a>, N>,
     /// Message submission response TLVs ([`MessageSubmissionResponseTlvValue`])
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> SubmitMultiResp<'a, N> {
@@ -73,7 +73,7 @@ pub struct SubmitSm<'a, const N: usize> {
     /// short messages stored on the MC. If not using a MC canned message, set to NULL.
     pub sm_default_msg_id: u8,
     /// Length in octets of the short_message user data.
-    sm_length: u8,
+    pub(crate) sm_length: u8,
     /// Up to 255 octets of short message user data.
     ///
     /// The exact physical limit for short_message size may
@@ -82,11 +82,11 @@ pub struct SubmitSm<'a, const N: usize> {
     /// Note: this field is superceded by the message_payload TLV if
     /// specified.
     #[rusmpp(length = sm_length)]
-    short_message: OctetString<'a, 0, 255>,
+    pub(crate) short_message: OctetString<'a, 0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> SubmitSm<'a, N> {
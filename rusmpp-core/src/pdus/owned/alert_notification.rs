@@ -1,7 +1,14 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::owned::{Tlv, TlvValue},
     types::owned::COctetString,
     values::*,
@@ -17,10 +24,16 @@ use crate::{
 ///
 /// Note: There is no associated alert_notification_resp PDU.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct AlertNotification {
     /// Type of Number for alert SME.
     pub source_addr_ton: Ton,
@@ -38,7 +51,7 @@ pub struct AlertNotification {
     pub esme_addr: COctetString<1, 65>,
     /// The status of the mobile station [`MsAvailabilityStatus`].
     #[rusmpp(length = "checked")]
-    ms_availability_status: Option<Tlv>,
+    pub(crate) ms_availability_status: Option<Tlv>,
 }
 
 impl AlertNotification {
@@ -97,9 +110,46 @@ impl From<AlertNotification> for Pdu {
     }
 }
 
+impl From<borrowed::AlertNotification<'_>> for AlertNotification {
+    fn from(value: borrowed::AlertNotification<'_>) -> Self {
+        Self {
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            esme_addr_ton: value.esme_addr_ton,
+            esme_addr_npi: value.esme_addr_npi,
+            esme_addr: value.esme_addr.into(),
+            ms_availability_status: value.ms_availability_status.map(Into::into),
+        }
+    }
+}
+
+impl borrowed::AlertNotification<'_> {
+    /// Converts this borrowed [`AlertNotification`](borrowed::AlertNotification) into an owned [`AlertNotification`].
+    pub fn into_owned(self) -> AlertNotification {
+        self.into()
+    }
+}
+
+impl AlertNotification {
+    /// Returns a borrowed [`AlertNotification`](borrowed::AlertNotification) view of this [`AlertNotification`].
+    pub fn as_borrowed(&self) -> borrowed::AlertNotification<'_> {
+        borrowed::AlertNotification {
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            esme_addr_ton: self.esme_addr_ton,
+            esme_addr_npi: self.esme_addr_npi,
+            esme_addr: self.esme_addr.as_borrowed(),
+            ms_availability_status: self.ms_availability_status.as_ref().map(Tlv::as_borrowed),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct AlertNotificationBuilder {
     inner: AlertNotification,
+    error: Option<BuildError>,
 }
 
 impl AlertNotificationBuilder {
@@ -117,8 +167,13 @@ impl AlertNotificationBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 65>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
         self
     }
 
@@ -132,8 +187,13 @@ impl AlertNotificationBuilder {
         self
     }
 
-    pub fn esme_addr(mut self, esme_addr: COctetString<1, 65>) -> Self {
-        self.inner.esme_addr = esme_addr;
+    pub fn esme_addr(mut self, esme_addr: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.esme_addr,
+            SmppField::esme_addr,
+            esme_addr,
+        );
         self
     }
 
@@ -146,9 +206,20 @@ impl AlertNotificationBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> AlertNotification {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<AlertNotification, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
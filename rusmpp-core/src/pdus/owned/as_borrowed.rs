@@ -0,0 +1,50 @@
+use crate::fields::SmppField;
+
+/// Errors that can occur when creating a borrowed view of an owned PDU.
+///
+/// Borrowed PDUs store their TLVs and destination addresses in a fixed capacity `N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AsBorrowedError {
+    /// The field holds more elements than the borrowed PDU can store.
+    TooManyElements {
+        /// The field that holds too many elements.
+        field: SmppField,
+        /// The number of elements in the owned PDU.
+        actual: usize,
+        /// The capacity of the borrowed PDU.
+        max: usize,
+    },
+}
+
+impl core::fmt::Display for AsBorrowedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooManyElements { field, actual, max } => {
+                write!(
+                    f,
+                    "Too many elements in {field:?}. actual: {actual}, max: {max}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for AsBorrowedError {}
+
+/// Maps `items` into a [`heapless::vec::Vec`] with capacity `N`.
+pub(crate) fn heapless_vec<'a, T, U, const N: usize>(
+    items: &'a [T],
+    field: SmppField,
+    f: impl FnMut(&'a T) -> U,
+) -> Result<heapless::vec::Vec<U, N>, AsBorrowedError> {
+    if items.len() > N {
+        return Err(AsBorrowedError::TooManyElements {
+            field,
+            actual: items.len(),
+            max: N,
+        });
+    }
+
+    Ok(items.iter().map(f).collect())
+}
@@ -1,6 +1,17 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{pdus::owned::Pdu, types::owned::COctetString, values::*};
+use crate::{
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    types::owned::COctetString,
+    values::*,
+};
 
 macro_rules! bind {
     ($name:ident) => {
@@ -8,7 +19,10 @@ macro_rules! bind {
         #[rusmpp(decode = owned, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-        #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+        #[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
         pub struct $name {
             /// Identifies the ESME system
             /// requesting to bind with the MC.
@@ -72,6 +86,7 @@ macro_rules! bind {
             #[derive(Debug, Default)]
             pub struct [<$name Builder>] {
                inner: $name,
+               error: Option<BuildError>,
             }
 
             impl [<$name Builder>] {
@@ -79,18 +94,39 @@ macro_rules! bind {
                     Self::default()
                 }
 
-                pub fn system_id(mut self, system_id: COctetString<1, 16>) -> Self {
-                    self.inner.system_id = system_id;
+                pub fn system_id(
+                    mut self,
+                    system_id: impl TryIntoField<COctetString<1, 16>>,
+                ) -> Self {
+                    try_build::set(
+                        &mut self.error,
+                        &mut self.inner.system_id,
+                        SmppField::system_id,
+                        system_id,
+                    );
                     self
                 }
 
-                pub fn password(mut self, password: COctetString<1, 9>) -> Self {
-                    self.inner.password = password;
+                pub fn password(mut self, password: impl TryIntoField<COctetString<1, 9>>) -> Self {
+                    try_build::set(
+                        &mut self.error,
+                        &mut self.inner.password,
+                        SmppField::password,
+                        password,
+                    );
                     self
                 }
 
-                pub fn system_type(mut self, system_type: COctetString<1, 13>) -> Self {
-                    self.inner.system_type = system_type;
+                pub fn system_type(
+                    mut self,
+                    system_type: impl TryIntoField<COctetString<1, 13>>,
+                ) -> Self {
+                    try_build::set(
+                        &mut self.error,
+                        &mut self.inner.system_type,
+                        SmppField::system_type,
+                        system_type,
+                    );
                     self
                 }
 
@@ -109,14 +145,33 @@ macro_rules! bind {
                     self
                 }
 
-                pub fn address_range(mut self, address_range: COctetString<1, 41>) -> Self {
-                    self.inner.address_range = address_range;
+                pub fn address_range(
+                    mut self,
+                    address_range: impl TryIntoField<COctetString<1, 41>>,
+                ) -> Self {
+                    try_build::set(
+                        &mut self.error,
+                        &mut self.inner.address_range,
+                        SmppField::address_range,
+                        address_range,
+                    );
                     self
                 }
 
+                /// Builds the PDU.
+                ///
+                /// Values that could not be converted are ignored, see [`Self::try_build`].
                 pub fn build(self) -> $name {
                     self.inner
                 }
+
+                /// Builds the PDU, returning the first value that could not be converted
+                /// or the first violated semantic rule of the PDU.
+                ///
+                /// See [`Validate`](crate::validate::Validate).
+                pub fn try_build(self) -> Result<$name, BuildError> {
+                    try_build::try_build(self.error, self.inner)
+                }
             }
         }
     };
@@ -133,18 +188,132 @@ impl From<BindTransmitter> for Pdu {
     }
 }
 
+impl From<borrowed::BindTransmitter<'_>> for BindTransmitter {
+    fn from(value: borrowed::BindTransmitter<'_>) -> Self {
+        Self {
+            system_id: value.system_id.into(),
+            password: value.password.into(),
+            system_type: value.system_type.into(),
+            interface_version: value.interface_version,
+            addr_ton: value.addr_ton,
+            addr_npi: value.addr_npi,
+            address_range: value.address_range.into(),
+        }
+    }
+}
+
+impl borrowed::BindTransmitter<'_> {
+    /// Converts this borrowed [`BindTransmitter`](borrowed::BindTransmitter) into an owned [`BindTransmitter`].
+    pub fn into_owned(self) -> BindTransmitter {
+        self.into()
+    }
+}
+
+impl BindTransmitter {
+    /// Returns a borrowed [`BindTransmitter`](borrowed::BindTransmitter) view of this [`BindTransmitter`].
+    pub fn as_borrowed(&self) -> borrowed::BindTransmitter<'_> {
+        borrowed::BindTransmitter {
+            system_id: self.system_id.as_borrowed(),
+            password: self.password.as_borrowed(),
+            system_type: self.system_type.as_borrowed(),
+            interface_version: self.interface_version,
+            addr_ton: self.addr_ton,
+            addr_npi: self.addr_npi,
+            address_range: self.address_range.as_borrowed(),
+        }
+    }
+}
+
 impl From<BindReceiver> for Pdu {
     fn from(value: BindReceiver) -> Self {
         Self::BindReceiver(value)
     }
 }
 
+impl From<borrowed::BindReceiver<'_>> for BindReceiver {
+    fn from(value: borrowed::BindReceiver<'_>) -> Self {
+        Self {
+            system_id: value.system_id.into(),
+            password: value.password.into(),
+            system_type: value.system_type.into(),
+            interface_version: value.interface_version,
+            addr_ton: value.addr_ton,
+            addr_npi: value.addr_npi,
+            address_range: value.address_range.into(),
+        }
+    }
+}
+
+impl borrowed::BindReceiver<'_> {
+    /// Converts this borrowed [`BindReceiver`](borrowed::BindReceiver) into an owned [`BindReceiver`].
+    pub fn into_owned(self) -> BindReceiver {
+        self.into()
+    }
+}
+
+impl BindReceiver {
+    /// Returns a borrowed [`BindReceiver`](borrowed::BindReceiver) view of this [`BindReceiver`].
+    pub fn as_borrowed(&self) -> borrowed::BindReceiver<'_> {
+        borrowed::BindReceiver {
+            system_id: self.system_id.as_borrowed(),
+            password: self.password.as_borrowed(),
+            system_type: self.system_type.as_borrowed(),
+            interface_version: self.interface_version,
+            addr_ton: self.addr_ton,
+            addr_npi: self.addr_npi,
+            address_range: self.address_range.as_borrowed(),
+        }
+    }
+}
+
 impl From<BindTransceiver> for Pdu {
     fn from(value: BindTransceiver) -> Self {
         Self::BindTransceiver(value)
     }
 }
 
+impl From<borrowed::BindTransceiver<'_>> for BindTransceiver {
+    fn from(value: borrowed::BindTransceiver<'_>) -> Self {
+        Self {
+            system_id: value.system_id.into(),
+            password: value.password.into(),
+            system_type: value.system_type.into(),
+            interface_version: value.interface_version,
+            addr_ton: value.addr_ton,
+            addr_npi: value.addr_npi,
+            address_range: value.address_range.into(),
+        }
+    }
+}
+
+impl borrowed::BindTransceiver<'_> {
+    /// Converts this borrowed [`BindTransceiver`](borrowed::BindTransceiver) into an owned [`BindTransceiver`].
+    pub fn into_owned(self) -> BindTransceiver {
+        self.into()
+    }
+}
+
+impl BindTransceiver {
+    /// Returns a borrowed [`BindTransceiver`](borrowed::BindTransceiver) view of this [`BindTransceiver`].
+    pub fn as_borrowed(&self) -> borrowed::BindTransceiver<'_> {
+        borrowed::BindTransceiver {
+            system_id: self.system_id.as_borrowed(),
+            password: self.password.as_borrowed(),
+            system_type: self.system_type.as_borrowed(),
+            interface_version: self.interface_version,
+            addr_ton: self.addr_ton,
+            addr_npi: self.addr_npi,
+            address_range: self.address_range.as_borrowed(),
+        }
+    }
+}
+
+impl crate::validate::Validate for BindAny {
+    fn validate(&self) -> Result<(), crate::CommandStatus> {
+        Ok(())
+    }
+}
+
 impl From<BindAny> for BindTransmitter {
     fn from(value: BindAny) -> Self {
         Self {
@@ -1,7 +1,14 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::owned::{Tlv, TlvValue},
     types::owned::COctetString,
     values::*,
@@ -10,10 +17,16 @@ use crate::{
 macro_rules! bind_resp {
     ($name:ident) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-        #[rusmpp(decode = owned, test = skip)]
+        #[rusmpp(decode = owned, test = skip, deserialize = checked)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-        #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+        #[cfg_attr(
+            all(
+                feature = "serde-deserialize-unchecked",
+                not(feature = "serde-deserialize")
+            ),
+            derive(::serde::Deserialize)
+        )]
         pub struct $name {
             /// MC identifier.
             ///
@@ -21,7 +34,7 @@ macro_rules! bind_resp {
             pub system_id: COctetString<1, 16>,
             /// `SMPP` version supported by MC. [`ScInterfaceVersion`].
             #[rusmpp(length = "checked")]
-            sc_interface_version: Option<Tlv>,
+            pub(crate) sc_interface_version: Option<Tlv>,
         }
 
         impl $name {
@@ -70,6 +83,7 @@ macro_rules! bind_resp {
             #[derive(Debug, Default)]
             pub struct [<$name Builder>] {
                inner: $name,
+               error: Option<BuildError>,
             }
 
             impl [<$name Builder>] {
@@ -77,8 +91,16 @@ macro_rules! bind_resp {
                     Self::default()
                 }
 
-                pub fn system_id(mut self, system_id: COctetString<1, 16>) -> Self {
-                    self.inner.system_id = system_id;
+                pub fn system_id(
+                    mut self,
+                    system_id: impl TryIntoField<COctetString<1, 16>>,
+                ) -> Self {
+                    try_build::set(
+                        &mut self.error,
+                        &mut self.inner.system_id,
+                        SmppField::system_id,
+                        system_id,
+                    );
                     self
                 }
 
@@ -90,9 +112,20 @@ macro_rules! bind_resp {
                     self
                 }
 
+                /// Builds the PDU.
+                ///
+                /// Values that could not be converted are ignored, see [`Self::try_build`].
                 pub fn build(self) -> $name {
                     self.inner
                 }
+
+                /// Builds the PDU, returning the first value that could not be converted
+                /// or the first violated semantic rule of the PDU.
+                ///
+                /// See [`Validate`](crate::validate::Validate).
+                pub fn try_build(self) -> Result<$name, BuildError> {
+                    try_build::try_build(self.error, self.inner)
+                }
             }
         }
     };
@@ -108,18 +141,96 @@ impl From<BindTransmitterResp> for Pdu {
     }
 }
 
+impl From<borrowed::BindTransmitterResp<'_>> for BindTransmitterResp {
+    fn from(value: borrowed::BindTransmitterResp<'_>) -> Self {
+        Self {
+            system_id: value.system_id.into(),
+            sc_interface_version: value.sc_interface_version.map(Into::into),
+        }
+    }
+}
+
+impl borrowed::BindTransmitterResp<'_> {
+    /// Converts this borrowed [`BindTransmitterResp`](borrowed::BindTransmitterResp) into an owned [`BindTransmitterResp`].
+    pub fn into_owned(self) -> BindTransmitterResp {
+        self.into()
+    }
+}
+
+impl BindTransmitterResp {
+    /// Returns a borrowed [`BindTransmitterResp`](borrowed::BindTransmitterResp) view of this [`BindTransmitterResp`].
+    pub fn as_borrowed(&self) -> borrowed::BindTransmitterResp<'_> {
+        borrowed::BindTransmitterResp {
+            system_id: self.system_id.as_borrowed(),
+            sc_interface_version: self.sc_interface_version.as_ref().map(Tlv::as_borrowed),
+        }
+    }
+}
+
 impl From<BindReceiverResp> for Pdu {
     fn from(value: BindReceiverResp) -> Self {
         Self::BindReceiverResp(value)
     }
 }
 
+impl From<borrowed::BindReceiverResp<'_>> for BindReceiverResp {
+    fn from(value: borrowed::BindReceiverResp<'_>) -> Self {
+        Self {
+            system_id: value.system_id.into(),
+            sc_interface_version: value.sc_interface_version.map(Into::into),
+        }
+    }
+}
+
+impl borrowed::BindReceiverResp<'_> {
+    /// Converts this borrowed [`BindReceiverResp`](borrowed::BindReceiverResp) into an owned [`BindReceiverResp`].
+    pub fn into_owned(self) -> BindReceiverResp {
+        self.into()
+    }
+}
+
+impl BindReceiverResp {
+    /// Returns a borrowed [`BindReceiverResp`](borrowed::BindReceiverResp) view of this [`BindReceiverResp`].
+    pub fn as_borrowed(&self) -> borrowed::BindReceiverResp<'_> {
+        borrowed::BindReceiverResp {
+            system_id: self.system_id.as_borrowed(),
+            sc_interface_version: self.sc_interface_version.as_ref().map(Tlv::as_borrowed),
+        }
+    }
+}
+
 impl From<BindTransceiverResp> for Pdu {
     fn from(value: BindTransceiverResp) -> Self {
         Self::BindTransceiverResp(value)
     }
 }
 
+impl From<borrowed::BindTransceiverResp<'_>> for BindTransceiverResp {
+    fn from(value: borrowed::BindTransceiverResp<'_>) -> Self {
+        Self {
+            system_id: value.system_id.into(),
+            sc_interface_version: value.sc_interface_version.map(Into::into),
+        }
+    }
+}
+
+impl borrowed::BindTransceiverResp<'_> {
+    /// Converts this borrowed [`BindTransceiverResp`](borrowed::BindTransceiverResp) into an owned [`BindTransceiverResp`].
+    pub fn into_owned(self) -> BindTransceiverResp {
+        self.into()
+    }
+}
+
+impl BindTransceiverResp {
+    /// Returns a borrowed [`BindTransceiverResp`](borrowed::BindTransceiverResp) view of this [`BindTransceiverResp`].
+    pub fn as_borrowed(&self) -> borrowed::BindTransceiverResp<'_> {
+        borrowed::BindTransceiverResp {
+            system_id: self.system_id.as_borrowed(),
+            sc_interface_version: self.sc_interface_version.as_ref().map(Tlv::as_borrowed),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
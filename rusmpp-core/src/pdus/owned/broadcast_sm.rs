@@ -1,8 +1,16 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
-    tlvs::owned::{BroadcastRequestTlvValue, Tlv},
+    decode::DecodeError,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    tlvs::owned::{BroadcastRequestTlvValue, Tlv, VendorTlvValue, vendor_value},
     types::owned::{COctetString, EmptyOrFullCOctetString},
     values::{owned::*, *},
 };
@@ -10,10 +18,16 @@ use crate::{
 /// This operation is issued by the ESME to submit a message to the Message Centre for
 /// broadcast to a specified geographical area or set of geographical areas.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct BroadcastSm {
     /// The service_type parameter can be used to
     /// indicate the SMS Application service
@@ -83,7 +97,7 @@ pub struct BroadcastSm {
     pub sm_default_msg_id: u8,
     /// Broadcast request TLVs ([`BroadcastRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl BroadcastSm {
@@ -124,6 +138,13 @@ impl BroadcastSm {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<BroadcastRequestTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
@@ -139,6 +160,20 @@ impl BroadcastSm {
     pub fn builder() -> BroadcastSmBuilder {
         BroadcastSmBuilder::new()
     }
+
+    /// Parses the [`BroadcastSm::schedule_delivery_time`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the schedule delivery time is not set.
+    pub fn parse_schedule_delivery_time(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.schedule_delivery_time.to_smpp_time()
+    }
+
+    /// Parses the [`BroadcastSm::validity_period`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the validity period is not set.
+    pub fn parse_validity_period(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.validity_period.to_smpp_time()
+    }
 }
 
 impl From<BroadcastSm> for Pdu {
@@ -147,9 +182,60 @@ impl From<BroadcastSm> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::BroadcastSm<'_, N>> for BroadcastSm {
+    fn from(value: borrowed::BroadcastSm<'_, N>) -> Self {
+        Self {
+            service_type: value.service_type.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            message_id: value.message_id.into(),
+            priority_flag: value.priority_flag,
+            schedule_delivery_time: value.schedule_delivery_time.into(),
+            validity_period: value.validity_period.into(),
+            replace_if_present_flag: value.replace_if_present_flag,
+            data_coding: value.data_coding,
+            sm_default_msg_id: value.sm_default_msg_id,
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::BroadcastSm<'_, N> {
+    /// Converts this borrowed [`BroadcastSm`](borrowed::BroadcastSm) into an owned [`BroadcastSm`].
+    pub fn into_owned(self) -> BroadcastSm {
+        self.into()
+    }
+}
+
+impl BroadcastSm {
+    /// Returns a borrowed [`BroadcastSm`](borrowed::BroadcastSm) view of this [`BroadcastSm`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::BroadcastSm<'_, N>, AsBorrowedError> {
+        Ok(borrowed::BroadcastSm {
+            service_type: self.service_type.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            message_id: self.message_id.as_borrowed(),
+            priority_flag: self.priority_flag,
+            schedule_delivery_time: self.schedule_delivery_time.as_borrowed(),
+            validity_period: self.validity_period.as_borrowed(),
+            replace_if_present_flag: self.replace_if_present_flag,
+            data_coding: self.data_coding,
+            sm_default_msg_id: self.sm_default_msg_id,
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct BroadcastSmBuilder {
     inner: BroadcastSm,
+    error: Option<BuildError>,
 }
 
 impl BroadcastSmBuilder {
@@ -157,8 +243,13 @@ impl BroadcastSmBuilder {
         Self::default()
     }
 
-    pub fn service_type(mut self, service_type: ServiceType) -> Self {
-        self.inner.service_type = service_type;
+    pub fn service_type(mut self, service_type: impl TryIntoField<ServiceType>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.service_type,
+            SmppField::service_type,
+            service_type,
+        );
         self
     }
 
@@ -172,13 +263,33 @@ impl BroadcastSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
         self
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    /// Sets the [`BroadcastSm::source_addr_ton`], [`BroadcastSm::source_addr_npi`] and [`BroadcastSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
+        self
+    }
+
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -189,14 +300,27 @@ impl BroadcastSmBuilder {
 
     pub fn schedule_delivery_time(
         mut self,
-        schedule_delivery_time: EmptyOrFullCOctetString<17>,
+        schedule_delivery_time: impl TryIntoField<EmptyOrFullCOctetString<17>>,
     ) -> Self {
-        self.inner.schedule_delivery_time = schedule_delivery_time;
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.schedule_delivery_time,
+            SmppField::schedule_delivery_time,
+            schedule_delivery_time,
+        );
         self
     }
 
-    pub fn validity_period(mut self, validity_period: EmptyOrFullCOctetString<17>) -> Self {
-        self.inner.validity_period = validity_period;
+    pub fn validity_period(
+        mut self,
+        validity_period: impl TryIntoField<EmptyOrFullCOctetString<17>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.validity_period,
+            SmppField::validity_period,
+            validity_period,
+        );
         self
     }
 
@@ -233,9 +357,37 @@ impl BroadcastSmBuilder {
         self
     }
 
+    /// Pushes a [`CallbackNum`](BroadcastRequestTlvValue::CallbackNum) TLV encoding `callback_num`.
+    ///
+    /// Records an error if the address can not be encoded, see [`SmppAddress::to_callback_num`] and [`Self::try_build`].
+    pub fn callback_num(mut self, callback_num: &SmppAddress) -> Self {
+        match callback_num.to_callback_num() {
+            Ok(callback_num) => self.push_tlv(BroadcastRequestTlvValue::CallbackNum(callback_num)),
+            Err(error) => {
+                try_build::record(
+                    &mut self.error,
+                    BuildError::new(SmppField::callback_num, error.into()),
+                );
+
+                self
+            }
+        }
+    }
+
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> BroadcastSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<BroadcastSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -1,16 +1,30 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
-    tlvs::owned::{BroadcastResponseTlvValue, Tlv},
+    decode::DecodeError,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    tlvs::owned::{BroadcastResponseTlvValue, Tlv, VendorTlvValue, vendor_value},
     types::owned::COctetString,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct BroadcastSmResp {
     /// This field contains the MC message ID of the submitted
     /// message. It may be used at a later stage to perform
@@ -18,7 +32,7 @@ pub struct BroadcastSmResp {
     pub message_id: COctetString<1, 65>,
     /// Broadcast response TLVs ([`BroadcastResponseTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl BroadcastSmResp {
@@ -35,6 +49,13 @@ impl BroadcastSmResp {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<BroadcastResponseTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
@@ -58,9 +79,40 @@ impl From<BroadcastSmResp> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::BroadcastSmResp<'_, N>> for BroadcastSmResp {
+    fn from(value: borrowed::BroadcastSmResp<'_, N>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::BroadcastSmResp<'_, N> {
+    /// Converts this borrowed [`BroadcastSmResp`](borrowed::BroadcastSmResp) into an owned [`BroadcastSmResp`].
+    pub fn into_owned(self) -> BroadcastSmResp {
+        self.into()
+    }
+}
+
+impl BroadcastSmResp {
+    /// Returns a borrowed [`BroadcastSmResp`](borrowed::BroadcastSmResp) view of this [`BroadcastSmResp`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::BroadcastSmResp<'_, N>, AsBorrowedError> {
+        Ok(borrowed::BroadcastSmResp {
+            message_id: self.message_id.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct BroadcastSmRespBuilder {
     inner: BroadcastSmResp,
+    error: Option<BuildError>,
 }
 
 impl BroadcastSmRespBuilder {
@@ -68,8 +120,13 @@ impl BroadcastSmRespBuilder {
         Self::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -88,9 +145,20 @@ impl BroadcastSmRespBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> BroadcastSmResp {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<BroadcastSmResp, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,16 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
-    tlvs::owned::{CancelBroadcastTlvValue, Tlv},
+    decode::DecodeError,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    tlvs::owned::{CancelBroadcastTlvValue, Tlv, VendorTlvValue, vendor_value},
     types::owned::COctetString,
     values::{owned::*, *},
 };
@@ -24,10 +32,16 @@ use crate::{
 /// Where the original broadcast_sm ‘source address’ was defaulted to NULL, then the source
 /// address in the cancel_broadcast_sm command should also be NULL.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct CancelBroadcastSm {
     /// Set to indicate CBS Application service, if
     /// cancellation of a group of application service
@@ -62,7 +76,7 @@ pub struct CancelBroadcastSm {
     pub source_addr: COctetString<1, 21>,
     /// Cancel broadcast  TLVs ([`CancelBroadcastTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl CancelBroadcastSm {
@@ -90,6 +104,13 @@ impl CancelBroadcastSm {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<CancelBroadcastTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
@@ -113,9 +134,48 @@ impl From<CancelBroadcastSm> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::CancelBroadcastSm<'_, N>> for CancelBroadcastSm {
+    fn from(value: borrowed::CancelBroadcastSm<'_, N>) -> Self {
+        Self {
+            service_type: value.service_type.into(),
+            message_id: value.message_id.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::CancelBroadcastSm<'_, N> {
+    /// Converts this borrowed [`CancelBroadcastSm`](borrowed::CancelBroadcastSm) into an owned [`CancelBroadcastSm`].
+    pub fn into_owned(self) -> CancelBroadcastSm {
+        self.into()
+    }
+}
+
+impl CancelBroadcastSm {
+    /// Returns a borrowed [`CancelBroadcastSm`](borrowed::CancelBroadcastSm) view of this [`CancelBroadcastSm`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::CancelBroadcastSm<'_, N>, AsBorrowedError> {
+        Ok(borrowed::CancelBroadcastSm {
+            service_type: self.service_type.as_borrowed(),
+            message_id: self.message_id.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CancelBroadcastSmBuilder {
     inner: CancelBroadcastSm,
+    error: Option<BuildError>,
 }
 
 impl CancelBroadcastSmBuilder {
@@ -123,13 +183,23 @@ impl CancelBroadcastSmBuilder {
         Self::default()
     }
 
-    pub fn service_type(mut self, service_type: ServiceType) -> Self {
-        self.inner.service_type = service_type;
+    pub fn service_type(mut self, service_type: impl TryIntoField<ServiceType>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.service_type,
+            SmppField::service_type,
+            service_type,
+        );
         self
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -143,8 +213,23 @@ impl CancelBroadcastSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`CancelBroadcastSm::source_addr_ton`], [`CancelBroadcastSm::source_addr_npi`] and [`CancelBroadcastSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
@@ -163,9 +248,20 @@ impl CancelBroadcastSmBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> CancelBroadcastSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<CancelBroadcastSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
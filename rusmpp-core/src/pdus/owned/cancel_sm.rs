@@ -1,7 +1,14 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     types::owned::COctetString,
     values::{owned::*, *},
 };
@@ -22,7 +29,10 @@ use crate::{
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct CancelSm {
     /// Set to indicate SMS Application service,
     /// if cancellation of a group of application
@@ -132,9 +142,48 @@ impl From<CancelSm> for Pdu {
     }
 }
 
+impl From<borrowed::CancelSm<'_>> for CancelSm {
+    fn from(value: borrowed::CancelSm<'_>) -> Self {
+        Self {
+            service_type: value.service_type.into(),
+            message_id: value.message_id.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            dest_addr_ton: value.dest_addr_ton,
+            dest_addr_npi: value.dest_addr_npi,
+            destination_addr: value.destination_addr.into(),
+        }
+    }
+}
+
+impl borrowed::CancelSm<'_> {
+    /// Converts this borrowed [`CancelSm`](borrowed::CancelSm) into an owned [`CancelSm`].
+    pub fn into_owned(self) -> CancelSm {
+        self.into()
+    }
+}
+
+impl CancelSm {
+    /// Returns a borrowed [`CancelSm`](borrowed::CancelSm) view of this [`CancelSm`].
+    pub fn as_borrowed(&self) -> borrowed::CancelSm<'_> {
+        borrowed::CancelSm {
+            service_type: self.service_type.as_borrowed(),
+            message_id: self.message_id.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.as_borrowed(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CancelSmBuilder {
     inner: CancelSm,
+    error: Option<BuildError>,
 }
 
 impl CancelSmBuilder {
@@ -142,13 +191,23 @@ impl CancelSmBuilder {
         Self::default()
     }
 
-    pub fn service_type(mut self, service_type: ServiceType) -> Self {
-        self.inner.service_type = service_type;
+    pub fn service_type(mut self, service_type: impl TryIntoField<ServiceType>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.service_type,
+            SmppField::service_type,
+            service_type,
+        );
         self
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -162,8 +221,23 @@ impl CancelSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`CancelSm::source_addr_ton`], [`CancelSm::source_addr_npi`] and [`CancelSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
@@ -177,14 +251,43 @@ impl CancelSmBuilder {
         self
     }
 
-    pub fn destination_addr(mut self, destination_addr: COctetString<1, 21>) -> Self {
-        self.inner.destination_addr = destination_addr;
+    pub fn destination_addr(
+        mut self,
+        destination_addr: impl TryIntoField<COctetString<1, 21>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.destination_addr,
+            SmppField::destination_addr,
+            destination_addr,
+        );
         self
     }
 
+    /// Sets the [`CancelSm::dest_addr_ton`], [`CancelSm::dest_addr_npi`] and [`CancelSm::destination_addr`].
+    pub fn destination(mut self, destination: SmppAddress) -> Self {
+        let (ton, npi, addr) = destination.into_parts();
+
+        self.inner.dest_addr_ton = ton;
+        self.inner.dest_addr_npi = npi;
+        self.inner.destination_addr = addr;
+        self
+    }
+
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> CancelSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<CancelSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
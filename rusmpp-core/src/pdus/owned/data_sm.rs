@@ -1,20 +1,47 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
-    tlvs::owned::{MessageSubmissionRequestTlvValue, Tlv},
-    types::owned::COctetString,
+    decode::{DecodeError, owned::Decode},
+    delivery_receipt::DeliveryReceipt,
+    encoding::text::TextEncoder,
+    fields::SmppField,
+    multipart::{Sar, SplitError, SplitMessage, Splitter},
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, MessageKind, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    tlvs::{
+        TlvTag,
+        owned::{
+            MessageSubmissionRequestTlvValue, Tlv, VendorTlvValue, message_payload, vendor_value,
+        },
+    },
+    types::owned::{AnyOctetString, COctetString, OctetString},
+    udhs::{errors::ConcatenatedShortMessageError, owned::UserDataHeader},
     values::{owned::*, *},
 };
+
+/// Maximum length of a TLV value.
+const MAX_MESSAGE_PAYLOAD_LENGTH: usize = u16::MAX as usize;
+
 /// The data_sm operation is similar to the submit_sm in that it provides a means to submit a
 /// mobile-terminated message. However, data_sm is intended for packet-based applications
 /// such as WAP in that it features a reduced PDU body containing fields relevant to WAP or
 /// packet-based applications.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct DataSm {
     /// The service_type parameter can be used to indicate the
     /// SMS Application service associated with the message.
@@ -63,7 +90,7 @@ pub struct DataSm {
     pub data_coding: DataCoding,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl DataSm {
@@ -102,6 +129,13 @@ impl DataSm {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
@@ -114,6 +148,127 @@ impl DataSm {
         self.tlvs.push(Tlv::from(tlv.into()));
     }
 
+    /// Encodes `text` and splits it into concatenated parts using `self` as a template.
+    ///
+    /// Every part is a clone of `self` with the [`DataSm::data_coding`] set and the part pushed
+    /// as a [`MessagePayload`] TLV. The UDH Indicator of the [`DataSm::esm_class`] is set if the
+    /// parts start with a UDH and cleared otherwise. In [SAR](crate::multipart::Concatenation::Sar) mode,
+    /// the SAR TLVs are pushed to every part.
+    /// The message payload and SAR TLVs of the template are discarded.
+    ///
+    /// See [`Splitter::split_text`].
+    pub fn split_text(
+        &self,
+        text: &str,
+        splitter: &Splitter<'_>,
+    ) -> Result<alloc::vec::Vec<DataSm>, SplitError> {
+        splitter
+            .split_text(text)
+            .map(|message| self.parts_from_split_message(message))
+    }
+
+    /// Splits the encoded `bytes` into concatenated parts using `self` as a template.
+    ///
+    /// See [`DataSm::split_text`] and [`Splitter::split_bytes`].
+    pub fn split_bytes(
+        &self,
+        bytes: &[u8],
+        data_coding: DataCoding,
+        splitter: &Splitter<'_>,
+    ) -> Result<alloc::vec::Vec<DataSm>, SplitError> {
+        splitter
+            .split_bytes(bytes, data_coding)
+            .map(|message| self.parts_from_split_message(message))
+    }
+
+    fn parts_from_split_message(&self, message: SplitMessage) -> alloc::vec::Vec<DataSm> {
+        let data_coding = message.data_coding();
+        let has_udh = message.has_udh();
+        let sars = (0..message.parts().len())
+            .map(|index| message.sar(index))
+            .collect::<alloc::vec::Vec<_>>();
+
+        let mut template = self.clone();
+
+        template.data_coding = data_coding;
+        template.esm_class = if has_udh {
+            template.esm_class.with_udhi_indicator()
+        } else {
+            template.esm_class.without_udhi_indicator()
+        };
+
+        // Every part carries its own message payload and SAR TLVs.
+        template.tlvs.retain(|tlv| {
+            !matches!(
+                tlv.tag(),
+                TlvTag::MessagePayload
+                    | TlvTag::SarMsgRefNum
+                    | TlvTag::SarTotalSegments
+                    | TlvTag::SarSegmentSeqnum
+            )
+        });
+
+        message
+            .into_short_messages()
+            .into_iter()
+            .zip(sars)
+            .map(|(payload, sar)| {
+                let mut data_sm = template.clone();
+
+                if let Some(sar) = sar {
+                    for tlv in sar.message_submission_request_tlvs() {
+                        data_sm.push_tlv(tlv);
+                    }
+                }
+
+                data_sm.push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                    MessagePayload::new(AnyOctetString::from(payload)),
+                ));
+
+                data_sm
+            })
+            .collect()
+    }
+
+    /// Returns the [`Sar`] read from the SAR TLVs.
+    ///
+    /// See [`Sar::from_tlvs`].
+    pub fn sar(&self) -> Option<Result<Sar, ConcatenatedShortMessageError>> {
+        Sar::from_tlvs(&self.tlvs)
+    }
+
+    /// Returns the [`UserDataHeader`] at the start of the message_payload TLV.
+    ///
+    /// Returns `None` if the UDH Indicator is not set in the [`DataSm::esm_class`].
+    pub fn user_data_header(&self) -> Option<Result<UserDataHeader, DecodeError>> {
+        if !self.esm_class.has_udhi_indicator() {
+            return None;
+        }
+
+        Some(UserDataHeader::decode(message_payload(&self.tlvs)).map(|(udh, _)| udh))
+    }
+
+    /// Returns the [`DeliveryReceipt`] carried by this [`DataSm`].
+    ///
+    /// The receipt text is read from the message_payload TLV.
+    ///
+    /// Returns `None` if the [`DataSm::esm_class`] does not mark an MC delivery receipt.
+    pub fn delivery_receipt(&self) -> Option<DeliveryReceipt> {
+        DeliveryReceipt::from_pdu(self.esm_class, message_payload(&self.tlvs), &self.tlvs)
+    }
+
+    /// Returns the [`MessageKind`] of this [`DataSm`], read from the message type bits of the [`DataSm::esm_class`].
+    ///
+    /// The user data is read from the message_payload TLV.
+    pub fn kind(&self) -> MessageKind<'_> {
+        MessageKind::new(
+            self.esm_class,
+            self.data_coding,
+            message_payload(&self.tlvs),
+            &self.tlvs,
+        )
+    }
+
     pub fn builder() -> DataSmBuilder {
         DataSmBuilder::new()
     }
@@ -125,9 +280,56 @@ impl From<DataSm> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::DataSm<'_, N>> for DataSm {
+    fn from(value: borrowed::DataSm<'_, N>) -> Self {
+        Self {
+            service_type: value.service_type.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            dest_addr_ton: value.dest_addr_ton,
+            dest_addr_npi: value.dest_addr_npi,
+            destination_addr: value.destination_addr.into(),
+            esm_class: value.esm_class,
+            registered_delivery: value.registered_delivery,
+            data_coding: value.data_coding,
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::DataSm<'_, N> {
+    /// Converts this borrowed [`DataSm`](borrowed::DataSm) into an owned [`DataSm`].
+    pub fn into_owned(self) -> DataSm {
+        self.into()
+    }
+}
+
+impl DataSm {
+    /// Returns a borrowed [`DataSm`](borrowed::DataSm) view of this [`DataSm`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(&self) -> Result<borrowed::DataSm<'_, N>, AsBorrowedError> {
+        Ok(borrowed::DataSm {
+            service_type: self.service_type.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.as_borrowed(),
+            esm_class: self.esm_class,
+            registered_delivery: self.registered_delivery,
+            data_coding: self.data_coding,
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DataSmBuilder {
     inner: DataSm,
+    error: Option<BuildError>,
 }
 
 impl DataSmBuilder {
@@ -135,8 +337,13 @@ impl DataSmBuilder {
         Default::default()
     }
 
-    pub fn service_type(mut self, service_type: ServiceType) -> Self {
-        self.inner.service_type = service_type;
+    pub fn service_type(mut self, service_type: impl TryIntoField<ServiceType>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.service_type,
+            SmppField::service_type,
+            service_type,
+        );
         self
     }
 
@@ -150,8 +357,23 @@ impl DataSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`DataSm::source_addr_ton`], [`DataSm::source_addr_npi`] and [`DataSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
@@ -165,8 +387,26 @@ impl DataSmBuilder {
         self
     }
 
-    pub fn destination_addr(mut self, destination_addr: COctetString<1, 21>) -> Self {
-        self.inner.destination_addr = destination_addr;
+    pub fn destination_addr(
+        mut self,
+        destination_addr: impl TryIntoField<COctetString<1, 21>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.destination_addr,
+            SmppField::destination_addr,
+            destination_addr,
+        );
+        self
+    }
+
+    /// Sets the [`DataSm::dest_addr_ton`], [`DataSm::dest_addr_npi`] and [`DataSm::destination_addr`].
+    pub fn destination(mut self, destination: SmppAddress) -> Self {
+        let (ton, npi, addr) = destination.into_parts();
+
+        self.inner.dest_addr_ton = ton;
+        self.inner.dest_addr_npi = npi;
+        self.inner.destination_addr = addr;
         self
     }
 
@@ -185,6 +425,47 @@ impl DataSmBuilder {
         self
     }
 
+    /// Encodes `text` using the default [`TextEncoder`].
+    ///
+    /// See [`DataSmBuilder::text_with`].
+    pub fn text(self, text: &str) -> Self {
+        self.text_with(text, &TextEncoder::new())
+    }
+
+    /// Encodes `text` using `encoder`, sets the [`DataSm::data_coding`]
+    /// and sets the encoded text as the [`MessagePayload`] TLV, replacing an existing one.
+    ///
+    /// If the encoded text starts with a UDH, the UDH Indicator is set in the [`DataSm::esm_class`].
+    /// Call this method after setting the [`DataSm::esm_class`].
+    ///
+    /// Records an error if the encoded text exceeds `65535` octets, see [`Self::try_build`].
+    pub fn text_with(mut self, text: &str, encoder: &TextEncoder<'_>) -> Self {
+        let encoded = encoder.encode(text);
+
+        let Some(message_payload) = try_build::convert::<OctetString<0, MAX_MESSAGE_PAYLOAD_LENGTH>>(
+            &mut self.error,
+            SmppField::message_payload,
+            encoded.bytes(),
+        ) else {
+            return self;
+        };
+
+        if encoded.has_udh() {
+            self.inner.esm_class = self.inner.esm_class.with_udhi_indicator();
+        }
+
+        self.inner.data_coding = encoded.data_coding();
+        self.inner
+            .tlvs
+            .retain(|tlv| tlv.tag() != TlvTag::MessagePayload);
+        self.inner
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::from(message_payload)),
+            ));
+
+        self
+    }
+
     pub fn tlvs(
         mut self,
         tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>,
@@ -203,9 +484,39 @@ impl DataSmBuilder {
         self
     }
 
+    /// Pushes a [`CallbackNum`](MessageSubmissionRequestTlvValue::CallbackNum) TLV encoding `callback_num`.
+    ///
+    /// Records an error if the address can not be encoded, see [`SmppAddress::to_callback_num`] and [`Self::try_build`].
+    pub fn callback_num(mut self, callback_num: &SmppAddress) -> Self {
+        match callback_num.to_callback_num() {
+            Ok(callback_num) => {
+                self.push_tlv(MessageSubmissionRequestTlvValue::CallbackNum(callback_num))
+            }
+            Err(error) => {
+                try_build::record(
+                    &mut self.error,
+                    BuildError::new(SmppField::callback_num, error.into()),
+                );
+
+                self
+            }
+        }
+    }
+
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> DataSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<DataSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -263,4 +574,92 @@ mod tests {
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<DataSm>();
     }
+
+    #[test]
+    fn text() {
+        let text = "Привет".repeat(50);
+
+        let data_sm = DataSm::builder().text("Hello").text(&text).build();
+
+        assert_eq!(data_sm.data_coding, DataCoding::Ucs2);
+        assert_eq!(data_sm.tlvs().len(), 1);
+
+        let Some(crate::tlvs::owned::TlvValue::MessagePayload(message_payload)) =
+            data_sm.tlvs()[0].value()
+        else {
+            panic!("Expected message payload");
+        };
+
+        assert_eq!(message_payload.value.bytes().len(), 600);
+    }
+
+    #[test]
+    fn text_too_long() {
+        let err = DataSm::builder()
+            .text(&"a".repeat(65536))
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            BuildError::new(
+                SmppField::message_payload,
+                try_build::BuildErrorKind::TooManyBytes {
+                    actual: 65536,
+                    max: 65535
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn split_text_sar() {
+        use crate::multipart::{Concatenation, Splitter};
+
+        let template = DataSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .text("Template")
+            .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+            .build();
+
+        let parts = template
+            .split_text(&"a".repeat(200), &Splitter::new(Concatenation::sar(7)))
+            .unwrap();
+
+        assert_eq!(parts.len(), 2);
+
+        for (index, part) in parts.iter().enumerate() {
+            assert!(!part.esm_class.has_udhi_indicator());
+            assert_eq!(part.tlvs().len(), 4);
+            assert_eq!(
+                part.sar().unwrap().unwrap(),
+                Sar::new(7, 2, index as u8 + 1).unwrap()
+            );
+            assert!(
+                part.tlvs()
+                    .iter()
+                    .any(|tlv| tlv.tag() == crate::tlvs::TlvTag::MessagePayload)
+            );
+        }
+    }
+
+    #[test]
+    fn delivery_receipt() {
+        let data_sm = DataSm::builder()
+            .esm_class(
+                EsmClass::default()
+                    .with_message_type(MessageType::ShortMessageContainsMCDeliveryReceipt),
+            )
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"id:7 stat:EXPIRED")),
+            ))
+            .build();
+
+        let receipt = data_sm.delivery_receipt().unwrap();
+
+        assert_eq!(receipt.id(), Some("7"));
+        assert_eq!(receipt.message_state(), Some(MessageState::Expired));
+
+        assert!(DataSm::default().delivery_receipt().is_none());
+    }
 }
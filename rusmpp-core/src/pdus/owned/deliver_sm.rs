@@ -1,23 +1,43 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
+    decode::{DecodeError, owned::Decode},
+    delivery_receipt::DeliveryReceipt,
     encode::Length,
-    pdus::owned::Pdu,
+    encoding::text::TextEncoder,
+    fields::SmppField,
+    multipart::Sar,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, MessageKind, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::{
         TlvTag,
-        owned::{MessageDeliveryRequestTlvValue, Tlv},
+        owned::{
+            MessageDeliveryRequestTlvValue, Tlv, VendorTlvValue, message_payload, vendor_value,
+        },
     },
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    udhs::{errors::ConcatenatedShortMessageError, owned::UserDataHeader},
     values::{owned::*, *},
 };
 
 /// This operation is used by an ESME to submit a short message to the MC for onward
 /// transmission to a specified short message entity (SME).
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct DeliverSm {
     /// The service_type parameter can be used to
     /// indicate the SMS Application service
@@ -88,7 +108,7 @@ pub struct DeliverSm {
     short_message: OctetString<0, 255>,
     /// Message delivery request TLVs ([`MessageDeliveryRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl DeliverSm {
@@ -167,6 +187,13 @@ impl DeliverSm {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageDeliveryRequestTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
@@ -201,9 +228,72 @@ impl DeliverSm {
         false
     }
 
+    /// Returns the [`Sar`] read from the SAR TLVs.
+    ///
+    /// See [`Sar::from_tlvs`].
+    pub fn sar(&self) -> Option<Result<Sar, ConcatenatedShortMessageError>> {
+        Sar::from_tlvs(&self.tlvs)
+    }
+
+    /// Returns the [`UserDataHeader`] at the start of the short_message, or of the message_payload TLV if the short_message is empty.
+    ///
+    /// Returns `None` if the UDH Indicator is not set in the [`DeliverSm::esm_class`].
+    pub fn user_data_header(&self) -> Option<Result<UserDataHeader, DecodeError>> {
+        if !self.esm_class.has_udhi_indicator() {
+            return None;
+        }
+
+        let user_data = match self.short_message.bytes() {
+            [] => message_payload(&self.tlvs),
+            short_message => short_message,
+        };
+
+        Some(UserDataHeader::decode(user_data).map(|(udh, _)| udh))
+    }
+
+    /// Returns the [`DeliveryReceipt`] carried by this [`DeliverSm`].
+    ///
+    /// The receipt text is read from the short_message, or from the message_payload TLV if the short_message is empty.
+    ///
+    /// Returns `None` if the [`DeliverSm::esm_class`] does not mark an MC delivery receipt.
+    pub fn delivery_receipt(&self) -> Option<DeliveryReceipt> {
+        let body = match self.short_message.bytes() {
+            [] => message_payload(&self.tlvs),
+            short_message => short_message,
+        };
+
+        DeliveryReceipt::from_pdu(self.esm_class, body, &self.tlvs)
+    }
+
+    /// Returns the [`MessageKind`] of this [`DeliverSm`], read from the message type bits of the [`DeliverSm::esm_class`].
+    ///
+    /// The user data is read from the short_message, or from the message_payload TLV if the short_message is empty.
+    pub fn kind(&self) -> MessageKind<'_> {
+        let user_data = match self.short_message.bytes() {
+            [] => message_payload(&self.tlvs),
+            short_message => short_message,
+        };
+
+        MessageKind::new(self.esm_class, self.data_coding, user_data, &self.tlvs)
+    }
+
     pub fn builder() -> DeliverSmBuilder {
         DeliverSmBuilder::new()
     }
+
+    /// Parses the [`DeliverSm::schedule_delivery_time`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the schedule delivery time is not set.
+    pub fn parse_schedule_delivery_time(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.schedule_delivery_time.to_smpp_time()
+    }
+
+    /// Parses the [`DeliverSm::validity_period`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the validity period is not set.
+    pub fn parse_validity_period(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.validity_period.to_smpp_time()
+    }
 }
 
 impl From<DeliverSm> for Pdu {
@@ -212,9 +302,74 @@ impl From<DeliverSm> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::DeliverSm<'_, N>> for DeliverSm {
+    fn from(value: borrowed::DeliverSm<'_, N>) -> Self {
+        Self {
+            service_type: value.service_type.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            dest_addr_ton: value.dest_addr_ton,
+            dest_addr_npi: value.dest_addr_npi,
+            destination_addr: value.destination_addr.into(),
+            esm_class: value.esm_class,
+            protocol_id: value.protocol_id,
+            priority_flag: value.priority_flag,
+            schedule_delivery_time: value.schedule_delivery_time.into(),
+            validity_period: value.validity_period.into(),
+            registered_delivery: value.registered_delivery,
+            replace_if_present_flag: value.replace_if_present_flag,
+            data_coding: value.data_coding,
+            sm_default_msg_id: value.sm_default_msg_id,
+            sm_length: value.sm_length,
+            short_message: value.short_message.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::DeliverSm<'_, N> {
+    /// Converts this borrowed [`DeliverSm`](borrowed::DeliverSm) into an owned [`DeliverSm`].
+    pub fn into_owned(self) -> DeliverSm {
+        self.into()
+    }
+}
+
+impl DeliverSm {
+    /// Returns a borrowed [`DeliverSm`](borrowed::DeliverSm) view of this [`DeliverSm`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::DeliverSm<'_, N>, AsBorrowedError> {
+        Ok(borrowed::DeliverSm {
+            service_type: self.service_type.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.as_borrowed(),
+            esm_class: self.esm_class,
+            protocol_id: self.protocol_id,
+            priority_flag: self.priority_flag,
+            schedule_delivery_time: self.schedule_delivery_time.as_borrowed(),
+            validity_period: self.validity_period.as_borrowed(),
+            registered_delivery: self.registered_delivery,
+            replace_if_present_flag: self.replace_if_present_flag,
+            data_coding: self.data_coding,
+            sm_default_msg_id: self.sm_default_msg_id,
+            sm_length: self.sm_length,
+            short_message: self.short_message.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DeliverSmBuilder {
     inner: DeliverSm,
+    error: Option<BuildError>,
 }
 
 impl DeliverSmBuilder {
@@ -222,8 +377,32 @@ impl DeliverSmBuilder {
         Self::default()
     }
 
-    pub fn service_type(mut self, service_type: ServiceType) -> Self {
-        self.inner.service_type = service_type;
+    /// Sets the short message, recording a conflict if the message payload discards it.
+    fn set_short_message(&mut self, short_message: OctetString<0, 255>) {
+        let discarded = !short_message.is_empty() && !self.inner.set_short_message(short_message);
+
+        try_build::record_discarded_short_message(&mut self.error, discarded);
+    }
+
+    /// Updates the TLVs, recording a conflict if the message payload discards the short message.
+    fn update_tlvs(&mut self, update: impl FnOnce(&mut DeliverSm)) {
+        let had_short_message = !self.inner.short_message().is_empty();
+
+        update(&mut self.inner);
+
+        try_build::record_discarded_short_message(
+            &mut self.error,
+            had_short_message && self.inner.short_message().is_empty(),
+        );
+    }
+
+    pub fn service_type(mut self, service_type: impl TryIntoField<ServiceType>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.service_type,
+            SmppField::service_type,
+            service_type,
+        );
         self
     }
 
@@ -237,8 +416,23 @@ impl DeliverSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`DeliverSm::source_addr_ton`], [`DeliverSm::source_addr_npi`] and [`DeliverSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
@@ -252,8 +446,26 @@ impl DeliverSmBuilder {
         self
     }
 
-    pub fn destination_addr(mut self, destination_addr: COctetString<1, 21>) -> Self {
-        self.inner.destination_addr = destination_addr;
+    pub fn destination_addr(
+        mut self,
+        destination_addr: impl TryIntoField<COctetString<1, 21>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.destination_addr,
+            SmppField::destination_addr,
+            destination_addr,
+        );
+        self
+    }
+
+    /// Sets the [`DeliverSm::dest_addr_ton`], [`DeliverSm::dest_addr_npi`] and [`DeliverSm::destination_addr`].
+    pub fn destination(mut self, destination: SmppAddress) -> Self {
+        let (ton, npi, addr) = destination.into_parts();
+
+        self.inner.dest_addr_ton = ton;
+        self.inner.dest_addr_npi = npi;
+        self.inner.destination_addr = addr;
         self
     }
 
@@ -274,14 +486,27 @@ impl DeliverSmBuilder {
 
     pub fn schedule_delivery_time(
         mut self,
-        schedule_delivery_time: EmptyOrFullCOctetString<17>,
+        schedule_delivery_time: impl TryIntoField<EmptyOrFullCOctetString<17>>,
     ) -> Self {
-        self.inner.schedule_delivery_time = schedule_delivery_time;
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.schedule_delivery_time,
+            SmppField::schedule_delivery_time,
+            schedule_delivery_time,
+        );
         self
     }
 
-    pub fn validity_period(mut self, validity_period: EmptyOrFullCOctetString<17>) -> Self {
-        self.inner.validity_period = validity_period;
+    pub fn validity_period(
+        mut self,
+        validity_period: impl TryIntoField<EmptyOrFullCOctetString<17>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.validity_period,
+            SmppField::validity_period,
+            validity_period,
+        );
         self
     }
 
@@ -308,8 +533,96 @@ impl DeliverSmBuilder {
         self
     }
 
-    pub fn short_message(mut self, short_message: OctetString<0, 255>) -> Self {
-        self.inner.set_short_message(short_message);
+    pub fn short_message(mut self, short_message: impl TryIntoField<OctetString<0, 255>>) -> Self {
+        if let Some(short_message) =
+            try_build::convert(&mut self.error, SmppField::short_message, short_message)
+        {
+            self.set_short_message(short_message);
+        }
+
+        self
+    }
+
+    /// Encodes `text` using the default [`TextEncoder`].
+    ///
+    /// See [`DeliverSmBuilder::text_with`].
+    pub fn text(self, text: &str) -> Self {
+        self.text_with(text, &TextEncoder::new())
+    }
+
+    /// Encodes `text` using `encoder` and sets the [`DeliverSm::data_coding`] and the [`DeliverSm::short_message`].
+    ///
+    /// If the encoded text starts with a UDH, the UDH Indicator is set in the [`DeliverSm::esm_class`].
+    /// Call this method after setting the [`DeliverSm::esm_class`].
+    ///
+    /// Records an error if the encoded text exceeds `255` octets, see [`Self::try_build`].
+    pub fn text_with(mut self, text: &str, encoder: &TextEncoder<'_>) -> Self {
+        let encoded = encoder.encode(text);
+
+        let Some(short_message) =
+            try_build::convert(&mut self.error, SmppField::short_message, encoded.bytes())
+        else {
+            return self;
+        };
+
+        if encoded.has_udh() {
+            self.inner.esm_class = self.inner.esm_class.with_udhi_indicator();
+        }
+
+        self.inner.data_coding = encoded.data_coding();
+        self.set_short_message(short_message);
+
+        self
+    }
+
+    /// Sets the [`DeliverSm::esm_class`] message type to MC delivery receipt, the receipt text as
+    /// the [`DeliverSm::short_message`] and pushes the `receipted_message_id`, `message_state`
+    /// and `network_error_code` TLVs for the fields that are set.
+    ///
+    /// Records an error if the id is not a valid `receipted_message_id` or the receipt text exceeds `255` octets,
+    /// see [`Self::try_build`].
+    pub fn delivery_receipt(mut self, receipt: &DeliveryReceipt) -> Self {
+        let Some(short_message) = try_build::convert(
+            &mut self.error,
+            SmppField::short_message,
+            alloc::string::ToString::to_string(receipt).as_str(),
+        ) else {
+            return self;
+        };
+
+        let receipted_message_id = match receipt.id() {
+            Some(id) => {
+                match try_build::convert(&mut self.error, SmppField::receipted_message_id, id) {
+                    Some(id) => Some(id),
+                    None => return self,
+                }
+            }
+            None => None,
+        };
+
+        if let Some(id) = receipted_message_id {
+            self.inner
+                .push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(id));
+        }
+
+        if let Some(message_state) = receipt.message_state() {
+            self.inner
+                .push_tlv(MessageDeliveryRequestTlvValue::MessageState(message_state));
+        }
+
+        if let Some(network_error_code) = receipt.network_error_code() {
+            self.inner
+                .push_tlv(MessageDeliveryRequestTlvValue::NetworkErrorCode(
+                    network_error_code.clone(),
+                ));
+        }
+
+        self.inner.esm_class = self
+            .inner
+            .esm_class
+            .with_message_type(MessageType::ShortMessageContainsMCDeliveryReceipt);
+        self.set_short_message(short_message);
+
         self
     }
 
@@ -317,7 +630,7 @@ impl DeliverSmBuilder {
         mut self,
         tlvs: alloc::vec::Vec<impl Into<MessageDeliveryRequestTlvValue>>,
     ) -> Self {
-        self.inner.set_tlvs(tlvs);
+        self.update_tlvs(|pdu| pdu.set_tlvs(tlvs));
         self
     }
 
@@ -327,13 +640,43 @@ impl DeliverSmBuilder {
     }
 
     pub fn push_tlv(mut self, tlv: impl Into<MessageDeliveryRequestTlvValue>) -> Self {
-        self.inner.push_tlv(tlv);
+        self.update_tlvs(|pdu| pdu.push_tlv(tlv));
         self
     }
 
+    /// Pushes a [`CallbackNum`](MessageDeliveryRequestTlvValue::CallbackNum) TLV encoding `callback_num`.
+    ///
+    /// Records an error if the address can not be encoded, see [`SmppAddress::to_callback_num`] and [`Self::try_build`].
+    pub fn callback_num(mut self, callback_num: &SmppAddress) -> Self {
+        match callback_num.to_callback_num() {
+            Ok(callback_num) => {
+                self.push_tlv(MessageDeliveryRequestTlvValue::CallbackNum(callback_num))
+            }
+            Err(error) => {
+                try_build::record(
+                    &mut self.error,
+                    BuildError::new(SmppField::callback_num, error.into()),
+                );
+
+                self
+            }
+        }
+    }
+
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> DeliverSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<DeliverSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -490,4 +833,135 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn sar() {
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::SarMsgRefNum(0x1234))
+            .push_tlv(MessageDeliveryRequestTlvValue::SarTotalSegments(3))
+            .push_tlv(MessageDeliveryRequestTlvValue::SarSegmentSeqnum(2))
+            .build();
+
+        assert_eq!(
+            deliver_sm.sar().unwrap().unwrap(),
+            Sar::new(0x1234, 3, 2).unwrap()
+        );
+
+        assert!(DeliverSm::default().sar().is_none());
+    }
+
+    #[test]
+    fn delivery_receipt() {
+        use crate::delivery_receipt::DeliveryReceipt;
+
+        let receipt = DeliveryReceipt::builder()
+            .id("abc")
+            .submitted(1)
+            .delivered(0)
+            .message_state(MessageState::Undeliverable)
+            .network_error_code(NetworkErrorCode::new(ErrorCodeNetworkType::Gsm, 0x22))
+            .err("034")
+            .build();
+
+        let deliver_sm = DeliverSm::builder().delivery_receipt(&receipt).build();
+
+        assert_eq!(
+            deliver_sm.esm_class.message_type,
+            MessageType::ShortMessageContainsMCDeliveryReceipt
+        );
+        assert_eq!(
+            deliver_sm.short_message().bytes(),
+            b"id:abc sub:001 dlvrd:000 stat:UNDELIV err:034"
+        );
+        assert_eq!(deliver_sm.tlvs().len(), 3);
+        assert_eq!(deliver_sm.delivery_receipt().unwrap(), receipt);
+
+        // Not a delivery receipt
+        assert!(DeliverSm::default().delivery_receipt().is_none());
+
+        // The TLVs take precedence over the text
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(
+                EsmClass::default()
+                    .with_message_type(MessageType::ShortMessageContainsMCDeliveryReceipt),
+            )
+            .short_message(OctetString::new(b"id:1 stat:DELIVRD").unwrap())
+            .push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(
+                COctetString::from_str("2").unwrap(),
+            ))
+            .push_tlv(MessageDeliveryRequestTlvValue::MessageState(
+                MessageState::Rejected,
+            ))
+            .build();
+
+        let receipt = deliver_sm.delivery_receipt().unwrap();
+
+        assert_eq!(receipt.id(), Some("2"));
+        assert_eq!(receipt.stat(), Some("DELIVRD"));
+        assert_eq!(receipt.message_state(), Some(MessageState::Rejected));
+
+        // The receipted_message_id is limited to 64 characters
+        let receipt = DeliveryReceipt::builder().id("1".repeat(65)).build();
+
+        assert_eq!(
+            DeliverSm::builder()
+                .delivery_receipt(&receipt)
+                .try_build()
+                .unwrap_err(),
+            BuildError::new(
+                SmppField::receipted_message_id,
+                try_build::BuildErrorKind::TooManyBytes {
+                    actual: 66,
+                    max: 65
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn user_data_header() {
+        use crate::udhs::owned::ApplicationPortAddressing16Bit;
+
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .short_message(
+                OctetString::new([0x06, 0x05, 0x04, 0x0B, 0x84, 0x23, 0xF0, 0x01, 0x02]).unwrap(),
+            )
+            .build();
+
+        let udh = deliver_sm.user_data_header().unwrap().unwrap();
+
+        assert_eq!(
+            udh.application_port(),
+            Some(ApplicationPortAddressing16Bit::new(2948, 9200))
+        );
+
+        // The UDH is read from the message_payload if the short_message is empty
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .push_tlv(MessageDeliveryRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new([0x03, 0x07, 0x01, 0x03])),
+            ))
+            .build();
+
+        let udh = deliver_sm.user_data_header().unwrap().unwrap();
+
+        assert_eq!(
+            udh.get(crate::udhs::UdhId::UdhSourceIndicator),
+            Some(&crate::udhs::owned::UdhValue::UdhSourceIndicator(
+                crate::udhs::owned::UdhSourceIndicator::Smsc
+            ))
+        );
+
+        // Truncated UDH
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .short_message(OctetString::new([0x06, 0x05, 0x04]).unwrap())
+            .build();
+
+        assert!(deliver_sm.user_data_header().unwrap().is_err());
+
+        // UDHI not set
+        assert!(DeliverSm::default().user_data_header().is_none());
+    }
 }
@@ -0,0 +1,317 @@
+use crate::{
+    decode::{DecodeError, owned::Decode},
+    delivery_receipt::DeliveryReceipt,
+    encoding::{
+        gsm7bit::Gsm7BitPacking,
+        text::{TextDecodeError, TextEncoding},
+    },
+    tlvs::owned::{Tlv, TlvValue},
+    udhs::owned::UserDataHeader,
+    values::{DataCoding, EsmClass, UserMessageReference},
+};
+
+/// The kind of message carried by a [`DeliverSm`](super::DeliverSm) or a [`DataSm`](super::DataSm),
+/// read from the message type bits (5-2) of the `esm_class`.
+///
+/// See [`DeliverSm::kind`](super::DeliverSm::kind) and [`DataSm::kind`](super::DataSm::kind).
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::{
+///     pdus::owned::{DeliverSm, MessageKind},
+///     values::EsmClass,
+/// };
+///
+/// // Message type bits: MC delivery receipt.
+/// let deliver_sm = DeliverSm::builder()
+///     .esm_class(EsmClass::from(0b0000_0100))
+///     .text("id:123 sub:001 dlvrd:001 submit date:2501011200 done date:2501011201 stat:DELIVRD err:000 text:Hi")
+///     .build();
+///
+/// match deliver_sm.kind() {
+///     MessageKind::DeliveryReceipt(receipt) => assert_eq!(receipt.id(), Some("123")),
+///     _ => unreachable!(),
+/// }
+///
+/// // Message type bits not set: mobile originated.
+/// let deliver_sm = DeliverSm::builder()
+///     .esm_class(EsmClass::from(0b0000_0000))
+///     .text("Hello")
+///     .build();
+///
+/// match deliver_sm.kind() {
+///     MessageKind::MobileOriginated(message) => {
+///         assert_eq!(message.text(), Some(Ok("Hello".into())))
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageKind<'a> {
+    /// A mobile originated message, the message type bits are not set.
+    MobileOriginated(UserMessage<'a>),
+    /// An MC delivery receipt.
+    DeliveryReceipt(DeliveryReceipt),
+    /// An intermediate delivery notification.
+    IntermediateNotification(DeliveryReceipt),
+    /// An SME delivery acknowledgement.
+    DeliveryAcknowledgement(UserMessage<'a>),
+    /// A manual/user acknowledgement.
+    UserAcknowledgement(UserMessage<'a>),
+    /// A conversation abort (Korean CDMA).
+    ConversationAbort(UserMessage<'a>),
+    /// A reserved combination of the message type bits.
+    Reserved(UserMessage<'a>),
+}
+
+impl<'a> MessageKind<'a> {
+    /// Classifies a message with the given `esm_class`, `data_coding`, `user_data` and `tlvs`.
+    ///
+    /// `user_data` is the short_message, or the message_payload TLV if the short_message is empty.
+    pub(crate) fn new(
+        esm_class: EsmClass,
+        data_coding: DataCoding,
+        user_data: &'a [u8],
+        tlvs: &'a [Tlv],
+    ) -> Self {
+        let message = UserMessage {
+            esm_class,
+            data_coding,
+            user_data,
+            tlvs,
+        };
+
+        match u8::from(esm_class) & 0b00_11_11_00 {
+            0b00_00_00_00 => Self::MobileOriginated(message),
+            0b00_00_01_00 => Self::DeliveryReceipt(DeliveryReceipt::from_body(user_data, tlvs)),
+            0b00_10_00_00 => {
+                Self::IntermediateNotification(DeliveryReceipt::from_body(user_data, tlvs))
+            }
+            0b00_00_10_00 => Self::DeliveryAcknowledgement(message),
+            0b00_01_00_00 => Self::UserAcknowledgement(message),
+            0b00_01_10_00 => Self::ConversationAbort(message),
+            _ => Self::Reserved(message),
+        }
+    }
+}
+
+/// A view of the user data of a [`DeliverSm`](super::DeliverSm) or a [`DataSm`](super::DataSm).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserMessage<'a> {
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+    user_data: &'a [u8],
+    tlvs: &'a [Tlv],
+}
+
+impl<'a> UserMessage<'a> {
+    /// Returns the `esm_class` of the message.
+    pub const fn esm_class(&self) -> EsmClass {
+        self.esm_class
+    }
+
+    /// Returns the `data_coding` of the message.
+    pub const fn data_coding(&self) -> DataCoding {
+        self.data_coding
+    }
+
+    /// Returns the user data, including the UDH if any.
+    pub const fn user_data(&self) -> &'a [u8] {
+        self.user_data
+    }
+
+    /// Returns the TLVs of the message.
+    pub const fn tlvs(&self) -> &'a [Tlv] {
+        self.tlvs
+    }
+
+    /// Returns the [`UserDataHeader`] at the start of the user data.
+    ///
+    /// Returns `None` if the UDH Indicator is not set in the `esm_class`.
+    pub fn user_data_header(&self) -> Option<Result<UserDataHeader, DecodeError>> {
+        self.decode_user_data_header()
+            .map(|result| result.map(|(udh, _)| udh))
+    }
+
+    /// Returns the user data without the UDH.
+    ///
+    /// Returns the whole user data if the UDH Indicator is not set or the UDH can not be decoded.
+    pub fn payload(&self) -> &'a [u8] {
+        &self.user_data[self.udh_length()..]
+    }
+
+    /// Returns the value of the `user_message_reference` TLV.
+    pub fn user_message_reference(&self) -> Option<UserMessageReference> {
+        self.tlvs.iter().find_map(|tlv| match tlv.value() {
+            Some(TlvValue::UserMessageReference(value)) => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// Decodes the text of the message, assuming unpacked GSM 7-bit.
+    ///
+    /// See [`UserMessage::text_with`].
+    pub fn text(&self) -> Option<Result<alloc::string::String, TextDecodeError>> {
+        self.text_with(Gsm7BitPacking::Unpacked)
+    }
+
+    /// Decodes the text of the message, without the UDH.
+    ///
+    /// The encoding is [picked](TextEncoding::from_data_coding) from the `data_coding`.
+    /// GSM 7-bit text is decoded using the default alphabet and `packing`.
+    ///
+    /// Returns `None` if the `data_coding` does not describe text, e.g. 8-bit data.
+    pub fn text_with(
+        &self,
+        packing: Gsm7BitPacking,
+    ) -> Option<Result<alloc::string::String, TextDecodeError>> {
+        let encoding = TextEncoding::from_data_coding(self.data_coding, packing)?;
+
+        let udh_length = self.udh_length();
+
+        Some(encoding.decode_to_string_after_udh(udh_length, &self.user_data[udh_length..]))
+    }
+
+    fn decode_user_data_header(&self) -> Option<Result<(UserDataHeader, usize), DecodeError>> {
+        if !self.esm_class.has_udhi_indicator() {
+            return None;
+        }
+
+        Some(UserDataHeader::decode(self.user_data))
+    }
+
+    fn udh_length(&self) -> usize {
+        match self.decode_user_data_header() {
+            Some(Ok((_, size))) => size,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pdus::owned::{DataSm, DeliverSm},
+        tlvs::owned::{MessageDeliveryRequestTlvValue, MessageSubmissionRequestTlvValue},
+        types::owned::{AnyOctetString, OctetString},
+        values::{MessageState, owned::MessagePayload},
+    };
+
+    use super::*;
+
+    fn deliver_sm(esm_class: u8) -> DeliverSm {
+        DeliverSm::builder()
+            .esm_class(EsmClass::from(esm_class))
+            .short_message(OctetString::new(b"Hello").unwrap())
+            .push_tlv(MessageDeliveryRequestTlvValue::UserMessageReference(
+                UserMessageReference::new(7),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn kinds() {
+        assert!(matches!(
+            deliver_sm(0x00).kind(),
+            MessageKind::MobileOriginated(_)
+        ));
+        assert!(matches!(
+            deliver_sm(0x04).kind(),
+            MessageKind::DeliveryReceipt(_)
+        ));
+        assert!(matches!(
+            deliver_sm(0x20).kind(),
+            MessageKind::IntermediateNotification(_)
+        ));
+        assert!(matches!(
+            deliver_sm(0x08).kind(),
+            MessageKind::DeliveryAcknowledgement(_)
+        ));
+        assert!(matches!(
+            deliver_sm(0x10).kind(),
+            MessageKind::UserAcknowledgement(_)
+        ));
+        assert!(matches!(
+            deliver_sm(0x18).kind(),
+            MessageKind::ConversationAbort(_)
+        ));
+        assert!(matches!(deliver_sm(0x0C).kind(), MessageKind::Reserved(_)));
+
+        // Messaging mode and GSM features do not change the kind.
+        assert!(matches!(
+            deliver_sm(0x83).kind(),
+            MessageKind::MobileOriginated(_)
+        ));
+    }
+
+    #[test]
+    fn user_message() {
+        let deliver_sm = deliver_sm(0x08);
+
+        let MessageKind::DeliveryAcknowledgement(message) = deliver_sm.kind() else {
+            panic!("Expected a delivery acknowledgement");
+        };
+
+        assert_eq!(message.user_data(), b"Hello");
+        assert_eq!(message.payload(), b"Hello");
+        assert_eq!(message.text(), Some(Ok("Hello".into())));
+        assert_eq!(
+            message.user_message_reference(),
+            Some(UserMessageReference::new(7))
+        );
+        assert!(message.user_data_header().is_none());
+    }
+
+    #[test]
+    fn user_message_with_udh() {
+        let mut user_data = alloc::vec![0x05, 0x00, 0x03, 0x2A, 0x02, 0x01];
+        user_data.extend_from_slice(b"Hello");
+
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::from(0x40))
+            .short_message(OctetString::new(&user_data).unwrap())
+            .build();
+
+        let MessageKind::MobileOriginated(message) = deliver_sm.kind() else {
+            panic!("Expected a mobile originated message");
+        };
+
+        assert!(matches!(message.user_data_header(), Some(Ok(_))));
+        assert_eq!(message.payload(), b"Hello");
+        assert_eq!(message.text(), Some(Ok("Hello".into())));
+    }
+
+    #[test]
+    fn binary_has_no_text() {
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::from(0x00))
+            .data_coding(DataCoding::OctetUnspecified)
+            .short_message(OctetString::new([0xFF, 0x00]).unwrap())
+            .build();
+
+        let MessageKind::MobileOriginated(message) = deliver_sm.kind() else {
+            panic!("Expected a mobile originated message");
+        };
+
+        assert_eq!(message.text(), None);
+        assert_eq!(message.payload(), &[0xFF, 0x00]);
+    }
+
+    #[test]
+    fn data_sm_reads_message_payload() {
+        let data_sm = DataSm::builder()
+            .esm_class(EsmClass::from(0x20))
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"id:42 stat:ENROUTE")),
+            ))
+            .build();
+
+        let MessageKind::IntermediateNotification(receipt) = data_sm.kind() else {
+            panic!("Expected an intermediate notification");
+        };
+
+        assert_eq!(receipt.id(), Some("42"));
+        assert_eq!(receipt.message_state(), Some(MessageState::Enroute));
+    }
+}
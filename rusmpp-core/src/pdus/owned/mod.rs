@@ -3,7 +3,15 @@
 mod pdu;
 pub use pdu::Pdu;
 
+mod as_borrowed;
+pub use as_borrowed::AsBorrowedError;
+pub(crate) use as_borrowed::heapless_vec;
+
+mod try_build;
+
 pub mod builders {
+    pub use super::try_build::{BuildError, BuildErrorKind, TryIntoField};
+
     pub use super::alert_notification::AlertNotificationBuilder;
     pub use super::bind::{BindReceiverBuilder, BindTransceiverBuilder, BindTransmitterBuilder};
     pub use super::bind_resp::{
@@ -71,6 +79,9 @@ pub use data_sm::DataSm;
 mod deliver_sm;
 pub use deliver_sm::DeliverSm;
 
+mod message_kind;
+pub use message_kind::{MessageKind, UserMessage};
+
 mod outbind;
 pub use outbind::Outbind;
 
@@ -112,3 +123,6 @@ pub use query_broadcast_sm_resp::QueryBroadcastSmResp;
 
 mod cancel_broadcast_sm;
 pub use cancel_broadcast_sm::CancelBroadcastSm;
+
+mod vendor;
+pub use vendor::{VendorPdu, VendorPduBody};
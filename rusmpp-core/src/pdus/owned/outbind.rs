@@ -1,6 +1,16 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{pdus::owned::Pdu, types::owned::COctetString};
+use crate::{
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    types::owned::COctetString,
+};
 
 /// Authentication PDU used by a Message Centre to Outbind to
 /// an ESME to inform it that messages are present in the MC.
@@ -12,7 +22,10 @@ use crate::{pdus::owned::Pdu, types::owned::COctetString};
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct Outbind {
     /// MC identifier.
     ///
@@ -44,9 +57,36 @@ impl From<Outbind> for Pdu {
     }
 }
 
+impl From<borrowed::Outbind<'_>> for Outbind {
+    fn from(value: borrowed::Outbind<'_>) -> Self {
+        Self {
+            system_id: value.system_id.into(),
+            password: value.password.into(),
+        }
+    }
+}
+
+impl borrowed::Outbind<'_> {
+    /// Converts this borrowed [`Outbind`](borrowed::Outbind) into an owned [`Outbind`].
+    pub fn into_owned(self) -> Outbind {
+        self.into()
+    }
+}
+
+impl Outbind {
+    /// Returns a borrowed [`Outbind`](borrowed::Outbind) view of this [`Outbind`].
+    pub fn as_borrowed(&self) -> borrowed::Outbind<'_> {
+        borrowed::Outbind {
+            system_id: self.system_id.as_borrowed(),
+            password: self.password.as_borrowed(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct OutbindBuilder {
     inner: Outbind,
+    error: Option<BuildError>,
 }
 
 impl OutbindBuilder {
@@ -54,19 +94,40 @@ impl OutbindBuilder {
         Self::default()
     }
 
-    pub fn system_id(mut self, system_id: COctetString<1, 16>) -> Self {
-        self.inner.system_id = system_id;
+    pub fn system_id(mut self, system_id: impl TryIntoField<COctetString<1, 16>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.system_id,
+            SmppField::system_id,
+            system_id,
+        );
         self
     }
 
-    pub fn password(mut self, password: COctetString<1, 9>) -> Self {
-        self.inner.password = password;
+    pub fn password(mut self, password: impl TryIntoField<COctetString<1, 9>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.password,
+            SmppField::password,
+            password,
+        );
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> Outbind {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<Outbind, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -5,16 +5,24 @@ use crate::{
         owned::{Decode, DecodeWithKeyOptional, DecodeWithLength},
     },
     encode::{Encode, Length},
+    pdus::borrowed,
+    tlvs::owned::{Tlv, VendorTlvValue, vendor_value},
     types::owned::AnyOctetString,
 };
 
+#[cfg(feature = "tokio-codec")]
+use crate::encode::EncodeBytesMut;
+
 use super::*;
 
 /// `SMPP` PDU.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum Pdu {
     /// Authentication PDU used by a transmitter ESME to bind to
     /// the Message Centre. The PDU contains identification
@@ -224,6 +232,67 @@ impl Pdu {
             Pdu::CancelBroadcastSmResp => CommandId::CancelBroadcastSmResp,
         }
     }
+
+    /// Returns the TLVs of this [`Pdu`].
+    pub(crate) fn tlvs(&self) -> &[Tlv] {
+        match self {
+            Pdu::BindTransmitterResp(pdu) => pdu.sc_interface_version.as_slice(),
+            Pdu::BindReceiverResp(pdu) => pdu.sc_interface_version.as_slice(),
+            Pdu::BindTransceiverResp(pdu) => pdu.sc_interface_version.as_slice(),
+            Pdu::AlertNotification(pdu) => pdu.ms_availability_status.as_slice(),
+            Pdu::SubmitSm(pdu) => &pdu.tlvs,
+            Pdu::SubmitSmResp(pdu) => &pdu.tlvs,
+            Pdu::DeliverSm(pdu) => &pdu.tlvs,
+            Pdu::DeliverSmResp(pdu) => &pdu.tlvs,
+            Pdu::DataSm(pdu) => &pdu.tlvs,
+            Pdu::DataSmResp(pdu) => &pdu.tlvs,
+            Pdu::SubmitMulti(pdu) => &pdu.tlvs,
+            Pdu::SubmitMultiResp(pdu) => &pdu.tlvs,
+            Pdu::BroadcastSm(pdu) => &pdu.tlvs,
+            Pdu::BroadcastSmResp(pdu) => &pdu.tlvs,
+            Pdu::QueryBroadcastSmResp(pdu) => &pdu.tlvs,
+            Pdu::CancelBroadcastSm(pdu) => &pdu.tlvs,
+            _ => &[],
+        }
+    }
+
+    /// Returns the first vendor-specific TLV value `T` of this [`Pdu`].
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(self.tlvs())
+    }
+
+    /// Retains only the TLVs specified by the predicate.
+    pub(crate) fn retain_tlvs(&mut self, mut f: impl FnMut(&Tlv) -> bool) {
+        match self {
+            Pdu::BindTransmitterResp(pdu) => {
+                pdu.sc_interface_version = pdu.sc_interface_version.take().filter(|tlv| f(tlv));
+            }
+            Pdu::BindReceiverResp(pdu) => {
+                pdu.sc_interface_version = pdu.sc_interface_version.take().filter(|tlv| f(tlv));
+            }
+            Pdu::BindTransceiverResp(pdu) => {
+                pdu.sc_interface_version = pdu.sc_interface_version.take().filter(|tlv| f(tlv));
+            }
+            Pdu::AlertNotification(pdu) => {
+                pdu.ms_availability_status = pdu.ms_availability_status.take().filter(|tlv| f(tlv));
+            }
+            Pdu::SubmitSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::SubmitSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DeliverSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DeliverSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DataSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::DataSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::SubmitMulti(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::SubmitMultiResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::BroadcastSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::BroadcastSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::QueryBroadcastSmResp(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            Pdu::CancelBroadcastSm(pdu) => pdu.tlvs.retain(|tlv| f(tlv)),
+            _ => {}
+        }
+    }
 }
 
 impl Length for Pdu {
@@ -308,6 +377,48 @@ impl Encode for Pdu {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl EncodeBytesMut for Pdu {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        match self {
+            Pdu::BindTransmitter(body) => body.encode_buf_mut(dst),
+            Pdu::BindTransmitterResp(body) => body.encode_buf_mut(dst),
+            Pdu::BindReceiver(body) => body.encode_buf_mut(dst),
+            Pdu::BindReceiverResp(body) => body.encode_buf_mut(dst),
+            Pdu::BindTransceiver(body) => body.encode_buf_mut(dst),
+            Pdu::BindTransceiverResp(body) => body.encode_buf_mut(dst),
+            Pdu::Outbind(body) => body.encode_buf_mut(dst),
+            Pdu::AlertNotification(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitSm(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::QuerySm(body) => body.encode_buf_mut(dst),
+            Pdu::QuerySmResp(body) => body.encode_buf_mut(dst),
+            Pdu::DeliverSm(body) => body.encode_buf_mut(dst),
+            Pdu::DeliverSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::DataSm(body) => body.encode_buf_mut(dst),
+            Pdu::DataSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::CancelSm(body) => body.encode_buf_mut(dst),
+            Pdu::ReplaceSm(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitMulti(body) => body.encode_buf_mut(dst),
+            Pdu::SubmitMultiResp(body) => body.encode_buf_mut(dst),
+            Pdu::BroadcastSm(body) => body.encode_buf_mut(dst),
+            Pdu::BroadcastSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::QueryBroadcastSm(body) => body.encode_buf_mut(dst),
+            Pdu::QueryBroadcastSmResp(body) => body.encode_buf_mut(dst),
+            Pdu::CancelBroadcastSm(body) => body.encode_buf_mut(dst),
+            Pdu::Unbind
+            | Pdu::UnbindResp
+            | Pdu::EnquireLink
+            | Pdu::EnquireLinkResp
+            | Pdu::GenericNack
+            | Pdu::CancelSmResp
+            | Pdu::ReplaceSmResp
+            | Pdu::CancelBroadcastSmResp => 0,
+            Pdu::Other { body, .. } => body.encode_buf_mut(dst),
+        }
+    }
+}
+
 impl DecodeWithKeyOptional for Pdu {
     type Key = CommandId;
 
@@ -410,3 +521,109 @@ impl DecodeWithKeyOptional for Pdu {
         Ok(Some((body, size)))
     }
 }
+
+impl<const N: usize> From<borrowed::Pdu<'_, N>> for Pdu {
+    fn from(value: borrowed::Pdu<'_, N>) -> Self {
+        match value {
+            borrowed::Pdu::BindTransmitter(value) => Self::BindTransmitter(value.into()),
+            borrowed::Pdu::BindTransmitterResp(value) => Self::BindTransmitterResp(value.into()),
+            borrowed::Pdu::BindReceiver(value) => Self::BindReceiver(value.into()),
+            borrowed::Pdu::BindReceiverResp(value) => Self::BindReceiverResp(value.into()),
+            borrowed::Pdu::BindTransceiver(value) => Self::BindTransceiver(value.into()),
+            borrowed::Pdu::BindTransceiverResp(value) => Self::BindTransceiverResp(value.into()),
+            borrowed::Pdu::Outbind(value) => Self::Outbind(value.into()),
+            borrowed::Pdu::AlertNotification(value) => Self::AlertNotification(value.into()),
+            borrowed::Pdu::SubmitSm(value) => Self::SubmitSm(value.into()),
+            borrowed::Pdu::SubmitSmResp(value) => Self::SubmitSmResp(value.into()),
+            borrowed::Pdu::QuerySm(value) => Self::QuerySm(value.into()),
+            borrowed::Pdu::QuerySmResp(value) => Self::QuerySmResp(value.into()),
+            borrowed::Pdu::DeliverSm(value) => Self::DeliverSm(value.into()),
+            borrowed::Pdu::DeliverSmResp(value) => Self::DeliverSmResp(value.into()),
+            borrowed::Pdu::DataSm(value) => Self::DataSm(value.into()),
+            borrowed::Pdu::DataSmResp(value) => Self::DataSmResp(value.into()),
+            borrowed::Pdu::CancelSm(value) => Self::CancelSm(value.into()),
+            borrowed::Pdu::ReplaceSm(value) => Self::ReplaceSm(value.into()),
+            borrowed::Pdu::SubmitMulti(value) => Self::SubmitMulti(value.into()),
+            borrowed::Pdu::SubmitMultiResp(value) => Self::SubmitMultiResp(value.into()),
+            borrowed::Pdu::BroadcastSm(value) => Self::BroadcastSm(value.into()),
+            borrowed::Pdu::BroadcastSmResp(value) => Self::BroadcastSmResp(value.into()),
+            borrowed::Pdu::QueryBroadcastSm(value) => Self::QueryBroadcastSm(value.into()),
+            borrowed::Pdu::QueryBroadcastSmResp(value) => Self::QueryBroadcastSmResp(value.into()),
+            borrowed::Pdu::CancelBroadcastSm(value) => Self::CancelBroadcastSm(value.into()),
+            borrowed::Pdu::Unbind => Self::Unbind,
+            borrowed::Pdu::UnbindResp => Self::UnbindResp,
+            borrowed::Pdu::EnquireLink => Self::EnquireLink,
+            borrowed::Pdu::EnquireLinkResp => Self::EnquireLinkResp,
+            borrowed::Pdu::GenericNack => Self::GenericNack,
+            borrowed::Pdu::CancelSmResp => Self::CancelSmResp,
+            borrowed::Pdu::ReplaceSmResp => Self::ReplaceSmResp,
+            borrowed::Pdu::CancelBroadcastSmResp => Self::CancelBroadcastSmResp,
+            borrowed::Pdu::Other { command_id, body } => Self::Other {
+                command_id,
+                body: body.into(),
+            },
+        }
+    }
+}
+
+impl<const N: usize> borrowed::Pdu<'_, N> {
+    /// Converts this borrowed [`Pdu`](borrowed::Pdu) into an owned [`Pdu`].
+    pub fn into_owned(self) -> Pdu {
+        self.into()
+    }
+}
+
+impl Pdu {
+    /// Returns a borrowed [`Pdu`](borrowed::Pdu) view of this [`Pdu`].
+    ///
+    /// Fails if the TLVs, destination addresses or unsuccessful SMEs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(&self) -> Result<borrowed::Pdu<'_, N>, AsBorrowedError> {
+        Ok(match self {
+            Self::BindTransmitter(value) => borrowed::Pdu::BindTransmitter(value.as_borrowed()),
+            Self::BindTransmitterResp(value) => {
+                borrowed::Pdu::BindTransmitterResp(value.as_borrowed())
+            }
+            Self::BindReceiver(value) => borrowed::Pdu::BindReceiver(value.as_borrowed()),
+            Self::BindReceiverResp(value) => borrowed::Pdu::BindReceiverResp(value.as_borrowed()),
+            Self::BindTransceiver(value) => borrowed::Pdu::BindTransceiver(value.as_borrowed()),
+            Self::BindTransceiverResp(value) => {
+                borrowed::Pdu::BindTransceiverResp(value.as_borrowed())
+            }
+            Self::Outbind(value) => borrowed::Pdu::Outbind(value.as_borrowed()),
+            Self::AlertNotification(value) => borrowed::Pdu::AlertNotification(value.as_borrowed()),
+            Self::SubmitSm(value) => borrowed::Pdu::SubmitSm(value.as_borrowed()?),
+            Self::SubmitSmResp(value) => borrowed::Pdu::SubmitSmResp(value.as_borrowed()?),
+            Self::QuerySm(value) => borrowed::Pdu::QuerySm(value.as_borrowed()),
+            Self::QuerySmResp(value) => borrowed::Pdu::QuerySmResp(value.as_borrowed()),
+            Self::DeliverSm(value) => borrowed::Pdu::DeliverSm(value.as_borrowed()?),
+            Self::DeliverSmResp(value) => borrowed::Pdu::DeliverSmResp(value.as_borrowed()?),
+            Self::DataSm(value) => borrowed::Pdu::DataSm(value.as_borrowed()?),
+            Self::DataSmResp(value) => borrowed::Pdu::DataSmResp(value.as_borrowed()?),
+            Self::CancelSm(value) => borrowed::Pdu::CancelSm(value.as_borrowed()),
+            Self::ReplaceSm(value) => borrowed::Pdu::ReplaceSm(value.as_borrowed()),
+            Self::SubmitMulti(value) => borrowed::Pdu::SubmitMulti(value.as_borrowed()?),
+            Self::SubmitMultiResp(value) => borrowed::Pdu::SubmitMultiResp(value.as_borrowed()?),
+            Self::BroadcastSm(value) => borrowed::Pdu::BroadcastSm(value.as_borrowed()?),
+            Self::BroadcastSmResp(value) => borrowed::Pdu::BroadcastSmResp(value.as_borrowed()?),
+            Self::QueryBroadcastSm(value) => borrowed::Pdu::QueryBroadcastSm(value.as_borrowed()),
+            Self::QueryBroadcastSmResp(value) => {
+                borrowed::Pdu::QueryBroadcastSmResp(value.as_borrowed()?)
+            }
+            Self::CancelBroadcastSm(value) => {
+                borrowed::Pdu::CancelBroadcastSm(value.as_borrowed()?)
+            }
+            Self::Unbind => borrowed::Pdu::Unbind,
+            Self::UnbindResp => borrowed::Pdu::UnbindResp,
+            Self::EnquireLink => borrowed::Pdu::EnquireLink,
+            Self::EnquireLinkResp => borrowed::Pdu::EnquireLinkResp,
+            Self::GenericNack => borrowed::Pdu::GenericNack,
+            Self::CancelSmResp => borrowed::Pdu::CancelSmResp,
+            Self::ReplaceSmResp => borrowed::Pdu::ReplaceSmResp,
+            Self::CancelBroadcastSmResp => borrowed::Pdu::CancelBroadcastSmResp,
+            Self::Other { command_id, body } => borrowed::Pdu::Other {
+                command_id: *command_id,
+                body: body.as_borrowed(),
+            },
+        })
+    }
+}
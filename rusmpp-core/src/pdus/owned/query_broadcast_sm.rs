@@ -1,10 +1,17 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::owned::{Tlv, TlvValue},
     types::owned::COctetString,
-    values::*,
+    values::{owned::SmppAddress, *},
 };
 
 /// This command is issued by the ESME to query the status of a previously submitted
@@ -21,10 +28,16 @@ use crate::{
 /// recently submitted message with the specified user_message_reference value will be
 /// returned in the query_broadcast_sm_resp.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct QueryBroadcastSm {
     /// Message ID of the message to be queried. This must be
     /// the MC assigned Message ID allocated to the original
@@ -105,9 +118,42 @@ impl From<QueryBroadcastSm> for Pdu {
     }
 }
 
+impl From<borrowed::QueryBroadcastSm<'_>> for QueryBroadcastSm {
+    fn from(value: borrowed::QueryBroadcastSm<'_>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            user_message_reference: value.user_message_reference.map(Into::into),
+        }
+    }
+}
+
+impl borrowed::QueryBroadcastSm<'_> {
+    /// Converts this borrowed [`QueryBroadcastSm`](borrowed::QueryBroadcastSm) into an owned [`QueryBroadcastSm`].
+    pub fn into_owned(self) -> QueryBroadcastSm {
+        self.into()
+    }
+}
+
+impl QueryBroadcastSm {
+    /// Returns a borrowed [`QueryBroadcastSm`](borrowed::QueryBroadcastSm) view of this [`QueryBroadcastSm`].
+    pub fn as_borrowed(&self) -> borrowed::QueryBroadcastSm<'_> {
+        borrowed::QueryBroadcastSm {
+            message_id: self.message_id.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            user_message_reference: self.user_message_reference.as_ref().map(Tlv::as_borrowed),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QueryBroadcastSmBuilder {
     inner: QueryBroadcastSm,
+    error: Option<BuildError>,
 }
 
 impl QueryBroadcastSmBuilder {
@@ -115,8 +161,13 @@ impl QueryBroadcastSmBuilder {
         Self::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -130,8 +181,23 @@ impl QueryBroadcastSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`QueryBroadcastSm::source_addr_ton`], [`QueryBroadcastSm::source_addr_npi`] and [`QueryBroadcastSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
@@ -144,9 +210,20 @@ impl QueryBroadcastSmBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> QueryBroadcastSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<QueryBroadcastSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
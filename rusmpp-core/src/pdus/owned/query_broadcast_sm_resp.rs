@@ -1,16 +1,30 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
-    tlvs::owned::{QueryBroadcastResponseTlvValue, Tlv},
+    decode::DecodeError,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    tlvs::owned::{QueryBroadcastResponseTlvValue, Tlv, VendorTlvValue, vendor_value},
     types::owned::COctetString,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct QueryBroadcastSmResp {
     /// Message ID of the queried message. This must be the MC
     /// assigned Message ID allocated to the original short message
@@ -19,7 +33,7 @@ pub struct QueryBroadcastSmResp {
     pub message_id: COctetString<1, 65>,
     /// Query broadcast response TLVs ([`QueryBroadcastResponseTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl QueryBroadcastSmResp {
@@ -36,6 +50,13 @@ impl QueryBroadcastSmResp {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<QueryBroadcastResponseTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
     }
@@ -59,9 +80,40 @@ impl From<QueryBroadcastSmResp> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::QueryBroadcastSmResp<'_, N>> for QueryBroadcastSmResp {
+    fn from(value: borrowed::QueryBroadcastSmResp<'_, N>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::QueryBroadcastSmResp<'_, N> {
+    /// Converts this borrowed [`QueryBroadcastSmResp`](borrowed::QueryBroadcastSmResp) into an owned [`QueryBroadcastSmResp`].
+    pub fn into_owned(self) -> QueryBroadcastSmResp {
+        self.into()
+    }
+}
+
+impl QueryBroadcastSmResp {
+    /// Returns a borrowed [`QueryBroadcastSmResp`](borrowed::QueryBroadcastSmResp) view of this [`QueryBroadcastSmResp`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::QueryBroadcastSmResp<'_, N>, AsBorrowedError> {
+        Ok(borrowed::QueryBroadcastSmResp {
+            message_id: self.message_id.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QueryBroadcastSmRespBuilder {
     inner: QueryBroadcastSmResp,
+    error: Option<BuildError>,
 }
 
 impl QueryBroadcastSmRespBuilder {
@@ -69,8 +121,13 @@ impl QueryBroadcastSmRespBuilder {
         Self::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -92,9 +149,20 @@ impl QueryBroadcastSmRespBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> QueryBroadcastSmResp {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<QueryBroadcastSmResp, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,17 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{pdus::owned::Pdu, types::owned::COctetString, values::*};
+use crate::{
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    types::owned::COctetString,
+    values::{owned::SmppAddress, *},
+};
 
 /// This command is issued by the ESME to query the status of a previously submitted short
 /// message.
@@ -11,7 +22,10 @@ use crate::{pdus::owned::Pdu, types::owned::COctetString, values::*};
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct QuerySm {
     /// Message ID of the message whose state
     /// is to be queried. This must be the MC
@@ -72,9 +86,40 @@ impl From<QuerySm> for Pdu {
     }
 }
 
+impl From<borrowed::QuerySm<'_>> for QuerySm {
+    fn from(value: borrowed::QuerySm<'_>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+        }
+    }
+}
+
+impl borrowed::QuerySm<'_> {
+    /// Converts this borrowed [`QuerySm`](borrowed::QuerySm) into an owned [`QuerySm`].
+    pub fn into_owned(self) -> QuerySm {
+        self.into()
+    }
+}
+
+impl QuerySm {
+    /// Returns a borrowed [`QuerySm`](borrowed::QuerySm) view of this [`QuerySm`].
+    pub fn as_borrowed(&self) -> borrowed::QuerySm<'_> {
+        borrowed::QuerySm {
+            message_id: self.message_id.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QuerySmBuilder {
     inner: QuerySm,
+    error: Option<BuildError>,
 }
 
 impl QuerySmBuilder {
@@ -82,8 +127,13 @@ impl QuerySmBuilder {
         Default::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -97,14 +147,40 @@ impl QuerySmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
         self
     }
 
+    /// Sets the [`QuerySm::source_addr_ton`], [`QuerySm::source_addr_npi`] and [`QuerySm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
+        self
+    }
+
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> QuerySm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<QuerySm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,14 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     types::owned::{COctetString, EmptyOrFullCOctetString},
     values::*,
 };
@@ -10,7 +17,10 @@ use crate::{
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct QuerySmResp {
     /// MC Message ID of the message whose
     /// state is being queried.
@@ -52,6 +62,13 @@ impl QuerySmResp {
     pub fn builder() -> QuerySmRespBuilder {
         QuerySmRespBuilder::new()
     }
+
+    /// Parses the [`QuerySmResp::final_date`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the final date is not set.
+    pub fn parse_final_date(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.final_date.to_smpp_time()
+    }
 }
 
 impl From<QuerySmResp> for Pdu {
@@ -60,9 +77,40 @@ impl From<QuerySmResp> for Pdu {
     }
 }
 
+impl From<borrowed::QuerySmResp<'_>> for QuerySmResp {
+    fn from(value: borrowed::QuerySmResp<'_>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            final_date: value.final_date.into(),
+            message_state: value.message_state,
+            error_code: value.error_code,
+        }
+    }
+}
+
+impl borrowed::QuerySmResp<'_> {
+    /// Converts this borrowed [`QuerySmResp`](borrowed::QuerySmResp) into an owned [`QuerySmResp`].
+    pub fn into_owned(self) -> QuerySmResp {
+        self.into()
+    }
+}
+
+impl QuerySmResp {
+    /// Returns a borrowed [`QuerySmResp`](borrowed::QuerySmResp) view of this [`QuerySmResp`].
+    pub fn as_borrowed(&self) -> borrowed::QuerySmResp<'_> {
+        borrowed::QuerySmResp {
+            message_id: self.message_id.as_borrowed(),
+            final_date: self.final_date.as_borrowed(),
+            message_state: self.message_state,
+            error_code: self.error_code,
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QuerySmRespBuilder {
     inner: QuerySmResp,
+    error: Option<BuildError>,
 }
 
 impl QuerySmRespBuilder {
@@ -70,13 +118,26 @@ impl QuerySmRespBuilder {
         Self::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
-    pub fn final_date(mut self, final_date: EmptyOrFullCOctetString<17>) -> Self {
-        self.inner.final_date = final_date;
+    pub fn final_date(
+        mut self,
+        final_date: impl TryIntoField<EmptyOrFullCOctetString<17>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.final_date,
+            SmppField::final_date,
+            final_date,
+        );
         self
     }
 
@@ -90,9 +151,20 @@ impl QuerySmRespBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> QuerySmResp {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<QuerySmResp, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
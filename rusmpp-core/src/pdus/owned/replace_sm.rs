@@ -2,7 +2,14 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     encode::Length,
-    pdus::owned::Pdu,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            Pdu,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::owned::{Tlv, TlvValue},
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
     values::{owned::*, *},
@@ -15,10 +22,16 @@ use crate::{
 /// Where the original submit_sm ‘source address’ was defaulted to NULL, then the source
 /// address in the replace_sm command should also be NULL.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct ReplaceSm {
     /// Message ID of the message to be replaced.
     /// This must be the MC assigned Message ID
@@ -175,6 +188,20 @@ impl ReplaceSm {
     pub fn builder() -> ReplaceSmBuilder {
         ReplaceSmBuilder::new()
     }
+
+    /// Parses the [`ReplaceSm::schedule_delivery_time`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the schedule delivery time is not set.
+    pub fn parse_schedule_delivery_time(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.schedule_delivery_time.to_smpp_time()
+    }
+
+    /// Parses the [`ReplaceSm::validity_period`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the validity period is not set.
+    pub fn parse_validity_period(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.validity_period.to_smpp_time()
+    }
 }
 
 impl From<ReplaceSm> for Pdu {
@@ -183,9 +210,54 @@ impl From<ReplaceSm> for Pdu {
     }
 }
 
+impl From<borrowed::ReplaceSm<'_>> for ReplaceSm {
+    fn from(value: borrowed::ReplaceSm<'_>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            schedule_delivery_time: value.schedule_delivery_time.into(),
+            validity_period: value.validity_period.into(),
+            registered_delivery: value.registered_delivery,
+            sm_default_msg_id: value.sm_default_msg_id,
+            sm_length: value.sm_length,
+            short_message: value.short_message.into(),
+            message_payload: value.message_payload.map(Into::into),
+        }
+    }
+}
+
+impl borrowed::ReplaceSm<'_> {
+    /// Converts this borrowed [`ReplaceSm`](borrowed::ReplaceSm) into an owned [`ReplaceSm`].
+    pub fn into_owned(self) -> ReplaceSm {
+        self.into()
+    }
+}
+
+impl ReplaceSm {
+    /// Returns a borrowed [`ReplaceSm`](borrowed::ReplaceSm) view of this [`ReplaceSm`].
+    pub fn as_borrowed(&self) -> borrowed::ReplaceSm<'_> {
+        borrowed::ReplaceSm {
+            message_id: self.message_id.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            schedule_delivery_time: self.schedule_delivery_time.as_borrowed(),
+            validity_period: self.validity_period.as_borrowed(),
+            registered_delivery: self.registered_delivery,
+            sm_default_msg_id: self.sm_default_msg_id,
+            sm_length: self.sm_length,
+            short_message: self.short_message.as_borrowed(),
+            message_payload: self.message_payload.as_ref().map(Tlv::as_borrowed),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ReplaceSmBuilder {
     inner: ReplaceSm,
+    error: Option<BuildError>,
 }
 
 impl ReplaceSmBuilder {
@@ -193,8 +265,13 @@ impl ReplaceSmBuilder {
         Self::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -208,21 +285,49 @@ impl ReplaceSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`ReplaceSm::source_addr_ton`], [`ReplaceSm::source_addr_npi`] and [`ReplaceSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
     pub fn schedule_delivery_time(
         mut self,
-        schedule_delivery_time: EmptyOrFullCOctetString<17>,
+        schedule_delivery_time: impl TryIntoField<EmptyOrFullCOctetString<17>>,
     ) -> Self {
-        self.inner.schedule_delivery_time = schedule_delivery_time;
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.schedule_delivery_time,
+            SmppField::schedule_delivery_time,
+            schedule_delivery_time,
+        );
         self
     }
 
-    pub fn validity_period(mut self, validity_period: EmptyOrFullCOctetString<17>) -> Self {
-        self.inner.validity_period = validity_period;
+    pub fn validity_period(
+        mut self,
+        validity_period: impl TryIntoField<EmptyOrFullCOctetString<17>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.validity_period,
+            SmppField::validity_period,
+            validity_period,
+        );
         self
     }
 
@@ -236,19 +341,46 @@ impl ReplaceSmBuilder {
         self
     }
 
-    pub fn short_message(mut self, short_message: OctetString<0, 255>) -> Self {
-        self.inner.set_short_message(short_message);
+    pub fn short_message(mut self, short_message: impl TryIntoField<OctetString<0, 255>>) -> Self {
+        if let Some(short_message) =
+            try_build::convert(&mut self.error, SmppField::short_message, short_message)
+        {
+            let discarded =
+                !short_message.is_empty() && !self.inner.set_short_message(short_message);
+
+            try_build::record_discarded_short_message(&mut self.error, discarded);
+        }
+
         self
     }
 
     pub fn message_payload(mut self, message_payload: Option<MessagePayload>) -> Self {
+        let had_short_message = !self.inner.short_message().is_empty();
+
         self.inner.set_message_payload(message_payload);
+
+        try_build::record_discarded_short_message(
+            &mut self.error,
+            had_short_message && self.inner.short_message().is_empty(),
+        );
+
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> ReplaceSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<ReplaceSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -1,18 +1,32 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
-    tlvs::owned::{MessageDeliveryResponseTlvValue, Tlv},
+    decode::DecodeError,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    tlvs::owned::{MessageDeliveryResponseTlvValue, Tlv, VendorTlvValue, vendor_value},
     types::owned::COctetString,
 };
 
 macro_rules! sm_resp {
     ($name:ident) => {
         #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-        #[rusmpp(decode = owned, test = skip)]
+        #[rusmpp(decode = owned, test = skip, deserialize = checked)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-        #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+        #[cfg_attr(
+            all(
+                feature = "serde-deserialize-unchecked",
+                not(feature = "serde-deserialize")
+            ),
+            derive(::serde::Deserialize)
+        )]
         pub struct $name {
             /// This field contains the MC message ID of the submitted message.
             /// It may be used at a later stage to query the status of a message,
@@ -20,7 +34,7 @@ macro_rules! sm_resp {
             message_id: COctetString<1, 65>,
             /// Message delivery response TLVs ([`MessageDeliveryResponseTlvValue`])
             #[rusmpp(length = "unchecked")]
-            tlvs: alloc::vec::Vec<Tlv>,
+            pub(crate) tlvs: alloc::vec::Vec<Tlv>,
         }
 
         impl $name {
@@ -41,6 +55,13 @@ macro_rules! sm_resp {
                 &self.tlvs
             }
 
+            /// Returns the first vendor-specific TLV value `T`.
+            ///
+            /// See [`VendorTlvValue`].
+            pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+                vendor_value(&self.tlvs)
+            }
+
             pub fn set_tlvs(
                 &mut self,
                 tlvs: alloc::vec::Vec<impl Into<MessageDeliveryResponseTlvValue>>,
@@ -63,6 +84,7 @@ macro_rules! sm_resp {
             #[derive(Debug, Default)]
             pub struct [<$name Builder>] {
                inner: $name,
+               error: Option<BuildError>,
             }
 
             impl [<$name Builder>] {
@@ -70,8 +92,16 @@ macro_rules! sm_resp {
                     Self::default()
                 }
 
-                pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-                    self.inner.message_id = message_id;
+                pub fn message_id(
+                    mut self,
+                    message_id: impl TryIntoField<COctetString<1, 65>>,
+                ) -> Self {
+                    try_build::set(
+                        &mut self.error,
+                        &mut self.inner.message_id,
+                        SmppField::message_id,
+                        message_id,
+                    );
                     self
                 }
 
@@ -88,9 +118,20 @@ macro_rules! sm_resp {
                     self
                 }
 
+                /// Builds the PDU.
+                ///
+                /// Values that could not be converted are ignored, see [`Self::try_build`].
                 pub fn build(self) -> $name {
                     self.inner
                 }
+
+                /// Builds the PDU, returning the first value that could not be converted
+                /// or the first violated semantic rule of the PDU.
+                ///
+                /// See [`Validate`](crate::validate::Validate).
+                pub fn try_build(self) -> Result<$name, BuildError> {
+                    try_build::try_build(self.error, self.inner)
+                }
             }
         }
     };
@@ -105,12 +146,72 @@ impl From<DeliverSmResp> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::DeliverSmResp<'_, N>> for DeliverSmResp {
+    fn from(value: borrowed::DeliverSmResp<'_, N>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::DeliverSmResp<'_, N> {
+    /// Converts this borrowed [`DeliverSmResp`](borrowed::DeliverSmResp) into an owned [`DeliverSmResp`].
+    pub fn into_owned(self) -> DeliverSmResp {
+        self.into()
+    }
+}
+
+impl DeliverSmResp {
+    /// Returns a borrowed [`DeliverSmResp`](borrowed::DeliverSmResp) view of this [`DeliverSmResp`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::DeliverSmResp<'_, N>, AsBorrowedError> {
+        Ok(borrowed::DeliverSmResp {
+            message_id: self.message_id.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 impl From<DataSmResp> for Pdu {
     fn from(value: DataSmResp) -> Self {
         Self::DataSmResp(value)
     }
 }
 
+impl<const N: usize> From<borrowed::DataSmResp<'_, N>> for DataSmResp {
+    fn from(value: borrowed::DataSmResp<'_, N>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::DataSmResp<'_, N> {
+    /// Converts this borrowed [`DataSmResp`](borrowed::DataSmResp) into an owned [`DataSmResp`].
+    pub fn into_owned(self) -> DataSmResp {
+        self.into()
+    }
+}
+
+impl DataSmResp {
+    /// Returns a borrowed [`DataSmResp`](borrowed::DataSmResp) view of this [`DataSmResp`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::DataSmResp<'_, N>, AsBorrowedError> {
+        Ok(borrowed::DataSmResp {
+            message_id: self.message_id.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -1,21 +1,36 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
+    decode::DecodeError,
     encode::Length,
-    pdus::owned::Pdu,
+    encoding::text::TextEncoder,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::{
         TlvTag,
-        owned::{MessageSubmissionRequestTlvValue, Tlv},
+        owned::{MessageSubmissionRequestTlvValue, Tlv, VendorTlvValue, vendor_value},
     },
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
     values::{owned::*, *},
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct SubmitMulti {
     /// The service_type parameter can be used to indicate the
     /// SMS Application service associated with the message.
@@ -97,7 +112,7 @@ pub struct SubmitMulti {
     short_message: OctetString<0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitMulti {
@@ -197,6 +212,13 @@ impl SubmitMulti {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
@@ -234,6 +256,20 @@ impl SubmitMulti {
     pub fn builder() -> SubmitMultiBuilder {
         SubmitMultiBuilder::new()
     }
+
+    /// Parses the [`SubmitMulti::schedule_delivery_time`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the schedule delivery time is not set.
+    pub fn parse_schedule_delivery_time(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.schedule_delivery_time.to_smpp_time()
+    }
+
+    /// Parses the [`SubmitMulti::validity_period`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the validity period is not set.
+    pub fn parse_validity_period(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.validity_period.to_smpp_time()
+    }
 }
 
 impl From<SubmitMulti> for Pdu {
@@ -242,9 +278,76 @@ impl From<SubmitMulti> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::SubmitMulti<'_, N>> for SubmitMulti {
+    fn from(value: borrowed::SubmitMulti<'_, N>) -> Self {
+        Self {
+            service_type: value.service_type.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            number_of_dests: value.number_of_dests,
+            dest_address: value.dest_address.into_iter().map(Into::into).collect(),
+            esm_class: value.esm_class,
+            protocol_id: value.protocol_id,
+            priority_flag: value.priority_flag,
+            schedule_delivery_time: value.schedule_delivery_time.into(),
+            validity_period: value.validity_period.into(),
+            registered_delivery: value.registered_delivery,
+            replace_if_present_flag: value.replace_if_present_flag,
+            data_coding: value.data_coding,
+            sm_default_msg_id: value.sm_default_msg_id,
+            sm_length: value.sm_length,
+            short_message: value.short_message.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::SubmitMulti<'_, N> {
+    /// Converts this borrowed [`SubmitMulti`](borrowed::SubmitMulti) into an owned [`SubmitMulti`].
+    pub fn into_owned(self) -> SubmitMulti {
+        self.into()
+    }
+}
+
+impl SubmitMulti {
+    /// Returns a borrowed [`SubmitMulti`](borrowed::SubmitMulti) view of this [`SubmitMulti`].
+    ///
+    /// Fails if the destination addresses or the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::SubmitMulti<'_, N>, AsBorrowedError> {
+        Ok(borrowed::SubmitMulti {
+            service_type: self.service_type.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            number_of_dests: self.number_of_dests,
+            dest_address: heapless_vec(
+                &self.dest_address,
+                SmppField::dest_address,
+                DestAddress::as_borrowed,
+            )?,
+            esm_class: self.esm_class,
+            protocol_id: self.protocol_id,
+            priority_flag: self.priority_flag,
+            schedule_delivery_time: self.schedule_delivery_time.as_borrowed(),
+            validity_period: self.validity_period.as_borrowed(),
+            registered_delivery: self.registered_delivery,
+            replace_if_present_flag: self.replace_if_present_flag,
+            data_coding: self.data_coding,
+            sm_default_msg_id: self.sm_default_msg_id,
+            sm_length: self.sm_length,
+            short_message: self.short_message.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SubmitMultiBuilder {
     inner: SubmitMulti,
+    error: Option<BuildError>,
 }
 
 impl SubmitMultiBuilder {
@@ -252,8 +355,32 @@ impl SubmitMultiBuilder {
         Default::default()
     }
 
-    pub fn service_type(mut self, service_type: ServiceType) -> Self {
-        self.inner.service_type = service_type;
+    /// Sets the short message, recording a conflict if the message payload discards it.
+    fn set_short_message(&mut self, short_message: OctetString<0, 255>) {
+        let discarded = !short_message.is_empty() && !self.inner.set_short_message(short_message);
+
+        try_build::record_discarded_short_message(&mut self.error, discarded);
+    }
+
+    /// Updates the TLVs, recording a conflict if the message payload discards the short message.
+    fn update_tlvs(&mut self, update: impl FnOnce(&mut SubmitMulti)) {
+        let had_short_message = !self.inner.short_message().is_empty();
+
+        update(&mut self.inner);
+
+        try_build::record_discarded_short_message(
+            &mut self.error,
+            had_short_message && self.inner.short_message().is_empty(),
+        );
+    }
+
+    pub fn service_type(mut self, service_type: impl TryIntoField<ServiceType>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.service_type,
+            SmppField::service_type,
+            service_type,
+        );
         self
     }
 
@@ -267,8 +394,23 @@ impl SubmitMultiBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`SubmitMulti::source_addr_ton`], [`SubmitMulti::source_addr_npi`] and [`SubmitMulti::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
@@ -304,14 +446,27 @@ impl SubmitMultiBuilder {
 
     pub fn schedule_delivery_time(
         mut self,
-        schedule_delivery_time: EmptyOrFullCOctetString<17>,
+        schedule_delivery_time: impl TryIntoField<EmptyOrFullCOctetString<17>>,
     ) -> Self {
-        self.inner.schedule_delivery_time = schedule_delivery_time;
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.schedule_delivery_time,
+            SmppField::schedule_delivery_time,
+            schedule_delivery_time,
+        );
         self
     }
 
-    pub fn validity_period(mut self, validity_period: EmptyOrFullCOctetString<17>) -> Self {
-        self.inner.validity_period = validity_period;
+    pub fn validity_period(
+        mut self,
+        validity_period: impl TryIntoField<EmptyOrFullCOctetString<17>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.validity_period,
+            SmppField::validity_period,
+            validity_period,
+        );
         self
     }
 
@@ -338,8 +493,45 @@ impl SubmitMultiBuilder {
         self
     }
 
-    pub fn short_message(mut self, short_message: OctetString<0, 255>) -> Self {
-        self.inner.set_short_message(short_message);
+    pub fn short_message(mut self, short_message: impl TryIntoField<OctetString<0, 255>>) -> Self {
+        if let Some(short_message) =
+            try_build::convert(&mut self.error, SmppField::short_message, short_message)
+        {
+            self.set_short_message(short_message);
+        }
+
+        self
+    }
+
+    /// Encodes `text` using the default [`TextEncoder`].
+    ///
+    /// See [`SubmitMultiBuilder::text_with`].
+    pub fn text(self, text: &str) -> Self {
+        self.text_with(text, &TextEncoder::new())
+    }
+
+    /// Encodes `text` using `encoder` and sets the [`SubmitMulti::data_coding`] and the [`SubmitMulti::short_message`].
+    ///
+    /// If the encoded text starts with a UDH, the UDH Indicator is set in the [`SubmitMulti::esm_class`].
+    /// Call this method after setting the [`SubmitMulti::esm_class`].
+    ///
+    /// Records an error if the encoded text exceeds `255` octets, see [`Self::try_build`].
+    pub fn text_with(mut self, text: &str, encoder: &TextEncoder<'_>) -> Self {
+        let encoded = encoder.encode(text);
+
+        let Some(short_message) =
+            try_build::convert(&mut self.error, SmppField::short_message, encoded.bytes())
+        else {
+            return self;
+        };
+
+        if encoded.has_udh() {
+            self.inner.esm_class = self.inner.esm_class.with_udhi_indicator();
+        }
+
+        self.inner.data_coding = encoded.data_coding();
+        self.set_short_message(short_message);
+
         self
     }
 
@@ -347,7 +539,7 @@ impl SubmitMultiBuilder {
         mut self,
         tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>,
     ) -> Self {
-        self.inner.set_tlvs(tlvs);
+        self.update_tlvs(|pdu| pdu.set_tlvs(tlvs));
         self
     }
 
@@ -357,13 +549,43 @@ impl SubmitMultiBuilder {
     }
 
     pub fn push_tlv(mut self, tlv: impl Into<MessageSubmissionRequestTlvValue>) -> Self {
-        self.inner.push_tlv(tlv);
+        self.update_tlvs(|pdu| pdu.push_tlv(tlv));
         self
     }
 
+    /// Pushes a [`CallbackNum`](MessageSubmissionRequestTlvValue::CallbackNum) TLV encoding `callback_num`.
+    ///
+    /// Records an error if the address can not be encoded, see [`SmppAddress::to_callback_num`] and [`Self::try_build`].
+    pub fn callback_num(mut self, callback_num: &SmppAddress) -> Self {
+        match callback_num.to_callback_num() {
+            Ok(callback_num) => {
+                self.push_tlv(MessageSubmissionRequestTlvValue::CallbackNum(callback_num))
+            }
+            Err(error) => {
+                try_build::record(
+                    &mut self.error,
+                    BuildError::new(SmppField::callback_num, error.into()),
+                );
+
+                self
+            }
+        }
+    }
+
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> SubmitMulti {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<SubmitMulti, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
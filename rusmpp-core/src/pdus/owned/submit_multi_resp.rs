@@ -1,17 +1,31 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
-    tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv},
+    decode::DecodeError,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
+    tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv, VendorTlvValue, vendor_value},
     types::owned::COctetString,
     values::owned::*,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct SubmitMultiResp {
     /// This field contains the MC message ID of the submitted
     /// message. It may be used at a later stage to query the status
@@ -29,7 +43,7 @@ pub struct SubmitMultiResp {
     unsuccess_sme: alloc::vec::Vec<UnsuccessSme>,
     /// Message submission response TLVs ([`MessageSubmissionResponseTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitMultiResp {
@@ -77,6 +91,13 @@ impl SubmitMultiResp {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(
         &mut self,
         tlvs: alloc::vec::Vec<impl Into<MessageSubmissionResponseTlvValue>>,
@@ -103,9 +124,48 @@ impl From<SubmitMultiResp> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::SubmitMultiResp<'_, N>> for SubmitMultiResp {
+    fn from(value: borrowed::SubmitMultiResp<'_, N>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            no_unsuccess: value.no_unsuccess,
+            unsuccess_sme: value.unsuccess_sme.into_iter().map(Into::into).collect(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::SubmitMultiResp<'_, N> {
+    /// Converts this borrowed [`SubmitMultiResp`](borrowed::SubmitMultiResp) into an owned [`SubmitMultiResp`].
+    pub fn into_owned(self) -> SubmitMultiResp {
+        self.into()
+    }
+}
+
+impl SubmitMultiResp {
+    /// Returns a borrowed [`SubmitMultiResp`](borrowed::SubmitMultiResp) view of this [`SubmitMultiResp`].
+    ///
+    /// Fails if the unsuccessful SMEs or the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::SubmitMultiResp<'_, N>, AsBorrowedError> {
+        Ok(borrowed::SubmitMultiResp {
+            message_id: self.message_id.as_borrowed(),
+            no_unsuccess: self.no_unsuccess,
+            unsuccess_sme: heapless_vec(
+                &self.unsuccess_sme,
+                SmppField::unsuccess_sme,
+                UnsuccessSme::as_borrowed,
+            )?,
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SubmitMultiRespBuilder {
     inner: SubmitMultiResp,
+    error: Option<BuildError>,
 }
 
 impl SubmitMultiRespBuilder {
@@ -113,8 +173,13 @@ impl SubmitMultiRespBuilder {
         Self::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -151,9 +216,20 @@ impl SubmitMultiRespBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> SubmitMultiResp {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<SubmitMultiResp, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -1,23 +1,42 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
+    decode::{DecodeError, owned::Decode},
     encode::Length,
-    pdus::owned::Pdu,
+    encoding::text::TextEncoder,
+    fields::SmppField,
+    multipart::{Sar, SplitError, SplitMessage, Splitter},
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::{
         TlvTag,
-        owned::{MessageSubmissionRequestTlvValue, Tlv},
+        owned::{
+            MessageSubmissionRequestTlvValue, Tlv, VendorTlvValue, message_payload, vendor_value,
+        },
     },
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    udhs::{errors::ConcatenatedShortMessageError, owned::UserDataHeader},
     values::{owned::*, *},
 };
 
 /// This operation is used by an ESME to submit a short message to the MC for onward
 /// transmission to a specified short message entity (SME).
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct SubmitSm {
     /// The service_type parameter can be used to
     /// indicate the SMS Application service
@@ -86,7 +105,7 @@ pub struct SubmitSm {
     short_message: OctetString<0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitSm {
@@ -191,6 +210,13 @@ impl SubmitSm {
         &self.tlvs
     }
 
+    /// Returns the first vendor-specific TLV value `T`.
+    ///
+    /// See [`VendorTlvValue`].
+    pub fn vendor_tlv<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        vendor_value(&self.tlvs)
+    }
+
     pub fn set_tlvs(&mut self, tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>) {
         self.tlvs = tlvs.into_iter().map(Into::into).map(From::from).collect();
 
@@ -225,10 +251,125 @@ impl SubmitSm {
         false
     }
 
+    /// Encodes `text` and splits it into concatenated parts using `self` as a template.
+    ///
+    /// Every part is a clone of `self` with the [`SubmitSm::data_coding`] and the [`SubmitSm::short_message`] set.
+    /// The UDH Indicator of the [`SubmitSm::esm_class`] is set if the parts start with a UDH and cleared otherwise.
+    /// In [SAR](crate::multipart::Concatenation::Sar) mode, the SAR TLVs are pushed to every part.
+    /// The message payload and SAR TLVs of the template are discarded.
+    ///
+    /// See [`Splitter::split_text`].
+    pub fn split_text(
+        &self,
+        text: &str,
+        splitter: &Splitter<'_>,
+    ) -> Result<alloc::vec::Vec<SubmitSm>, SplitError> {
+        splitter
+            .split_text(text)
+            .map(|message| self.parts_from_split_message(message))
+    }
+
+    /// Splits the encoded `bytes` into concatenated parts using `self` as a template.
+    ///
+    /// See [`SubmitSm::split_text`] and [`Splitter::split_bytes`].
+    pub fn split_bytes(
+        &self,
+        bytes: &[u8],
+        data_coding: DataCoding,
+        splitter: &Splitter<'_>,
+    ) -> Result<alloc::vec::Vec<SubmitSm>, SplitError> {
+        splitter
+            .split_bytes(bytes, data_coding)
+            .map(|message| self.parts_from_split_message(message))
+    }
+
+    fn parts_from_split_message(&self, message: SplitMessage) -> alloc::vec::Vec<SubmitSm> {
+        let data_coding = message.data_coding();
+        let has_udh = message.has_udh();
+        let sars = (0..message.parts().len())
+            .map(|index| message.sar(index))
+            .collect::<alloc::vec::Vec<_>>();
+
+        let mut template = self.clone().with_data_coding(data_coding);
+
+        template.esm_class = if has_udh {
+            template.esm_class.with_udhi_indicator()
+        } else {
+            template.esm_class.without_udhi_indicator()
+        };
+
+        // Every part carries its own short message and SAR TLVs.
+        template.tlvs.retain(|tlv| {
+            !matches!(
+                tlv.tag(),
+                TlvTag::MessagePayload
+                    | TlvTag::SarMsgRefNum
+                    | TlvTag::SarTotalSegments
+                    | TlvTag::SarSegmentSeqnum
+            )
+        });
+
+        message
+            .into_short_messages()
+            .into_iter()
+            .zip(sars)
+            .map(|(short_message, sar)| {
+                let mut submit_sm = template.clone();
+
+                if let Some(sar) = sar {
+                    for tlv in sar.message_submission_request_tlvs() {
+                        submit_sm.push_tlv(tlv);
+                    }
+                }
+
+                submit_sm.with_short_message(
+                    OctetString::new(short_message).expect("Parts do not exceed 255 octets"),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the [`Sar`] read from the SAR TLVs.
+    ///
+    /// See [`Sar::from_tlvs`].
+    pub fn sar(&self) -> Option<Result<Sar, ConcatenatedShortMessageError>> {
+        Sar::from_tlvs(&self.tlvs)
+    }
+
+    /// Returns the [`UserDataHeader`] at the start of the short_message, or of the message_payload TLV if the short_message is empty.
+    ///
+    /// Returns `None` if the UDH Indicator is not set in the [`SubmitSm::esm_class`].
+    pub fn user_data_header(&self) -> Option<Result<UserDataHeader, DecodeError>> {
+        if !self.esm_class.has_udhi_indicator() {
+            return None;
+        }
+
+        let user_data = match self.short_message.bytes() {
+            [] => message_payload(&self.tlvs),
+            short_message => short_message,
+        };
+
+        Some(UserDataHeader::decode(user_data).map(|(udh, _)| udh))
+    }
+
     pub fn builder() -> SubmitSmBuilder {
         SubmitSmBuilder::new()
     }
 
+    /// Parses the [`SubmitSm::schedule_delivery_time`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the schedule delivery time is not set.
+    pub fn parse_schedule_delivery_time(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.schedule_delivery_time.to_smpp_time()
+    }
+
+    /// Parses the [`SubmitSm::validity_period`] as an [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the validity period is not set.
+    pub fn parse_validity_period(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        self.validity_period.to_smpp_time()
+    }
+
     /// Sets the [`SubmitSm::data_coding`].
     pub fn with_data_coding(mut self, data_coding: DataCoding) -> Self {
         self.data_coding = data_coding;
@@ -256,9 +397,74 @@ impl From<SubmitSm> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::SubmitSm<'_, N>> for SubmitSm {
+    fn from(value: borrowed::SubmitSm<'_, N>) -> Self {
+        Self {
+            service_type: value.service_type.into(),
+            source_addr_ton: value.source_addr_ton,
+            source_addr_npi: value.source_addr_npi,
+            source_addr: value.source_addr.into(),
+            dest_addr_ton: value.dest_addr_ton,
+            dest_addr_npi: value.dest_addr_npi,
+            destination_addr: value.destination_addr.into(),
+            esm_class: value.esm_class,
+            protocol_id: value.protocol_id,
+            priority_flag: value.priority_flag,
+            schedule_delivery_time: value.schedule_delivery_time.into(),
+            validity_period: value.validity_period.into(),
+            registered_delivery: value.registered_delivery,
+            replace_if_present_flag: value.replace_if_present_flag,
+            data_coding: value.data_coding,
+            sm_default_msg_id: value.sm_default_msg_id,
+            sm_length: value.sm_length,
+            short_message: value.short_message.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::SubmitSm<'_, N> {
+    /// Converts this borrowed [`SubmitSm`](borrowed::SubmitSm) into an owned [`SubmitSm`].
+    pub fn into_owned(self) -> SubmitSm {
+        self.into()
+    }
+}
+
+impl SubmitSm {
+    /// Returns a borrowed [`SubmitSm`](borrowed::SubmitSm) view of this [`SubmitSm`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::SubmitSm<'_, N>, AsBorrowedError> {
+        Ok(borrowed::SubmitSm {
+            service_type: self.service_type.as_borrowed(),
+            source_addr_ton: self.source_addr_ton,
+            source_addr_npi: self.source_addr_npi,
+            source_addr: self.source_addr.as_borrowed(),
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.as_borrowed(),
+            esm_class: self.esm_class,
+            protocol_id: self.protocol_id,
+            priority_flag: self.priority_flag,
+            schedule_delivery_time: self.schedule_delivery_time.as_borrowed(),
+            validity_period: self.validity_period.as_borrowed(),
+            registered_delivery: self.registered_delivery,
+            replace_if_present_flag: self.replace_if_present_flag,
+            data_coding: self.data_coding,
+            sm_default_msg_id: self.sm_default_msg_id,
+            sm_length: self.sm_length,
+            short_message: self.short_message.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct SubmitSmBuilder {
     inner: SubmitSm,
+    error: Option<BuildError>,
 }
 
 impl SubmitSmBuilder {
@@ -266,8 +472,32 @@ impl SubmitSmBuilder {
         Self::default()
     }
 
-    pub fn service_type(mut self, service_type: ServiceType) -> Self {
-        self.inner.service_type = service_type;
+    /// Sets the short message, recording a conflict if the message payload discards it.
+    fn set_short_message(&mut self, short_message: OctetString<0, 255>) {
+        let discarded = !short_message.is_empty() && !self.inner.set_short_message(short_message);
+
+        try_build::record_discarded_short_message(&mut self.error, discarded);
+    }
+
+    /// Updates the TLVs, recording a conflict if the message payload discards the short message.
+    fn update_tlvs(&mut self, update: impl FnOnce(&mut SubmitSm)) {
+        let had_short_message = !self.inner.short_message().is_empty();
+
+        update(&mut self.inner);
+
+        try_build::record_discarded_short_message(
+            &mut self.error,
+            had_short_message && self.inner.short_message().is_empty(),
+        );
+    }
+
+    pub fn service_type(mut self, service_type: impl TryIntoField<ServiceType>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.service_type,
+            SmppField::service_type,
+            service_type,
+        );
         self
     }
 
@@ -281,8 +511,23 @@ impl SubmitSmBuilder {
         self
     }
 
-    pub fn source_addr(mut self, source_addr: COctetString<1, 21>) -> Self {
-        self.inner.source_addr = source_addr;
+    pub fn source_addr(mut self, source_addr: impl TryIntoField<COctetString<1, 21>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.source_addr,
+            SmppField::source_addr,
+            source_addr,
+        );
+        self
+    }
+
+    /// Sets the [`SubmitSm::source_addr_ton`], [`SubmitSm::source_addr_npi`] and [`SubmitSm::source_addr`].
+    pub fn source(mut self, source: SmppAddress) -> Self {
+        let (ton, npi, addr) = source.into_parts();
+
+        self.inner.source_addr_ton = ton;
+        self.inner.source_addr_npi = npi;
+        self.inner.source_addr = addr;
         self
     }
 
@@ -296,8 +541,26 @@ impl SubmitSmBuilder {
         self
     }
 
-    pub fn destination_addr(mut self, destination_addr: COctetString<1, 21>) -> Self {
-        self.inner.destination_addr = destination_addr;
+    pub fn destination_addr(
+        mut self,
+        destination_addr: impl TryIntoField<COctetString<1, 21>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.destination_addr,
+            SmppField::destination_addr,
+            destination_addr,
+        );
+        self
+    }
+
+    /// Sets the [`SubmitSm::dest_addr_ton`], [`SubmitSm::dest_addr_npi`] and [`SubmitSm::destination_addr`].
+    pub fn destination(mut self, destination: SmppAddress) -> Self {
+        let (ton, npi, addr) = destination.into_parts();
+
+        self.inner.dest_addr_ton = ton;
+        self.inner.dest_addr_npi = npi;
+        self.inner.destination_addr = addr;
         self
     }
 
@@ -318,14 +581,27 @@ impl SubmitSmBuilder {
 
     pub fn schedule_delivery_time(
         mut self,
-        schedule_delivery_time: EmptyOrFullCOctetString<17>,
+        schedule_delivery_time: impl TryIntoField<EmptyOrFullCOctetString<17>>,
     ) -> Self {
-        self.inner.schedule_delivery_time = schedule_delivery_time;
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.schedule_delivery_time,
+            SmppField::schedule_delivery_time,
+            schedule_delivery_time,
+        );
         self
     }
 
-    pub fn validity_period(mut self, validity_period: EmptyOrFullCOctetString<17>) -> Self {
-        self.inner.validity_period = validity_period;
+    pub fn validity_period(
+        mut self,
+        validity_period: impl TryIntoField<EmptyOrFullCOctetString<17>>,
+    ) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.validity_period,
+            SmppField::validity_period,
+            validity_period,
+        );
         self
     }
 
@@ -352,8 +628,45 @@ impl SubmitSmBuilder {
         self
     }
 
-    pub fn short_message(mut self, short_message: OctetString<0, 255>) -> Self {
-        self.inner.set_short_message(short_message);
+    pub fn short_message(mut self, short_message: impl TryIntoField<OctetString<0, 255>>) -> Self {
+        if let Some(short_message) =
+            try_build::convert(&mut self.error, SmppField::short_message, short_message)
+        {
+            self.set_short_message(short_message);
+        }
+
+        self
+    }
+
+    /// Encodes `text` using the default [`TextEncoder`].
+    ///
+    /// See [`SubmitSmBuilder::text_with`].
+    pub fn text(self, text: &str) -> Self {
+        self.text_with(text, &TextEncoder::new())
+    }
+
+    /// Encodes `text` using `encoder` and sets the [`SubmitSm::data_coding`] and the [`SubmitSm::short_message`].
+    ///
+    /// If the encoded text starts with a UDH, the UDH Indicator is set in the [`SubmitSm::esm_class`].
+    /// Call this method after setting the [`SubmitSm::esm_class`].
+    ///
+    /// Records an error if the encoded text exceeds `255` octets, see [`Self::try_build`].
+    pub fn text_with(mut self, text: &str, encoder: &TextEncoder<'_>) -> Self {
+        let encoded = encoder.encode(text);
+
+        let Some(short_message) =
+            try_build::convert(&mut self.error, SmppField::short_message, encoded.bytes())
+        else {
+            return self;
+        };
+
+        if encoded.has_udh() {
+            self.inner.esm_class = self.inner.esm_class.with_udhi_indicator();
+        }
+
+        self.inner.data_coding = encoded.data_coding();
+        self.set_short_message(short_message);
+
         self
     }
 
@@ -361,7 +674,7 @@ impl SubmitSmBuilder {
         mut self,
         tlvs: alloc::vec::Vec<impl Into<MessageSubmissionRequestTlvValue>>,
     ) -> Self {
-        self.inner.set_tlvs(tlvs);
+        self.update_tlvs(|pdu| pdu.set_tlvs(tlvs));
         self
     }
 
@@ -371,13 +684,43 @@ impl SubmitSmBuilder {
     }
 
     pub fn push_tlv(mut self, tlv: impl Into<MessageSubmissionRequestTlvValue>) -> Self {
-        self.inner.push_tlv(tlv);
+        self.update_tlvs(|pdu| pdu.push_tlv(tlv));
         self
     }
 
+    /// Pushes a [`CallbackNum`](MessageSubmissionRequestTlvValue::CallbackNum) TLV encoding `callback_num`.
+    ///
+    /// Records an error if the address can not be encoded, see [`SmppAddress::to_callback_num`] and [`Self::try_build`].
+    pub fn callback_num(mut self, callback_num: &SmppAddress) -> Self {
+        match callback_num.to_callback_num() {
+            Ok(callback_num) => {
+                self.push_tlv(MessageSubmissionRequestTlvValue::CallbackNum(callback_num))
+            }
+            Err(error) => {
+                try_build::record(
+                    &mut self.error,
+                    BuildError::new(SmppField::callback_num, error.into()),
+                );
+
+                self
+            }
+        }
+    }
+
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> SubmitSm {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<SubmitSm, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -568,4 +911,195 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn text() {
+        let submit_sm = SubmitSm::builder().text("Hello").build();
+
+        assert_eq!(submit_sm.data_coding, DataCoding::McSpecific);
+        assert_eq!(submit_sm.short_message().bytes(), b"Hello");
+        assert_eq!(submit_sm.sm_length(), 5);
+
+        let submit_sm = SubmitSm::builder().text("€ á").build();
+
+        assert_eq!(submit_sm.data_coding, DataCoding::Ucs2);
+        assert_eq!(
+            submit_sm.short_message().bytes(),
+            &[0x20, 0xAC, 0x00, 0x20, 0x00, 0xE1]
+        );
+    }
+
+    #[test]
+    fn addresses() {
+        let source = SmppAddress::parse("ACME").unwrap();
+        let destination = SmppAddress::parse("+491701234567").unwrap();
+
+        let submit_sm = SubmitSm::builder()
+            .source(source)
+            .destination(destination.clone())
+            .callback_num(&destination)
+            .build();
+
+        assert_eq!(submit_sm.source_addr_ton, Ton::Alphanumeric);
+        assert_eq!(submit_sm.source_addr_npi, Npi::Unknown);
+        assert_eq!(submit_sm.source_addr.as_str(), "ACME");
+        assert_eq!(submit_sm.dest_addr_ton, Ton::International);
+        assert_eq!(submit_sm.dest_addr_npi, Npi::Isdn);
+        assert_eq!(submit_sm.destination_addr.as_str(), "491701234567");
+        assert_eq!(
+            submit_sm.tlvs()[0].value(),
+            Some(&crate::tlvs::owned::TlvValue::CallbackNum(
+                OctetString::new(b"\x01\x01\x01491701234567").unwrap()
+            ))
+        );
+
+        let error = SubmitSm::builder()
+            .callback_num(&SmppAddress::alphanumeric("ACME").unwrap())
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(error.field(), SmppField::callback_num);
+        assert!(matches!(
+            error.kind(),
+            try_build::BuildErrorKind::InvalidAddress(_)
+        ));
+    }
+
+    #[test]
+    fn text_with_national_language_sets_udhi() {
+        use crate::encoding::{gsm7bit::NationalLanguage, text::TextEncoder};
+
+        let encoder = TextEncoder::new()
+            .with_latin1(false)
+            .with_national_languages(&[NationalLanguage::Turkish]);
+
+        let submit_sm = SubmitSm::builder()
+            .esm_class(EsmClass::default())
+            .text_with("Günaydın, Şule!", &encoder)
+            .build();
+
+        assert_eq!(submit_sm.data_coding, DataCoding::McSpecific);
+        assert_eq!(submit_sm.esm_class.gsm_features, GsmFeatures::UdhiIndicator);
+        assert_eq!(
+            &submit_sm.short_message().bytes()[..4],
+            &[0x03, 0x25, 0x01, 0x01]
+        );
+    }
+
+    #[test]
+    fn text_too_long() {
+        let text = "a".repeat(256);
+
+        let error = SubmitSm::builder().text(&text).try_build().unwrap_err();
+
+        assert_eq!(error.field(), SmppField::short_message);
+    }
+
+    #[test]
+    fn split_text() {
+        use crate::{
+            multipart::{Concatenation, Splitter},
+            udhs::owned::concatenation::ConcatenatedShortMessageType,
+        };
+
+        let template = SubmitSm::builder()
+            .source_addr(COctetString::from_str("Source Address").unwrap())
+            .build();
+
+        let text = "a".repeat(200);
+
+        let parts = template
+            .split_text(&text, &Splitter::new(ConcatenatedShortMessageType::u8(1)))
+            .unwrap();
+
+        assert_eq!(parts.len(), 2);
+
+        for part in &parts {
+            assert_eq!(part.source_addr, template.source_addr);
+            assert_eq!(part.esm_class.gsm_features, GsmFeatures::UdhiIndicator);
+            assert!(part.sar().is_none());
+        }
+
+        let parts = template
+            .split_text(&text, &Splitter::new(Concatenation::sar(0x1234)))
+            .unwrap();
+
+        assert_eq!(parts.len(), 2);
+
+        for (index, part) in parts.iter().enumerate() {
+            assert_eq!(part.esm_class.gsm_features, GsmFeatures::NotSelected);
+            assert_eq!(
+                part.sar().unwrap().unwrap(),
+                Sar::new(0x1234, 2, index as u8 + 1).unwrap()
+            );
+        }
+
+        assert_eq!(parts[0].short_message().bytes(), &text.as_bytes()[..140]);
+    }
+
+    #[test]
+    fn split_text_replaces_template_udhi_and_tlvs() {
+        use crate::multipart::{Concatenation, Splitter};
+
+        let template = SubmitSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .tlvs(alloc::vec![
+                MessageSubmissionRequestTlvValue::SarMsgRefNum(1),
+                MessageSubmissionRequestTlvValue::SarTotalSegments(1),
+                MessageSubmissionRequestTlvValue::SarSegmentSeqnum(1),
+                MessageSubmissionRequestTlvValue::MessagePayload(MessagePayload::new(
+                    AnyOctetString::new(b"Template"),
+                )),
+                MessageSubmissionRequestTlvValue::UserMessageReference(UserMessageReference::new(
+                    7
+                ),),
+            ])
+            .build();
+
+        let parts = template
+            .split_text(&"a".repeat(200), &Splitter::new(Concatenation::sar(0x1234)))
+            .unwrap();
+
+        assert_eq!(parts.len(), 2);
+
+        for (index, part) in parts.iter().enumerate() {
+            assert!(!part.esm_class.has_udhi_indicator());
+            assert_eq!(part.tlvs().len(), 4);
+            assert_eq!(part.tlvs()[0].tag(), TlvTag::UserMessageReference);
+            assert_eq!(
+                part.sar().unwrap().unwrap(),
+                Sar::new(0x1234, 2, index as u8 + 1).unwrap()
+            );
+            assert!(!part.short_message().is_empty());
+        }
+    }
+
+    #[test]
+    fn smpp_time() {
+        let schedule_delivery_time =
+            SmppTime::absolute(SmppDateTime::new(25, 10, 18, 13, 42, 0, 0).unwrap(), -14).unwrap();
+        let validity_period = SmppTime::relative(0, 0, 1, 0, 0, 0).unwrap();
+
+        let submit_sm = SubmitSm::builder()
+            .schedule_delivery_time(schedule_delivery_time)
+            .validity_period(validity_period)
+            .build();
+
+        assert_eq!(
+            submit_sm.schedule_delivery_time.bytes(),
+            b"251018134200014-\0"
+        );
+        assert_eq!(
+            submit_sm.parse_schedule_delivery_time(),
+            Ok(Some(schedule_delivery_time))
+        );
+        assert_eq!(submit_sm.parse_validity_period(), Ok(Some(validity_period)));
+        assert_eq!(SubmitSm::default().parse_validity_period(), Ok(None));
+
+        let submit_sm = SubmitSm::builder()
+            .validity_period(EmptyOrFullCOctetString::new(b"251318134200014-\0").unwrap())
+            .build();
+
+        assert!(submit_sm.parse_validity_period().is_err());
+    }
 }
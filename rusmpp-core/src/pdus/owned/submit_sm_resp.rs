@@ -1,16 +1,29 @@
 use rusmpp_macros::Rusmpp;
 
 use crate::{
-    pdus::owned::Pdu,
+    fields::SmppField,
+    pdus::{
+        borrowed,
+        owned::{
+            AsBorrowedError, Pdu, heapless_vec,
+            try_build::{self, BuildError, TryIntoField},
+        },
+    },
     tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv},
     types::owned::COctetString,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct SubmitSmResp {
     /// This field contains the MC message ID of the submitted message.
     /// It may be used at a later stage to query the status of a message,
@@ -18,7 +31,7 @@ pub struct SubmitSmResp {
     message_id: COctetString<1, 65>,
     /// Message submission response TLVs ([`MessageSubmissionResponseTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitSmResp {
@@ -65,9 +78,40 @@ impl From<SubmitSmResp> for Pdu {
     }
 }
 
+impl<const N: usize> From<borrowed::SubmitSmResp<'_, N>> for SubmitSmResp {
+    fn from(value: borrowed::SubmitSmResp<'_, N>) -> Self {
+        Self {
+            message_id: value.message_id.into(),
+            tlvs: value.tlvs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::SubmitSmResp<'_, N> {
+    /// Converts this borrowed [`SubmitSmResp`](borrowed::SubmitSmResp) into an owned [`SubmitSmResp`].
+    pub fn into_owned(self) -> SubmitSmResp {
+        self.into()
+    }
+}
+
+impl SubmitSmResp {
+    /// Returns a borrowed [`SubmitSmResp`](borrowed::SubmitSmResp) view of this [`SubmitSmResp`].
+    ///
+    /// Fails if the TLVs exceed the capacity `N`.
+    pub fn as_borrowed<const N: usize>(
+        &self,
+    ) -> Result<borrowed::SubmitSmResp<'_, N>, AsBorrowedError> {
+        Ok(borrowed::SubmitSmResp {
+            message_id: self.message_id.as_borrowed(),
+            tlvs: heapless_vec(&self.tlvs, SmppField::tlvs, Tlv::as_borrowed)?,
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SubmitSmRespBuilder {
     inner: SubmitSmResp,
+    error: Option<BuildError>,
 }
 
 impl SubmitSmRespBuilder {
@@ -75,8 +119,13 @@ impl SubmitSmRespBuilder {
         Self::default()
     }
 
-    pub fn message_id(mut self, message_id: COctetString<1, 65>) -> Self {
-        self.inner.message_id = message_id;
+    pub fn message_id(mut self, message_id: impl TryIntoField<COctetString<1, 65>>) -> Self {
+        try_build::set(
+            &mut self.error,
+            &mut self.inner.message_id,
+            SmppField::message_id,
+            message_id,
+        );
         self
     }
 
@@ -98,9 +147,20 @@ impl SubmitSmRespBuilder {
         self
     }
 
+    /// Builds the PDU.
+    ///
+    /// Values that could not be converted are ignored, see [`Self::try_build`].
     pub fn build(self) -> SubmitSmResp {
         self.inner
     }
+
+    /// Builds the PDU, returning the first value that could not be converted
+    /// or the first violated semantic rule of the PDU.
+    ///
+    /// See [`Validate`](crate::validate::Validate).
+    pub fn try_build(self) -> Result<SubmitSmResp, BuildError> {
+        try_build::try_build(self.error, self.inner)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,564 @@
+use crate::{
+    CommandStatus,
+    fields::SmppField,
+    types::{
+        COctetStringError, EmptyOrFullCOctetStringError, OctetStringError, borrowed,
+        owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    },
+    validate::Validate,
+    values::{GenericServiceType, SmppAddressError, SmppTime, owned::ServiceType},
+};
+
+/// A value that can be converted into a PDU field of type `T` by a builder.
+///
+/// Implemented for `T` itself and, for the string fields, for raw `&str` and `&[u8]` values.
+/// `&str` values are written without the null terminator, `&[u8]` values are taken as they
+/// appear on the wire, i.e. `C-Octet Strings` must be null terminated.
+///
+/// Builders record the first value that fails to convert. [`try_build`](super::builders::SubmitSmBuilder::try_build)
+/// returns it as a [`BuildError`], [`build`](super::builders::SubmitSmBuilder::build) ignores it and keeps the previous value.
+/// Use `try_build` when building from raw values.
+pub trait TryIntoField<T> {
+    /// Converts `self` into the field value.
+    fn try_into_field(self) -> Result<T, BuildErrorKind>;
+}
+
+impl<T> TryIntoField<T> for T {
+    fn try_into_field(self) -> Result<T, BuildErrorKind> {
+        Ok(self)
+    }
+}
+
+macro_rules! impl_try_into_field {
+    ($target:ty, [$($generics:tt)*], |$value:ident: $source:ty| $convert:expr) => {
+        impl<$($generics)*> TryIntoField<$target> for $source {
+            fn try_into_field(self) -> Result<$target, BuildErrorKind> {
+                let $value = self;
+
+                $convert.map_err(From::from)
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_into_c_octet_string {
+    ($target:ty, [$($generics:tt)*], $map_err:expr) => {
+        impl_try_into_field!($target, [$($generics)*], |value: &str| value.parse::<$target>().map_err($map_err));
+        impl_try_into_field!($target, ['a, $($generics)*], |value: &'a alloc::string::String| value.parse::<$target>().map_err($map_err));
+        impl_try_into_field!($target, [$($generics)*], |value: &[u8]| <$target>::new(value).map_err($map_err));
+        impl_try_into_field!($target, [$($generics)*, const L: usize], |value: &[u8; L]| <$target>::new(value).map_err($map_err));
+    };
+}
+
+impl_try_into_c_octet_string! { COctetString<MIN, MAX>, [const MIN: usize, const MAX: usize], BuildErrorKind::from }
+impl_try_into_c_octet_string! { EmptyOrFullCOctetString<N>, [const N: usize], empty_or_full_error::<N> }
+
+impl_try_into_field! { OctetString<MIN, MAX>, [const MIN: usize, const MAX: usize], |value: &str| OctetString::new(value) }
+impl_try_into_field! { OctetString<MIN, MAX>, ['a, const MIN: usize, const MAX: usize], |value: &'a alloc::string::String| OctetString::new(value) }
+impl_try_into_field! { OctetString<MIN, MAX>, [const MIN: usize, const MAX: usize], |value: &[u8]| OctetString::new(value) }
+impl_try_into_field! { OctetString<MIN, MAX>, [const MIN: usize, const MAX: usize, const L: usize], |value: &[u8; L]| OctetString::new(value) }
+
+impl_try_into_field! { ServiceType, [], |value: &str| value.parse().map(ServiceType::new) }
+impl_try_into_field! { ServiceType, ['a], |value: &'a alloc::string::String| value.parse().map(ServiceType::new) }
+impl_try_into_field! { ServiceType, [], |value: &[u8]| COctetString::new(value).map(ServiceType::new) }
+impl_try_into_field! { ServiceType, [const L: usize], |value: &[u8; L]| COctetString::new(value).map(ServiceType::new) }
+
+impl TryIntoField<ServiceType> for GenericServiceType {
+    fn try_into_field(self) -> Result<ServiceType, BuildErrorKind> {
+        Ok(self.into())
+    }
+}
+
+impl TryIntoField<EmptyOrFullCOctetString<17>> for SmppTime {
+    fn try_into_field(self) -> Result<EmptyOrFullCOctetString<17>, BuildErrorKind> {
+        Ok(self.into())
+    }
+}
+
+impl<const N: usize> TryIntoField<EmptyOrFullCOctetString<N>>
+    for borrowed::EmptyOrFullCOctetString<'_, N>
+{
+    fn try_into_field(self) -> Result<EmptyOrFullCOctetString<N>, BuildErrorKind> {
+        Ok(self.into())
+    }
+}
+
+/// The constraint violated by a [`BuildError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuildErrorKind {
+    /// The value is too long.
+    TooManyBytes {
+        /// The length of the value, including the null terminator of `C-Octet Strings`.
+        actual: usize,
+        /// The maximum length of the field.
+        max: usize,
+    },
+    /// The value is too short.
+    ///
+    /// Fields holding an optional time are either empty or exactly `min` bytes long.
+    TooFewBytes {
+        /// The length of the value, including the null terminator of `C-Octet Strings`.
+        actual: usize,
+        /// The minimum length of the field.
+        min: usize,
+    },
+    /// The value of a `C-Octet String` field is not null terminated.
+    NotNullTerminated,
+    /// The value of a `C-Octet String` field is not ASCII.
+    NotAscii,
+    /// The value of a `C-Octet String` field contains a null byte before the terminator.
+    NullByteFound,
+    /// The value is not a valid address.
+    InvalidAddress(SmppAddressError),
+    /// The field can not be used together with `other`.
+    MutuallyExclusive {
+        /// The other field.
+        other: SmppField,
+    },
+    /// The PDU violates a semantic rule of the specification.
+    ///
+    /// See [`Validate`].
+    Invalid(CommandStatus),
+}
+
+impl From<COctetStringError> for BuildErrorKind {
+    fn from(error: COctetStringError) -> Self {
+        match error {
+            COctetStringError::TooManyBytes { actual, max } => Self::TooManyBytes { actual, max },
+            COctetStringError::TooFewBytes { actual, min } => Self::TooFewBytes { actual, min },
+            COctetStringError::NotNullTerminated => Self::NotNullTerminated,
+            COctetStringError::NotAscii => Self::NotAscii,
+            COctetStringError::NullByteFound => Self::NullByteFound,
+        }
+    }
+}
+
+impl From<OctetStringError> for BuildErrorKind {
+    fn from(error: OctetStringError) -> Self {
+        match error {
+            OctetStringError::TooManyBytes { actual, max } => Self::TooManyBytes { actual, max },
+            OctetStringError::TooFewBytes { actual, min } => Self::TooFewBytes { actual, min },
+        }
+    }
+}
+
+impl From<SmppAddressError> for BuildErrorKind {
+    fn from(error: SmppAddressError) -> Self {
+        Self::InvalidAddress(error)
+    }
+}
+
+/// Maps an [`EmptyOrFullCOctetStringError`] of a field of length `N`.
+fn empty_or_full_error<const N: usize>(error: EmptyOrFullCOctetStringError) -> BuildErrorKind {
+    match error {
+        EmptyOrFullCOctetStringError::TooManyBytes { actual, max } => {
+            BuildErrorKind::TooManyBytes { actual, max }
+        }
+        EmptyOrFullCOctetStringError::TooFewBytes { actual } => {
+            BuildErrorKind::TooFewBytes { actual, min: N }
+        }
+        EmptyOrFullCOctetStringError::NotNullTerminated => BuildErrorKind::NotNullTerminated,
+        EmptyOrFullCOctetStringError::NotAscii => BuildErrorKind::NotAscii,
+        EmptyOrFullCOctetStringError::NullByteFound => BuildErrorKind::NullByteFound,
+    }
+}
+
+impl core::fmt::Display for BuildErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooManyBytes { actual, max } => {
+                write!(f, "Too many bytes. actual: {actual}, max: {max}")
+            }
+            Self::TooFewBytes { actual, min } => {
+                write!(f, "Too few bytes. actual: {actual}, min: {min}")
+            }
+            Self::NotNullTerminated => write!(f, "Not null terminated"),
+            Self::NotAscii => write!(f, "Not ASCII"),
+            Self::NullByteFound => write!(f, "Null byte found"),
+            Self::InvalidAddress(error) => write!(f, "Invalid address: {error}"),
+            Self::MutuallyExclusive { other } => write!(f, "Mutually exclusive with {other:?}"),
+            Self::Invalid(status) => write!(f, "{}", status.description()),
+        }
+    }
+}
+
+/// An error returned by the `try_build` method of the PDU builders.
+///
+/// Names the offending [`SmppField`] and the violated constraint.
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::{
+///     fields::SmppField,
+///     pdus::owned::{SubmitSm, builders::BuildErrorKind},
+/// };
+///
+/// let error = SubmitSm::builder()
+///     .source_addr("12345")
+///     .destination_addr("1234567890123456789012345")
+///     .short_message("Hello")
+///     .try_build()
+///     .unwrap_err();
+///
+/// assert_eq!(error.field(), SmppField::destination_addr);
+/// assert_eq!(error.kind(), BuildErrorKind::TooManyBytes { actual: 26, max: 21 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuildError {
+    field: SmppField,
+    kind: BuildErrorKind,
+}
+
+impl BuildError {
+    pub const fn new(field: SmppField, kind: BuildErrorKind) -> Self {
+        Self { field, kind }
+    }
+
+    /// Returns the offending field.
+    ///
+    /// Semantic rules spanning multiple fields or TLVs name the field the [`CommandStatus`] refers to,
+    /// e.g. [`SmppField::tlvs`] for [`CommandStatus::EsmeRmissingtlv`], or [`SmppField::pdu`] if there is none.
+    pub const fn field(&self) -> SmppField {
+        self.field
+    }
+
+    pub const fn kind(&self) -> BuildErrorKind {
+        self.kind
+    }
+
+    /// Creates a [`BuildError`] from the [`CommandStatus`] returned by [`Validate::validate`].
+    const fn invalid(status: CommandStatus) -> Self {
+        let field = match status {
+            CommandStatus::EsmeRinvsched => SmppField::schedule_delivery_time,
+            CommandStatus::EsmeRinvexpiry => SmppField::validity_period,
+            CommandStatus::EsmeRinvmsglen => SmppField::short_message,
+            CommandStatus::EsmeRinvesmclass => SmppField::esm_class,
+            CommandStatus::EsmeRinvnumdests => SmppField::number_of_dests,
+            CommandStatus::EsmeRtlvnotallwd | CommandStatus::EsmeRmissingtlv => SmppField::tlvs,
+            _ => SmppField::pdu,
+        };
+
+        Self::new(field, BuildErrorKind::Invalid(status))
+    }
+}
+
+impl core::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid {:?}: {}", self.field, self.kind)
+    }
+}
+
+impl core::error::Error for BuildError {}
+
+/// Converts `value` into `slot`, recording the first error.
+pub(crate) fn set<T>(
+    error: &mut Option<BuildError>,
+    slot: &mut T,
+    field: SmppField,
+    value: impl TryIntoField<T>,
+) {
+    match value.try_into_field() {
+        Ok(value) => *slot = value,
+        Err(kind) => record(error, BuildError::new(field, kind)),
+    }
+}
+
+/// Converts `value`, recording the first error.
+pub(crate) fn convert<T>(
+    error: &mut Option<BuildError>,
+    field: SmppField,
+    value: impl TryIntoField<T>,
+) -> Option<T> {
+    value
+        .try_into_field()
+        .map_err(|kind| record(error, BuildError::new(field, kind)))
+        .ok()
+}
+
+/// Records a conflict if the `message_payload` TLV discarded a non-empty `short_message`.
+///
+/// `build` keeps the message payload, `try_build` fails.
+pub(crate) fn record_discarded_short_message(error: &mut Option<BuildError>, discarded: bool) {
+    if discarded {
+        record(
+            error,
+            BuildError::new(
+                SmppField::short_message,
+                BuildErrorKind::MutuallyExclusive {
+                    other: SmppField::message_payload,
+                },
+            ),
+        );
+    }
+}
+
+/// Records `new` unless an error was already recorded.
+pub(crate) fn record(error: &mut Option<BuildError>, new: BuildError) {
+    error.get_or_insert(new);
+}
+
+/// Returns the first recorded error, or validates the built `pdu`.
+pub(crate) fn try_build<T: Validate>(error: Option<BuildError>, pdu: T) -> Result<T, BuildError> {
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    pdu.validate().map_err(BuildError::invalid)?;
+
+    Ok(pdu)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pdus::owned::{
+            BindTransmitter, BroadcastSm, DeliverSm, QuerySmResp, ReplaceSm, SubmitMulti, SubmitSm,
+        },
+        tlvs::owned::{MessageDeliveryRequestTlvValue, MessageSubmissionRequestTlvValue},
+        types::owned::AnyOctetString,
+        values::{DataCoding, owned::MessagePayload},
+    };
+
+    use super::*;
+
+    #[test]
+    fn raw_values() {
+        let bind = BindTransmitter::builder()
+            .system_id("system")
+            .password(b"secret\0")
+            .system_type(&alloc::string::String::from("type"))
+            .address_range(b"".as_slice())
+            .try_build();
+
+        assert!(bind.is_err());
+
+        let bind = BindTransmitter::builder()
+            .system_id("system")
+            .password(b"secret\0")
+            .system_type(&alloc::string::String::from("type"))
+            .address_range(b"\0".as_slice())
+            .try_build()
+            .unwrap();
+
+        assert_eq!(bind.system_id.as_str(), "system");
+        assert_eq!(bind.password.as_str(), "secret");
+        assert_eq!(bind.system_type.as_str(), "type");
+        assert!(bind.address_range.is_empty());
+
+        let submit_sm = SubmitSm::builder()
+            .service_type("CMT")
+            .source_addr("12345")
+            .destination_addr("491701234567")
+            .validity_period("000001000000000R")
+            .short_message("Hello")
+            .try_build()
+            .unwrap();
+
+        assert_eq!(submit_sm.service_type.value().as_str(), "CMT");
+        assert_eq!(submit_sm.short_message().bytes(), b"Hello");
+        assert_eq!(submit_sm.sm_length(), 5);
+    }
+
+    #[test]
+    fn conversion_errors() {
+        let cases = [
+            (
+                BindTransmitter::builder()
+                    .system_id("a-system-id-that-is-too-long")
+                    .try_build()
+                    .unwrap_err(),
+                BuildError::new(
+                    SmppField::system_id,
+                    BuildErrorKind::TooManyBytes {
+                        actual: 29,
+                        max: 16,
+                    },
+                ),
+            ),
+            (
+                BindTransmitter::builder()
+                    .password(b"secret")
+                    .try_build()
+                    .unwrap_err(),
+                BuildError::new(SmppField::password, BuildErrorKind::NotNullTerminated),
+            ),
+            (
+                BindTransmitter::builder()
+                    .system_type("tüp")
+                    .try_build()
+                    .unwrap_err(),
+                BuildError::new(SmppField::system_type, BuildErrorKind::NotAscii),
+            ),
+            (
+                SubmitSm::builder()
+                    .source_addr("12\x0045")
+                    .try_build()
+                    .unwrap_err(),
+                BuildError::new(SmppField::source_addr, BuildErrorKind::NullByteFound),
+            ),
+            (
+                QuerySmResp::builder()
+                    .final_date("2501011200")
+                    .try_build()
+                    .unwrap_err(),
+                BuildError::new(
+                    SmppField::final_date,
+                    BuildErrorKind::TooFewBytes {
+                        actual: 11,
+                        min: 17,
+                    },
+                ),
+            ),
+            (
+                SubmitSm::builder()
+                    .short_message([b'a'; 256].as_slice())
+                    .try_build()
+                    .unwrap_err(),
+                BuildError::new(
+                    SmppField::short_message,
+                    BuildErrorKind::TooManyBytes {
+                        actual: 256,
+                        max: 255,
+                    },
+                ),
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(error, expected);
+        }
+    }
+
+    #[test]
+    fn first_error_wins() {
+        let error = SubmitSm::builder()
+            .source_addr("12345")
+            .destination_addr("1234567890123456789012345")
+            .service_type("too-long")
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(error.field(), SmppField::destination_addr);
+
+        let error = SubmitSm::builder()
+            .short_message("Hello")
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Hello")),
+            ))
+            .source_addr("1234567890123456789012345")
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(error.field(), SmppField::short_message);
+    }
+
+    #[test]
+    fn build_ignores_conversion_errors() {
+        let submit_sm = SubmitSm::builder()
+            .source_addr("12345")
+            .destination_addr("1234567890123456789012345")
+            .build();
+
+        assert_eq!(submit_sm.source_addr.as_str(), "12345");
+        assert!(submit_sm.destination_addr.is_empty());
+
+        // build keeps the message payload.
+        let submit_sm = SubmitSm::builder()
+            .short_message("Hello")
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Hello")),
+            ))
+            .build();
+
+        assert!(submit_sm.short_message().is_empty());
+    }
+
+    #[test]
+    fn mutually_exclusive_short_message_and_message_payload() {
+        let expected = BuildError::new(
+            SmppField::short_message,
+            BuildErrorKind::MutuallyExclusive {
+                other: SmppField::message_payload,
+            },
+        );
+
+        let error = SubmitSm::builder()
+            .short_message("Hello")
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Hello")),
+            ))
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(error, expected);
+
+        let error = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::new(b"Hello")),
+            ))
+            .short_message("Hello")
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(error, expected);
+
+        let error = ReplaceSm::builder()
+            .short_message("Hello")
+            .message_payload(Some(MessagePayload::new(AnyOctetString::new(b"Hello"))))
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(error, expected);
+
+        // An empty short message does not conflict.
+        assert!(
+            SubmitSm::builder()
+                .short_message("")
+                .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                    MessagePayload::new(AnyOctetString::new(b"Hello")),
+                ))
+                .try_build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn semantic_errors() {
+        let error = SubmitSm::builder()
+            .data_coding(DataCoding::Ucs2)
+            .short_message([0; 150].as_slice())
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            BuildError::new(
+                SmppField::short_message,
+                BuildErrorKind::Invalid(CommandStatus::EsmeRinvmsglen)
+            )
+        );
+
+        let error = SubmitSm::builder()
+            .validity_period("2023-10-01T12:00")
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(error.field(), SmppField::validity_period);
+
+        let error = SubmitMulti::builder().try_build().unwrap_err();
+
+        assert_eq!(error.field(), SmppField::number_of_dests);
+
+        let error = BroadcastSm::builder().try_build().unwrap_err();
+
+        assert_eq!(
+            error,
+            BuildError::new(
+                SmppField::tlvs,
+                BuildErrorKind::Invalid(CommandStatus::EsmeRmissingtlv)
+            )
+        );
+    }
+}
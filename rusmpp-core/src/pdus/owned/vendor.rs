@@ -0,0 +1,315 @@
+use crate::{
+    CommandId,
+    command::owned::Command,
+    decode::{DecodeError, owned::DecodeWithLength},
+    encode::Encode,
+    pdus::owned::Pdu,
+    types::owned::AnyOctetString,
+};
+
+/// A typed vendor-specific PDU body.
+///
+/// Commands with an unknown [`CommandId`], such as the ones in the range reserved for MC vendors
+/// (`0x00010200` - `0x000102FF` and their responses), are decoded as [`Pdu::Other`].
+/// Implementing this trait registers a typed definition for such a command id,
+/// which can then be read with [`Command::vendor_body`] or [`Pdu::vendor_body`] and written with [`VendorPdu`].
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::{
+///     decode::{DecodeError, owned::Decode},
+///     encode::{Encode, Length},
+///     pdus::owned::{Pdu, VendorPdu, VendorPduBody},
+/// };
+///
+/// /// Balance query response, sent by the MC with the command id `0x80010201`.
+/// #[derive(Debug, PartialEq)]
+/// struct BalanceResp {
+///     balance: u32,
+/// }
+///
+/// impl Length for BalanceResp {
+///     fn length(&self) -> usize {
+///         self.balance.length()
+///     }
+/// }
+///
+/// impl Encode for BalanceResp {
+///     fn encode(&self, dst: &mut [u8]) -> usize {
+///         self.balance.encode(dst)
+///     }
+/// }
+///
+/// impl Decode for BalanceResp {
+///     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+///         u32::decode(src).map(|(balance, size)| (Self { balance }, size))
+///     }
+/// }
+///
+/// impl VendorPduBody for BalanceResp {
+///     const COMMAND_ID: u32 = 0x80010201;
+/// }
+///
+/// let pdu = Pdu::from(VendorPdu(BalanceResp { balance: 100 }));
+///
+/// assert_eq!(u32::from(pdu.command_id()), 0x80010201);
+/// assert_eq!(
+///     pdu.vendor_body::<BalanceResp>().unwrap().unwrap(),
+///     BalanceResp { balance: 100 }
+/// );
+/// ```
+pub trait VendorPduBody: Encode + DecodeWithLength {
+    /// The command id of the PDU.
+    const COMMAND_ID: u32;
+}
+
+/// Wraps a [`VendorPduBody`] to convert it into a [`Pdu`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VendorPdu<T>(pub T);
+
+impl<T: VendorPduBody> From<VendorPdu<T>> for Pdu {
+    fn from(value: VendorPdu<T>) -> Self {
+        let mut body = alloc::vec![0; value.0.length()];
+
+        value.0.encode(&mut body);
+
+        Pdu::Other {
+            command_id: CommandId::from(T::COMMAND_ID),
+            body: AnyOctetString::from(body),
+        }
+    }
+}
+
+impl Pdu {
+    /// Decodes the body of this [`Pdu`] as the vendor-specific body `T`.
+    ///
+    /// Returns `None` if this is not a [`Pdu::Other`] with the command id [`VendorPduBody::COMMAND_ID`].
+    pub fn vendor_body<T: VendorPduBody>(&self) -> Option<Result<T, DecodeError>> {
+        match self {
+            Pdu::Other { command_id, body } if u32::from(*command_id) == T::COMMAND_ID => {
+                Some(decode_vendor_body(body.bytes()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Command {
+    /// Decodes the body of this [`Command`] as the vendor-specific body `T`.
+    ///
+    /// Unlike [`Pdu::vendor_body`], a command without a body, e.g. a header-only vendor-specific response,
+    /// is decoded from an empty body.
+    ///
+    /// Returns `None` if the command id is not [`VendorPduBody::COMMAND_ID`].
+    pub fn vendor_body<T: VendorPduBody>(&self) -> Option<Result<T, DecodeError>> {
+        match self.pdu() {
+            Some(pdu) => pdu.vendor_body(),
+            None if u32::from(self.id()) == T::COMMAND_ID => Some(decode_vendor_body(&[])),
+            None => None,
+        }
+    }
+}
+
+/// Decodes `body` as `T`, rejecting trailing bytes.
+fn decode_vendor_body<T: VendorPduBody>(body: &[u8]) -> Result<T, DecodeError> {
+    let (decoded, size) = T::decode(body, body.len())?;
+
+    if size != body.len() {
+        return Err(DecodeError::trailing_bytes(size, body.len()));
+    }
+
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use crate::{
+        CommandStatus,
+        decode::{
+            DecodeErrorKind,
+            owned::{Decode, DecodeWithLength},
+        },
+        encode::Length,
+        types::owned::COctetString,
+    };
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Balance {
+        account: COctetString<1, 16>,
+    }
+
+    impl Length for Balance {
+        fn length(&self) -> usize {
+            self.account.length()
+        }
+    }
+
+    impl Encode for Balance {
+        fn encode(&self, dst: &mut [u8]) -> usize {
+            self.account.encode(dst)
+        }
+    }
+
+    impl Decode for Balance {
+        fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+            Decode::decode(src).map(|(account, size)| (Self { account }, size))
+        }
+    }
+
+    impl VendorPduBody for Balance {
+        const COMMAND_ID: u32 = 0x00010201;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct BalanceResp {
+        balance: u32,
+    }
+
+    impl Length for BalanceResp {
+        fn length(&self) -> usize {
+            self.balance.length()
+        }
+    }
+
+    impl Encode for BalanceResp {
+        fn encode(&self, dst: &mut [u8]) -> usize {
+            self.balance.encode(dst)
+        }
+    }
+
+    impl Decode for BalanceResp {
+        fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+            Decode::decode(src).map(|(balance, size)| (Self { balance }, size))
+        }
+    }
+
+    impl VendorPduBody for BalanceResp {
+        const COMMAND_ID: u32 = 0x80010201;
+    }
+
+    #[test]
+    fn into_pdu() {
+        let pdu = Pdu::from(VendorPdu(BalanceResp { balance: 100 }));
+
+        assert_eq!(
+            pdu,
+            Pdu::Other {
+                command_id: CommandId::Other(0x80010201),
+                body: AnyOctetString::new([0x00, 0x00, 0x00, 0x64]),
+            }
+        );
+    }
+
+    #[test]
+    fn vendor_body() {
+        let pdu = Pdu::from(VendorPdu(BalanceResp { balance: 100 }));
+
+        assert_eq!(
+            pdu.vendor_body::<BalanceResp>().unwrap().unwrap(),
+            BalanceResp { balance: 100 }
+        );
+        assert!(pdu.vendor_body::<Balance>().is_none());
+
+        let pdu = Pdu::Other {
+            command_id: CommandId::Other(0x80010201),
+            body: AnyOctetString::new([0x00, 0x64]),
+        };
+
+        assert!(matches!(pdu.vendor_body::<BalanceResp>(), Some(Err(_))));
+
+        assert!(Pdu::EnquireLink.vendor_body::<Balance>().is_none());
+    }
+
+    #[test]
+    fn vendor_body_trailing_bytes() {
+        let pdu = Pdu::Other {
+            command_id: CommandId::Other(0x80010201),
+            body: AnyOctetString::new([0x00, 0x00, 0x00, 0x64, 0x00]),
+        };
+
+        let error = pdu.vendor_body::<BalanceResp>().unwrap().unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            DecodeErrorKind::TrailingBytes {
+                decoded: 4,
+                length: 5
+            }
+        ));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Ack;
+
+    impl Length for Ack {
+        fn length(&self) -> usize {
+            0
+        }
+    }
+
+    impl Encode for Ack {
+        fn encode(&self, _dst: &mut [u8]) -> usize {
+            0
+        }
+    }
+
+    impl Decode for Ack {
+        fn decode(_src: &[u8]) -> Result<(Self, usize), DecodeError> {
+            Ok((Self, 0))
+        }
+    }
+
+    impl VendorPduBody for Ack {
+        const COMMAND_ID: u32 = 0x80010202;
+    }
+
+    #[test]
+    fn command_vendor_body_without_body() {
+        let command = Command::new(CommandStatus::EsmeRok, 1, VendorPdu(Ack));
+
+        let mut buf = alloc::vec![0; command.length()];
+
+        command.encode(&mut buf);
+
+        let (decoded, _) = <Command as DecodeWithLength>::decode(&buf, buf.len()).unwrap();
+
+        assert!(decoded.pdu().is_none());
+        assert_eq!(decoded.vendor_body::<Ack>().unwrap().unwrap(), Ack);
+        assert!(decoded.vendor_body::<BalanceResp>().is_none());
+    }
+
+    #[test]
+    fn encode_decode() {
+        let command = Command::new(
+            CommandStatus::EsmeRok,
+            1,
+            VendorPdu(Balance {
+                account: COctetString::from_str("account").unwrap(),
+            }),
+        );
+
+        let mut buf = alloc::vec![0; command.length()];
+
+        command.encode(&mut buf);
+
+        let (decoded, _) = <Command as DecodeWithLength>::decode(&buf, buf.len()).unwrap();
+
+        assert_eq!(decoded.id(), CommandId::Other(0x00010201));
+        assert_eq!(
+            decoded
+                .pdu()
+                .unwrap()
+                .vendor_body::<Balance>()
+                .unwrap()
+                .unwrap(),
+            Balance {
+                account: COctetString::from_str("account").unwrap(),
+            }
+        );
+    }
+}
@@ -1,6 +1,10 @@
 //! Session state of the ESME <-> MC session.
 //!
 //! Can be useful if you write a MC or an ESME to describe the session state.
+//! [`Session`] drives the [`SessionState`] from the sent and received commands.
 
 mod session_state;
 pub use session_state::SessionState;
+
+mod tracker;
+pub use tracker::{Session, SessionCommand, SessionRole};
@@ -0,0 +1,509 @@
+use crate::{CommandId, CommandStatus, session::SessionState};
+
+/// The local end of a [`Session`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SessionRole {
+    /// The local end is an ESME. Sent commands are checked with [`SessionState::can_send_as_esme`].
+    Esme,
+    /// The local end is a MC. Sent commands are checked with [`SessionState::can_send_as_mc`].
+    Mc,
+}
+
+/// The header of a command that drives a [`Session`].
+pub trait SessionCommand {
+    fn command_id(&self) -> CommandId;
+
+    fn command_status(&self) -> CommandStatus;
+
+    fn sequence_number(&self) -> u32;
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl SessionCommand for crate::command::owned::Command {
+    fn command_id(&self) -> CommandId {
+        self.id()
+    }
+
+    fn command_status(&self) -> CommandStatus {
+        self.status()
+    }
+
+    fn sequence_number(&self) -> u32 {
+        self.sequence_number()
+    }
+}
+
+impl<const N: usize> SessionCommand for crate::command::borrowed::Command<'_, N> {
+    fn command_id(&self) -> CommandId {
+        self.id()
+    }
+
+    fn command_status(&self) -> CommandStatus {
+        self.status()
+    }
+
+    fn sequence_number(&self) -> u32 {
+        self.sequence_number()
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl SessionCommand for crate::tokio_codec::CommandFrame {
+    fn command_id(&self) -> CommandId {
+        self.id()
+    }
+
+    fn command_status(&self) -> CommandStatus {
+        self.status()
+    }
+
+    fn sequence_number(&self) -> u32 {
+        self.sequence_number()
+    }
+}
+
+/// A session state machine following the 2.3 Session States and 2.4 Operation Matrix of the `SMPP` 5.0 specification.
+///
+/// Every sent and received command is fed to the [`Session`], which checks it against the operation matrix
+/// of its [`SessionRole`] and drives the [`SessionState`]:
+///
+/// - A successful bind response to the pending bind request enters the matching bound state.
+///   A failed bind response or a generic_nack to the pending bind request keeps the current state.
+/// - An outbind enters the [`SessionState::Outbound`] state.
+/// - An unbind enters the [`SessionState::Unbound`] state. A successful unbind_resp enters the [`SessionState::Closed`] state.
+///   A failed unbind_resp or a generic_nack to the pending unbind request restores the previous bound state.
+///
+/// Violations do not change the state and are reported with the [`CommandStatus`] a compliant peer would respond with.
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::{
+///     CommandStatus,
+///     command::owned::Command,
+///     pdus::owned::{BindTransmitter, BindTransmitterResp, DeliverSm, Pdu},
+///     session::{Session, SessionRole, SessionState},
+/// };
+///
+/// let mut session = Session::new(SessionRole::Esme);
+///
+/// assert_eq!(session.state(), SessionState::Open);
+///
+/// session
+///     .send(&Command::new(CommandStatus::EsmeRok, 1, BindTransmitter::default()))
+///     .unwrap();
+///
+/// let state = session
+///     .receive(&Command::new(CommandStatus::EsmeRok, 1, BindTransmitterResp::default()))
+///     .unwrap();
+///
+/// assert_eq!(state, SessionState::BoundTx);
+///
+/// // A transmitter can not receive deliver_sm.
+/// let status = session
+///     .receive(&Command::new(CommandStatus::EsmeRok, 2, DeliverSm::default()))
+///     .unwrap_err();
+///
+/// assert_eq!(status, CommandStatus::EsmeRinvbndsts);
+///
+/// // Enquire links are allowed in all open states.
+/// session
+///     .send(&Command::new(CommandStatus::EsmeRok, 3, Pdu::EnquireLink))
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Session {
+    role: SessionRole,
+    state: SessionState,
+    /// The command id and sequence number of the pending bind request.
+    pending_bind: Option<(CommandId, u32)>,
+    /// The bound state and the sequence number of the pending unbind request.
+    pending_unbind: Option<(SessionState, u32)>,
+}
+
+impl Session {
+    /// Creates a new [`Session`] in the [`SessionState::Open`] state.
+    ///
+    /// The session is created when the connection is established.
+    pub const fn new(role: SessionRole) -> Self {
+        Self {
+            role,
+            state: SessionState::Open,
+            pending_bind: None,
+            pending_unbind: None,
+        }
+    }
+
+    #[inline]
+    pub const fn role(&self) -> SessionRole {
+        self.role
+    }
+
+    #[inline]
+    pub const fn state(&self) -> SessionState {
+        self.state
+    }
+
+    /// Enters the [`SessionState::Closed`] state, e.g. when the connection is lost.
+    pub fn close(&mut self) {
+        self.state = SessionState::Closed;
+        self.pending_bind = None;
+        self.pending_unbind = None;
+    }
+
+    /// Feeds a sent command to the session.
+    ///
+    /// Returns the new [`SessionState`], or the [`CommandStatus`] describing the violation.
+    pub fn send(&mut self, command: &impl SessionCommand) -> Result<SessionState, CommandStatus> {
+        let allowed = match self.role {
+            SessionRole::Esme => self.state.can_send_as_esme(command.command_id()),
+            SessionRole::Mc => self.state.can_send_as_mc(command.command_id()),
+        };
+
+        self.transition(command, allowed)
+    }
+
+    /// Feeds a received command to the session.
+    ///
+    /// Returns the new [`SessionState`], or the [`CommandStatus`] describing the violation.
+    pub fn receive(
+        &mut self,
+        command: &impl SessionCommand,
+    ) -> Result<SessionState, CommandStatus> {
+        let allowed = match self.role {
+            SessionRole::Esme => self.state.can_receive_as_esme(command.command_id()),
+            SessionRole::Mc => self.state.can_receive_as_mc(command.command_id()),
+        };
+
+        self.transition(command, allowed)
+    }
+
+    fn transition(
+        &mut self,
+        command: &impl SessionCommand,
+        allowed: bool,
+    ) -> Result<SessionState, CommandStatus> {
+        let id = command.command_id();
+        let status = command.command_status();
+        let sequence_number = command.sequence_number();
+
+        // The operation matrix does not list the unbind_resp of the unbound state.
+        let unbind_resp = id == CommandId::UnbindResp && self.state == SessionState::Unbound;
+
+        if !allowed && !unbind_resp {
+            return Err(self.violation(id));
+        }
+
+        match id {
+            CommandId::BindTransmitter | CommandId::BindReceiver | CommandId::BindTransceiver => {
+                if self.pending_bind.is_some() {
+                    return Err(CommandStatus::EsmeRinvbndsts);
+                }
+
+                self.pending_bind = Some((id, sequence_number));
+            }
+            CommandId::BindTransmitterResp
+            | CommandId::BindReceiverResp
+            | CommandId::BindTransceiverResp => {
+                if self.pending_bind != Some((id.matching_request(), sequence_number)) {
+                    return Err(CommandStatus::EsmeRinvbndsts);
+                }
+
+                self.pending_bind = None;
+
+                if status == CommandStatus::EsmeRok {
+                    self.state = match id {
+                        CommandId::BindTransmitterResp => SessionState::BoundTx,
+                        CommandId::BindReceiverResp => SessionState::BoundRx,
+                        _ => SessionState::BoundTrx,
+                    };
+                }
+            }
+            CommandId::Outbind => {
+                self.state = SessionState::Outbound;
+            }
+            CommandId::Unbind => {
+                self.pending_unbind = Some((self.state, sequence_number));
+                self.state = SessionState::Unbound;
+            }
+            CommandId::UnbindResp if unbind_resp => {
+                let Some((bound, _)) = self
+                    .pending_unbind
+                    .take_if(|(_, pending)| *pending == sequence_number)
+                else {
+                    return Err(CommandStatus::EsmeRinvbndsts);
+                };
+
+                self.state = match status {
+                    CommandStatus::EsmeRok => SessionState::Closed,
+                    _ => bound,
+                };
+            }
+            CommandId::GenericNack => {
+                // A generic_nack to the pending bind request keeps the current state.
+                self.pending_bind
+                    .take_if(|(_, pending)| *pending == sequence_number);
+
+                if let Some((bound, _)) = self
+                    .pending_unbind
+                    .take_if(|(_, pending)| *pending == sequence_number)
+                {
+                    self.state = bound;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(self.state)
+    }
+
+    fn violation(&self, id: CommandId) -> CommandStatus {
+        match id {
+            CommandId::Other(_) => CommandStatus::EsmeRinvcmdid,
+            CommandId::BindTransmitter | CommandId::BindReceiver | CommandId::BindTransceiver
+                if self.state.is_bound() =>
+            {
+                CommandStatus::EsmeRalybnd
+            }
+            _ => CommandStatus::EsmeRinvbndsts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        command::owned::Command,
+        pdus::owned::{
+            BindReceiver, BindReceiverResp, BindTransceiver, BindTransceiverResp, BindTransmitter,
+            BindTransmitterResp, DeliverSm, DeliverSmResp, Outbind, Pdu, SubmitSm, SubmitSmResp,
+        },
+    };
+
+    use super::*;
+
+    fn command(sequence_number: u32, pdu: impl Into<Pdu>) -> Command {
+        Command::new(CommandStatus::EsmeRok, sequence_number, pdu)
+    }
+
+    fn failed(sequence_number: u32, pdu: impl Into<Pdu>) -> Command {
+        Command::new(CommandStatus::EsmeRbindfail, sequence_number, pdu)
+    }
+
+    #[test]
+    fn esme_bind_and_unbind() {
+        let mut session = Session::new(SessionRole::Esme);
+
+        assert_eq!(
+            session.send(&command(1, BindTransceiver::default())),
+            Ok(SessionState::Open)
+        );
+        assert_eq!(
+            session.receive(&command(1, BindTransceiverResp::default())),
+            Ok(SessionState::BoundTrx)
+        );
+
+        assert!(session.send(&command(2, SubmitSm::default())).is_ok());
+        assert!(
+            session
+                .receive(&command(2, SubmitSmResp::default()))
+                .is_ok()
+        );
+        assert!(session.receive(&command(3, DeliverSm::default())).is_ok());
+        assert!(session.send(&command(3, DeliverSmResp::default())).is_ok());
+
+        assert_eq!(
+            session.send(&command(4, Pdu::Unbind)),
+            Ok(SessionState::Unbound)
+        );
+        assert_eq!(
+            session.send(&command(5, SubmitSm::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+        assert_eq!(
+            session.receive(&command(4, Pdu::UnbindResp)),
+            Ok(SessionState::Closed)
+        );
+
+        assert_eq!(
+            session.send(&command(6, Pdu::EnquireLink)),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+    }
+
+    #[test]
+    fn esme_bind_failed() {
+        let mut session = Session::new(SessionRole::Esme);
+
+        session
+            .send(&command(1, BindTransmitter::default()))
+            .unwrap();
+
+        // Another bind while the first one is pending.
+        assert_eq!(
+            session.send(&command(2, BindTransmitter::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+
+        // The response does not match the pending bind.
+        assert_eq!(
+            session.receive(&command(1, BindReceiverResp::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+
+        assert_eq!(
+            session.receive(&failed(1, BindTransmitterResp::default())),
+            Ok(SessionState::Open)
+        );
+
+        session
+            .send(&command(2, BindTransmitter::default()))
+            .unwrap();
+
+        assert_eq!(
+            session.receive(&command(2, Pdu::GenericNack)),
+            Ok(SessionState::Open)
+        );
+
+        session
+            .send(&command(3, BindTransmitter::default()))
+            .unwrap();
+
+        assert_eq!(
+            session.receive(&command(3, BindTransmitterResp::default())),
+            Ok(SessionState::BoundTx)
+        );
+    }
+
+    #[test]
+    fn esme_violations() {
+        let mut session = Session::new(SessionRole::Esme);
+
+        assert_eq!(
+            session.send(&command(1, SubmitSm::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+
+        session
+            .send(&command(1, BindTransmitter::default()))
+            .unwrap();
+        session
+            .receive(&command(1, BindTransmitterResp::default()))
+            .unwrap();
+
+        assert_eq!(
+            session.send(&command(2, BindTransmitter::default())),
+            Err(CommandStatus::EsmeRalybnd)
+        );
+        assert_eq!(
+            session.receive(&command(3, DeliverSm::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+        assert_eq!(
+            session.send(&command(
+                4,
+                Pdu::Other {
+                    command_id: CommandId::Other(0x00001234),
+                    body: Default::default(),
+                }
+            )),
+            Err(CommandStatus::EsmeRinvcmdid)
+        );
+
+        assert_eq!(session.state(), SessionState::BoundTx);
+    }
+
+    #[test]
+    fn esme_outbind() {
+        let mut session = Session::new(SessionRole::Esme);
+
+        assert_eq!(
+            session.receive(&command(1, Outbind::default())),
+            Ok(SessionState::Outbound)
+        );
+
+        session.send(&command(1, BindReceiver::default())).unwrap();
+
+        assert_eq!(
+            session.receive(&command(1, BindReceiverResp::default())),
+            Ok(SessionState::BoundRx)
+        );
+        assert!(session.receive(&command(2, DeliverSm::default())).is_ok());
+    }
+
+    #[test]
+    fn mc_bind_and_unbind() {
+        let mut session = Session::new(SessionRole::Mc);
+
+        assert_eq!(
+            session.send(&command(1, DeliverSm::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+
+        session
+            .receive(&command(1, BindReceiver::default()))
+            .unwrap();
+
+        assert_eq!(
+            session.send(&command(1, BindReceiverResp::default())),
+            Ok(SessionState::BoundRx)
+        );
+
+        assert!(session.send(&command(2, DeliverSm::default())).is_ok());
+        assert_eq!(
+            session.receive(&command(3, SubmitSm::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+
+        assert_eq!(
+            session.receive(&command(4, Pdu::Unbind)),
+            Ok(SessionState::Unbound)
+        );
+
+        // A failed unbind_resp restores the bound state.
+        assert_eq!(
+            session.send(&failed(4, Pdu::UnbindResp)),
+            Ok(SessionState::BoundRx)
+        );
+
+        assert_eq!(
+            session.receive(&command(5, Pdu::Unbind)),
+            Ok(SessionState::Unbound)
+        );
+        assert_eq!(
+            session.send(&command(6, Pdu::UnbindResp)),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+        assert_eq!(
+            session.send(&command(5, Pdu::UnbindResp)),
+            Ok(SessionState::Closed)
+        );
+    }
+
+    #[test]
+    fn mc_outbind() {
+        let mut session = Session::new(SessionRole::Mc);
+
+        assert_eq!(
+            session.send(&command(1, Outbind::default())),
+            Ok(SessionState::Outbound)
+        );
+
+        session
+            .receive(&command(1, BindTransceiver::default()))
+            .unwrap();
+
+        assert_eq!(
+            session.send(&command(1, BindTransceiverResp::default())),
+            Ok(SessionState::BoundTrx)
+        );
+
+        session.close();
+
+        assert_eq!(session.state(), SessionState::Closed);
+        assert_eq!(
+            session.receive(&command(2, SubmitSm::default())),
+            Err(CommandStatus::EsmeRinvbndsts)
+        );
+    }
+}
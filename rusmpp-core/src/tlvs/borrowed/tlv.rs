@@ -35,10 +35,10 @@ pub use query_broadcast_response::*;
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub struct Tlv<'a> {
-    tag: TlvTag,
-    value_length: u16,
+    pub(crate) tag: TlvTag,
+    pub(crate) value_length: u16,
     #[rusmpp(key = tag, length = value_length)]
-    value: Option<TlvValue<'a>>,
+    pub(crate) value: Option<TlvValue<'a>>,
 }
 
 impl<'a> Tlv<'a> {
@@ -10,6 +10,9 @@ use crate::{
     values::{borrowed::*, *},
 };
 
+#[cfg(feature = "tokio-codec")]
+use crate::encode::EncodeBytesMut;
+
 /// See module level documentation.
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -333,6 +336,79 @@ impl Encode for TlvValue<'_> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl EncodeBytesMut for TlvValue<'_> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        match self {
+            TlvValue::AdditionalStatusInfoText(value) => value.encode_buf_mut(dst),
+            TlvValue::AlertOnMessageDelivery(value) => value.encode_buf_mut(dst),
+            TlvValue::BillingIdentification(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastAreaIdentifier(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastAreaSuccess(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastContentTypeInfo(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastChannelIndicator(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastContentType(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastEndTime(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastErrorStatus(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastFrequencyInterval(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastMessageClass(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastRepNum(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastServiceGroup(value) => value.encode_buf_mut(dst),
+            TlvValue::CallbackNum(value) => value.encode_buf_mut(dst),
+            TlvValue::CallbackNumAtag(value) => value.encode_buf_mut(dst),
+            TlvValue::CallbackNumPresInd(value) => value.encode_buf_mut(dst),
+            TlvValue::CongestionState(value) => value.encode_buf_mut(dst),
+            TlvValue::DeliveryFailureReason(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrNpCountry(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrNpInformation(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrNpResolution(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrSubunit(value) => value.encode_buf_mut(dst),
+            TlvValue::DestBearerType(value) => value.encode_buf_mut(dst),
+            TlvValue::DestNetworkId(value) => value.encode_buf_mut(dst),
+            TlvValue::DestNetworkType(value) => value.encode_buf_mut(dst),
+            TlvValue::DestNodeId(value) => value.encode_buf_mut(dst),
+            TlvValue::DestSubaddress(value) => value.encode_buf_mut(dst),
+            TlvValue::DestTelematicsId(value) => value.encode_buf_mut(dst),
+            TlvValue::DestPort(value) => value.encode_buf_mut(dst),
+            TlvValue::DisplayTime(value) => value.encode_buf_mut(dst),
+            TlvValue::DpfResult(value) => value.encode_buf_mut(dst),
+            TlvValue::ItsReplyType(value) => value.encode_buf_mut(dst),
+            TlvValue::ItsSessionInfo(value) => value.encode_buf_mut(dst),
+            TlvValue::LanguageIndicator(value) => value.encode_buf_mut(dst),
+            TlvValue::MessagePayload(value) => value.encode_buf_mut(dst),
+            TlvValue::MessageState(value) => value.encode_buf_mut(dst),
+            TlvValue::MoreMessagesToSend(value) => value.encode_buf_mut(dst),
+            TlvValue::MsAvailabilityStatus(value) => value.encode_buf_mut(dst),
+            TlvValue::MsMsgWaitFacilities(value) => value.encode_buf_mut(dst),
+            TlvValue::MsValidity(value) => value.encode_buf_mut(dst),
+            TlvValue::NetworkErrorCode(value) => value.encode_buf_mut(dst),
+            TlvValue::NumberOfMessages(value) => value.encode_buf_mut(dst),
+            TlvValue::PayloadType(value) => value.encode_buf_mut(dst),
+            TlvValue::PrivacyIndicator(value) => value.encode_buf_mut(dst),
+            TlvValue::QosTimeToLive(value) => value.encode_buf_mut(dst),
+            TlvValue::ReceiptedMessageId(value) => value.encode_buf_mut(dst),
+            TlvValue::SarMsgRefNum(value) => value.encode_buf_mut(dst),
+            TlvValue::SarSegmentSeqnum(value) => value.encode_buf_mut(dst),
+            TlvValue::SarTotalSegments(value) => value.encode_buf_mut(dst),
+            TlvValue::ScInterfaceVersion(value) => value.encode_buf_mut(dst),
+            TlvValue::SetDpf(value) => value.encode_buf_mut(dst),
+            TlvValue::SmsSignal(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceAddrSubunit(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceBearerType(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceNetworkId(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceNetworkType(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceNodeId(value) => value.encode_buf_mut(dst),
+            TlvValue::SourcePort(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceSubaddress(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceTelematicsId(value) => value.encode_buf_mut(dst),
+            TlvValue::UserMessageReference(value) => value.encode_buf_mut(dst),
+            TlvValue::UserResponseCode(value) => value.encode_buf_mut(dst),
+            TlvValue::UssdServiceOp(value) => value.encode_buf_mut(dst),
+            TlvValue::Other { value, .. } => value.encode_buf_mut(dst),
+        }
+    }
+}
+
 impl<'a> DecodeWithKey<'a> for TlvValue<'a> {
     type Key = TlvTag;
 
@@ -1,5 +1,18 @@
 mod tlv;
 mod value;
+mod vendor;
 
 pub use tlv::*;
 pub use value::TlvValue;
+pub(crate) use vendor::vendor_value;
+pub use vendor::{VendorTlv, VendorTlvValue};
+
+/// Returns the value of the message_payload TLV, or an empty slice.
+pub(crate) fn message_payload(tlvs: &[Tlv]) -> &[u8] {
+    tlvs.iter()
+        .find_map(|tlv| match tlv.value() {
+            Some(TlvValue::MessagePayload(message_payload)) => Some(message_payload.value.bytes()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
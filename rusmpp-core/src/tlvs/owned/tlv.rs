@@ -2,7 +2,7 @@ use rusmpp_macros::Rusmpp;
 
 use crate::{
     encode::Length,
-    tlvs::{owned::TlvValue, tag::TlvTag},
+    tlvs::{borrowed, owned::TlvValue, tag::TlvTag},
 };
 
 mod broadcast_request;
@@ -31,10 +31,16 @@ pub use query_broadcast_response::*;
 
 /// See module level documentation.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct Tlv {
     tag: TlvTag,
     value_length: u16,
@@ -73,3 +79,31 @@ impl From<TlvValue> for Tlv {
         Self::new(value)
     }
 }
+
+impl From<borrowed::Tlv<'_>> for Tlv {
+    fn from(value: borrowed::Tlv<'_>) -> Self {
+        Self {
+            tag: value.tag,
+            value_length: value.value_length,
+            value: value.value.map(Into::into),
+        }
+    }
+}
+
+impl borrowed::Tlv<'_> {
+    /// Converts this borrowed [`Tlv`](borrowed::Tlv) into an owned [`Tlv`].
+    pub fn into_owned(self) -> Tlv {
+        self.into()
+    }
+}
+
+impl Tlv {
+    /// Returns a borrowed [`Tlv`](borrowed::Tlv) view of this [`Tlv`].
+    pub fn as_borrowed(&self) -> borrowed::Tlv<'_> {
+        borrowed::Tlv {
+            tag: self.tag,
+            value_length: self.value_length,
+            value: self.value.as_ref().map(TlvValue::as_borrowed),
+        }
+    }
+}
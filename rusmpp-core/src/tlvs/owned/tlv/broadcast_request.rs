@@ -2,7 +2,7 @@ use rusmpp_macros::TlvValue;
 
 use crate::{
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::{AnyOctetString, OctetString},
@@ -12,7 +12,10 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum BroadcastRequestTlvValue {
     BroadcastAreaIdentifier(BroadcastAreaIdentifier),
     BroadcastContentType(BroadcastContentType),
@@ -42,3 +45,167 @@ pub enum BroadcastRequestTlvValue {
     UserMessageReference(UserMessageReference),
     Other { tag: TlvTag, value: AnyOctetString },
 }
+
+impl From<borrowed::BroadcastRequestTlvValue<'_>> for BroadcastRequestTlvValue {
+    fn from(value: borrowed::BroadcastRequestTlvValue<'_>) -> Self {
+        match value {
+            borrowed::BroadcastRequestTlvValue::BroadcastAreaIdentifier(value) => {
+                Self::BroadcastAreaIdentifier(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::BroadcastContentType(value) => {
+                Self::BroadcastContentType(value)
+            }
+            borrowed::BroadcastRequestTlvValue::BroadcastFrequencyInterval(value) => {
+                Self::BroadcastFrequencyInterval(value)
+            }
+            borrowed::BroadcastRequestTlvValue::BroadcastRepNum(value) => {
+                Self::BroadcastRepNum(value)
+            }
+            borrowed::BroadcastRequestTlvValue::AlertOnMessageDelivery(value) => {
+                Self::AlertOnMessageDelivery(value)
+            }
+            borrowed::BroadcastRequestTlvValue::BroadcastChannelIndicator(value) => {
+                Self::BroadcastChannelIndicator(value)
+            }
+            borrowed::BroadcastRequestTlvValue::BroadcastContentTypeInfo(value) => {
+                Self::BroadcastContentTypeInfo(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::BroadcastMessageClass(value) => {
+                Self::BroadcastMessageClass(value)
+            }
+            borrowed::BroadcastRequestTlvValue::BroadcastServiceGroup(value) => {
+                Self::BroadcastServiceGroup(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::CallbackNum(value) => {
+                Self::CallbackNum(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::CallbackNumAtag(value) => {
+                Self::CallbackNumAtag(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::CallbackNumPresInd(value) => {
+                Self::CallbackNumPresInd(value)
+            }
+            borrowed::BroadcastRequestTlvValue::DestAddrSubunit(value) => {
+                Self::DestAddrSubunit(value)
+            }
+            borrowed::BroadcastRequestTlvValue::DestSubaddress(value) => {
+                Self::DestSubaddress(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::DestPort(value) => Self::DestPort(value),
+            borrowed::BroadcastRequestTlvValue::DisplayTime(value) => Self::DisplayTime(value),
+            borrowed::BroadcastRequestTlvValue::LanguageIndicator(value) => {
+                Self::LanguageIndicator(value)
+            }
+            borrowed::BroadcastRequestTlvValue::MessagePayload(value) => {
+                Self::MessagePayload(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::MsValidity(value) => Self::MsValidity(value),
+            borrowed::BroadcastRequestTlvValue::PayloadType(value) => Self::PayloadType(value),
+            borrowed::BroadcastRequestTlvValue::PrivacyIndicator(value) => {
+                Self::PrivacyIndicator(value)
+            }
+            borrowed::BroadcastRequestTlvValue::SmsSignal(value) => Self::SmsSignal(value),
+            borrowed::BroadcastRequestTlvValue::SourceAddrSubunit(value) => {
+                Self::SourceAddrSubunit(value)
+            }
+            borrowed::BroadcastRequestTlvValue::SourcePort(value) => Self::SourcePort(value),
+            borrowed::BroadcastRequestTlvValue::SourceSubaddress(value) => {
+                Self::SourceSubaddress(value.into())
+            }
+            borrowed::BroadcastRequestTlvValue::UserMessageReference(value) => {
+                Self::UserMessageReference(value)
+            }
+            borrowed::BroadcastRequestTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::BroadcastRequestTlvValue<'_> {
+    /// Converts this borrowed [`BroadcastRequestTlvValue`](borrowed::BroadcastRequestTlvValue) into an owned [`BroadcastRequestTlvValue`].
+    pub fn into_owned(self) -> BroadcastRequestTlvValue {
+        self.into()
+    }
+}
+
+impl BroadcastRequestTlvValue {
+    /// Returns a borrowed [`BroadcastRequestTlvValue`](borrowed::BroadcastRequestTlvValue) view of this [`BroadcastRequestTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::BroadcastRequestTlvValue<'_> {
+        match self {
+            Self::BroadcastAreaIdentifier(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastAreaIdentifier(value.as_borrowed())
+            }
+            Self::BroadcastContentType(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastContentType(*value)
+            }
+            Self::BroadcastFrequencyInterval(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastFrequencyInterval(*value)
+            }
+            Self::BroadcastRepNum(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastRepNum(*value)
+            }
+            Self::AlertOnMessageDelivery(value) => {
+                borrowed::BroadcastRequestTlvValue::AlertOnMessageDelivery(*value)
+            }
+            Self::BroadcastChannelIndicator(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastChannelIndicator(*value)
+            }
+            Self::BroadcastContentTypeInfo(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastContentTypeInfo(value.as_borrowed())
+            }
+            Self::BroadcastMessageClass(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastMessageClass(*value)
+            }
+            Self::BroadcastServiceGroup(value) => {
+                borrowed::BroadcastRequestTlvValue::BroadcastServiceGroup(value.as_borrowed())
+            }
+            Self::CallbackNum(value) => {
+                borrowed::BroadcastRequestTlvValue::CallbackNum(value.as_borrowed())
+            }
+            Self::CallbackNumAtag(value) => {
+                borrowed::BroadcastRequestTlvValue::CallbackNumAtag(value.as_borrowed())
+            }
+            Self::CallbackNumPresInd(value) => {
+                borrowed::BroadcastRequestTlvValue::CallbackNumPresInd(*value)
+            }
+            Self::DestAddrSubunit(value) => {
+                borrowed::BroadcastRequestTlvValue::DestAddrSubunit(*value)
+            }
+            Self::DestSubaddress(value) => {
+                borrowed::BroadcastRequestTlvValue::DestSubaddress(value.as_borrowed())
+            }
+            Self::DestPort(value) => borrowed::BroadcastRequestTlvValue::DestPort(*value),
+            Self::DisplayTime(value) => borrowed::BroadcastRequestTlvValue::DisplayTime(*value),
+            Self::LanguageIndicator(value) => {
+                borrowed::BroadcastRequestTlvValue::LanguageIndicator(*value)
+            }
+            Self::MessagePayload(value) => {
+                borrowed::BroadcastRequestTlvValue::MessagePayload(value.as_borrowed())
+            }
+            Self::MsValidity(value) => {
+                borrowed::BroadcastRequestTlvValue::MsValidity(value.clone())
+            }
+            Self::PayloadType(value) => borrowed::BroadcastRequestTlvValue::PayloadType(*value),
+            Self::PrivacyIndicator(value) => {
+                borrowed::BroadcastRequestTlvValue::PrivacyIndicator(*value)
+            }
+            Self::SmsSignal(value) => borrowed::BroadcastRequestTlvValue::SmsSignal(*value),
+            Self::SourceAddrSubunit(value) => {
+                borrowed::BroadcastRequestTlvValue::SourceAddrSubunit(*value)
+            }
+            Self::SourcePort(value) => borrowed::BroadcastRequestTlvValue::SourcePort(*value),
+            Self::SourceSubaddress(value) => {
+                borrowed::BroadcastRequestTlvValue::SourceSubaddress(value.as_borrowed())
+            }
+            Self::UserMessageReference(value) => {
+                borrowed::BroadcastRequestTlvValue::UserMessageReference(*value)
+            }
+            Self::Other { tag, value } => borrowed::BroadcastRequestTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
@@ -3,7 +3,7 @@ use rusmpp_macros::TlvValue;
 use crate::{
     CommandStatus,
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::AnyOctetString,
@@ -13,9 +13,54 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum BroadcastResponseTlvValue {
     BroadcastErrorStatus(CommandStatus),
     BroadcastAreaIdentifier(BroadcastAreaIdentifier),
     Other { tag: TlvTag, value: AnyOctetString },
 }
+
+impl From<borrowed::BroadcastResponseTlvValue<'_>> for BroadcastResponseTlvValue {
+    fn from(value: borrowed::BroadcastResponseTlvValue<'_>) -> Self {
+        match value {
+            borrowed::BroadcastResponseTlvValue::BroadcastErrorStatus(value) => {
+                Self::BroadcastErrorStatus(value)
+            }
+            borrowed::BroadcastResponseTlvValue::BroadcastAreaIdentifier(value) => {
+                Self::BroadcastAreaIdentifier(value.into())
+            }
+            borrowed::BroadcastResponseTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::BroadcastResponseTlvValue<'_> {
+    /// Converts this borrowed [`BroadcastResponseTlvValue`](borrowed::BroadcastResponseTlvValue) into an owned [`BroadcastResponseTlvValue`].
+    pub fn into_owned(self) -> BroadcastResponseTlvValue {
+        self.into()
+    }
+}
+
+impl BroadcastResponseTlvValue {
+    /// Returns a borrowed [`BroadcastResponseTlvValue`](borrowed::BroadcastResponseTlvValue) view of this [`BroadcastResponseTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::BroadcastResponseTlvValue<'_> {
+        match self {
+            Self::BroadcastErrorStatus(value) => {
+                borrowed::BroadcastResponseTlvValue::BroadcastErrorStatus(*value)
+            }
+            Self::BroadcastAreaIdentifier(value) => {
+                borrowed::BroadcastResponseTlvValue::BroadcastAreaIdentifier(value.as_borrowed())
+            }
+            Self::Other { tag, value } => borrowed::BroadcastResponseTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
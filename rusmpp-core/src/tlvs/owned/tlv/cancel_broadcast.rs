@@ -2,7 +2,7 @@ use rusmpp_macros::TlvValue;
 
 use crate::{
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::AnyOctetString,
@@ -12,7 +12,10 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum CancelBroadcastTlvValue {
     /// Specifies the content type of the message.
     BroadcastContentType(BroadcastContentType),
@@ -26,3 +29,45 @@ pub enum CancelBroadcastTlvValue {
         value: AnyOctetString,
     },
 }
+
+impl From<borrowed::CancelBroadcastTlvValue<'_>> for CancelBroadcastTlvValue {
+    fn from(value: borrowed::CancelBroadcastTlvValue<'_>) -> Self {
+        match value {
+            borrowed::CancelBroadcastTlvValue::BroadcastContentType(value) => {
+                Self::BroadcastContentType(value)
+            }
+            borrowed::CancelBroadcastTlvValue::UserMessageReference(value) => {
+                Self::UserMessageReference(value)
+            }
+            borrowed::CancelBroadcastTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::CancelBroadcastTlvValue<'_> {
+    /// Converts this borrowed [`CancelBroadcastTlvValue`](borrowed::CancelBroadcastTlvValue) into an owned [`CancelBroadcastTlvValue`].
+    pub fn into_owned(self) -> CancelBroadcastTlvValue {
+        self.into()
+    }
+}
+
+impl CancelBroadcastTlvValue {
+    /// Returns a borrowed [`CancelBroadcastTlvValue`](borrowed::CancelBroadcastTlvValue) view of this [`CancelBroadcastTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::CancelBroadcastTlvValue<'_> {
+        match self {
+            Self::BroadcastContentType(value) => {
+                borrowed::CancelBroadcastTlvValue::BroadcastContentType(*value)
+            }
+            Self::UserMessageReference(value) => {
+                borrowed::CancelBroadcastTlvValue::UserMessageReference(*value)
+            }
+            Self::Other { tag, value } => borrowed::CancelBroadcastTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
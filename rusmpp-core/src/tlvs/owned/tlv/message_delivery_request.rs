@@ -2,7 +2,7 @@ use rusmpp_macros::TlvValue;
 
 use crate::{
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::{AnyOctetString, COctetString, OctetString},
@@ -12,7 +12,10 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum MessageDeliveryRequestTlvValue {
     CallbackNum(OctetString<4, 19>),
     CallbackNumAtag(OctetString<0, 65>),
@@ -48,3 +51,213 @@ pub enum MessageDeliveryRequestTlvValue {
     UssdServiceOp(UssdServiceOp),
     Other { tag: TlvTag, value: AnyOctetString },
 }
+
+impl From<borrowed::MessageDeliveryRequestTlvValue<'_>> for MessageDeliveryRequestTlvValue {
+    fn from(value: borrowed::MessageDeliveryRequestTlvValue<'_>) -> Self {
+        match value {
+            borrowed::MessageDeliveryRequestTlvValue::CallbackNum(value) => {
+                Self::CallbackNum(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::CallbackNumAtag(value) => {
+                Self::CallbackNumAtag(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::CallbackNumPresInd(value) => {
+                Self::CallbackNumPresInd(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestAddrNpCountry(value) => {
+                Self::DestAddrNpCountry(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestAddrNpInformation(value) => {
+                Self::DestAddrNpInformation(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestAddrNpResolution(value) => {
+                Self::DestAddrNpResolution(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestAddrSubunit(value) => {
+                Self::DestAddrSubunit(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestNetworkId(value) => {
+                Self::DestNetworkId(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestNodeId(value) => {
+                Self::DestNodeId(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestSubaddress(value) => {
+                Self::DestSubaddress(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::DestPort(value) => Self::DestPort(value),
+            borrowed::MessageDeliveryRequestTlvValue::DpfResult(value) => Self::DpfResult(value),
+            borrowed::MessageDeliveryRequestTlvValue::ItsReplyType(value) => {
+                Self::ItsReplyType(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::ItsSessionInfo(value) => {
+                Self::ItsSessionInfo(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::LanguageIndicator(value) => {
+                Self::LanguageIndicator(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::MessagePayload(value) => {
+                Self::MessagePayload(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::MessageState(value) => {
+                Self::MessageState(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::NetworkErrorCode(value) => {
+                Self::NetworkErrorCode(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::PayloadType(value) => {
+                Self::PayloadType(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::PrivacyIndicator(value) => {
+                Self::PrivacyIndicator(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::ReceiptedMessageId(value) => {
+                Self::ReceiptedMessageId(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::SarMsgRefNum(value) => {
+                Self::SarMsgRefNum(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::SarSegmentSeqnum(value) => {
+                Self::SarSegmentSeqnum(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::SarTotalSegments(value) => {
+                Self::SarTotalSegments(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::SourceAddrSubunit(value) => {
+                Self::SourceAddrSubunit(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::SourceNetworkId(value) => {
+                Self::SourceNetworkId(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::SourceNodeId(value) => {
+                Self::SourceNodeId(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::SourcePort(value) => Self::SourcePort(value),
+            borrowed::MessageDeliveryRequestTlvValue::SourceSubaddress(value) => {
+                Self::SourceSubaddress(value.into())
+            }
+            borrowed::MessageDeliveryRequestTlvValue::UserMessageReference(value) => {
+                Self::UserMessageReference(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::UserResponseCode(value) => {
+                Self::UserResponseCode(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::UssdServiceOp(value) => {
+                Self::UssdServiceOp(value)
+            }
+            borrowed::MessageDeliveryRequestTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::MessageDeliveryRequestTlvValue<'_> {
+    /// Converts this borrowed [`MessageDeliveryRequestTlvValue`](borrowed::MessageDeliveryRequestTlvValue) into an owned [`MessageDeliveryRequestTlvValue`].
+    pub fn into_owned(self) -> MessageDeliveryRequestTlvValue {
+        self.into()
+    }
+}
+
+impl MessageDeliveryRequestTlvValue {
+    /// Returns a borrowed [`MessageDeliveryRequestTlvValue`](borrowed::MessageDeliveryRequestTlvValue) view of this [`MessageDeliveryRequestTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::MessageDeliveryRequestTlvValue<'_> {
+        match self {
+            Self::CallbackNum(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::CallbackNum(value.as_borrowed())
+            }
+            Self::CallbackNumAtag(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::CallbackNumAtag(value.as_borrowed())
+            }
+            Self::CallbackNumPresInd(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::CallbackNumPresInd(*value)
+            }
+            Self::DestAddrNpCountry(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::DestAddrNpCountry(value.as_borrowed())
+            }
+            Self::DestAddrNpInformation(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::DestAddrNpInformation(value.as_borrowed())
+            }
+            Self::DestAddrNpResolution(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::DestAddrNpResolution(*value)
+            }
+            Self::DestAddrSubunit(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::DestAddrSubunit(*value)
+            }
+            Self::DestNetworkId(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::DestNetworkId(value.as_borrowed())
+            }
+            Self::DestNodeId(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::DestNodeId(value.as_borrowed())
+            }
+            Self::DestSubaddress(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::DestSubaddress(value.as_borrowed())
+            }
+            Self::DestPort(value) => borrowed::MessageDeliveryRequestTlvValue::DestPort(*value),
+            Self::DpfResult(value) => borrowed::MessageDeliveryRequestTlvValue::DpfResult(*value),
+            Self::ItsReplyType(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::ItsReplyType(*value)
+            }
+            Self::ItsSessionInfo(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::ItsSessionInfo(*value)
+            }
+            Self::LanguageIndicator(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::LanguageIndicator(*value)
+            }
+            Self::MessagePayload(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::MessagePayload(value.as_borrowed())
+            }
+            Self::MessageState(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::MessageState(*value)
+            }
+            Self::NetworkErrorCode(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::NetworkErrorCode(value.clone())
+            }
+            Self::PayloadType(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::PayloadType(*value)
+            }
+            Self::PrivacyIndicator(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::PrivacyIndicator(*value)
+            }
+            Self::ReceiptedMessageId(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::ReceiptedMessageId(value.as_borrowed())
+            }
+            Self::SarMsgRefNum(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::SarMsgRefNum(*value)
+            }
+            Self::SarSegmentSeqnum(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::SarSegmentSeqnum(*value)
+            }
+            Self::SarTotalSegments(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::SarTotalSegments(*value)
+            }
+            Self::SourceAddrSubunit(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::SourceAddrSubunit(*value)
+            }
+            Self::SourceNetworkId(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::SourceNetworkId(value.as_borrowed())
+            }
+            Self::SourceNodeId(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::SourceNodeId(value.as_borrowed())
+            }
+            Self::SourcePort(value) => borrowed::MessageDeliveryRequestTlvValue::SourcePort(*value),
+            Self::SourceSubaddress(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::SourceSubaddress(value.as_borrowed())
+            }
+            Self::UserMessageReference(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::UserMessageReference(*value)
+            }
+            Self::UserResponseCode(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::UserResponseCode(*value)
+            }
+            Self::UssdServiceOp(value) => {
+                borrowed::MessageDeliveryRequestTlvValue::UssdServiceOp(*value)
+            }
+            Self::Other { tag, value } => borrowed::MessageDeliveryRequestTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
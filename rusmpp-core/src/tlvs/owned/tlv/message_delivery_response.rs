@@ -2,7 +2,7 @@ use rusmpp_macros::TlvValue;
 
 use crate::{
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::{AnyOctetString, COctetString},
@@ -12,10 +12,63 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum MessageDeliveryResponseTlvValue {
     AdditionalStatusInfoText(COctetString<1, 256>),
     DeliveryFailureReason(DeliveryFailureReason),
     NetworkErrorCode(NetworkErrorCode),
     Other { tag: TlvTag, value: AnyOctetString },
 }
+
+impl From<borrowed::MessageDeliveryResponseTlvValue<'_>> for MessageDeliveryResponseTlvValue {
+    fn from(value: borrowed::MessageDeliveryResponseTlvValue<'_>) -> Self {
+        match value {
+            borrowed::MessageDeliveryResponseTlvValue::AdditionalStatusInfoText(value) => {
+                Self::AdditionalStatusInfoText(value.into())
+            }
+            borrowed::MessageDeliveryResponseTlvValue::DeliveryFailureReason(value) => {
+                Self::DeliveryFailureReason(value)
+            }
+            borrowed::MessageDeliveryResponseTlvValue::NetworkErrorCode(value) => {
+                Self::NetworkErrorCode(value)
+            }
+            borrowed::MessageDeliveryResponseTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::MessageDeliveryResponseTlvValue<'_> {
+    /// Converts this borrowed [`MessageDeliveryResponseTlvValue`](borrowed::MessageDeliveryResponseTlvValue) into an owned [`MessageDeliveryResponseTlvValue`].
+    pub fn into_owned(self) -> MessageDeliveryResponseTlvValue {
+        self.into()
+    }
+}
+
+impl MessageDeliveryResponseTlvValue {
+    /// Returns a borrowed [`MessageDeliveryResponseTlvValue`](borrowed::MessageDeliveryResponseTlvValue) view of this [`MessageDeliveryResponseTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::MessageDeliveryResponseTlvValue<'_> {
+        match self {
+            Self::AdditionalStatusInfoText(value) => {
+                borrowed::MessageDeliveryResponseTlvValue::AdditionalStatusInfoText(
+                    value.as_borrowed(),
+                )
+            }
+            Self::DeliveryFailureReason(value) => {
+                borrowed::MessageDeliveryResponseTlvValue::DeliveryFailureReason(*value)
+            }
+            Self::NetworkErrorCode(value) => {
+                borrowed::MessageDeliveryResponseTlvValue::NetworkErrorCode(value.clone())
+            }
+            Self::Other { tag, value } => borrowed::MessageDeliveryResponseTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
@@ -2,7 +2,7 @@ use rusmpp_macros::TlvValue;
 
 use crate::{
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::{AnyOctetString, COctetString, OctetString},
@@ -12,7 +12,10 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum MessageSubmissionRequestTlvValue {
     AlertOnMessageDelivery(AlertOnMessageDelivery),
     BillingIdentification(OctetString<0, 1024>),
@@ -60,3 +63,289 @@ pub enum MessageSubmissionRequestTlvValue {
     UssdServiceOp(UssdServiceOp),
     Other { tag: TlvTag, value: AnyOctetString },
 }
+
+impl From<borrowed::MessageSubmissionRequestTlvValue<'_>> for MessageSubmissionRequestTlvValue {
+    fn from(value: borrowed::MessageSubmissionRequestTlvValue<'_>) -> Self {
+        match value {
+            borrowed::MessageSubmissionRequestTlvValue::AlertOnMessageDelivery(value) => {
+                Self::AlertOnMessageDelivery(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::BillingIdentification(value) => {
+                Self::BillingIdentification(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::CallbackNum(value) => {
+                Self::CallbackNum(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::CallbackNumAtag(value) => {
+                Self::CallbackNumAtag(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::CallbackNumPresInd(value) => {
+                Self::CallbackNumPresInd(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestAddrNpCountry(value) => {
+                Self::DestAddrNpCountry(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestAddrNpInformation(value) => {
+                Self::DestAddrNpInformation(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestAddrNpResolution(value) => {
+                Self::DestAddrNpResolution(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestAddrSubunit(value) => {
+                Self::DestAddrSubunit(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestBearerType(value) => {
+                Self::DestBearerType(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestNetworkId(value) => {
+                Self::DestNetworkId(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestNetworkType(value) => {
+                Self::DestNetworkType(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestNodeId(value) => {
+                Self::DestNodeId(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestSubaddress(value) => {
+                Self::DestSubaddress(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestTelematicsId(value) => {
+                Self::DestTelematicsId(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::DestPort(value) => Self::DestPort(value),
+            borrowed::MessageSubmissionRequestTlvValue::DisplayTime(value) => {
+                Self::DisplayTime(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::ItsReplyType(value) => {
+                Self::ItsReplyType(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::ItsSessionInfo(value) => {
+                Self::ItsSessionInfo(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::LanguageIndicator(value) => {
+                Self::LanguageIndicator(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::MessagePayload(value) => {
+                Self::MessagePayload(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::MoreMessagesToSend(value) => {
+                Self::MoreMessagesToSend(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::MsMsgWaitFacilities(value) => {
+                Self::MsMsgWaitFacilities(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::MsValidity(value) => {
+                Self::MsValidity(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::NumberOfMessages(value) => {
+                Self::NumberOfMessages(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::PayloadType(value) => {
+                Self::PayloadType(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::PrivacyIndicator(value) => {
+                Self::PrivacyIndicator(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::QosTimeToLive(value) => {
+                Self::QosTimeToLive(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SarMsgRefNum(value) => {
+                Self::SarMsgRefNum(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SarSegmentSeqnum(value) => {
+                Self::SarSegmentSeqnum(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SarTotalSegments(value) => {
+                Self::SarTotalSegments(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SetDpf(value) => Self::SetDpf(value),
+            borrowed::MessageSubmissionRequestTlvValue::SmsSignal(value) => Self::SmsSignal(value),
+            borrowed::MessageSubmissionRequestTlvValue::SourceAddrSubunit(value) => {
+                Self::SourceAddrSubunit(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SourceBearerType(value) => {
+                Self::SourceBearerType(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SourceNetworkId(value) => {
+                Self::SourceNetworkId(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SourceNetworkType(value) => {
+                Self::SourceNetworkType(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SourceNodeId(value) => {
+                Self::SourceNodeId(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SourcePort(value) => {
+                Self::SourcePort(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SourceSubaddress(value) => {
+                Self::SourceSubaddress(value.into())
+            }
+            borrowed::MessageSubmissionRequestTlvValue::SourceTelematicsId(value) => {
+                Self::SourceTelematicsId(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::UserMessageReference(value) => {
+                Self::UserMessageReference(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::UserResponseCode(value) => {
+                Self::UserResponseCode(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::UssdServiceOp(value) => {
+                Self::UssdServiceOp(value)
+            }
+            borrowed::MessageSubmissionRequestTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::MessageSubmissionRequestTlvValue<'_> {
+    /// Converts this borrowed [`MessageSubmissionRequestTlvValue`](borrowed::MessageSubmissionRequestTlvValue) into an owned [`MessageSubmissionRequestTlvValue`].
+    pub fn into_owned(self) -> MessageSubmissionRequestTlvValue {
+        self.into()
+    }
+}
+
+impl MessageSubmissionRequestTlvValue {
+    /// Returns a borrowed [`MessageSubmissionRequestTlvValue`](borrowed::MessageSubmissionRequestTlvValue) view of this [`MessageSubmissionRequestTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::MessageSubmissionRequestTlvValue<'_> {
+        match self {
+            Self::AlertOnMessageDelivery(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::AlertOnMessageDelivery(*value)
+            }
+            Self::BillingIdentification(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::BillingIdentification(
+                    value.as_borrowed(),
+                )
+            }
+            Self::CallbackNum(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::CallbackNum(value.as_borrowed())
+            }
+            Self::CallbackNumAtag(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::CallbackNumAtag(value.as_borrowed())
+            }
+            Self::CallbackNumPresInd(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::CallbackNumPresInd(*value)
+            }
+            Self::DestAddrNpCountry(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestAddrNpCountry(value.as_borrowed())
+            }
+            Self::DestAddrNpInformation(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestAddrNpInformation(
+                    value.as_borrowed(),
+                )
+            }
+            Self::DestAddrNpResolution(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestAddrNpResolution(*value)
+            }
+            Self::DestAddrSubunit(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestAddrSubunit(*value)
+            }
+            Self::DestBearerType(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestBearerType(*value)
+            }
+            Self::DestNetworkId(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestNetworkId(value.as_borrowed())
+            }
+            Self::DestNetworkType(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestNetworkType(*value)
+            }
+            Self::DestNodeId(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestNodeId(value.as_borrowed())
+            }
+            Self::DestSubaddress(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestSubaddress(value.as_borrowed())
+            }
+            Self::DestTelematicsId(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DestTelematicsId(*value)
+            }
+            Self::DestPort(value) => borrowed::MessageSubmissionRequestTlvValue::DestPort(*value),
+            Self::DisplayTime(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::DisplayTime(*value)
+            }
+            Self::ItsReplyType(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::ItsReplyType(*value)
+            }
+            Self::ItsSessionInfo(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::ItsSessionInfo(*value)
+            }
+            Self::LanguageIndicator(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::LanguageIndicator(*value)
+            }
+            Self::MessagePayload(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::MessagePayload(value.as_borrowed())
+            }
+            Self::MoreMessagesToSend(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::MoreMessagesToSend(*value)
+            }
+            Self::MsMsgWaitFacilities(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::MsMsgWaitFacilities(*value)
+            }
+            Self::MsValidity(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::MsValidity(value.clone())
+            }
+            Self::NumberOfMessages(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::NumberOfMessages(*value)
+            }
+            Self::PayloadType(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::PayloadType(*value)
+            }
+            Self::PrivacyIndicator(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::PrivacyIndicator(*value)
+            }
+            Self::QosTimeToLive(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::QosTimeToLive(*value)
+            }
+            Self::SarMsgRefNum(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SarMsgRefNum(*value)
+            }
+            Self::SarSegmentSeqnum(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SarSegmentSeqnum(*value)
+            }
+            Self::SarTotalSegments(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SarTotalSegments(*value)
+            }
+            Self::SetDpf(value) => borrowed::MessageSubmissionRequestTlvValue::SetDpf(*value),
+            Self::SmsSignal(value) => borrowed::MessageSubmissionRequestTlvValue::SmsSignal(*value),
+            Self::SourceAddrSubunit(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourceAddrSubunit(*value)
+            }
+            Self::SourceBearerType(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourceBearerType(*value)
+            }
+            Self::SourceNetworkId(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourceNetworkId(value.as_borrowed())
+            }
+            Self::SourceNetworkType(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourceNetworkType(*value)
+            }
+            Self::SourceNodeId(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourceNodeId(value.as_borrowed())
+            }
+            Self::SourcePort(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourcePort(*value)
+            }
+            Self::SourceSubaddress(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourceSubaddress(value.as_borrowed())
+            }
+            Self::SourceTelematicsId(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::SourceTelematicsId(*value)
+            }
+            Self::UserMessageReference(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::UserMessageReference(*value)
+            }
+            Self::UserResponseCode(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::UserResponseCode(*value)
+            }
+            Self::UssdServiceOp(value) => {
+                borrowed::MessageSubmissionRequestTlvValue::UssdServiceOp(*value)
+            }
+            Self::Other { tag, value } => borrowed::MessageSubmissionRequestTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
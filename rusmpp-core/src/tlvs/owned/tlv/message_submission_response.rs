@@ -2,7 +2,7 @@ use rusmpp_macros::TlvValue;
 
 use crate::{
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::{AnyOctetString, COctetString},
@@ -12,7 +12,10 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum MessageSubmissionResponseTlvValue {
     AdditionalStatusInfoText(COctetString<1, 256>),
     DeliveryFailureReason(DeliveryFailureReason),
@@ -20,3 +23,57 @@ pub enum MessageSubmissionResponseTlvValue {
     NetworkErrorCode(NetworkErrorCode),
     Other { tag: TlvTag, value: AnyOctetString },
 }
+
+impl From<borrowed::MessageSubmissionResponseTlvValue<'_>> for MessageSubmissionResponseTlvValue {
+    fn from(value: borrowed::MessageSubmissionResponseTlvValue<'_>) -> Self {
+        match value {
+            borrowed::MessageSubmissionResponseTlvValue::AdditionalStatusInfoText(value) => {
+                Self::AdditionalStatusInfoText(value.into())
+            }
+            borrowed::MessageSubmissionResponseTlvValue::DeliveryFailureReason(value) => {
+                Self::DeliveryFailureReason(value)
+            }
+            borrowed::MessageSubmissionResponseTlvValue::DpfResult(value) => Self::DpfResult(value),
+            borrowed::MessageSubmissionResponseTlvValue::NetworkErrorCode(value) => {
+                Self::NetworkErrorCode(value)
+            }
+            borrowed::MessageSubmissionResponseTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::MessageSubmissionResponseTlvValue<'_> {
+    /// Converts this borrowed [`MessageSubmissionResponseTlvValue`](borrowed::MessageSubmissionResponseTlvValue) into an owned [`MessageSubmissionResponseTlvValue`].
+    pub fn into_owned(self) -> MessageSubmissionResponseTlvValue {
+        self.into()
+    }
+}
+
+impl MessageSubmissionResponseTlvValue {
+    /// Returns a borrowed [`MessageSubmissionResponseTlvValue`](borrowed::MessageSubmissionResponseTlvValue) view of this [`MessageSubmissionResponseTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::MessageSubmissionResponseTlvValue<'_> {
+        match self {
+            Self::AdditionalStatusInfoText(value) => {
+                borrowed::MessageSubmissionResponseTlvValue::AdditionalStatusInfoText(
+                    value.as_borrowed(),
+                )
+            }
+            Self::DeliveryFailureReason(value) => {
+                borrowed::MessageSubmissionResponseTlvValue::DeliveryFailureReason(*value)
+            }
+            Self::DpfResult(value) => {
+                borrowed::MessageSubmissionResponseTlvValue::DpfResult(*value)
+            }
+            Self::NetworkErrorCode(value) => {
+                borrowed::MessageSubmissionResponseTlvValue::NetworkErrorCode(value.clone())
+            }
+            Self::Other { tag, value } => borrowed::MessageSubmissionResponseTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
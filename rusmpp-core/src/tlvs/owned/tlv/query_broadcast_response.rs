@@ -2,7 +2,7 @@ use rusmpp_macros::TlvValue;
 
 use crate::{
     tlvs::{
-        TlvTag,
+        TlvTag, borrowed,
         owned::{Tlv, TlvValue},
     },
     types::owned::{AnyOctetString, OctetString},
@@ -12,7 +12,10 @@ use crate::{
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum QueryBroadcastResponseTlvValue {
     MessageState(MessageState),
     BroadcastAreaIdentifier(BroadcastAreaIdentifier),
@@ -21,3 +24,65 @@ pub enum QueryBroadcastResponseTlvValue {
     UserMessageReference(UserMessageReference),
     Other { tag: TlvTag, value: AnyOctetString },
 }
+
+impl From<borrowed::QueryBroadcastResponseTlvValue<'_>> for QueryBroadcastResponseTlvValue {
+    fn from(value: borrowed::QueryBroadcastResponseTlvValue<'_>) -> Self {
+        match value {
+            borrowed::QueryBroadcastResponseTlvValue::MessageState(value) => {
+                Self::MessageState(value)
+            }
+            borrowed::QueryBroadcastResponseTlvValue::BroadcastAreaIdentifier(value) => {
+                Self::BroadcastAreaIdentifier(value.into())
+            }
+            borrowed::QueryBroadcastResponseTlvValue::BroadcastAreaSuccess(value) => {
+                Self::BroadcastAreaSuccess(value)
+            }
+            borrowed::QueryBroadcastResponseTlvValue::BroadcastEndTime(value) => {
+                Self::BroadcastEndTime(value.into())
+            }
+            borrowed::QueryBroadcastResponseTlvValue::UserMessageReference(value) => {
+                Self::UserMessageReference(value)
+            }
+            borrowed::QueryBroadcastResponseTlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::QueryBroadcastResponseTlvValue<'_> {
+    /// Converts this borrowed [`QueryBroadcastResponseTlvValue`](borrowed::QueryBroadcastResponseTlvValue) into an owned [`QueryBroadcastResponseTlvValue`].
+    pub fn into_owned(self) -> QueryBroadcastResponseTlvValue {
+        self.into()
+    }
+}
+
+impl QueryBroadcastResponseTlvValue {
+    /// Returns a borrowed [`QueryBroadcastResponseTlvValue`](borrowed::QueryBroadcastResponseTlvValue) view of this [`QueryBroadcastResponseTlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::QueryBroadcastResponseTlvValue<'_> {
+        match self {
+            Self::MessageState(value) => {
+                borrowed::QueryBroadcastResponseTlvValue::MessageState(*value)
+            }
+            Self::BroadcastAreaIdentifier(value) => {
+                borrowed::QueryBroadcastResponseTlvValue::BroadcastAreaIdentifier(
+                    value.as_borrowed(),
+                )
+            }
+            Self::BroadcastAreaSuccess(value) => {
+                borrowed::QueryBroadcastResponseTlvValue::BroadcastAreaSuccess(*value)
+            }
+            Self::BroadcastEndTime(value) => {
+                borrowed::QueryBroadcastResponseTlvValue::BroadcastEndTime(value.as_borrowed())
+            }
+            Self::UserMessageReference(value) => {
+                borrowed::QueryBroadcastResponseTlvValue::UserMessageReference(*value)
+            }
+            Self::Other { tag, value } => borrowed::QueryBroadcastResponseTlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
@@ -5,17 +5,23 @@ use crate::{
         owned::{Decode, DecodeWithKey, DecodeWithLength},
     },
     encode::{Encode, Length},
-    tlvs::TlvTag,
+    tlvs::{TlvTag, borrowed},
     types::owned::{AnyOctetString, COctetString, OctetString},
     values::{owned::*, *},
 };
 
+#[cfg(feature = "tokio-codec")]
+use crate::encode::EncodeBytesMut;
+
 /// See module level documentation.
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum TlvValue {
     AdditionalStatusInfoText(COctetString<1, 256>),
     AlertOnMessageDelivery(AlertOnMessageDelivery),
@@ -334,6 +340,79 @@ impl Encode for TlvValue {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl EncodeBytesMut for TlvValue {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        match self {
+            TlvValue::AdditionalStatusInfoText(value) => value.encode_buf_mut(dst),
+            TlvValue::AlertOnMessageDelivery(value) => value.encode_buf_mut(dst),
+            TlvValue::BillingIdentification(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastAreaIdentifier(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastAreaSuccess(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastContentTypeInfo(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastChannelIndicator(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastContentType(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastEndTime(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastErrorStatus(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastFrequencyInterval(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastMessageClass(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastRepNum(value) => value.encode_buf_mut(dst),
+            TlvValue::BroadcastServiceGroup(value) => value.encode_buf_mut(dst),
+            TlvValue::CallbackNum(value) => value.encode_buf_mut(dst),
+            TlvValue::CallbackNumAtag(value) => value.encode_buf_mut(dst),
+            TlvValue::CallbackNumPresInd(value) => value.encode_buf_mut(dst),
+            TlvValue::CongestionState(value) => value.encode_buf_mut(dst),
+            TlvValue::DeliveryFailureReason(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrNpCountry(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrNpInformation(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrNpResolution(value) => value.encode_buf_mut(dst),
+            TlvValue::DestAddrSubunit(value) => value.encode_buf_mut(dst),
+            TlvValue::DestBearerType(value) => value.encode_buf_mut(dst),
+            TlvValue::DestNetworkId(value) => value.encode_buf_mut(dst),
+            TlvValue::DestNetworkType(value) => value.encode_buf_mut(dst),
+            TlvValue::DestNodeId(value) => value.encode_buf_mut(dst),
+            TlvValue::DestSubaddress(value) => value.encode_buf_mut(dst),
+            TlvValue::DestTelematicsId(value) => value.encode_buf_mut(dst),
+            TlvValue::DestPort(value) => value.encode_buf_mut(dst),
+            TlvValue::DisplayTime(value) => value.encode_buf_mut(dst),
+            TlvValue::DpfResult(value) => value.encode_buf_mut(dst),
+            TlvValue::ItsReplyType(value) => value.encode_buf_mut(dst),
+            TlvValue::ItsSessionInfo(value) => value.encode_buf_mut(dst),
+            TlvValue::LanguageIndicator(value) => value.encode_buf_mut(dst),
+            TlvValue::MessagePayload(value) => value.encode_buf_mut(dst),
+            TlvValue::MessageState(value) => value.encode_buf_mut(dst),
+            TlvValue::MoreMessagesToSend(value) => value.encode_buf_mut(dst),
+            TlvValue::MsAvailabilityStatus(value) => value.encode_buf_mut(dst),
+            TlvValue::MsMsgWaitFacilities(value) => value.encode_buf_mut(dst),
+            TlvValue::MsValidity(value) => value.encode_buf_mut(dst),
+            TlvValue::NetworkErrorCode(value) => value.encode_buf_mut(dst),
+            TlvValue::NumberOfMessages(value) => value.encode_buf_mut(dst),
+            TlvValue::PayloadType(value) => value.encode_buf_mut(dst),
+            TlvValue::PrivacyIndicator(value) => value.encode_buf_mut(dst),
+            TlvValue::QosTimeToLive(value) => value.encode_buf_mut(dst),
+            TlvValue::ReceiptedMessageId(value) => value.encode_buf_mut(dst),
+            TlvValue::SarMsgRefNum(value) => value.encode_buf_mut(dst),
+            TlvValue::SarSegmentSeqnum(value) => value.encode_buf_mut(dst),
+            TlvValue::SarTotalSegments(value) => value.encode_buf_mut(dst),
+            TlvValue::ScInterfaceVersion(value) => value.encode_buf_mut(dst),
+            TlvValue::SetDpf(value) => value.encode_buf_mut(dst),
+            TlvValue::SmsSignal(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceAddrSubunit(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceBearerType(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceNetworkId(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceNetworkType(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceNodeId(value) => value.encode_buf_mut(dst),
+            TlvValue::SourcePort(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceSubaddress(value) => value.encode_buf_mut(dst),
+            TlvValue::SourceTelematicsId(value) => value.encode_buf_mut(dst),
+            TlvValue::UserMessageReference(value) => value.encode_buf_mut(dst),
+            TlvValue::UserResponseCode(value) => value.encode_buf_mut(dst),
+            TlvValue::UssdServiceOp(value) => value.encode_buf_mut(dst),
+            TlvValue::Other { value, .. } => value.encode_buf_mut(dst),
+        }
+    }
+}
+
 impl DecodeWithKey for TlvValue {
     type Key = TlvTag;
 
@@ -486,3 +565,209 @@ impl DecodeWithKey for TlvValue {
         Ok((value, size))
     }
 }
+
+impl From<borrowed::TlvValue<'_>> for TlvValue {
+    fn from(value: borrowed::TlvValue<'_>) -> Self {
+        match value {
+            borrowed::TlvValue::AdditionalStatusInfoText(value) => {
+                Self::AdditionalStatusInfoText(value.into())
+            }
+            borrowed::TlvValue::AlertOnMessageDelivery(value) => {
+                Self::AlertOnMessageDelivery(value)
+            }
+            borrowed::TlvValue::BillingIdentification(value) => {
+                Self::BillingIdentification(value.into())
+            }
+            borrowed::TlvValue::BroadcastAreaIdentifier(value) => {
+                Self::BroadcastAreaIdentifier(value.into())
+            }
+            borrowed::TlvValue::BroadcastAreaSuccess(value) => Self::BroadcastAreaSuccess(value),
+            borrowed::TlvValue::BroadcastContentTypeInfo(value) => {
+                Self::BroadcastContentTypeInfo(value.into())
+            }
+            borrowed::TlvValue::BroadcastChannelIndicator(value) => {
+                Self::BroadcastChannelIndicator(value)
+            }
+            borrowed::TlvValue::BroadcastContentType(value) => Self::BroadcastContentType(value),
+            borrowed::TlvValue::BroadcastEndTime(value) => Self::BroadcastEndTime(value.into()),
+            borrowed::TlvValue::BroadcastErrorStatus(value) => Self::BroadcastErrorStatus(value),
+            borrowed::TlvValue::BroadcastFrequencyInterval(value) => {
+                Self::BroadcastFrequencyInterval(value)
+            }
+            borrowed::TlvValue::BroadcastMessageClass(value) => Self::BroadcastMessageClass(value),
+            borrowed::TlvValue::BroadcastRepNum(value) => Self::BroadcastRepNum(value),
+            borrowed::TlvValue::BroadcastServiceGroup(value) => {
+                Self::BroadcastServiceGroup(value.into())
+            }
+            borrowed::TlvValue::CallbackNum(value) => Self::CallbackNum(value.into()),
+            borrowed::TlvValue::CallbackNumAtag(value) => Self::CallbackNumAtag(value.into()),
+            borrowed::TlvValue::CallbackNumPresInd(value) => Self::CallbackNumPresInd(value),
+            borrowed::TlvValue::CongestionState(value) => Self::CongestionState(value),
+            borrowed::TlvValue::DeliveryFailureReason(value) => Self::DeliveryFailureReason(value),
+            borrowed::TlvValue::DestAddrNpCountry(value) => Self::DestAddrNpCountry(value.into()),
+            borrowed::TlvValue::DestAddrNpInformation(value) => {
+                Self::DestAddrNpInformation(value.into())
+            }
+            borrowed::TlvValue::DestAddrNpResolution(value) => Self::DestAddrNpResolution(value),
+            borrowed::TlvValue::DestAddrSubunit(value) => Self::DestAddrSubunit(value),
+            borrowed::TlvValue::DestBearerType(value) => Self::DestBearerType(value),
+            borrowed::TlvValue::DestNetworkId(value) => Self::DestNetworkId(value.into()),
+            borrowed::TlvValue::DestNetworkType(value) => Self::DestNetworkType(value),
+            borrowed::TlvValue::DestNodeId(value) => Self::DestNodeId(value.into()),
+            borrowed::TlvValue::DestSubaddress(value) => Self::DestSubaddress(value.into()),
+            borrowed::TlvValue::DestTelematicsId(value) => Self::DestTelematicsId(value),
+            borrowed::TlvValue::DestPort(value) => Self::DestPort(value),
+            borrowed::TlvValue::DisplayTime(value) => Self::DisplayTime(value),
+            borrowed::TlvValue::DpfResult(value) => Self::DpfResult(value),
+            borrowed::TlvValue::ItsReplyType(value) => Self::ItsReplyType(value),
+            borrowed::TlvValue::ItsSessionInfo(value) => Self::ItsSessionInfo(value),
+            borrowed::TlvValue::LanguageIndicator(value) => Self::LanguageIndicator(value),
+            borrowed::TlvValue::MessagePayload(value) => Self::MessagePayload(value.into()),
+            borrowed::TlvValue::MessageState(value) => Self::MessageState(value),
+            borrowed::TlvValue::MoreMessagesToSend(value) => Self::MoreMessagesToSend(value),
+            borrowed::TlvValue::MsAvailabilityStatus(value) => Self::MsAvailabilityStatus(value),
+            borrowed::TlvValue::MsMsgWaitFacilities(value) => Self::MsMsgWaitFacilities(value),
+            borrowed::TlvValue::MsValidity(value) => Self::MsValidity(value),
+            borrowed::TlvValue::NetworkErrorCode(value) => Self::NetworkErrorCode(value),
+            borrowed::TlvValue::NumberOfMessages(value) => Self::NumberOfMessages(value),
+            borrowed::TlvValue::PayloadType(value) => Self::PayloadType(value),
+            borrowed::TlvValue::PrivacyIndicator(value) => Self::PrivacyIndicator(value),
+            borrowed::TlvValue::QosTimeToLive(value) => Self::QosTimeToLive(value),
+            borrowed::TlvValue::ReceiptedMessageId(value) => Self::ReceiptedMessageId(value.into()),
+            borrowed::TlvValue::SarMsgRefNum(value) => Self::SarMsgRefNum(value),
+            borrowed::TlvValue::SarSegmentSeqnum(value) => Self::SarSegmentSeqnum(value),
+            borrowed::TlvValue::SarTotalSegments(value) => Self::SarTotalSegments(value),
+            borrowed::TlvValue::ScInterfaceVersion(value) => Self::ScInterfaceVersion(value),
+            borrowed::TlvValue::SetDpf(value) => Self::SetDpf(value),
+            borrowed::TlvValue::SmsSignal(value) => Self::SmsSignal(value),
+            borrowed::TlvValue::SourceAddrSubunit(value) => Self::SourceAddrSubunit(value),
+            borrowed::TlvValue::SourceBearerType(value) => Self::SourceBearerType(value),
+            borrowed::TlvValue::SourceNetworkId(value) => Self::SourceNetworkId(value.into()),
+            borrowed::TlvValue::SourceNetworkType(value) => Self::SourceNetworkType(value),
+            borrowed::TlvValue::SourceNodeId(value) => Self::SourceNodeId(value.into()),
+            borrowed::TlvValue::SourcePort(value) => Self::SourcePort(value),
+            borrowed::TlvValue::SourceSubaddress(value) => Self::SourceSubaddress(value.into()),
+            borrowed::TlvValue::SourceTelematicsId(value) => Self::SourceTelematicsId(value),
+            borrowed::TlvValue::UserMessageReference(value) => Self::UserMessageReference(value),
+            borrowed::TlvValue::UserResponseCode(value) => Self::UserResponseCode(value),
+            borrowed::TlvValue::UssdServiceOp(value) => Self::UssdServiceOp(value),
+            borrowed::TlvValue::Other { tag, value } => Self::Other {
+                tag,
+                value: value.into(),
+            },
+        }
+    }
+}
+
+impl borrowed::TlvValue<'_> {
+    /// Converts this borrowed [`TlvValue`](borrowed::TlvValue) into an owned [`TlvValue`].
+    pub fn into_owned(self) -> TlvValue {
+        self.into()
+    }
+}
+
+impl TlvValue {
+    /// Returns a borrowed [`TlvValue`](borrowed::TlvValue) view of this [`TlvValue`].
+    pub fn as_borrowed(&self) -> borrowed::TlvValue<'_> {
+        match self {
+            Self::AdditionalStatusInfoText(value) => {
+                borrowed::TlvValue::AdditionalStatusInfoText(value.as_borrowed())
+            }
+            Self::AlertOnMessageDelivery(value) => {
+                borrowed::TlvValue::AlertOnMessageDelivery(*value)
+            }
+            Self::BillingIdentification(value) => {
+                borrowed::TlvValue::BillingIdentification(value.as_borrowed())
+            }
+            Self::BroadcastAreaIdentifier(value) => {
+                borrowed::TlvValue::BroadcastAreaIdentifier(value.as_borrowed())
+            }
+            Self::BroadcastAreaSuccess(value) => borrowed::TlvValue::BroadcastAreaSuccess(*value),
+            Self::BroadcastContentTypeInfo(value) => {
+                borrowed::TlvValue::BroadcastContentTypeInfo(value.as_borrowed())
+            }
+            Self::BroadcastChannelIndicator(value) => {
+                borrowed::TlvValue::BroadcastChannelIndicator(*value)
+            }
+            Self::BroadcastContentType(value) => borrowed::TlvValue::BroadcastContentType(*value),
+            Self::BroadcastEndTime(value) => {
+                borrowed::TlvValue::BroadcastEndTime(value.as_borrowed())
+            }
+            Self::BroadcastErrorStatus(value) => borrowed::TlvValue::BroadcastErrorStatus(*value),
+            Self::BroadcastFrequencyInterval(value) => {
+                borrowed::TlvValue::BroadcastFrequencyInterval(*value)
+            }
+            Self::BroadcastMessageClass(value) => borrowed::TlvValue::BroadcastMessageClass(*value),
+            Self::BroadcastRepNum(value) => borrowed::TlvValue::BroadcastRepNum(*value),
+            Self::BroadcastServiceGroup(value) => {
+                borrowed::TlvValue::BroadcastServiceGroup(value.as_borrowed())
+            }
+            Self::CallbackNum(value) => borrowed::TlvValue::CallbackNum(value.as_borrowed()),
+            Self::CallbackNumAtag(value) => {
+                borrowed::TlvValue::CallbackNumAtag(value.as_borrowed())
+            }
+            Self::CallbackNumPresInd(value) => borrowed::TlvValue::CallbackNumPresInd(*value),
+            Self::CongestionState(value) => borrowed::TlvValue::CongestionState(*value),
+            Self::DeliveryFailureReason(value) => borrowed::TlvValue::DeliveryFailureReason(*value),
+            Self::DestAddrNpCountry(value) => {
+                borrowed::TlvValue::DestAddrNpCountry(value.as_borrowed())
+            }
+            Self::DestAddrNpInformation(value) => {
+                borrowed::TlvValue::DestAddrNpInformation(value.as_borrowed())
+            }
+            Self::DestAddrNpResolution(value) => borrowed::TlvValue::DestAddrNpResolution(*value),
+            Self::DestAddrSubunit(value) => borrowed::TlvValue::DestAddrSubunit(*value),
+            Self::DestBearerType(value) => borrowed::TlvValue::DestBearerType(*value),
+            Self::DestNetworkId(value) => borrowed::TlvValue::DestNetworkId(value.as_borrowed()),
+            Self::DestNetworkType(value) => borrowed::TlvValue::DestNetworkType(*value),
+            Self::DestNodeId(value) => borrowed::TlvValue::DestNodeId(value.as_borrowed()),
+            Self::DestSubaddress(value) => borrowed::TlvValue::DestSubaddress(value.as_borrowed()),
+            Self::DestTelematicsId(value) => borrowed::TlvValue::DestTelematicsId(*value),
+            Self::DestPort(value) => borrowed::TlvValue::DestPort(*value),
+            Self::DisplayTime(value) => borrowed::TlvValue::DisplayTime(*value),
+            Self::DpfResult(value) => borrowed::TlvValue::DpfResult(*value),
+            Self::ItsReplyType(value) => borrowed::TlvValue::ItsReplyType(*value),
+            Self::ItsSessionInfo(value) => borrowed::TlvValue::ItsSessionInfo(*value),
+            Self::LanguageIndicator(value) => borrowed::TlvValue::LanguageIndicator(*value),
+            Self::MessagePayload(value) => borrowed::TlvValue::MessagePayload(value.as_borrowed()),
+            Self::MessageState(value) => borrowed::TlvValue::MessageState(*value),
+            Self::MoreMessagesToSend(value) => borrowed::TlvValue::MoreMessagesToSend(*value),
+            Self::MsAvailabilityStatus(value) => borrowed::TlvValue::MsAvailabilityStatus(*value),
+            Self::MsMsgWaitFacilities(value) => borrowed::TlvValue::MsMsgWaitFacilities(*value),
+            Self::MsValidity(value) => borrowed::TlvValue::MsValidity(value.clone()),
+            Self::NetworkErrorCode(value) => borrowed::TlvValue::NetworkErrorCode(value.clone()),
+            Self::NumberOfMessages(value) => borrowed::TlvValue::NumberOfMessages(*value),
+            Self::PayloadType(value) => borrowed::TlvValue::PayloadType(*value),
+            Self::PrivacyIndicator(value) => borrowed::TlvValue::PrivacyIndicator(*value),
+            Self::QosTimeToLive(value) => borrowed::TlvValue::QosTimeToLive(*value),
+            Self::ReceiptedMessageId(value) => {
+                borrowed::TlvValue::ReceiptedMessageId(value.as_borrowed())
+            }
+            Self::SarMsgRefNum(value) => borrowed::TlvValue::SarMsgRefNum(*value),
+            Self::SarSegmentSeqnum(value) => borrowed::TlvValue::SarSegmentSeqnum(*value),
+            Self::SarTotalSegments(value) => borrowed::TlvValue::SarTotalSegments(*value),
+            Self::ScInterfaceVersion(value) => borrowed::TlvValue::ScInterfaceVersion(*value),
+            Self::SetDpf(value) => borrowed::TlvValue::SetDpf(*value),
+            Self::SmsSignal(value) => borrowed::TlvValue::SmsSignal(*value),
+            Self::SourceAddrSubunit(value) => borrowed::TlvValue::SourceAddrSubunit(*value),
+            Self::SourceBearerType(value) => borrowed::TlvValue::SourceBearerType(*value),
+            Self::SourceNetworkId(value) => {
+                borrowed::TlvValue::SourceNetworkId(value.as_borrowed())
+            }
+            Self::SourceNetworkType(value) => borrowed::TlvValue::SourceNetworkType(*value),
+            Self::SourceNodeId(value) => borrowed::TlvValue::SourceNodeId(value.as_borrowed()),
+            Self::SourcePort(value) => borrowed::TlvValue::SourcePort(*value),
+            Self::SourceSubaddress(value) => {
+                borrowed::TlvValue::SourceSubaddress(value.as_borrowed())
+            }
+            Self::SourceTelematicsId(value) => borrowed::TlvValue::SourceTelematicsId(*value),
+            Self::UserMessageReference(value) => borrowed::TlvValue::UserMessageReference(*value),
+            Self::UserResponseCode(value) => borrowed::TlvValue::UserResponseCode(*value),
+            Self::UssdServiceOp(value) => borrowed::TlvValue::UssdServiceOp(*value),
+            Self::Other { tag, value } => borrowed::TlvValue::Other {
+                tag: *tag,
+                value: value.as_borrowed(),
+            },
+        }
+    }
+}
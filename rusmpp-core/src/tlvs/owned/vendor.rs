@@ -0,0 +1,379 @@
+use crate::{
+    decode::{DecodeError, owned::DecodeWithLength},
+    encode::Encode,
+    tlvs::{
+        TlvTag,
+        owned::{
+            BroadcastRequestTlvValue, BroadcastResponseTlvValue, CancelBroadcastTlvValue,
+            MessageDeliveryRequestTlvValue, MessageDeliveryResponseTlvValue,
+            MessageSubmissionRequestTlvValue, MessageSubmissionResponseTlvValue,
+            QueryBroadcastResponseTlvValue, Tlv, TlvValue,
+        },
+    },
+    types::owned::AnyOctetString,
+};
+
+/// A typed vendor-specific TLV value.
+///
+/// Vendor-specific TLVs use tags in the range `0x1400` - `0x3FFF` and are decoded as
+/// [`TlvValue::Other`]. Implementing this trait defines a typed value for such a tag,
+/// which can then be read with [`Tlv::vendor_value`] and written with [`VendorTlv`].
+///
+/// The definition is not registered with the decoder: a vendor-specific TLV always stays a
+/// [`TlvValue::Other`] holding the raw bytes, and is decoded lazily on every call to
+/// [`Tlv::vendor_value`]. A malformed value therefore does not fail the decoding of the `PDU`,
+/// the error is returned on access instead. Keep the decoded value if it is read more than once.
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::{
+///     decode::{DecodeError, owned::Decode},
+///     encode::{Encode, Length},
+///     tlvs::owned::{Tlv, VendorTlv, VendorTlvValue},
+/// };
+///
+/// /// Billing price in cents, sent by the carrier in tag `0x1401`.
+/// #[derive(Debug, PartialEq)]
+/// struct BillingPrice(u32);
+///
+/// impl Length for BillingPrice {
+///     fn length(&self) -> usize {
+///         self.0.length()
+///     }
+/// }
+///
+/// impl Encode for BillingPrice {
+///     fn encode(&self, dst: &mut [u8]) -> usize {
+///         self.0.encode(dst)
+///     }
+/// }
+///
+/// impl Decode for BillingPrice {
+///     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+///         u32::decode(src).map(|(value, size)| (Self(value), size))
+///     }
+/// }
+///
+/// impl VendorTlvValue for BillingPrice {
+///     const TAG: u16 = 0x1401;
+/// }
+///
+/// let tlv = Tlv::from(VendorTlv(BillingPrice(150)));
+///
+/// assert_eq!(tlv.value_length(), 4);
+/// assert_eq!(
+///     tlv.vendor_value::<BillingPrice>().unwrap().unwrap(),
+///     BillingPrice(150)
+/// );
+/// ```
+pub trait VendorTlvValue: Encode + DecodeWithLength {
+    /// The tag of the TLV.
+    ///
+    /// Must be in the range `0x1400` - `0x3FFF`, checked at compile time.
+    const TAG: u16;
+}
+
+/// Wraps a [`VendorTlvValue`] to convert it into a [`Tlv`] or any `TlvValue`-like enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VendorTlv<T>(pub T);
+
+impl<T: VendorTlvValue> VendorTlv<T> {
+    /// [`VendorTlvValue::TAG`], asserted to be in the vendor-specific range.
+    const TAG: u16 = {
+        assert!(
+            T::TAG >= 0x1400 && T::TAG <= 0x3FFF,
+            "VendorTlvValue::TAG must be in the range 0x1400 - 0x3FFF"
+        );
+
+        T::TAG
+    };
+
+    fn into_other(self) -> (TlvTag, AnyOctetString) {
+        let mut value = alloc::vec![0; self.0.length()];
+
+        self.0.encode(&mut value);
+
+        (TlvTag::from(Self::TAG), AnyOctetString::from(value))
+    }
+}
+
+macro_rules! impl_from_vendor_tlv {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<T: VendorTlvValue> From<VendorTlv<T>> for $name {
+                fn from(value: VendorTlv<T>) -> Self {
+                    let (tag, value) = value.into_other();
+
+                    Self::Other { tag, value }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_vendor_tlv!(
+    TlvValue,
+    BroadcastRequestTlvValue,
+    BroadcastResponseTlvValue,
+    CancelBroadcastTlvValue,
+    MessageDeliveryRequestTlvValue,
+    MessageDeliveryResponseTlvValue,
+    MessageSubmissionRequestTlvValue,
+    MessageSubmissionResponseTlvValue,
+    QueryBroadcastResponseTlvValue,
+);
+
+impl<T: VendorTlvValue> From<VendorTlv<T>> for Tlv {
+    fn from(value: VendorTlv<T>) -> Self {
+        Self::new(TlvValue::from(value))
+    }
+}
+
+impl TlvValue {
+    /// Decodes this [`TlvValue`] as the vendor-specific value `T`.
+    ///
+    /// The raw bytes are decoded on every call, see [`VendorTlvValue`].
+    ///
+    /// Returns `None` if this is not a [`TlvValue::Other`] with the tag [`VendorTlvValue::TAG`],
+    /// and `Some(Err(_))` if the value is malformed.
+    pub fn vendor_value<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        match self {
+            TlvValue::Other { tag, value } if u16::from(*tag) == VendorTlv::<T>::TAG => {
+                Some(decode_vendor_value(value.bytes()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Tlv {
+    /// Decodes the value of this [`Tlv`] as the vendor-specific value `T`.
+    ///
+    /// A TLV without a value is decoded from an empty value, see [`TlvValue::vendor_value`].
+    pub fn vendor_value<T: VendorTlvValue>(&self) -> Option<Result<T, DecodeError>> {
+        match self.value() {
+            Some(value) => value.vendor_value(),
+            None if u16::from(self.tag()) == VendorTlv::<T>::TAG => Some(decode_vendor_value(&[])),
+            None => None,
+        }
+    }
+}
+
+/// Decodes `value` as `T`, rejecting trailing bytes.
+fn decode_vendor_value<T: VendorTlvValue>(value: &[u8]) -> Result<T, DecodeError> {
+    let (decoded, size) = T::decode(value, value.len())?;
+
+    if size != value.len() {
+        return Err(DecodeError::trailing_bytes(size, value.len()));
+    }
+
+    Ok(decoded)
+}
+
+/// Returns the first vendor-specific TLV value `T` found in `tlvs`.
+pub(crate) fn vendor_value<T: VendorTlvValue>(tlvs: &[Tlv]) -> Option<Result<T, DecodeError>> {
+    tlvs.iter().find_map(Tlv::vendor_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::{DecodeErrorKind, OctetStringDecodeError, owned::Decode},
+        encode::Length,
+        pdus::owned::{DeliverSm, Pdu, SubmitSm},
+        types::owned::OctetString,
+    };
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct PortedNetwork(OctetString<0, 8>);
+
+    impl Length for PortedNetwork {
+        fn length(&self) -> usize {
+            self.0.length()
+        }
+    }
+
+    impl Encode for PortedNetwork {
+        fn encode(&self, dst: &mut [u8]) -> usize {
+            self.0.encode(dst)
+        }
+    }
+
+    impl DecodeWithLength for PortedNetwork {
+        fn decode(src: &[u8], length: usize) -> Result<(Self, usize), DecodeError> {
+            OctetString::decode(src, length).map(|(value, size)| (Self(value), size))
+        }
+    }
+
+    impl VendorTlvValue for PortedNetwork {
+        const TAG: u16 = 0x1402;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Price(u32);
+
+    impl Length for Price {
+        fn length(&self) -> usize {
+            self.0.length()
+        }
+    }
+
+    impl Encode for Price {
+        fn encode(&self, dst: &mut [u8]) -> usize {
+            self.0.encode(dst)
+        }
+    }
+
+    impl Decode for Price {
+        fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+            Decode::decode(src).map(|(value, size)| (Self(value), size))
+        }
+    }
+
+    impl VendorTlvValue for Price {
+        const TAG: u16 = 0x1401;
+    }
+
+    #[test]
+    fn into_tlv() {
+        let tlv = Tlv::from(VendorTlv(Price(150)));
+
+        assert_eq!(tlv.tag(), TlvTag::Other(0x1401));
+        assert_eq!(tlv.value_length(), 4);
+        assert_eq!(
+            tlv.value(),
+            Some(&TlvValue::Other {
+                tag: TlvTag::Other(0x1401),
+                value: AnyOctetString::new([0x00, 0x00, 0x00, 0x96]),
+            })
+        );
+    }
+
+    #[test]
+    fn vendor_value() {
+        let tlv = Tlv::from(VendorTlv(Price(150)));
+
+        assert_eq!(tlv.vendor_value::<Price>().unwrap().unwrap(), Price(150));
+        assert!(tlv.vendor_value::<PortedNetwork>().is_none());
+
+        let tlv = Tlv::new(TlvValue::Other {
+            tag: TlvTag::Other(0x1401),
+            value: AnyOctetString::new([0x00, 0x96]),
+        });
+
+        assert!(matches!(tlv.vendor_value::<Price>(), Some(Err(_))));
+
+        let tlv = Tlv::new(TlvValue::SarMsgRefNum(1));
+
+        assert!(tlv.vendor_value::<Price>().is_none());
+    }
+
+    #[test]
+    fn vendor_value_trailing_bytes() {
+        let tlv = Tlv::new(TlvValue::Other {
+            tag: TlvTag::Other(0x1401),
+            value: AnyOctetString::new([0x00, 0x00, 0x00, 0x96, 0x00]),
+        });
+
+        let error = tlv.vendor_value::<Price>().unwrap().unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            DecodeErrorKind::TrailingBytes {
+                decoded: 4,
+                length: 5
+            }
+        ));
+    }
+
+    #[test]
+    fn vendor_value_malformed() {
+        let tlv = Tlv::new(TlvValue::Other {
+            tag: TlvTag::Other(0x1401),
+            value: AnyOctetString::new([0x00, 0x96]),
+        });
+
+        let error = tlv.vendor_value::<Price>().unwrap().unwrap_err();
+
+        assert!(matches!(error.kind(), DecodeErrorKind::UnexpectedEof));
+
+        let tlv = Tlv::new(TlvValue::Other {
+            tag: TlvTag::Other(0x1402),
+            value: AnyOctetString::new(b"mnp-01-long"),
+        });
+
+        let error = tlv.vendor_value::<PortedNetwork>().unwrap().unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            DecodeErrorKind::OctetStringDecodeError(OctetStringDecodeError::TooManyBytes {
+                actual: 11,
+                max: 8
+            })
+        ));
+    }
+
+    #[test]
+    fn pdu_vendor_tlv_malformed() {
+        // sar_msg_ref_num followed by a truncated vendor-specific price
+        let tlvs = [
+            0x02, 0x0C, 0x00, 0x02, 0x00, 0x01, 0x14, 0x01, 0x00, 0x02, 0x00, 0x96,
+        ];
+
+        let submit_sm = SubmitSm::default();
+        let mut encoded = alloc::vec![0; submit_sm.length()];
+
+        submit_sm.encode(&mut encoded);
+        encoded.extend_from_slice(&tlvs);
+
+        // The malformed value does not fail the decoding of the PDU
+        let (submit_sm, _) = SubmitSm::decode(&encoded, encoded.len()).unwrap();
+
+        assert_eq!(submit_sm.tlvs().len(), 2);
+
+        // but is reported on every access
+        for _ in 0..2 {
+            let error = submit_sm.vendor_tlv::<Price>().unwrap().unwrap_err();
+
+            assert!(matches!(error.kind(), DecodeErrorKind::UnexpectedEof));
+        }
+    }
+
+    #[test]
+    fn vendor_value_without_value() {
+        let (tlv, _) = <Tlv as Decode>::decode(&[0x14, 0x02, 0x00, 0x00]).unwrap();
+
+        assert!(tlv.value().is_none());
+        assert_eq!(
+            tlv.vendor_value::<PortedNetwork>().unwrap().unwrap(),
+            PortedNetwork(OctetString::empty())
+        );
+        assert!(tlv.vendor_value::<Price>().is_none());
+    }
+
+    #[test]
+    fn pdu_vendor_tlv() {
+        let submit_sm = SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+            .push_tlv(VendorTlv(Price(150)))
+            .push_tlv(VendorTlv(PortedNetwork(
+                OctetString::new(b"mnp-01").unwrap(),
+            )))
+            .build();
+
+        let pdu = Pdu::from(submit_sm);
+
+        assert_eq!(pdu.vendor_tlv::<Price>().unwrap().unwrap(), Price(150));
+        assert_eq!(
+            pdu.vendor_tlv::<PortedNetwork>().unwrap().unwrap(),
+            PortedNetwork(OctetString::new(b"mnp-01").unwrap())
+        );
+
+        let deliver_sm = DeliverSm::builder().build();
+
+        assert!(deliver_sm.vendor_tlv::<Price>().is_none());
+    }
+}
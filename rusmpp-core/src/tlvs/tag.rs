@@ -1,12 +1,17 @@
 use rusmpp_macros::Rusmpp;
 
+use crate::values::InterfaceVersion;
+
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
 #[repr(u16)]
 #[rusmpp(test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum TlvTag {
     /// The subcomponent in the destination device for which the user data is intended.
     ///
@@ -429,3 +434,40 @@ pub enum TlvTag {
     /// or logging purposes.
     Other(u16),
 }
+
+impl TlvTag {
+    /// Returns true if this [`TlvTag`] is defined by the given [`InterfaceVersion`].
+    ///
+    /// `SMPP` v3.3 and earlier do not support TLVs at all. The broadcast, billing, network,
+    /// node, number portability and congestion TLVs were introduced in `SMPP` v5.0.
+    /// Unknown interface versions and [`TlvTag::Other`] tags are supported from `SMPP` v3.4.
+    pub fn is_supported_by(self, interface_version: InterfaceVersion) -> bool {
+        match interface_version {
+            InterfaceVersion::Smpp3_3OrEarlier(_) => false,
+            InterfaceVersion::Smpp3_4 => !matches!(
+                self,
+                TlvTag::CongestionState
+                    | TlvTag::BroadcastChannelIndicator
+                    | TlvTag::BroadcastContentType
+                    | TlvTag::BroadcastContentTypeInfo
+                    | TlvTag::BroadcastMessageClass
+                    | TlvTag::BroadcastRepNum
+                    | TlvTag::BroadcastFrequencyInterval
+                    | TlvTag::BroadcastAreaIdentifier
+                    | TlvTag::BroadcastErrorStatus
+                    | TlvTag::BroadcastAreaSuccess
+                    | TlvTag::BroadcastEndTime
+                    | TlvTag::BroadcastServiceGroup
+                    | TlvTag::BillingIdentification
+                    | TlvTag::SourceNetworkId
+                    | TlvTag::DestNetworkId
+                    | TlvTag::SourceNodeId
+                    | TlvTag::DestNodeId
+                    | TlvTag::DestAddrNpResolution
+                    | TlvTag::DestAddrNpInformation
+                    | TlvTag::DestAddrNpCountry
+            ),
+            InterfaceVersion::Smpp5_0 | InterfaceVersion::Other(_) => true,
+        }
+    }
+}
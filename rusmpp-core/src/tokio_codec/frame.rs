@@ -0,0 +1,223 @@
+use tokio_util::{
+    bytes::{BufMut, Bytes, BytesMut},
+    codec::{Decoder, Encoder},
+};
+
+use crate::{
+    CommandId, CommandStatus,
+    command::owned::Command,
+    decode::owned::DecodeWithLength,
+    logging::{debug, error},
+};
+
+use super::{CommandCodec, DecodeError, EncodeError};
+
+/// A decoded `SMPP` command together with the [`Bytes`] of its frame.
+///
+/// The [`Command`] is decoded once by the [`FrameCodec`], while the frame is split off the codec's read buffer
+/// without copying, so it can be forwarded unchanged.
+/// The frame is `'static` and `Send`, so it can be moved across tasks.
+///
+/// See [`FrameCodec`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommandFrame {
+    /// The entire frame, including the command length.
+    frame: Bytes,
+    command: Command,
+}
+
+impl CommandFrame {
+    /// Returns the entire frame, including the command length.
+    #[inline]
+    pub const fn frame(&self) -> &Bytes {
+        &self.frame
+    }
+
+    /// Consumes the [`CommandFrame`] and returns the entire frame, including the command length.
+    #[inline]
+    pub fn into_frame(self) -> Bytes {
+        self.frame
+    }
+
+    #[inline]
+    pub const fn id(&self) -> CommandId {
+        self.command.id()
+    }
+
+    #[inline]
+    pub const fn status(&self) -> CommandStatus {
+        self.command.status()
+    }
+
+    #[inline]
+    pub const fn sequence_number(&self) -> u32 {
+        self.command.sequence_number()
+    }
+
+    /// Returns the decoded [`Command`].
+    ///
+    /// TLVs that are not supported by the interface version of the codec are removed from the command, but not from the [`frame`](CommandFrame::frame).
+    #[inline]
+    pub const fn command(&self) -> &Command {
+        &self.command
+    }
+
+    /// Consumes the [`CommandFrame`] and returns the decoded [`Command`].
+    #[inline]
+    pub fn into_command(self) -> Command {
+        self.command
+    }
+
+    /// Consumes the [`CommandFrame`] and returns the entire frame and the decoded [`Command`].
+    #[inline]
+    pub fn into_parts(self) -> (Bytes, Command) {
+        (self.frame, self.command)
+    }
+}
+
+/// Codec for decoding `SMPP` PDUs into [`CommandFrame`]s.
+///
+/// Framing, maximum length and interface version are handled by the inner [`CommandCodec`].
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::{
+///     CommandId, CommandStatus,
+///     command::owned::Command,
+///     pdus::owned::SubmitSm,
+///     tokio_codec::{CommandCodec, FrameCodec},
+/// };
+/// use tokio_util::{
+///     bytes::BytesMut,
+///     codec::{Decoder, Encoder},
+/// };
+///
+/// let command = Command::new(CommandStatus::EsmeRok, 1, SubmitSm::default());
+///
+/// let mut buf = BytesMut::new();
+///
+/// CommandCodec::new().encode(&command, &mut buf).unwrap();
+///
+/// let mut codec = FrameCodec::new();
+///
+/// let frame = codec.decode(&mut buf).unwrap().unwrap();
+///
+/// assert_eq!(frame.id(), CommandId::SubmitSm);
+/// assert_eq!(frame.sequence_number(), 1);
+/// assert_eq!(frame.command(), &command);
+/// ```
+#[derive(Debug, Default)]
+pub struct FrameCodec {
+    codec: CommandCodec,
+}
+
+impl FrameCodec {
+    /// Creates a new [`FrameCodec`] with the default [`CommandCodec`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            codec: CommandCodec::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn codec(&self) -> &CommandCodec {
+        &self.codec
+    }
+}
+
+impl From<CommandCodec> for FrameCodec {
+    fn from(codec: CommandCodec) -> Self {
+        Self { codec }
+    }
+}
+
+impl Decoder for FrameCodec {
+    type Item = CommandFrame;
+    type Error = DecodeError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(command_length) = self.codec.next_command_length(src)? else {
+            return Ok(None);
+        };
+
+        // command_length is at least 16 bytes
+        let pdu_len = command_length - 4;
+
+        let mut command = match Command::decode(&src[4..command_length], pdu_len) {
+            Ok((command, _size)) => {
+                debug!(target: "rusmpp::codec::decode", command=?command, command_length, decoded_length=_size, "Decoded");
+
+                command
+            }
+            Err(err) => {
+                error!(target: "rusmpp::codec::decode", ?err);
+
+                return Err(DecodeError::Decode(err));
+            }
+        };
+
+        let frame = src.split_to(command_length).freeze();
+
+        if !command.id().is_supported_by(self.codec.interface_version) {
+            error!(target: "rusmpp::codec::decode", id=?command.id(), interface_version=?self.codec.interface_version, "Unsupported command");
+        }
+
+        if command.has_unsupported_tlvs(self.codec.interface_version) {
+            command.retain_supported_tlvs(self.codec.interface_version);
+
+            debug!(target: "rusmpp::codec::decode", command=?command, interface_version=?self.codec.interface_version, "Removed unsupported TLVs");
+        }
+
+        Ok(Some(CommandFrame { frame, command }))
+    }
+}
+
+impl Encoder<&CommandFrame> for FrameCodec {
+    type Error = EncodeError;
+
+    /// Writes the frame unchanged.
+    fn encode(&mut self, frame: &CommandFrame, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let id = frame.id();
+
+        if !id.is_supported_by(self.codec.interface_version) {
+            error!(target: "rusmpp::codec::encode", ?id, interface_version=?self.codec.interface_version, "Unsupported command");
+
+            return Err(EncodeError::UnsupportedCommand {
+                id,
+                interface_version: self.codec.interface_version,
+            });
+        }
+
+        dst.put_slice(&frame.frame);
+
+        debug!(target: "rusmpp::codec::encode", encoded=?crate::formatter::Formatter(&frame.frame), command_length=frame.frame.len(), "Encoded frame");
+
+        Ok(())
+    }
+}
+
+impl Encoder<CommandFrame> for FrameCodec {
+    type Error = EncodeError;
+
+    fn encode(&mut self, frame: CommandFrame, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(&frame, dst)
+    }
+}
+
+impl Encoder<&Command> for FrameCodec {
+    type Error = EncodeError;
+
+    fn encode(&mut self, command: &Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.codec.encode(command, dst)
+    }
+}
+
+impl Encoder<Command> for FrameCodec {
+    type Error = EncodeError;
+
+    fn encode(&mut self, command: Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.codec.encode(command, dst)
+    }
+}
@@ -8,27 +8,48 @@ use tokio_util::{
 };
 
 use crate::{
+    CommandId,
     command::owned::Command,
     decode::owned::DecodeWithLength,
-    encode::{Encode, Length},
+    encode::{EncodeBytesMut, Length},
     logging::{debug, error, trace},
+    values::InterfaceVersion,
 };
 
+mod frame;
+pub use frame::{CommandFrame, FrameCodec};
+
 #[cfg(test)]
 mod tests;
 
+const HEADER_LENGTH: usize = 16;
+
 /// Codec for encoding and decoding `SMPP` PDUs.
+///
+/// # Interface version
+///
+/// By default, the codec speaks `SMPP` v5.0. Use [`CommandCodec::with_interface_version`] to talk to older peers:
+///
+/// - [`InterfaceVersion::Smpp3_4`]: v5.0 only PDUs are rejected and v5.0 only TLVs are removed.
+/// - [`InterfaceVersion::Smpp3_3OrEarlier`]: v3.4 and v5.0 only PDUs are rejected and all TLVs are removed.
+///
+/// Unsupported PDUs are rejected when encoding. Decoded unsupported PDUs are still returned,
+/// so that the caller can answer them with a `generic_nack` instead of ending the stream.
+///
+/// See [`CommandId::is_supported_by`] and [`TlvTag::is_supported_by`](crate::tlvs::TlvTag::is_supported_by).
 #[derive(Debug)]
 pub struct CommandCodec {
     max_length: Option<usize>,
+    interface_version: InterfaceVersion,
 }
 
 impl CommandCodec {
-    /// Creates a new [`CommandCodec`] with a default maximum length of `8192` bytes.
+    /// Creates a new [`CommandCodec`] with a default maximum length of `8192` bytes and [`InterfaceVersion::Smpp5_0`].
     #[inline]
     pub const fn new() -> Self {
         Self {
             max_length: Some(8192),
+            interface_version: InterfaceVersion::Smpp5_0,
         }
     }
 
@@ -48,6 +69,17 @@ impl CommandCodec {
         self.max_length = None;
         self
     }
+
+    #[inline]
+    pub const fn interface_version(&self) -> InterfaceVersion {
+        self.interface_version
+    }
+
+    #[inline]
+    pub fn with_interface_version(mut self, interface_version: InterfaceVersion) -> Self {
+        self.interface_version = interface_version;
+        self
+    }
 }
 
 impl Default for CommandCodec {
@@ -62,6 +94,11 @@ impl Default for CommandCodec {
 pub enum EncodeError {
     /// I/O error.
     Io(std::io::Error),
+    /// The command is not supported by the interface version of the codec.
+    UnsupportedCommand {
+        id: CommandId,
+        interface_version: InterfaceVersion,
+    },
 }
 
 impl From<std::io::Error> for EncodeError {
@@ -74,6 +111,13 @@ impl core::fmt::Display for EncodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             EncodeError::Io(e) => write!(f, "I/O error: {e}"),
+            EncodeError::UnsupportedCommand {
+                id,
+                interface_version,
+            } => write!(
+                f,
+                "Unsupported command. id: {id:?}, interface_version: {interface_version:?}"
+            ),
         }
     }
 }
@@ -82,6 +126,7 @@ impl core::error::Error for EncodeError {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             EncodeError::Io(e) => Some(e),
+            EncodeError::UnsupportedCommand { .. } => None,
         }
     }
 
@@ -94,32 +139,59 @@ impl Encoder<&Command> for CommandCodec {
     type Error = EncodeError;
 
     fn encode(&mut self, command: &Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
-        let command_length = 4 + command.length();
+        if !command.id().is_supported_by(self.interface_version) {
+            error!(target: "rusmpp::codec::encode", id=?command.id(), interface_version=?self.interface_version, "Unsupported command");
 
-        dst.reserve(command_length);
-        dst.put_u32(command_length as u32);
+            return Err(EncodeError::UnsupportedCommand {
+                id: command.id(),
+                interface_version: self.interface_version,
+            });
+        }
 
-        // TODO: Can we encode directly into dst?
-        let mut buf = alloc::vec![0; command.length()];
-        let _ = command.encode(buf.as_mut_slice());
+        if command.has_unsupported_tlvs(self.interface_version) {
+            let mut command = command.clone();
 
-        dst.put_slice(&buf);
+            command.retain_supported_tlvs(self.interface_version);
 
-        debug!(target: "rusmpp::codec::encode", command=?command, "Encoding");
-        debug!(target: "rusmpp::codec::encode", encoded=?crate::formatter::Formatter(&buf), encoded_length=command.length(), command_length, "Encoded");
+            debug!(target: "rusmpp::codec::encode", interface_version=?self.interface_version, "Removed unsupported TLVs");
 
-        Ok(())
+            return self.encode_supported(&command, dst);
+        }
+
+        self.encode_supported(command, dst)
     }
 }
 
 impl Encoder<Command> for CommandCodec {
     type Error = EncodeError;
 
-    fn encode(&mut self, command: Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
+    fn encode(&mut self, mut command: Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        if command.id().is_supported_by(self.interface_version) {
+            command.retain_supported_tlvs(self.interface_version);
+        }
+
         self.encode(&command, dst)
     }
 }
 
+impl CommandCodec {
+    /// Encodes a [`Command`] that is supported by the interface version of the codec.
+    fn encode_supported(&self, command: &Command, dst: &mut BytesMut) -> Result<(), EncodeError> {
+        let encoded_length = command.length();
+        let command_length = 4 + encoded_length;
+
+        dst.reserve(command_length);
+        dst.put_u32(command_length as u32);
+
+        let _ = command.encode_buf_mut(dst);
+
+        debug!(target: "rusmpp::codec::encode", command=?command, "Encoding");
+        debug!(target: "rusmpp::codec::encode", encoded=?crate::formatter::Formatter(&dst[dst.len() - encoded_length..]), encoded_length, command_length, "Encoded");
+
+        Ok(())
+    }
+}
+
 /// An error that can occur when decoding a `Command`.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -182,13 +254,9 @@ impl core::error::Error for DecodeError {
     }
 }
 
-impl Decoder for CommandCodec {
-    type Item = Command;
-    type Error = DecodeError;
-
-    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        const HEADER_LENGTH: usize = 16;
-
+impl CommandCodec {
+    /// Returns the length of the next command in `src`, or `None` if `src` does not hold the entire command yet.
+    fn next_command_length(&self, src: &mut BytesMut) -> Result<Option<usize>, DecodeError> {
         if src.len() < HEADER_LENGTH {
             trace!(target: "rusmpp::codec::decode", source_length=src.len(), "Not enough bytes to read the header");
 
@@ -235,12 +303,25 @@ impl Decoder for CommandCodec {
             return Ok(None);
         }
 
+        debug!(target: "rusmpp::codec::decode", decoding=?crate::formatter::Formatter(&src[..command_length]), "Decoding");
+
+        Ok(Some(command_length))
+    }
+}
+
+impl Decoder for CommandCodec {
+    type Item = Command;
+    type Error = DecodeError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(command_length) = self.next_command_length(src)? else {
+            return Ok(None);
+        };
+
         // command_length is at least 16 bytes
         let pdu_len = command_length - 4;
 
-        debug!(target: "rusmpp::codec::decode", decoding=?crate::formatter::Formatter(&src[..command_length]), "Decoding");
-
-        let (command, _size) = match Command::decode(&src[4..command_length], pdu_len) {
+        let (mut command, _size) = match Command::decode(&src[4..command_length], pdu_len) {
             Ok((command, size)) => {
                 debug!(target: "rusmpp::codec::decode", command=?command, command_length, decoded_length=size, "Decoded");
 
@@ -255,6 +336,16 @@ impl Decoder for CommandCodec {
 
         src.advance(command_length);
 
+        if !command.id().is_supported_by(self.interface_version) {
+            error!(target: "rusmpp::codec::decode", id=?command.id(), interface_version=?self.interface_version, "Unsupported command");
+        }
+
+        if command.has_unsupported_tlvs(self.interface_version) {
+            command.retain_supported_tlvs(self.interface_version);
+
+            debug!(target: "rusmpp::codec::decode", command=?command, interface_version=?self.interface_version, "Removed unsupported TLVs");
+        }
+
         Ok(Some(command))
     }
 }
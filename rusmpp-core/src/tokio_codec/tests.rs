@@ -7,16 +7,19 @@ use testcontainers::{
     runners::AsyncRunner,
 };
 use tokio::{io::AsyncWriteExt, net::TcpStream};
-use tokio_util::codec::{Framed, FramedRead, FramedWrite};
+use tokio_util::{
+    bytes::BytesMut,
+    codec::{Decoder, Encoder, Framed, FramedRead, FramedWrite},
+};
 
 use crate::{
-    CommandStatus,
+    CommandId, CommandStatus,
     command::owned::Command,
-    encode::{Encode, Length},
+    encode::{Encode, EncodeBytesMut, Length},
     pdus::owned::*,
     tests::owned::test_commands,
     tlvs::owned::{BroadcastRequestTlvValue, MessageSubmissionRequestTlvValue},
-    tokio_codec::{CommandCodec, DecodeError},
+    tokio_codec::{CommandCodec, CommandFrame, DecodeError, EncodeError, FrameCodec},
     types::owned::{AnyOctetString, COctetString, OctetString},
     values::{owned::*, *},
 };
@@ -102,6 +105,137 @@ async fn min_length() {
     }
 }
 
+#[test]
+fn encode_appends_to_dst() {
+    let mut codec = CommandCodec::new();
+    let mut buf = BytesMut::new();
+    let mut expected = Vec::new();
+
+    for command in test_commands() {
+        let mut encoded = std::vec![0; command.length()];
+        let size = command.encode(&mut encoded);
+
+        expected.extend_from_slice(&(4 + size as u32).to_be_bytes());
+        expected.extend_from_slice(&encoded[..size]);
+
+        codec.encode(&command, &mut buf).unwrap();
+    }
+
+    assert_eq!(&buf[..], &expected[..]);
+}
+
+#[test]
+fn encode_bytes_mut_matches_encode() {
+    for command in test_commands() {
+        let mut encoded = std::vec![0; command.length()];
+        let size = command.encode(&mut encoded);
+
+        let mut buf = BytesMut::new();
+
+        assert_eq!(command.encode_bytes_mut(&mut buf), size);
+        assert_eq!(&buf[..], &encoded[..size]);
+    }
+}
+
+#[test]
+fn interface_version_rejects_unsupported_commands_on_encode_only() {
+    let mut codec = CommandCodec::new().with_interface_version(InterfaceVersion::Smpp3_4);
+    let mut buf = BytesMut::new();
+
+    let command = Command::new(CommandStatus::EsmeRok, 1, BroadcastSm::default());
+
+    match codec.encode(&command, &mut buf).unwrap_err() {
+        EncodeError::UnsupportedCommand {
+            id,
+            interface_version,
+        } => {
+            assert_eq!(id, CommandId::BroadcastSm);
+            assert_eq!(interface_version, InterfaceVersion::Smpp3_4);
+        }
+        _ => panic!("Encode must fail with `EncodeError::UnsupportedCommand`"),
+    }
+
+    assert!(buf.is_empty());
+
+    CommandCodec::new().encode(&command, &mut buf).unwrap();
+
+    // Unsupported commands are still decoded, so that the caller can answer them with a generic_nack.
+    let decoded = codec.decode(&mut buf).unwrap().unwrap();
+
+    assert_eq!(decoded.id(), CommandId::BroadcastSm);
+    assert_eq!(decoded.sequence_number(), 1);
+    assert!(!decoded.id().is_supported_by(codec.interface_version()));
+    assert!(buf.is_empty());
+
+    // The stream goes on.
+    let command = Command::new(CommandStatus::EsmeRok, 2, SubmitSm::default());
+
+    CommandCodec::new().encode(&command, &mut buf).unwrap();
+
+    assert_eq!(codec.decode(&mut buf).unwrap().unwrap(), command);
+}
+
+#[test]
+fn interface_version_3_4_removes_v5_tlvs() {
+    let submit_sm = SubmitSm::builder()
+        .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+        .push_tlv(MessageSubmissionRequestTlvValue::BillingIdentification(
+            OctetString::new(b"billing").unwrap(),
+        ))
+        .build();
+
+    let command = Command::new(CommandStatus::EsmeRok, 1, submit_sm);
+
+    let mut v5 = CommandCodec::new();
+    let mut v3_4 = CommandCodec::new().with_interface_version(InterfaceVersion::Smpp3_4);
+
+    let expected = Command::new(
+        CommandStatus::EsmeRok,
+        1,
+        SubmitSm::builder()
+            .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+            .build(),
+    );
+
+    let mut buf = BytesMut::new();
+
+    v3_4.encode(&command, &mut buf).unwrap();
+    assert_eq!(v5.decode(&mut buf).unwrap().unwrap(), expected);
+
+    v5.encode(&command, &mut buf).unwrap();
+    assert_eq!(v3_4.decode(&mut buf).unwrap().unwrap(), expected);
+}
+
+#[test]
+fn interface_version_3_3_removes_tlvs() {
+    let command = Command::new(
+        CommandStatus::EsmeRok,
+        1,
+        BindTransmitterResp::new(
+            COctetString::from_str("SMPP3TEST").unwrap(),
+            Some(InterfaceVersion::Smpp5_0),
+        ),
+    );
+
+    let mut v5 = CommandCodec::new();
+    let mut v3_3 =
+        CommandCodec::new().with_interface_version(InterfaceVersion::Smpp3_3OrEarlier(0x33));
+
+    let expected = Command::new(
+        CommandStatus::EsmeRok,
+        1,
+        BindTransmitterResp::new(COctetString::from_str("SMPP3TEST").unwrap(), None),
+    );
+
+    let mut buf = BytesMut::new();
+
+    v3_3.encode(command.clone(), &mut buf).unwrap();
+    assert_eq!(v5.decode(&mut buf).unwrap().unwrap(), expected);
+
+    v5.encode(&command, &mut buf).unwrap();
+    assert_eq!(v3_3.decode(&mut buf).unwrap().unwrap(), expected);
+}
+
 /// Connect to localhost:2775 and send a command.
 ///
 /// I use this function to throw random commands at a server and catch them in wireshark.
@@ -328,3 +462,78 @@ async fn do_codec() {
 
     tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
 }
+
+#[test]
+fn frame_codec_decodes_frames() {
+    fn assert_send_static<T: Send + 'static>() {}
+
+    assert_send_static::<CommandFrame>();
+
+    let mut buf = BytesMut::new();
+
+    for command in test_commands() {
+        CommandCodec::new().encode(&command, &mut buf).unwrap();
+    }
+
+    let encoded = buf.clone();
+
+    let mut codec = FrameCodec::new();
+    let mut forwarded = BytesMut::new();
+
+    for command in test_commands() {
+        let frame = codec.decode(&mut buf).unwrap().unwrap();
+
+        assert_eq!(frame.id(), command.id());
+        assert_eq!(frame.status(), command.status());
+        assert_eq!(frame.sequence_number(), command.sequence_number());
+        assert_eq!(frame.command(), &command);
+
+        codec.encode(frame, &mut forwarded).unwrap();
+    }
+
+    assert!(buf.is_empty());
+    assert_eq!(forwarded, encoded);
+}
+
+#[test]
+fn frame_codec_interface_version() {
+    let mut codec =
+        FrameCodec::from(CommandCodec::new().with_interface_version(InterfaceVersion::Smpp3_4));
+    let mut buf = BytesMut::new();
+
+    let command = Command::new(CommandStatus::EsmeRok, 1, BroadcastSm::default());
+
+    CommandCodec::new().encode(&command, &mut buf).unwrap();
+
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+
+    assert_eq!(frame.id(), CommandId::BroadcastSm);
+    assert!(buf.is_empty());
+
+    let submit_sm = SubmitSm::builder()
+        .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+        .push_tlv(MessageSubmissionRequestTlvValue::BillingIdentification(
+            OctetString::new(b"billing").unwrap(),
+        ))
+        .build();
+
+    let command = Command::new(CommandStatus::EsmeRok, 2, submit_sm);
+
+    CommandCodec::new().encode(&command, &mut buf).unwrap();
+
+    let length = buf.len();
+    let frame = codec.decode(&mut buf).unwrap().unwrap();
+
+    // The frame is forwarded unchanged, but the command drops the unsupported TLVs.
+    assert_eq!(frame.frame().len(), length);
+    assert_eq!(
+        frame.into_command(),
+        Command::new(
+            CommandStatus::EsmeRok,
+            2,
+            SubmitSm::builder()
+                .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+                .build(),
+        )
+    );
+}
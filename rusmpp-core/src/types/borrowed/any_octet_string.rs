@@ -9,7 +9,7 @@ use crate::{
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct AnyOctetString<'a> {
-    bytes: &'a [u8],
+    pub(crate) bytes: &'a [u8],
 }
 
 impl<'a> AnyOctetString<'a> {
@@ -90,6 +90,15 @@ impl Encode for AnyOctetString<'_> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for AnyOctetString<'_> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl<'a> DecodeWithLength<'a> for AnyOctetString<'a> {
     fn decode(src: &'a [u8], length: usize) -> Result<(Self, usize), DecodeError> {
         if src.len() < length {
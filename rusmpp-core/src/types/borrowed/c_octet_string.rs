@@ -65,7 +65,7 @@ use crate::{
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct COctetString<'a, const MIN: usize, const MAX: usize> {
-    bytes: &'a [u8],
+    pub(crate) bytes: &'a [u8],
 }
 
 impl<'a, const MIN: usize, const MAX: usize> COctetString<'a, MIN, MAX> {
@@ -206,6 +206,17 @@ impl<const MIN: usize, const MAX: usize> Encode for COctetString<'_, MIN, MAX> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<const MIN: usize, const MAX: usize> crate::encode::EncodeBytesMut
+    for COctetString<'_, MIN, MAX>
+{
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl<'a, const MIN: usize, const MAX: usize> Decode<'a> for COctetString<'a, MIN, MAX> {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), DecodeError> {
         Self::_ASSERT_VALID;
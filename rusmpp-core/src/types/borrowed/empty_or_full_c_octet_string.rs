@@ -28,7 +28,7 @@ use crate::{
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct EmptyOrFullCOctetString<'a, const N: usize> {
-    bytes: &'a [u8],
+    pub(crate) bytes: &'a [u8],
 }
 
 impl<'a, const N: usize> EmptyOrFullCOctetString<'a, N> {
@@ -155,6 +155,15 @@ impl<const N: usize> Encode for EmptyOrFullCOctetString<'_, N> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<const N: usize> crate::encode::EncodeBytesMut for EmptyOrFullCOctetString<'_, N> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl<'a, const N: usize> Decode<'a> for EmptyOrFullCOctetString<'a, N> {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), DecodeError> {
         Self::_ASSERT_NON_ZERO;
@@ -44,7 +44,7 @@ use crate::{
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct OctetString<'a, const MIN: usize, const MAX: usize> {
-    bytes: &'a [u8],
+    pub(crate) bytes: &'a [u8],
 }
 
 impl<'a, const MIN: usize, const MAX: usize> OctetString<'a, MIN, MAX> {
@@ -152,6 +152,17 @@ impl<const MIN: usize, const MAX: usize> Encode for OctetString<'_, MIN, MAX> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<const MIN: usize, const MAX: usize> crate::encode::EncodeBytesMut
+    for OctetString<'_, MIN, MAX>
+{
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl<'a, const MIN: usize, const MAX: usize> DecodeWithLength<'a> for OctetString<'a, MIN, MAX> {
     fn decode(src: &'a [u8], length: usize) -> Result<(Self, usize), DecodeError> {
         Self::_ASSERT_MIN_LESS_THAN_OR_EQUAL_TO_MAX;
@@ -3,15 +3,23 @@ use alloc::{string::String, string::ToString, vec::Vec};
 use crate::{
     decode::{DecodeError, owned::DecodeWithLength},
     encode::{Encode, Length},
+    types::borrowed,
 };
 
 /// No fixed size [`OctetString`](struct@crate::types::owned::octet_string::OctetString).
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
 #[cfg_attr(
-    any(feature = "serde", feature = "serde-deserialize-unchecked"),
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
+#[cfg_attr(
+    any(
+        feature = "serde",
+        feature = "serde-deserialize",
+        feature = "serde-deserialize-unchecked"
+    ),
     serde(transparent)
 )]
 pub struct AnyOctetString {
@@ -130,6 +138,15 @@ impl Encode for AnyOctetString {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for AnyOctetString {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl DecodeWithLength for AnyOctetString {
     fn decode(src: &[u8], length: usize) -> Result<(Self, usize), DecodeError> {
         if src.len() < length {
@@ -144,6 +161,28 @@ impl DecodeWithLength for AnyOctetString {
     }
 }
 
+impl From<borrowed::AnyOctetString<'_>> for AnyOctetString {
+    fn from(value: borrowed::AnyOctetString<'_>) -> Self {
+        Self {
+            bytes: value.bytes.to_vec(),
+        }
+    }
+}
+
+impl borrowed::AnyOctetString<'_> {
+    /// Converts this borrowed [`AnyOctetString`](borrowed::AnyOctetString) into an owned [`AnyOctetString`].
+    pub fn into_owned(self) -> AnyOctetString {
+        self.into()
+    }
+}
+
+impl AnyOctetString {
+    /// Returns a borrowed [`AnyOctetString`](borrowed::AnyOctetString) view of this [`AnyOctetString`].
+    pub fn as_borrowed(&self) -> borrowed::AnyOctetString<'_> {
+        borrowed::AnyOctetString { bytes: &self.bytes }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
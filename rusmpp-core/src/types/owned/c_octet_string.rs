@@ -5,7 +5,7 @@ use alloc::{string::String, string::ToString, vec::Vec};
 use crate::{
     decode::{COctetStringDecodeError, DecodeError, owned::Decode},
     encode::{Encode, Length},
-    types::c_octet_string::Error,
+    types::{borrowed, c_octet_string::Error},
 };
 
 /// A [`COctetString`] is a sequence of ASCII characters
@@ -65,7 +65,13 @@ use crate::{
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-deserialize-unchecked"),
     serde(transparent)
@@ -273,6 +279,15 @@ impl<const MIN: usize, const MAX: usize> Encode for COctetString<MIN, MAX> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<const MIN: usize, const MAX: usize> crate::encode::EncodeBytesMut for COctetString<MIN, MAX> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl<const MIN: usize, const MAX: usize> Decode for COctetString<MIN, MAX> {
     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
         Self::_ASSERT_VALID;
@@ -314,6 +329,43 @@ impl<const MIN: usize, const MAX: usize> Decode for COctetString<MIN, MAX> {
     }
 }
 
+/// Deserializes the bytes and checks them with [`new`](Self::new).
+#[cfg(feature = "serde-deserialize")]
+impl<'de, const MIN: usize, const MAX: usize> ::serde::Deserialize<'de> for COctetString<MIN, MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let bytes = <Vec<u8> as ::serde::Deserialize>::deserialize(deserializer)?;
+
+        Self::new(bytes).map_err(::serde::de::Error::custom)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> From<borrowed::COctetString<'_, MIN, MAX>>
+    for COctetString<MIN, MAX>
+{
+    fn from(value: borrowed::COctetString<'_, MIN, MAX>) -> Self {
+        Self {
+            bytes: value.bytes.to_vec(),
+        }
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> borrowed::COctetString<'_, MIN, MAX> {
+    /// Converts this borrowed [`COctetString`](borrowed::COctetString) into an owned [`COctetString`].
+    pub fn into_owned(self) -> COctetString<MIN, MAX> {
+        self.into()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> COctetString<MIN, MAX> {
+    /// Returns a borrowed [`COctetString`](borrowed::COctetString) view of this [`COctetString`].
+    pub fn as_borrowed(&self) -> borrowed::COctetString<'_, MIN, MAX> {
+        borrowed::COctetString { bytes: &self.bytes }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
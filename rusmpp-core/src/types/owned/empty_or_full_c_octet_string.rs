@@ -5,7 +5,7 @@ use alloc::{string::String, string::ToString, vec::Vec};
 use crate::{
     decode::{COctetStringDecodeError, DecodeError, owned::Decode},
     encode::{Encode, Length},
-    types::empty_or_full_c_octet_string::Error,
+    types::{borrowed, empty_or_full_c_octet_string::Error},
 };
 
 /// Empty or full [`COctetString`](struct@crate::types::owned::COctetString).
@@ -28,7 +28,13 @@ use crate::{
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-deserialize-unchecked"),
     serde(transparent)
@@ -230,6 +236,15 @@ impl<const N: usize> Encode for EmptyOrFullCOctetString<N> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<const N: usize> crate::encode::EncodeBytesMut for EmptyOrFullCOctetString<N> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl<const N: usize> Decode for EmptyOrFullCOctetString<N> {
     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
         Self::_ASSERT_NON_ZERO;
@@ -273,6 +288,41 @@ impl<const N: usize> Decode for EmptyOrFullCOctetString<N> {
     }
 }
 
+/// Deserializes the bytes and checks them with [`new`](Self::new).
+#[cfg(feature = "serde-deserialize")]
+impl<'de, const N: usize> ::serde::Deserialize<'de> for EmptyOrFullCOctetString<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let bytes = <Vec<u8> as ::serde::Deserialize>::deserialize(deserializer)?;
+
+        Self::new(bytes).map_err(::serde::de::Error::custom)
+    }
+}
+
+impl<const N: usize> From<borrowed::EmptyOrFullCOctetString<'_, N>> for EmptyOrFullCOctetString<N> {
+    fn from(value: borrowed::EmptyOrFullCOctetString<'_, N>) -> Self {
+        Self {
+            bytes: value.bytes.to_vec(),
+        }
+    }
+}
+
+impl<const N: usize> borrowed::EmptyOrFullCOctetString<'_, N> {
+    /// Converts this borrowed [`EmptyOrFullCOctetString`](borrowed::EmptyOrFullCOctetString) into an owned [`EmptyOrFullCOctetString`].
+    pub fn into_owned(self) -> EmptyOrFullCOctetString<N> {
+        self.into()
+    }
+}
+
+impl<const N: usize> EmptyOrFullCOctetString<N> {
+    /// Returns a borrowed [`EmptyOrFullCOctetString`](borrowed::EmptyOrFullCOctetString) view of this [`EmptyOrFullCOctetString`].
+    pub fn as_borrowed(&self) -> borrowed::EmptyOrFullCOctetString<'_, N> {
+        borrowed::EmptyOrFullCOctetString { bytes: &self.bytes }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,7 +4,7 @@ use alloc::{string::String, string::ToString, vec::Vec};
 use crate::{
     decode::{DecodeError, OctetStringDecodeError, owned::DecodeWithLength},
     encode::{Encode, Length},
-    types::octet_string::Error,
+    types::{borrowed, octet_string::Error},
 };
 
 /// An [`OctetString`] is a sequence of octets not necessarily
@@ -43,7 +43,13 @@ use crate::{
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 #[cfg_attr(
     any(feature = "serde", feature = "serde-deserialize-unchecked"),
     serde(transparent)
@@ -197,6 +203,15 @@ impl<const MIN: usize, const MAX: usize> Encode for OctetString<MIN, MAX> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl<const MIN: usize, const MAX: usize> crate::encode::EncodeBytesMut for OctetString<MIN, MAX> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes);
+
+        self.bytes.len()
+    }
+}
+
 impl<const MIN: usize, const MAX: usize> DecodeWithLength for OctetString<MIN, MAX> {
     fn decode(src: &[u8], length: usize) -> Result<(Self, usize), DecodeError> {
         Self::_ASSERT_MIN_LESS_THAN_OR_EQUAL_TO_MAX;
@@ -229,6 +244,43 @@ impl<const MIN: usize, const MAX: usize> DecodeWithLength for OctetString<MIN, M
     }
 }
 
+/// Deserializes the bytes and checks them with [`new`](Self::new).
+#[cfg(feature = "serde-deserialize")]
+impl<'de, const MIN: usize, const MAX: usize> ::serde::Deserialize<'de> for OctetString<MIN, MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let bytes = <Vec<u8> as ::serde::Deserialize>::deserialize(deserializer)?;
+
+        Self::new(bytes).map_err(::serde::de::Error::custom)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> From<borrowed::OctetString<'_, MIN, MAX>>
+    for OctetString<MIN, MAX>
+{
+    fn from(value: borrowed::OctetString<'_, MIN, MAX>) -> Self {
+        Self {
+            bytes: value.bytes.to_vec(),
+        }
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> borrowed::OctetString<'_, MIN, MAX> {
+    /// Converts this borrowed [`OctetString`](borrowed::OctetString) into an owned [`OctetString`].
+    pub fn into_owned(self) -> OctetString<MIN, MAX> {
+        self.into()
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> OctetString<MIN, MAX> {
+    /// Returns a borrowed [`OctetString`](borrowed::OctetString) view of this [`OctetString`].
+    pub fn as_borrowed(&self) -> borrowed::OctetString<'_, MIN, MAX> {
+        borrowed::OctetString { bytes: &self.bytes }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
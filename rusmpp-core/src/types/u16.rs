@@ -28,6 +28,15 @@ impl Encode for u16 {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for u16 {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_u16(dst, *self);
+
+        2
+    }
+}
+
 impl owned::Decode for u16 {
     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
         if src.len() < 2 {
@@ -30,6 +30,15 @@ impl Encode for u32 {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for u32 {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_u32(dst, *self);
+
+        4
+    }
+}
+
 impl owned::Decode for u32 {
     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
         if src.len() < 4 {
@@ -25,6 +25,15 @@ impl Encode for u8 {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for u8 {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_u8(dst, *self);
+
+        1
+    }
+}
+
 impl owned::Decode for u8 {
     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
         if src.is_empty() {
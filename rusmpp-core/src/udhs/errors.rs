@@ -1,7 +1,7 @@
 //! Errors related to User Data Header (UDH).
 
 /// Errors that can occur when creating `ConcatenatedShortMessage8Bit` or `ConcatenatedShortMessage16Bit`.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConcatenatedShortMessageError {
     /// The total number of parts is zero.
     TotalPartsZero,
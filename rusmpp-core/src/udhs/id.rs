@@ -8,12 +8,18 @@ use rusmpp_macros::Rusmpp;
 pub enum UdhId {
     /// Concatenated short messages, 8-bit reference number.
     ConcatenatedShortMessages8Bit = 0x00,
+    /// Special SMS message indication.
+    SpecialSmsMessageIndication = 0x01,
     /// Concatenated short messages, 16-bit reference number.
     ConcatenatedShortMessages16Bit = 0x08,
     /// Application port addressing scheme, 8-bit address.
     ApplicationPortAddressing8Bit = 0x04,
     /// Application port addressing scheme, 16-bit address.
     ApplicationPortAddressing16Bit = 0x05,
+    /// User data header source indicator.
+    UdhSourceIndicator = 0x07,
+    /// Text formatting (EMS).
+    TextFormatting = 0x0A,
     /// National language single shift.
     NationalLanguageSingleShift = 0x24,
     /// National language locking shift.
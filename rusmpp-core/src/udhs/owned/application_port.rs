@@ -0,0 +1,322 @@
+use crate::{
+    decode::DecodeError,
+    encode::Length,
+    udhs::owned::{UdhValue, information_element_data},
+};
+
+/// 8-bit Application Port Addressing UDH.
+///
+/// 8-bit port numbers (IEI = 0x04). Ports `240`-`255` are available for allocation by applications.
+///
+/// # Format
+///
+/// This format shows the [`ApplicationPortAddressing8Bit`] encoded as a full UDH.
+///
+/// ```txt
+/// 04 04 02 DP OP
+/// │  │  │  │  └─ Originator port
+/// │  │  │  └──── Destination port
+/// │  │  └─────── IE Data Length = 2
+/// │  └────────── IEI = 04 (8-bit address)
+/// └───────────── UDH length = 4
+/// ```
+///
+/// # Note
+///
+/// The first 2 bytes `(04 04)` are part of the UDH header and are not stored in the struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApplicationPortAddressing8Bit {
+    /// Destination port.
+    destination_port: u8,
+    /// Originator port.
+    originator_port: u8,
+}
+
+impl ApplicationPortAddressing8Bit {
+    /// The length of [`ApplicationPortAddressing8Bit`], including the IE Data Length.
+    const LENGTH: usize = 3;
+
+    /// The length of [`ApplicationPortAddressing8Bit`] encoded as a full UDH.
+    pub const UDH_LENGTH: usize = Self::LENGTH + 2;
+
+    /// Creates a new [`ApplicationPortAddressing8Bit`].
+    pub const fn new(destination_port: u8, originator_port: u8) -> Self {
+        Self {
+            destination_port,
+            originator_port,
+        }
+    }
+
+    /// Returns the destination port.
+    pub const fn destination_port(&self) -> u8 {
+        self.destination_port
+    }
+
+    /// Returns the originator port.
+    pub const fn originator_port(&self) -> u8 {
+        self.originator_port
+    }
+
+    /// The bytes representation of [`ApplicationPortAddressing8Bit`].
+    const fn bytes(&self) -> [u8; Self::LENGTH] {
+        [
+            0x02, // IE Data Length = 2 bytes
+            self.destination_port,
+            self.originator_port,
+        ]
+    }
+
+    /// The bytes representation of [`ApplicationPortAddressing8Bit`] encoded as a full UDH.
+    pub const fn udh_bytes(&self) -> [u8; Self::UDH_LENGTH] {
+        let bytes = self.bytes();
+
+        [
+            0x04, // UDH Length = 4 bytes
+            0x04, // IEI = 04 (8-bit address)
+            bytes[0], bytes[1], bytes[2],
+        ]
+    }
+}
+
+impl Length for ApplicationPortAddressing8Bit {
+    fn length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl crate::encode::Encode for ApplicationPortAddressing8Bit {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        dst[..Self::LENGTH].copy_from_slice(&self.bytes());
+
+        Self::LENGTH
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for ApplicationPortAddressing8Bit {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes());
+
+        Self::LENGTH
+    }
+}
+
+impl crate::decode::owned::Decode for ApplicationPortAddressing8Bit {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let data = information_element_data(src, 0x02)?;
+
+        Ok((Self::new(data[0], data[1]), Self::LENGTH))
+    }
+}
+
+impl From<ApplicationPortAddressing8Bit> for UdhValue {
+    fn from(udh: ApplicationPortAddressing8Bit) -> Self {
+        UdhValue::ApplicationPortAddressing8Bit(udh)
+    }
+}
+
+/// 16-bit Application Port Addressing UDH.
+///
+/// 16-bit port numbers (IEI = 0x05), e.g. `2948` for WAP push.
+///
+/// # Format
+///
+/// This format shows the [`ApplicationPortAddressing16Bit`] encoded as a full UDH.
+///
+/// ```txt
+/// 06 05 04 DP DP OP OP
+/// │  │  │  │     └─ Originator port (2 bytes)
+/// │  │  │  └─────── Destination port (2 bytes)
+/// │  │  └────────── IE Data Length = 4
+/// │  └───────────── IEI = 05 (16-bit address)
+/// └──────────────── UDH length = 6
+/// ```
+///
+/// # Note
+///
+/// The first 2 bytes `(06 05)` are part of the UDH header and are not stored in the struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApplicationPortAddressing16Bit {
+    /// Destination port.
+    destination_port: u16,
+    /// Originator port.
+    originator_port: u16,
+}
+
+impl ApplicationPortAddressing16Bit {
+    /// The length of [`ApplicationPortAddressing16Bit`], including the IE Data Length.
+    const LENGTH: usize = 5;
+
+    /// The length of [`ApplicationPortAddressing16Bit`] encoded as a full UDH.
+    pub const UDH_LENGTH: usize = Self::LENGTH + 2;
+
+    /// Creates a new [`ApplicationPortAddressing16Bit`].
+    pub const fn new(destination_port: u16, originator_port: u16) -> Self {
+        Self {
+            destination_port,
+            originator_port,
+        }
+    }
+
+    /// Returns the destination port.
+    pub const fn destination_port(&self) -> u16 {
+        self.destination_port
+    }
+
+    /// Returns the originator port.
+    pub const fn originator_port(&self) -> u16 {
+        self.originator_port
+    }
+
+    /// The bytes representation of [`ApplicationPortAddressing16Bit`].
+    const fn bytes(&self) -> [u8; Self::LENGTH] {
+        let destination_port = self.destination_port.to_be_bytes();
+        let originator_port = self.originator_port.to_be_bytes();
+
+        [
+            0x04, // IE Data Length = 4 bytes
+            destination_port[0],
+            destination_port[1],
+            originator_port[0],
+            originator_port[1],
+        ]
+    }
+
+    /// The bytes representation of [`ApplicationPortAddressing16Bit`] encoded as a full UDH.
+    pub const fn udh_bytes(&self) -> [u8; Self::UDH_LENGTH] {
+        let bytes = self.bytes();
+
+        [
+            0x06, // UDH Length = 6 bytes
+            0x05, // IEI = 05 (16-bit address)
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4],
+        ]
+    }
+}
+
+impl Length for ApplicationPortAddressing16Bit {
+    fn length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl crate::encode::Encode for ApplicationPortAddressing16Bit {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        dst[..Self::LENGTH].copy_from_slice(&self.bytes());
+
+        Self::LENGTH
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for ApplicationPortAddressing16Bit {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes());
+
+        Self::LENGTH
+    }
+}
+
+impl crate::decode::owned::Decode for ApplicationPortAddressing16Bit {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let data = information_element_data(src, 0x04)?;
+
+        Ok((
+            Self::new(
+                u16::from_be_bytes([data[0], data[1]]),
+                u16::from_be_bytes([data[2], data[3]]),
+            ),
+            Self::LENGTH,
+        ))
+    }
+}
+
+impl From<ApplicationPortAddressing16Bit> for UdhValue {
+    fn from(udh: ApplicationPortAddressing16Bit) -> Self {
+        UdhValue::ApplicationPortAddressing16Bit(udh)
+    }
+}
+
+/// Converts an [`ApplicationPortAddressing8Bit`] into an [`ApplicationPortAddressing16Bit`].
+impl From<ApplicationPortAddressing8Bit> for ApplicationPortAddressing16Bit {
+    fn from(udh: ApplicationPortAddressing8Bit) -> Self {
+        ApplicationPortAddressing16Bit::new(udh.destination_port as u16, udh.originator_port as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::{DecodeErrorKind, UdhDecodeError, owned::Decode},
+        encode::Encode,
+    };
+
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let udh = ApplicationPortAddressing8Bit::new(0xF5, 0xF0);
+
+        let mut buf = [0u8; 3];
+        let size = udh.encode(&mut buf);
+
+        assert_eq!(size, 3);
+        assert_eq!(buf, [0x02, 0xF5, 0xF0]);
+        assert_eq!(udh.udh_bytes(), [0x04, 0x04, 0x02, 0xF5, 0xF0]);
+
+        let udh = ApplicationPortAddressing16Bit::new(2948, 9200);
+
+        let mut buf = [0u8; 5];
+        let size = udh.encode(&mut buf);
+
+        assert_eq!(size, 5);
+        assert_eq!(buf, [0x04, 0x0B, 0x84, 0x23, 0xF0]);
+        assert_eq!(udh.udh_bytes(), [0x06, 0x05, 0x04, 0x0B, 0x84, 0x23, 0xF0]);
+    }
+
+    #[test]
+    fn decode() {
+        let (udh, size) = ApplicationPortAddressing8Bit::decode(&[0x02, 0xF5, 0xF0, 0xFF]).unwrap();
+
+        assert_eq!(size, 3);
+        assert_eq!(udh, ApplicationPortAddressing8Bit::new(0xF5, 0xF0));
+
+        let (udh, size) =
+            ApplicationPortAddressing16Bit::decode(&[0x04, 0x0B, 0x84, 0x23, 0xF0]).unwrap();
+
+        assert_eq!(size, 5);
+        assert_eq!(udh.destination_port(), 2948);
+        assert_eq!(udh.originator_port(), 9200);
+    }
+
+    #[test]
+    fn decode_too_few_bytes() {
+        let err = ApplicationPortAddressing16Bit::decode(&[0x04, 0x0B, 0x84]).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::TooFewBytes { actual: 3, min: 5 })
+        ));
+    }
+
+    #[test]
+    fn decode_invalid_information_element_length() {
+        let err = ApplicationPortAddressing8Bit::decode(&[0x03, 0xF5, 0xF0, 0x00]).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::InvalidInformationElementLength {
+                actual: 3,
+                expected: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn into_16_bit() {
+        assert_eq!(
+            ApplicationPortAddressing16Bit::from(ApplicationPortAddressing8Bit::new(0xF5, 0xF0)),
+            ApplicationPortAddressing16Bit::new(0xF5, 0xF0)
+        );
+    }
+}
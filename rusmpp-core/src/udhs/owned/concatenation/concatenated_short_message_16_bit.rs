@@ -163,6 +163,15 @@ impl crate::encode::Encode for ConcatenatedShortMessage16Bit {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for ConcatenatedShortMessage16Bit {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes());
+
+        Self::LENGTH
+    }
+}
+
 impl crate::decode::owned::Decode for ConcatenatedShortMessage16Bit {
     fn decode(src: &[u8]) -> Result<(Self, usize), crate::decode::DecodeError> {
         if src.len() < Self::LENGTH {
@@ -164,6 +164,15 @@ impl crate::encode::Encode for ConcatenatedShortMessage8Bit {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for ConcatenatedShortMessage8Bit {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes());
+
+        Self::LENGTH
+    }
+}
+
 impl crate::decode::owned::Decode for ConcatenatedShortMessage8Bit {
     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
         if src.len() < Self::LENGTH {
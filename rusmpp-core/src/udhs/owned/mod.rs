@@ -1,6 +1,51 @@
 //! Owned User Data Headers (UDHs).
 
+use crate::decode::{DecodeError, UdhDecodeError};
+
 mod udh;
 pub use udh::{Udh, UdhValue};
 
+mod user_data_header;
+pub use user_data_header::UserDataHeader;
+
 pub mod concatenation;
+
+mod national_language;
+pub use national_language::{NationalLanguageLockingShift, NationalLanguageSingleShift};
+
+mod application_port;
+pub use application_port::{ApplicationPortAddressing8Bit, ApplicationPortAddressing16Bit};
+
+mod special_sms_message_indication;
+pub use special_sms_message_indication::{SpecialSmsMessageIndication, SpecialSmsMessageType};
+
+mod text_formatting;
+pub use text_formatting::{Alignment, FontSize, TextColor, TextColors, TextFormatting};
+
+mod udh_source_indicator;
+pub use udh_source_indicator::UdhSourceIndicator;
+
+/// Returns the data of an information element whose IE Data Length must be `length`.
+///
+/// `src` starts with the IE Data Length.
+fn information_element_data(src: &[u8], length: u8) -> Result<&[u8], DecodeError> {
+    let min = length as usize + 1;
+
+    if src.len() < min {
+        return Err(DecodeError::udh_decode_error(UdhDecodeError::TooFewBytes {
+            actual: src.len(),
+            min,
+        }));
+    }
+
+    if src[0] != length {
+        return Err(DecodeError::udh_decode_error(
+            UdhDecodeError::InvalidInformationElementLength {
+                actual: src[0],
+                expected: length,
+            },
+        ));
+    }
+
+    Ok(&src[1..min])
+}
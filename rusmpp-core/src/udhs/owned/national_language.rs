@@ -0,0 +1,215 @@
+use crate::{
+    decode::{DecodeError, UdhDecodeError},
+    encode::Length,
+    encoding::gsm7bit::NationalLanguage,
+    udhs::owned::UdhValue,
+};
+
+macro_rules! national_language_shift {
+    ($(#[$meta:meta])* $name:ident, $iei:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name {
+            /// National language identifier.
+            language: NationalLanguage,
+        }
+
+        impl $name {
+            /// The length of the UDH value, including the IE Data Length.
+            const LENGTH: usize = 2;
+
+            /// The length of the UDH value encoded as a full UDH.
+            pub const UDH_LENGTH: usize = Self::LENGTH + 2;
+
+            /// Creates a new UDH value with the given national language identifier.
+            pub const fn new(language: NationalLanguage) -> Self {
+                Self { language }
+            }
+
+            /// Returns the national language identifier.
+            pub const fn language(&self) -> NationalLanguage {
+                self.language
+            }
+
+            /// The bytes representation of the UDH value.
+            fn bytes(&self) -> [u8; Self::LENGTH] {
+                [
+                    0x01, // IE Data Length = 1 byte
+                    self.language.into(),
+                ]
+            }
+
+            /// The bytes representation of the UDH value encoded as a full UDH.
+            pub fn udh_bytes(&self) -> [u8; Self::UDH_LENGTH] {
+                let bytes = self.bytes();
+
+                [
+                    0x03, // UDH Length = 3 bytes
+                    $iei, bytes[0], bytes[1],
+                ]
+            }
+        }
+
+        impl Length for $name {
+            fn length(&self) -> usize {
+                Self::LENGTH
+            }
+        }
+
+        impl crate::encode::Encode for $name {
+            fn encode(&self, dst: &mut [u8]) -> usize {
+                dst[..Self::LENGTH].copy_from_slice(&self.bytes());
+
+                Self::LENGTH
+            }
+        }
+
+        #[cfg(feature = "tokio-codec")]
+        impl crate::encode::EncodeBytesMut for $name {
+            fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+                tokio_util::bytes::BufMut::put_slice(dst, &self.bytes());
+
+                Self::LENGTH
+            }
+        }
+
+        impl crate::decode::owned::Decode for $name {
+            fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+                if src.len() < Self::LENGTH {
+                    return Err(DecodeError::udh_decode_error(
+                        UdhDecodeError::TooFewBytes {
+                            actual: src.len(),
+                            min: Self::LENGTH,
+                        },
+                    ));
+                }
+
+                if src[0] != 0x01_u8 {
+                    return Err(DecodeError::udh_decode_error(
+                        UdhDecodeError::InvalidInformationElementLength {
+                            actual: src[0],
+                            expected: 0x01_u8,
+                        },
+                    ));
+                }
+
+                Ok((Self::new(NationalLanguage::from(src[1])), Self::LENGTH))
+            }
+        }
+
+        impl From<$name> for UdhValue {
+            fn from(udh: $name) -> Self {
+                UdhValue::$name(udh)
+            }
+        }
+    };
+}
+
+national_language_shift! {
+    /// National Language Single Shift UDH.
+    ///
+    /// Selects the GSM 7-bit national language single shift table (IEI = 0x24).
+    ///
+    /// # Format
+    ///
+    /// This format shows the [`NationalLanguageSingleShift`] encoded as a full UDH.
+    ///
+    /// ```txt
+    /// 03 24 01 LL
+    /// │  │  │  └─ National language identifier
+    /// │  │  └──── IE Data Length = 1
+    /// │  └─────── IEI = 24 (single shift)
+    /// └────────── UDH length = 3
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// The first 2 bytes `(03 24)` are part of the UDH header and are not stored in the struct.
+    NationalLanguageSingleShift,
+    0x24
+}
+
+national_language_shift! {
+    /// National Language Locking Shift UDH.
+    ///
+    /// Selects the GSM 7-bit national language locking shift table (IEI = 0x25).
+    ///
+    /// # Format
+    ///
+    /// This format shows the [`NationalLanguageLockingShift`] encoded as a full UDH.
+    ///
+    /// ```txt
+    /// 03 25 01 LL
+    /// │  │  │  └─ National language identifier
+    /// │  │  └──── IE Data Length = 1
+    /// │  └─────── IEI = 25 (locking shift)
+    /// └────────── UDH length = 3
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// The first 2 bytes `(03 25)` are part of the UDH header and are not stored in the struct.
+    NationalLanguageLockingShift,
+    0x25
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::{DecodeErrorKind, owned::Decode},
+        encode::Encode,
+    };
+
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let udh = NationalLanguageSingleShift::new(NationalLanguage::Turkish);
+
+        let mut buf = [0u8; 2];
+        let size = udh.encode(&mut buf);
+
+        assert_eq!(size, 2);
+        assert_eq!(buf, [0x01, 0x01]);
+        assert_eq!(udh.udh_bytes(), [0x03, 0x24, 0x01, 0x01]);
+
+        let udh = NationalLanguageLockingShift::new(NationalLanguage::Hindi);
+
+        assert_eq!(udh.udh_bytes(), [0x03, 0x25, 0x01, 0x06]);
+    }
+
+    #[test]
+    fn decode() {
+        let (udh, size) = NationalLanguageLockingShift::decode(&[0x01, 0x03, 0xFF]).unwrap();
+
+        assert_eq!(size, 2);
+        assert_eq!(udh.language(), NationalLanguage::Portuguese);
+
+        let (udh, _) = NationalLanguageSingleShift::decode(&[0x01, 0x42]).unwrap();
+
+        assert_eq!(udh.language(), NationalLanguage::Other(0x42));
+    }
+
+    #[test]
+    fn decode_too_few_bytes() {
+        let err = NationalLanguageSingleShift::decode(&[0x01]).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::TooFewBytes { actual: 1, min: 2 })
+        ));
+    }
+
+    #[test]
+    fn decode_invalid_information_element_length() {
+        let err = NationalLanguageSingleShift::decode(&[0x02, 0x01]).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::InvalidInformationElementLength {
+                actual: 2,
+                expected: 1
+            })
+        ));
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    decode::DecodeError,
+    encode::Length,
+    udhs::owned::{UdhValue, information_element_data},
+};
+
+/// The type of waiting message of a [`SpecialSmsMessageIndication`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SpecialSmsMessageType {
+    /// Voice message waiting.
+    VoiceMessageWaiting,
+    /// Fax message waiting.
+    FaxMessageWaiting,
+    /// Electronic mail message waiting.
+    ElectronicMailMessageWaiting,
+    /// Video message waiting.
+    VideoMessageWaiting,
+    /// Other message waiting, with the extended message type (`0`-`7`).
+    Other(u8),
+}
+
+impl SpecialSmsMessageType {
+    /// Decodes bits `4`-`0` of the message indication octet.
+    const fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => Self::VoiceMessageWaiting,
+            0b01 => Self::FaxMessageWaiting,
+            0b10 => Self::ElectronicMailMessageWaiting,
+            _ => match (bits >> 2) & 0b111 {
+                0b001 => Self::VideoMessageWaiting,
+                extended => Self::Other(extended),
+            },
+        }
+    }
+
+    /// Encodes bits `4`-`0` of the message indication octet.
+    const fn bits(self) -> u8 {
+        match self {
+            Self::VoiceMessageWaiting => 0b00,
+            Self::FaxMessageWaiting => 0b01,
+            Self::ElectronicMailMessageWaiting => 0b10,
+            Self::VideoMessageWaiting => 0b0_0111,
+            Self::Other(extended) => ((extended & 0b111) << 2) | 0b11,
+        }
+    }
+}
+
+/// Special SMS Message Indication UDH.
+///
+/// Indicates waiting messages, e.g. voice mail (IEI = 0x01).
+///
+/// # Format
+///
+/// This format shows the [`SpecialSmsMessageIndication`] encoded as a full UDH.
+///
+/// ```txt
+/// 04 01 02 MI MC
+/// │  │  │  │  └─ Message count
+/// │  │  │  └──── Message indication type and storage
+/// │  │  └─────── IE Data Length = 2
+/// │  └────────── IEI = 01 (special SMS message indication)
+/// └───────────── UDH length = 4
+/// ```
+///
+/// The message indication octet holds the storage flag (bit 7), the profile id (bits 6-5),
+/// the extended message type (bits 4-2) and the basic message type (bits 1-0).
+///
+/// # Note
+///
+/// The first 2 bytes `(04 01)` are part of the UDH header and are not stored in the struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpecialSmsMessageIndication {
+    /// Whether the message should be stored.
+    store: bool,
+    /// Profile id (`0`-`3`).
+    profile_id: u8,
+    /// Type of the waiting messages.
+    message_type: SpecialSmsMessageType,
+    /// Number of waiting messages.
+    message_count: u8,
+}
+
+impl SpecialSmsMessageIndication {
+    /// The length of [`SpecialSmsMessageIndication`], including the IE Data Length.
+    const LENGTH: usize = 3;
+
+    /// The length of [`SpecialSmsMessageIndication`] encoded as a full UDH.
+    pub const UDH_LENGTH: usize = Self::LENGTH + 2;
+
+    /// Creates a new [`SpecialSmsMessageIndication`].
+    ///
+    /// The message is discarded after updating the indication and the profile id is `0`.
+    pub const fn new(message_type: SpecialSmsMessageType, message_count: u8) -> Self {
+        Self {
+            store: false,
+            profile_id: 0,
+            message_type,
+            message_count,
+        }
+    }
+
+    /// Sets whether the message should be stored.
+    pub const fn with_store(mut self, store: bool) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Sets the profile id. Only the two least significant bits are used.
+    pub const fn with_profile_id(mut self, profile_id: u8) -> Self {
+        self.profile_id = profile_id & 0b11;
+        self
+    }
+
+    /// Returns whether the message should be stored.
+    pub const fn store(&self) -> bool {
+        self.store
+    }
+
+    /// Returns the profile id.
+    pub const fn profile_id(&self) -> u8 {
+        self.profile_id
+    }
+
+    /// Returns the type of the waiting messages.
+    pub const fn message_type(&self) -> SpecialSmsMessageType {
+        self.message_type
+    }
+
+    /// Returns the number of waiting messages.
+    pub const fn message_count(&self) -> u8 {
+        self.message_count
+    }
+
+    /// The bytes representation of [`SpecialSmsMessageIndication`].
+    const fn bytes(&self) -> [u8; Self::LENGTH] {
+        [
+            0x02, // IE Data Length = 2 bytes
+            ((self.store as u8) << 7) | (self.profile_id << 5) | self.message_type.bits(),
+            self.message_count,
+        ]
+    }
+
+    /// The bytes representation of [`SpecialSmsMessageIndication`] encoded as a full UDH.
+    pub const fn udh_bytes(&self) -> [u8; Self::UDH_LENGTH] {
+        let bytes = self.bytes();
+
+        [
+            0x04, // UDH Length = 4 bytes
+            0x01, // IEI = 01 (special SMS message indication)
+            bytes[0], bytes[1], bytes[2],
+        ]
+    }
+}
+
+impl Length for SpecialSmsMessageIndication {
+    fn length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl crate::encode::Encode for SpecialSmsMessageIndication {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        dst[..Self::LENGTH].copy_from_slice(&self.bytes());
+
+        Self::LENGTH
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for SpecialSmsMessageIndication {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_slice(dst, &self.bytes());
+
+        Self::LENGTH
+    }
+}
+
+impl crate::decode::owned::Decode for SpecialSmsMessageIndication {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let data = information_element_data(src, 0x02)?;
+
+        let indication = data[0];
+
+        let decoded = Self::new(SpecialSmsMessageType::from_bits(indication), data[1])
+            .with_store(indication & 0b1000_0000 != 0)
+            .with_profile_id(indication >> 5);
+
+        Ok((decoded, Self::LENGTH))
+    }
+}
+
+impl From<SpecialSmsMessageIndication> for UdhValue {
+    fn from(udh: SpecialSmsMessageIndication) -> Self {
+        UdhValue::SpecialSmsMessageIndication(udh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode::owned::Decode, encode::Encode};
+
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let udh = SpecialSmsMessageIndication::new(SpecialSmsMessageType::VoiceMessageWaiting, 3)
+            .with_store(true);
+
+        let mut buf = [0u8; 3];
+        let size = udh.encode(&mut buf);
+
+        assert_eq!(size, 3);
+        assert_eq!(buf, [0x02, 0x80, 0x03]);
+        assert_eq!(udh.udh_bytes(), [0x04, 0x01, 0x02, 0x80, 0x03]);
+
+        let udh = SpecialSmsMessageIndication::new(SpecialSmsMessageType::VideoMessageWaiting, 1)
+            .with_profile_id(2);
+
+        assert_eq!(udh.udh_bytes(), [0x04, 0x01, 0x02, 0b0100_0111, 0x01]);
+    }
+
+    #[test]
+    fn decode() {
+        let (udh, size) = SpecialSmsMessageIndication::decode(&[0x02, 0x81, 0x05]).unwrap();
+
+        assert_eq!(size, 3);
+        assert!(udh.store());
+        assert_eq!(udh.profile_id(), 0);
+        assert_eq!(udh.message_type(), SpecialSmsMessageType::FaxMessageWaiting);
+        assert_eq!(udh.message_count(), 5);
+
+        let (udh, _) = SpecialSmsMessageIndication::decode(&[0x02, 0b0110_1011, 0x00]).unwrap();
+
+        assert!(!udh.store());
+        assert_eq!(udh.profile_id(), 3);
+        assert_eq!(udh.message_type(), SpecialSmsMessageType::Other(0b010));
+    }
+
+    #[test]
+    fn encode_decode() {
+        for message_type in [
+            SpecialSmsMessageType::VoiceMessageWaiting,
+            SpecialSmsMessageType::FaxMessageWaiting,
+            SpecialSmsMessageType::ElectronicMailMessageWaiting,
+            SpecialSmsMessageType::VideoMessageWaiting,
+            SpecialSmsMessageType::Other(0),
+            SpecialSmsMessageType::Other(7),
+        ] {
+            let udh = SpecialSmsMessageIndication::new(message_type, 42)
+                .with_store(true)
+                .with_profile_id(1);
+
+            let mut buf = [0u8; 3];
+            udh.encode(&mut buf);
+
+            assert_eq!(SpecialSmsMessageIndication::decode(&buf).unwrap().0, udh);
+        }
+    }
+}
@@ -0,0 +1,437 @@
+use crate::{
+    decode::{DecodeError, UdhDecodeError},
+    encode::Length,
+    udhs::owned::UdhValue,
+};
+
+/// Text alignment of a [`TextFormatting`] (bits 1-0 of the formatting mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+    /// Language dependent alignment.
+    #[default]
+    LanguageDependent,
+}
+
+/// Font size of a [`TextFormatting`] (bits 3-2 of the formatting mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum FontSize {
+    #[default]
+    Normal,
+    Large,
+    Small,
+    Reserved,
+}
+
+/// Text color of a [`TextFormatting`].
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TextColor {
+    Black = 0x0,
+    DarkGrey = 0x1,
+    DarkRed = 0x2,
+    DarkYellow = 0x3,
+    DarkGreen = 0x4,
+    DarkCyan = 0x5,
+    DarkBlue = 0x6,
+    DarkMagenta = 0x7,
+    Grey = 0x8,
+    White = 0x9,
+    BrightRed = 0xA,
+    BrightYellow = 0xB,
+    BrightGreen = 0xC,
+    BrightCyan = 0xD,
+    BrightBlue = 0xE,
+    BrightMagenta = 0xF,
+}
+
+impl TextColor {
+    /// Decodes the four least significant bits of `value`.
+    const fn from_nibble(value: u8) -> Self {
+        match value & 0x0F {
+            0x0 => Self::Black,
+            0x1 => Self::DarkGrey,
+            0x2 => Self::DarkRed,
+            0x3 => Self::DarkYellow,
+            0x4 => Self::DarkGreen,
+            0x5 => Self::DarkCyan,
+            0x6 => Self::DarkBlue,
+            0x7 => Self::DarkMagenta,
+            0x8 => Self::Grey,
+            0x9 => Self::White,
+            0xA => Self::BrightRed,
+            0xB => Self::BrightYellow,
+            0xC => Self::BrightGreen,
+            0xD => Self::BrightCyan,
+            0xE => Self::BrightBlue,
+            _ => Self::BrightMagenta,
+        }
+    }
+}
+
+/// Foreground and background colors of a [`TextFormatting`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TextColors {
+    /// Text foreground color.
+    pub foreground: TextColor,
+    /// Text background color.
+    pub background: TextColor,
+}
+
+/// Text Formatting UDH.
+///
+/// Enhanced Messaging Service (EMS) text formatting (IEI = 0x0A).
+///
+/// # Format
+///
+/// This format shows the [`TextFormatting`] encoded as a full UDH.
+///
+/// ```txt
+/// 06 0A 04 SP TL FM CC
+/// │  │  │  │  │  │  └─ Text colors (optional)
+/// │  │  │  │  │  └──── Formatting mode
+/// │  │  │  │  └─────── Text formatting length
+/// │  │  │  └────────── Start position
+/// │  │  └───────────── IE Data Length = 3 or 4
+/// │  └──────────────── IEI = 0A (text formatting)
+/// └─────────────────── UDH length = 5 or 6
+/// ```
+///
+/// The formatting mode holds the strikethrough (bit 7), underlined (bit 6), italic (bit 5) and bold (bit 4) flags,
+/// the [`FontSize`] (bits 3-2) and the [`Alignment`] (bits 1-0).
+/// The text colors hold the background (bits 7-4) and the foreground (bits 3-0) [`TextColor`].
+///
+/// # Note
+///
+/// The first 2 bytes `(06 0A)` are part of the UDH header and are not stored in the struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TextFormatting {
+    /// Position of the first formatted character.
+    start_position: u8,
+    /// Number of formatted characters.
+    text_formatting_length: u8,
+    alignment: Alignment,
+    font_size: FontSize,
+    bold: bool,
+    italic: bool,
+    underlined: bool,
+    strikethrough: bool,
+    colors: Option<TextColors>,
+}
+
+impl TextFormatting {
+    /// Creates a new [`TextFormatting`] for `text_formatting_length` characters starting at `start_position`.
+    ///
+    /// The formatting defaults to [`Alignment::LanguageDependent`], [`FontSize::Normal`] and no style or colors.
+    pub const fn new(start_position: u8, text_formatting_length: u8) -> Self {
+        Self {
+            start_position,
+            text_formatting_length,
+            alignment: Alignment::LanguageDependent,
+            font_size: FontSize::Normal,
+            bold: false,
+            italic: false,
+            underlined: false,
+            strikethrough: false,
+            colors: None,
+        }
+    }
+
+    pub const fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub const fn with_font_size(mut self, font_size: FontSize) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    pub const fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    pub const fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    pub const fn with_underlined(mut self, underlined: bool) -> Self {
+        self.underlined = underlined;
+        self
+    }
+
+    pub const fn with_strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    pub const fn with_colors(mut self, foreground: TextColor, background: TextColor) -> Self {
+        self.colors = Some(TextColors {
+            foreground,
+            background,
+        });
+        self
+    }
+
+    /// Returns the position of the first formatted character.
+    pub const fn start_position(&self) -> u8 {
+        self.start_position
+    }
+
+    /// Returns the number of formatted characters.
+    pub const fn text_formatting_length(&self) -> u8 {
+        self.text_formatting_length
+    }
+
+    pub const fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    pub const fn font_size(&self) -> FontSize {
+        self.font_size
+    }
+
+    pub const fn bold(&self) -> bool {
+        self.bold
+    }
+
+    pub const fn italic(&self) -> bool {
+        self.italic
+    }
+
+    pub const fn underlined(&self) -> bool {
+        self.underlined
+    }
+
+    pub const fn strikethrough(&self) -> bool {
+        self.strikethrough
+    }
+
+    pub const fn colors(&self) -> Option<TextColors> {
+        self.colors
+    }
+
+    /// The formatting mode octet.
+    const fn formatting_mode(&self) -> u8 {
+        let alignment = match self.alignment {
+            Alignment::Left => 0b00,
+            Alignment::Center => 0b01,
+            Alignment::Right => 0b10,
+            Alignment::LanguageDependent => 0b11,
+        };
+
+        let font_size = match self.font_size {
+            FontSize::Normal => 0b00,
+            FontSize::Large => 0b01,
+            FontSize::Small => 0b10,
+            FontSize::Reserved => 0b11,
+        };
+
+        ((self.strikethrough as u8) << 7)
+            | ((self.underlined as u8) << 6)
+            | ((self.italic as u8) << 5)
+            | ((self.bold as u8) << 4)
+            | (font_size << 2)
+            | alignment
+    }
+}
+
+impl Length for TextFormatting {
+    fn length(&self) -> usize {
+        match self.colors {
+            Some(_) => 5,
+            None => 4,
+        }
+    }
+}
+
+impl crate::encode::Encode for TextFormatting {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        let length = self.length();
+
+        dst[0] = length as u8 - 1; // IE Data Length = 3 or 4 bytes
+        dst[1] = self.start_position;
+        dst[2] = self.text_formatting_length;
+        dst[3] = self.formatting_mode();
+
+        if let Some(colors) = self.colors {
+            dst[4] = ((colors.background as u8) << 4) | colors.foreground as u8;
+        }
+
+        length
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for TextFormatting {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        use tokio_util::bytes::BufMut;
+
+        let length = self.length();
+
+        dst.put_u8(length as u8 - 1); // IE Data Length = 3 or 4 bytes
+        dst.put_u8(self.start_position);
+        dst.put_u8(self.text_formatting_length);
+        dst.put_u8(self.formatting_mode());
+
+        if let Some(colors) = self.colors {
+            dst.put_u8(((colors.background as u8) << 4) | colors.foreground as u8);
+        }
+
+        length
+    }
+}
+
+impl crate::decode::owned::Decode for TextFormatting {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        const MIN_LENGTH: usize = 4;
+
+        if src.len() < MIN_LENGTH {
+            return Err(DecodeError::udh_decode_error(UdhDecodeError::TooFewBytes {
+                actual: src.len(),
+                min: MIN_LENGTH,
+            }));
+        }
+
+        let length = src[0] as usize + 1;
+
+        if !matches!(src[0], 0x03 | 0x04) {
+            return Err(DecodeError::udh_decode_error(
+                UdhDecodeError::InvalidInformationElementLength {
+                    actual: src[0],
+                    expected: 0x03_u8,
+                },
+            ));
+        }
+
+        if src.len() < length {
+            return Err(DecodeError::udh_decode_error(UdhDecodeError::TooFewBytes {
+                actual: src.len(),
+                min: length,
+            }));
+        }
+
+        let mode = src[3];
+
+        let alignment = match mode & 0b11 {
+            0b00 => Alignment::Left,
+            0b01 => Alignment::Center,
+            0b10 => Alignment::Right,
+            _ => Alignment::LanguageDependent,
+        };
+
+        let font_size = match (mode >> 2) & 0b11 {
+            0b00 => FontSize::Normal,
+            0b01 => FontSize::Large,
+            0b10 => FontSize::Small,
+            _ => FontSize::Reserved,
+        };
+
+        let mut decoded = Self::new(src[1], src[2])
+            .with_alignment(alignment)
+            .with_font_size(font_size)
+            .with_bold(mode & 0b0001_0000 != 0)
+            .with_italic(mode & 0b0010_0000 != 0)
+            .with_underlined(mode & 0b0100_0000 != 0)
+            .with_strikethrough(mode & 0b1000_0000 != 0);
+
+        if length == 5 {
+            decoded = decoded.with_colors(
+                TextColor::from_nibble(src[4]),
+                TextColor::from_nibble(src[4] >> 4),
+            );
+        }
+
+        Ok((decoded, length))
+    }
+}
+
+impl From<TextFormatting> for UdhValue {
+    fn from(udh: TextFormatting) -> Self {
+        UdhValue::TextFormatting(udh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::{DecodeErrorKind, owned::Decode},
+        encode::Encode,
+    };
+
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let udh = TextFormatting::new(0, 5).with_bold(true);
+
+        let mut buf = [0u8; 5];
+        let size = udh.encode(&mut buf);
+
+        assert_eq!(size, 4);
+        assert_eq!(&buf[..size], &[0x03, 0x00, 0x05, 0b0001_0011]);
+
+        let udh = TextFormatting::new(2, 3)
+            .with_alignment(Alignment::Center)
+            .with_font_size(FontSize::Large)
+            .with_italic(true)
+            .with_underlined(true)
+            .with_strikethrough(true)
+            .with_colors(TextColor::BrightRed, TextColor::White);
+
+        let size = udh.encode(&mut buf);
+
+        assert_eq!(size, 5);
+        assert_eq!(buf, [0x04, 0x02, 0x03, 0b1110_0101, 0x9A]);
+    }
+
+    #[test]
+    fn decode() {
+        let (udh, size) = TextFormatting::decode(&[0x03, 0x00, 0x05, 0b0001_0011, 0xFF]).unwrap();
+
+        assert_eq!(size, 4);
+        assert_eq!(udh, TextFormatting::new(0, 5).with_bold(true));
+        assert_eq!(udh.colors(), None);
+
+        let (udh, size) = TextFormatting::decode(&[0x04, 0x02, 0x03, 0b1110_0101, 0x9A]).unwrap();
+
+        assert_eq!(size, 5);
+        assert_eq!(udh.alignment(), Alignment::Center);
+        assert_eq!(udh.font_size(), FontSize::Large);
+        assert!(!udh.bold());
+        assert!(udh.italic());
+        assert!(udh.underlined());
+        assert!(udh.strikethrough());
+        assert_eq!(
+            udh.colors(),
+            Some(TextColors {
+                foreground: TextColor::BrightRed,
+                background: TextColor::White,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_invalid_information_element_length() {
+        let err = TextFormatting::decode(&[0x05, 0x00, 0x05, 0x00, 0x00, 0x00]).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::InvalidInformationElementLength {
+                actual: 5,
+                expected: 3
+            })
+        ));
+
+        let err = TextFormatting::decode(&[0x04, 0x00, 0x05, 0x00]).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::TooFewBytes { actual: 4, min: 5 })
+        ));
+    }
+}
@@ -9,7 +9,12 @@ use crate::{
     types::owned::AnyOctetString,
     udhs::{
         UdhId,
-        owned::concatenation::{ConcatenatedShortMessage8Bit, ConcatenatedShortMessage16Bit},
+        owned::{
+            ApplicationPortAddressing8Bit, ApplicationPortAddressing16Bit,
+            NationalLanguageLockingShift, NationalLanguageSingleShift, SpecialSmsMessageIndication,
+            TextFormatting, UdhSourceIndicator,
+            concatenation::{ConcatenatedShortMessage8Bit, ConcatenatedShortMessage16Bit},
+        },
     },
 };
 
@@ -69,6 +74,20 @@ pub enum UdhValue {
     ConcatenatedShortMessage8Bit(ConcatenatedShortMessage8Bit),
     /// 16-bit Concatenated Short Message UDH.
     ConcatenatedShortMessage16Bit(ConcatenatedShortMessage16Bit),
+    /// Special SMS Message Indication UDH.
+    SpecialSmsMessageIndication(SpecialSmsMessageIndication),
+    /// 8-bit Application Port Addressing UDH.
+    ApplicationPortAddressing8Bit(ApplicationPortAddressing8Bit),
+    /// 16-bit Application Port Addressing UDH.
+    ApplicationPortAddressing16Bit(ApplicationPortAddressing16Bit),
+    /// User Data Header Source Indicator UDH.
+    UdhSourceIndicator(UdhSourceIndicator),
+    /// Text Formatting UDH.
+    TextFormatting(TextFormatting),
+    /// National Language Single Shift UDH.
+    NationalLanguageSingleShift(NationalLanguageSingleShift),
+    /// National Language Locking Shift UDH.
+    NationalLanguageLockingShift(NationalLanguageLockingShift),
     /// Other UDH types.
     Other {
         udh_id: UdhId,
@@ -82,6 +101,13 @@ impl UdhValue {
         match self {
             UdhValue::ConcatenatedShortMessage8Bit(_) => UdhId::ConcatenatedShortMessages8Bit,
             UdhValue::ConcatenatedShortMessage16Bit(_) => UdhId::ConcatenatedShortMessages16Bit,
+            UdhValue::SpecialSmsMessageIndication(_) => UdhId::SpecialSmsMessageIndication,
+            UdhValue::ApplicationPortAddressing8Bit(_) => UdhId::ApplicationPortAddressing8Bit,
+            UdhValue::ApplicationPortAddressing16Bit(_) => UdhId::ApplicationPortAddressing16Bit,
+            UdhValue::UdhSourceIndicator(_) => UdhId::UdhSourceIndicator,
+            UdhValue::TextFormatting(_) => UdhId::TextFormatting,
+            UdhValue::NationalLanguageSingleShift(_) => UdhId::NationalLanguageSingleShift,
+            UdhValue::NationalLanguageLockingShift(_) => UdhId::NationalLanguageLockingShift,
             UdhValue::Other { udh_id, .. } => *udh_id,
         }
     }
@@ -92,6 +118,13 @@ impl Length for UdhValue {
         match self {
             UdhValue::ConcatenatedShortMessage8Bit(udh) => udh.length(),
             UdhValue::ConcatenatedShortMessage16Bit(udh) => udh.length(),
+            UdhValue::SpecialSmsMessageIndication(udh) => udh.length(),
+            UdhValue::ApplicationPortAddressing8Bit(udh) => udh.length(),
+            UdhValue::ApplicationPortAddressing16Bit(udh) => udh.length(),
+            UdhValue::UdhSourceIndicator(udh) => udh.length(),
+            UdhValue::TextFormatting(udh) => udh.length(),
+            UdhValue::NationalLanguageSingleShift(udh) => udh.length(),
+            UdhValue::NationalLanguageLockingShift(udh) => udh.length(),
             UdhValue::Other { value, .. } => value.length(),
         }
     }
@@ -102,11 +135,56 @@ impl Encode for UdhValue {
         match self {
             UdhValue::ConcatenatedShortMessage8Bit(udh) => udh.encode(dst),
             UdhValue::ConcatenatedShortMessage16Bit(udh) => udh.encode(dst),
+            UdhValue::SpecialSmsMessageIndication(udh) => udh.encode(dst),
+            UdhValue::ApplicationPortAddressing8Bit(udh) => udh.encode(dst),
+            UdhValue::ApplicationPortAddressing16Bit(udh) => udh.encode(dst),
+            UdhValue::UdhSourceIndicator(udh) => udh.encode(dst),
+            UdhValue::TextFormatting(udh) => udh.encode(dst),
+            UdhValue::NationalLanguageSingleShift(udh) => udh.encode(dst),
+            UdhValue::NationalLanguageLockingShift(udh) => udh.encode(dst),
             UdhValue::Other { value, .. } => value.encode(dst),
         }
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for UdhValue {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        match self {
+            UdhValue::ConcatenatedShortMessage8Bit(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::ConcatenatedShortMessage16Bit(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::SpecialSmsMessageIndication(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::ApplicationPortAddressing8Bit(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::ApplicationPortAddressing16Bit(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::UdhSourceIndicator(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::TextFormatting(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::NationalLanguageSingleShift(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::NationalLanguageLockingShift(udh) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(udh, dst)
+            }
+            UdhValue::Other { value, .. } => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(value, dst)
+            }
+        }
+    }
+}
+
 impl DecodeWithKey for UdhValue {
     type Key = UdhId;
 
@@ -118,6 +196,25 @@ impl DecodeWithKey for UdhValue {
             UdhId::ConcatenatedShortMessages16Bit => {
                 Decode::decode(src).map_decoded(Self::ConcatenatedShortMessage16Bit)?
             }
+            UdhId::SpecialSmsMessageIndication => {
+                Decode::decode(src).map_decoded(Self::SpecialSmsMessageIndication)?
+            }
+            UdhId::ApplicationPortAddressing8Bit => {
+                Decode::decode(src).map_decoded(Self::ApplicationPortAddressing8Bit)?
+            }
+            UdhId::ApplicationPortAddressing16Bit => {
+                Decode::decode(src).map_decoded(Self::ApplicationPortAddressing16Bit)?
+            }
+            UdhId::UdhSourceIndicator => {
+                Decode::decode(src).map_decoded(Self::UdhSourceIndicator)?
+            }
+            UdhId::TextFormatting => Decode::decode(src).map_decoded(Self::TextFormatting)?,
+            UdhId::NationalLanguageSingleShift => {
+                Decode::decode(src).map_decoded(Self::NationalLanguageSingleShift)?
+            }
+            UdhId::NationalLanguageLockingShift => {
+                Decode::decode(src).map_decoded(Self::NationalLanguageLockingShift)?
+            }
             other => {
                 DecodeWithLength::decode(src, length).map_decoded(|value| UdhValue::Other {
                     udh_id: other,
@@ -162,6 +259,8 @@ impl Decode for Udh {
 
 #[cfg(test)]
 mod tests {
+    use crate::encoding::gsm7bit::NationalLanguage;
+
     use super::*;
 
     mod encode {
@@ -202,6 +301,20 @@ mod tests {
 
             assert_eq!(size, 6);
             assert_eq!(&buf[..size], &expected);
+
+            let udh = Udh::new(NationalLanguageLockingShift::new(NationalLanguage::Turkish));
+            let expected = [
+                0x03, // UDH length (following bytes = 3)
+                0x25, // UDH ID: National Language Locking Shift
+                0x01, // IE Data Length = 1 byte
+                0x01, // Turkish
+            ];
+
+            let mut buf = [0u8; 24];
+            let size = udh.encode(&mut buf);
+
+            assert_eq!(size, 4);
+            assert_eq!(&buf[..size], &expected);
         }
     }
 
@@ -250,6 +363,22 @@ mod tests {
                 udh,
                 Udh::new(ConcatenatedShortMessage8Bit::new(0x12, 3, 1).unwrap())
             );
+
+            let buf = [
+                0x03, // UDH length (following bytes = 3)
+                0x24, // UDH ID: National Language Single Shift
+                0x01, // IE Data Length = 1 byte
+                0x06, // Hindi
+                0x00, // Extra bytes
+            ];
+
+            let (udh, size) = <Udh as Decode>::decode(&buf).unwrap();
+
+            assert_eq!(size, 4);
+            assert_eq!(
+                udh,
+                Udh::new(NationalLanguageSingleShift::new(NationalLanguage::Hindi))
+            );
         }
     }
 }
@@ -0,0 +1,152 @@
+use crate::{
+    decode::DecodeError,
+    encode::Length,
+    udhs::owned::{UdhValue, information_element_data},
+};
+
+/// User Data Header Source Indicator UDH.
+///
+/// Indicates the originator of the information elements that follow it (IEI = 0x07).
+///
+/// # Format
+///
+/// This format shows the [`UdhSourceIndicator`] encoded as a full UDH.
+///
+/// ```txt
+/// 03 07 01 SI
+/// │  │  │  └─ Source indicator
+/// │  │  └──── IE Data Length = 1
+/// │  └─────── IEI = 07 (UDH source indicator)
+/// └────────── UDH length = 3
+/// ```
+///
+/// # Note
+///
+/// The first 2 bytes `(03 07)` are part of the UDH header and are not stored in the enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UdhSourceIndicator {
+    /// The following part was created by the original sender.
+    OriginalSender,
+    /// The following part was created by the original receiver.
+    OriginalReceiver,
+    /// The following part was created by the SMSC.
+    Smsc,
+    /// Reserved source indicator.
+    Other(u8),
+}
+
+impl UdhSourceIndicator {
+    /// The length of [`UdhSourceIndicator`], including the IE Data Length.
+    const LENGTH: usize = 2;
+
+    /// The length of [`UdhSourceIndicator`] encoded as a full UDH.
+    pub const UDH_LENGTH: usize = Self::LENGTH + 2;
+
+    /// Returns the source indicator octet.
+    pub const fn value(&self) -> u8 {
+        match self {
+            Self::OriginalSender => 0x01,
+            Self::OriginalReceiver => 0x02,
+            Self::Smsc => 0x03,
+            Self::Other(value) => *value,
+        }
+    }
+
+    /// The bytes representation of [`UdhSourceIndicator`] encoded as a full UDH.
+    pub const fn udh_bytes(&self) -> [u8; Self::UDH_LENGTH] {
+        [
+            0x03, // UDH Length = 3 bytes
+            0x07, // IEI = 07 (UDH source indicator)
+            0x01, // IE Data Length = 1 byte
+            self.value(),
+        ]
+    }
+}
+
+impl From<u8> for UdhSourceIndicator {
+    fn from(value: u8) -> Self {
+        match value {
+            0x01 => Self::OriginalSender,
+            0x02 => Self::OriginalReceiver,
+            0x03 => Self::Smsc,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<UdhSourceIndicator> for u8 {
+    fn from(value: UdhSourceIndicator) -> Self {
+        value.value()
+    }
+}
+
+impl Length for UdhSourceIndicator {
+    fn length(&self) -> usize {
+        Self::LENGTH
+    }
+}
+
+impl crate::encode::Encode for UdhSourceIndicator {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        dst[0] = 0x01; // IE Data Length = 1 byte
+        dst[1] = self.value();
+
+        Self::LENGTH
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for UdhSourceIndicator {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_u8(dst, 0x01); // IE Data Length = 1 byte
+        tokio_util::bytes::BufMut::put_u8(dst, self.value());
+
+        Self::LENGTH
+    }
+}
+
+impl crate::decode::owned::Decode for UdhSourceIndicator {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let data = information_element_data(src, 0x01)?;
+
+        Ok((Self::from(data[0]), Self::LENGTH))
+    }
+}
+
+impl From<UdhSourceIndicator> for UdhValue {
+    fn from(udh: UdhSourceIndicator) -> Self {
+        UdhValue::UdhSourceIndicator(udh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode::owned::Decode, encode::Encode};
+
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let mut buf = [0u8; 2];
+        let size = UdhSourceIndicator::Smsc.encode(&mut buf);
+
+        assert_eq!(size, 2);
+        assert_eq!(buf, [0x01, 0x03]);
+        assert_eq!(
+            UdhSourceIndicator::OriginalReceiver.udh_bytes(),
+            [0x03, 0x07, 0x01, 0x02]
+        );
+    }
+
+    #[test]
+    fn decode() {
+        let (udh, size) = UdhSourceIndicator::decode(&[0x01, 0x01, 0xFF]).unwrap();
+
+        assert_eq!(size, 2);
+        assert_eq!(udh, UdhSourceIndicator::OriginalSender);
+
+        let (udh, _) = UdhSourceIndicator::decode(&[0x01, 0x10]).unwrap();
+
+        assert_eq!(udh, UdhSourceIndicator::Other(0x10));
+    }
+}
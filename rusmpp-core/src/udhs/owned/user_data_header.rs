@@ -0,0 +1,361 @@
+use alloc::vec::Vec;
+
+use crate::{
+    decode::{
+        DecodeError, UdhDecodeError,
+        owned::{Decode, DecodeWithKey},
+    },
+    encode::{Encode, Length},
+    udhs::{
+        UdhId,
+        owned::{
+            ApplicationPortAddressing16Bit, UdhValue, concatenation::ConcatenatedShortMessage,
+        },
+    },
+};
+
+/// User Data Header (UDH) containing several information elements.
+///
+/// Unlike [`Udh`](super::Udh), which holds a single information element,
+/// [`UserDataHeader`] holds every information element found in the header of a `short_message`
+/// (or `message_payload`) sent with the UDHI indicator set.
+///
+/// # Format
+///
+/// ```txt
+/// 0B 05 04 0B 84 23 F0 00 03 2A 02 01
+/// │  │                 └─ Second information element
+/// │  └─────────────────── First information element (IEI, IE Data Length, IE Data)
+/// └────────────────────── UDH length = 11
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::{
+///     decode::owned::Decode,
+///     udhs::owned::{ApplicationPortAddressing16Bit, UserDataHeader},
+/// };
+///
+/// let short_message = [
+///     0x06, 0x05, 0x04, 0x0B, 0x84, 0x23, 0xF0, // UDH
+///     0x01, 0x02, 0x03, // Payload
+/// ];
+///
+/// let (udh, size) = UserDataHeader::decode(&short_message).unwrap();
+///
+/// assert_eq!(
+///     udh.application_port(),
+///     Some(ApplicationPortAddressing16Bit::new(2948, 9200))
+/// );
+/// assert_eq!(&short_message[size..], &[0x01, 0x02, 0x03]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserDataHeader {
+    elements: Vec<UdhValue>,
+}
+
+impl UserDataHeader {
+    /// Creates a new [`UserDataHeader`] from the given information elements.
+    pub fn new(elements: impl IntoIterator<Item = impl Into<UdhValue>>) -> Self {
+        Self {
+            elements: elements.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Appends an information element.
+    pub fn push(&mut self, element: impl Into<UdhValue>) {
+        self.elements.push(element.into());
+    }
+
+    /// Appends an information element.
+    pub fn with_element(mut self, element: impl Into<UdhValue>) -> Self {
+        self.push(element);
+        self
+    }
+
+    /// Returns the information elements.
+    pub fn elements(&self) -> &[UdhValue] {
+        &self.elements
+    }
+
+    /// Consumes the [`UserDataHeader`] and returns the information elements.
+    pub fn into_elements(self) -> Vec<UdhValue> {
+        self.elements
+    }
+
+    /// Returns `true` if the header contains no information elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the first information element with the given identifier.
+    pub fn get(&self, id: UdhId) -> Option<&UdhValue> {
+        self.elements.iter().find(|element| element.id() == id)
+    }
+
+    /// Returns the application port addressing information element.
+    ///
+    /// 8-bit ports are converted to [`ApplicationPortAddressing16Bit`].
+    pub fn application_port(&self) -> Option<ApplicationPortAddressing16Bit> {
+        self.elements.iter().find_map(|element| match element {
+            UdhValue::ApplicationPortAddressing8Bit(ports) => Some((*ports).into()),
+            UdhValue::ApplicationPortAddressing16Bit(ports) => Some(*ports),
+            _ => None,
+        })
+    }
+
+    /// Returns the concatenated short message information element.
+    pub fn concatenation(&self) -> Option<ConcatenatedShortMessage> {
+        self.elements.iter().find_map(|element| match element {
+            UdhValue::ConcatenatedShortMessage8Bit(concatenation) => {
+                Some(ConcatenatedShortMessage::EightBit(concatenation.clone()))
+            }
+            UdhValue::ConcatenatedShortMessage16Bit(concatenation) => {
+                Some(ConcatenatedShortMessage::SixteenBit(concatenation.clone()))
+            }
+            _ => None,
+        })
+    }
+}
+
+impl Length for UserDataHeader {
+    fn length(&self) -> usize {
+        1 + self
+            .elements
+            .iter()
+            .map(|element| element.id().length() + element.length())
+            .sum::<usize>()
+    }
+}
+
+impl Encode for UserDataHeader {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        dst[0] = (self.length() - 1) as u8;
+
+        let mut size = 1;
+
+        for element in self.elements.iter() {
+            size += element.id().encode(&mut dst[size..]);
+            size += element.encode(&mut dst[size..]);
+        }
+
+        size
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for UserDataHeader {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        tokio_util::bytes::BufMut::put_u8(dst, (self.length() - 1) as u8);
+
+        let mut size = 1;
+
+        for element in self.elements.iter() {
+            size += element.id().encode_buf_mut(dst);
+            size += element.encode_buf_mut(dst);
+        }
+
+        size
+    }
+}
+
+impl Decode for UserDataHeader {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let Some(&udh_length) = src.first() else {
+            return Err(DecodeError::udh_decode_error(UdhDecodeError::TooFewBytes {
+                actual: 0,
+                min: 1,
+            }));
+        };
+
+        let end = 1 + udh_length as usize;
+
+        if src.len() < end {
+            return Err(DecodeError::udh_decode_error(UdhDecodeError::TooFewBytes {
+                actual: src.len(),
+                min: end,
+            }));
+        }
+
+        let mut elements = Vec::new();
+        let mut size = 1;
+
+        while size < end {
+            // IEI and IE Data Length
+            if end - size < 2 {
+                return Err(DecodeError::udh_decode_error(UdhDecodeError::TooFewBytes {
+                    actual: end - size,
+                    min: 2,
+                }));
+            }
+
+            let id = UdhId::from(src[size]);
+            let value_end = size + 2 + src[size + 1] as usize;
+
+            if value_end > end {
+                return Err(DecodeError::udh_decode_error(UdhDecodeError::TooFewBytes {
+                    actual: end - size,
+                    min: value_end - size,
+                }));
+            }
+
+            // The value starts with the IE Data Length and must not read past its own element.
+            let (value, _) = <UdhValue as DecodeWithKey>::decode(
+                id,
+                &src[size + 1..value_end],
+                value_end - size - 1,
+            )?;
+
+            elements.push(value);
+            size = value_end;
+        }
+
+        Ok((Self { elements }, size))
+    }
+}
+
+impl From<UdhValue> for UserDataHeader {
+    fn from(value: UdhValue) -> Self {
+        Self {
+            elements: alloc::vec![value],
+        }
+    }
+}
+
+impl FromIterator<UdhValue> for UserDataHeader {
+    fn from_iter<T: IntoIterator<Item = UdhValue>>(iter: T) -> Self {
+        Self {
+            elements: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::DecodeErrorKind,
+        encoding::gsm7bit::NationalLanguage,
+        types::owned::AnyOctetString,
+        udhs::owned::{
+            ApplicationPortAddressing8Bit, NationalLanguageSingleShift, TextFormatting,
+            concatenation::ConcatenatedShortMessage8Bit,
+        },
+    };
+
+    use super::*;
+
+    const BYTES: [u8; 17] = [
+        0x0E, // UDH length = 14
+        0x05, 0x04, 0x0B, 0x84, 0x23, 0xF0, // 16-bit application port addressing
+        0x00, 0x03, 0x2A, 0x02, 0x01, // 8-bit concatenation
+        0x24, 0x01, 0x01, // National language single shift
+        b'H', b'i', // Payload
+    ];
+
+    fn header() -> UserDataHeader {
+        UserDataHeader::default()
+            .with_element(ApplicationPortAddressing16Bit::new(2948, 9200))
+            .with_element(ConcatenatedShortMessage8Bit::new(0x2A, 2, 1).unwrap())
+            .with_element(NationalLanguageSingleShift::new(NationalLanguage::Turkish))
+    }
+
+    #[test]
+    fn encode() {
+        let udh = header();
+
+        let mut buf = [0u8; 32];
+        let size = udh.encode(&mut buf);
+
+        assert_eq!(size, 15);
+        assert_eq!(udh.length(), 15);
+        assert_eq!(&buf[..size], &BYTES[..15]);
+    }
+
+    #[test]
+    fn decode() {
+        let (udh, size) = UserDataHeader::decode(&BYTES).unwrap();
+
+        assert_eq!(size, 15);
+        assert_eq!(udh, header());
+        assert_eq!(&BYTES[size..], b"Hi");
+
+        assert_eq!(
+            udh.application_port(),
+            Some(ApplicationPortAddressing16Bit::new(2948, 9200))
+        );
+        assert_eq!(
+            udh.concatenation(),
+            Some(ConcatenatedShortMessage::EightBit(
+                ConcatenatedShortMessage8Bit::new(0x2A, 2, 1).unwrap()
+            ))
+        );
+        assert!(udh.get(UdhId::NationalLanguageSingleShift).is_some());
+        assert!(udh.get(UdhId::TextFormatting).is_none());
+    }
+
+    #[test]
+    fn decode_unknown_and_variable_length_elements() {
+        let bytes = [
+            0x0B, // UDH length = 11
+            0x04, 0x02, 0xF5, 0xF0, // 8-bit application port addressing
+            0x0A, 0x03, 0x00, 0x02, 0x10, // Text formatting
+            0x70, 0x00, // Unknown element without data
+        ];
+
+        let (udh, size) = UserDataHeader::decode(&bytes).unwrap();
+
+        assert_eq!(size, 12);
+        assert_eq!(
+            udh.elements(),
+            &[
+                UdhValue::ApplicationPortAddressing8Bit(ApplicationPortAddressing8Bit::new(
+                    0xF5, 0xF0
+                )),
+                UdhValue::TextFormatting(
+                    TextFormatting::new(0, 2)
+                        .with_bold(true)
+                        .with_alignment(crate::udhs::owned::Alignment::Left)
+                ),
+                UdhValue::Other {
+                    udh_id: UdhId::Other(0x70),
+                    value: AnyOctetString::new([0x00]),
+                },
+            ]
+        );
+        assert_eq!(
+            udh.application_port(),
+            Some(ApplicationPortAddressing16Bit::new(0xF5, 0xF0))
+        );
+
+        let mut buf = [0u8; 12];
+        assert_eq!(udh.encode(&mut buf), 12);
+        assert_eq!(buf, bytes);
+    }
+
+    #[test]
+    fn decode_element_overflows_header() {
+        let bytes = [
+            0x05, // UDH length = 5
+            0x05, 0x04, 0x0B, 0x84, 0x23, // 16-bit application port addressing, truncated
+            0xF0,
+        ];
+
+        let err = UserDataHeader::decode(&bytes).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::TooFewBytes { actual: 5, min: 6 })
+        ));
+    }
+
+    #[test]
+    fn decode_header_overflows_source() {
+        let err = UserDataHeader::decode(&[0x06, 0x05, 0x04]).unwrap_err();
+
+        assert!(matches!(
+            err.kind(),
+            DecodeErrorKind::UdhDecodeError(UdhDecodeError::TooFewBytes { actual: 3, min: 7 })
+        ));
+    }
+}
@@ -0,0 +1,540 @@
+//! Semantic validation of `SMPP` PDUs.
+//!
+//! Decoding only checks the wire structure of a PDU. The [`Validate`] trait checks the semantic rules
+//! of the specification and returns the [`CommandStatus`] a compliant MC or ESME should respond with.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusmpp_core::{
+//!     CommandStatus, pdus::owned::SubmitSm, types::owned::OctetString, validate::Validate,
+//!     values::EsmClass,
+//! };
+//!
+//! // The UDH Indicator is set, but the short_message does not start with a valid UDH.
+//! let submit_sm = SubmitSm::builder()
+//!     .esm_class(EsmClass::default().with_udhi_indicator())
+//!     .short_message(OctetString::new(b"\x05\x00\x03").unwrap())
+//!     .build();
+//!
+//! assert_eq!(submit_sm.validate(), Err(CommandStatus::EsmeRinvesmclass));
+//! ```
+
+use crate::{
+    CommandStatus,
+    command::owned::Command,
+    decode::owned::Decode,
+    pdus::owned::*,
+    tlvs::{
+        TlvTag,
+        owned::{
+            BroadcastRequestTlvValue, BroadcastResponseTlvValue, CancelBroadcastTlvValue,
+            MessageDeliveryRequestTlvValue, MessageDeliveryResponseTlvValue,
+            MessageSubmissionRequestTlvValue, MessageSubmissionResponseTlvValue,
+            QueryBroadcastResponseTlvValue, Tlv, message_payload,
+        },
+    },
+    types::owned::{EmptyOrFullCOctetString, OctetString},
+    udhs::owned::UserDataHeader,
+    values::EsmClass,
+};
+
+/// Semantic validation of `SMPP` PDUs.
+///
+/// See the [module level documentation](self).
+pub trait Validate {
+    /// Validates the semantic rules of `self`.
+    ///
+    /// Returns the [`CommandStatus`] describing the first violated rule.
+    fn validate(&self) -> Result<(), CommandStatus>;
+}
+
+/// Maximum sm_length of a single message.
+///
+/// A short message holds at most 140 octets whatever the [`DataCoding`](crate::values::DataCoding),
+/// e.g. 140 unpacked GSM 7-bit characters or 70 UCS2 characters.
+const MAX_SM_LENGTH: usize = 140;
+
+fn validate_tlvs(tlvs: &[Tlv], allows: fn(TlvTag) -> bool) -> Result<(), CommandStatus> {
+    match tlvs.iter().all(|tlv| allows(tlv.tag())) {
+        true => Ok(()),
+        false => Err(CommandStatus::EsmeRtlvnotallwd),
+    }
+}
+
+fn validate_tlv(tlv: Option<&Tlv>, tag: TlvTag) -> Result<(), CommandStatus> {
+    match tlv.is_none_or(|tlv| tlv.tag() == tag) {
+        true => Ok(()),
+        false => Err(CommandStatus::EsmeRtlvnotallwd),
+    }
+}
+
+fn validate_required_tlv(tlvs: &[Tlv], tag: TlvTag) -> Result<(), CommandStatus> {
+    match tlvs.iter().any(|tlv| tlv.tag() == tag) {
+        true => Ok(()),
+        false => Err(CommandStatus::EsmeRmissingtlv),
+    }
+}
+
+fn validate_time(
+    time: &EmptyOrFullCOctetString<17>,
+    status: CommandStatus,
+) -> Result<(), CommandStatus> {
+    time.to_smpp_time().map(|_| ()).map_err(|_| status)
+}
+
+/// Validates the short_message and the message_payload TLV.
+fn validate_short_message(
+    esm_class: EsmClass,
+    sm_length: u8,
+    short_message: &OctetString<0, 255>,
+    tlvs: &[Tlv],
+) -> Result<(), CommandStatus> {
+    let sm_length = sm_length as usize;
+    let has_message_payload = tlvs.iter().any(|tlv| tlv.tag() == TlvTag::MessagePayload);
+
+    if sm_length != short_message.bytes().len()
+        || (has_message_payload && sm_length != 0)
+        || sm_length > MAX_SM_LENGTH
+    {
+        return Err(CommandStatus::EsmeRinvmsglen);
+    }
+
+    let user_data = match short_message.bytes() {
+        [] => message_payload(tlvs),
+        short_message => short_message,
+    };
+
+    validate_user_data_header(esm_class, user_data)
+}
+
+fn validate_user_data_header(esm_class: EsmClass, user_data: &[u8]) -> Result<(), CommandStatus> {
+    if !esm_class.has_udhi_indicator() {
+        return Ok(());
+    }
+
+    UserDataHeader::decode(user_data)
+        .map(|_| ())
+        .map_err(|_| CommandStatus::EsmeRinvesmclass)
+}
+
+macro_rules! impl_validate_ok {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl Validate for $name {
+                fn validate(&self) -> Result<(), CommandStatus> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_ok!(
+    BindTransmitter,
+    BindReceiver,
+    BindTransceiver,
+    Outbind,
+    QuerySm,
+    QuerySmResp,
+    CancelSm,
+);
+
+macro_rules! impl_validate_bind_resp {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl Validate for $name {
+                fn validate(&self) -> Result<(), CommandStatus> {
+                    validate_tlv(self.sc_interface_version_tlv(), TlvTag::ScInterfaceVersion)
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_bind_resp!(BindTransmitterResp, BindReceiverResp, BindTransceiverResp);
+
+impl Validate for AlertNotification {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlv(
+            self.ms_availability_status_tlv(),
+            TlvTag::MsAvailabilityStatus,
+        )
+    }
+}
+
+impl Validate for SubmitSm {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), MessageSubmissionRequestTlvValue::allows)?;
+        validate_time(&self.schedule_delivery_time, CommandStatus::EsmeRinvsched)?;
+        validate_time(&self.validity_period, CommandStatus::EsmeRinvexpiry)?;
+        validate_short_message(
+            self.esm_class,
+            self.sm_length(),
+            self.short_message(),
+            self.tlvs(),
+        )
+    }
+}
+
+impl Validate for SubmitSmResp {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), MessageSubmissionResponseTlvValue::allows)
+    }
+}
+
+impl Validate for DeliverSm {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), MessageDeliveryRequestTlvValue::allows)?;
+        validate_short_message(
+            self.esm_class,
+            self.sm_length(),
+            self.short_message(),
+            self.tlvs(),
+        )
+    }
+}
+
+impl Validate for DeliverSmResp {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), MessageDeliveryResponseTlvValue::allows)
+    }
+}
+
+impl Validate for DataSm {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        // data_sm is used in both directions.
+        validate_tlvs(self.tlvs(), |tag| {
+            MessageSubmissionRequestTlvValue::allows(tag)
+                || MessageDeliveryRequestTlvValue::allows(tag)
+        })?;
+        validate_user_data_header(self.esm_class, message_payload(self.tlvs()))
+    }
+}
+
+impl Validate for DataSmResp {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), MessageDeliveryResponseTlvValue::allows)
+    }
+}
+
+impl Validate for ReplaceSm {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlv(self.message_payload_tlv(), TlvTag::MessagePayload)?;
+        validate_time(&self.schedule_delivery_time, CommandStatus::EsmeRinvsched)?;
+        validate_time(&self.validity_period, CommandStatus::EsmeRinvexpiry)?;
+
+        let sm_length = self.sm_length() as usize;
+
+        if sm_length != self.short_message().bytes().len()
+            || (self.message_payload_tlv().is_some() && sm_length != 0)
+        {
+            return Err(CommandStatus::EsmeRinvmsglen);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for SubmitMulti {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        let number_of_dests = self.number_of_dests() as usize;
+
+        if number_of_dests == 0
+            || number_of_dests > 254
+            || number_of_dests != self.dest_address().len()
+        {
+            return Err(CommandStatus::EsmeRinvnumdests);
+        }
+
+        validate_tlvs(self.tlvs(), MessageSubmissionRequestTlvValue::allows)?;
+        validate_time(&self.schedule_delivery_time, CommandStatus::EsmeRinvsched)?;
+        validate_time(&self.validity_period, CommandStatus::EsmeRinvexpiry)?;
+        validate_short_message(
+            self.esm_class,
+            self.sm_length(),
+            self.short_message(),
+            self.tlvs(),
+        )
+    }
+}
+
+impl Validate for SubmitMultiResp {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), MessageSubmissionResponseTlvValue::allows)
+    }
+}
+
+impl Validate for BroadcastSm {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), BroadcastRequestTlvValue::allows)?;
+
+        for tag in [
+            TlvTag::BroadcastAreaIdentifier,
+            TlvTag::BroadcastContentType,
+            TlvTag::BroadcastRepNum,
+            TlvTag::BroadcastFrequencyInterval,
+        ] {
+            validate_required_tlv(self.tlvs(), tag)?;
+        }
+
+        validate_time(&self.schedule_delivery_time, CommandStatus::EsmeRinvsched)?;
+        validate_time(&self.validity_period, CommandStatus::EsmeRinvexpiry)
+    }
+}
+
+impl Validate for BroadcastSmResp {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), BroadcastResponseTlvValue::allows)
+    }
+}
+
+impl Validate for QueryBroadcastSm {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlv(
+            self.user_message_reference_tlv(),
+            TlvTag::UserMessageReference,
+        )
+    }
+}
+
+impl Validate for QueryBroadcastSmResp {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), QueryBroadcastResponseTlvValue::allows)?;
+        validate_required_tlv(self.tlvs(), TlvTag::MessageState)?;
+        validate_required_tlv(self.tlvs(), TlvTag::BroadcastAreaIdentifier)?;
+        validate_required_tlv(self.tlvs(), TlvTag::BroadcastAreaSuccess)
+    }
+}
+
+impl Validate for CancelBroadcastSm {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        validate_tlvs(self.tlvs(), CancelBroadcastTlvValue::allows)
+    }
+}
+
+impl Validate for Pdu {
+    fn validate(&self) -> Result<(), CommandStatus> {
+        match self {
+            Pdu::BindTransmitter(pdu) => pdu.validate(),
+            Pdu::BindTransmitterResp(pdu) => pdu.validate(),
+            Pdu::BindReceiver(pdu) => pdu.validate(),
+            Pdu::BindReceiverResp(pdu) => pdu.validate(),
+            Pdu::BindTransceiver(pdu) => pdu.validate(),
+            Pdu::BindTransceiverResp(pdu) => pdu.validate(),
+            Pdu::Outbind(pdu) => pdu.validate(),
+            Pdu::AlertNotification(pdu) => pdu.validate(),
+            Pdu::SubmitSm(pdu) => pdu.validate(),
+            Pdu::SubmitSmResp(pdu) => pdu.validate(),
+            Pdu::QuerySm(pdu) => pdu.validate(),
+            Pdu::QuerySmResp(pdu) => pdu.validate(),
+            Pdu::DeliverSm(pdu) => pdu.validate(),
+            Pdu::DeliverSmResp(pdu) => pdu.validate(),
+            Pdu::DataSm(pdu) => pdu.validate(),
+            Pdu::DataSmResp(pdu) => pdu.validate(),
+            Pdu::CancelSm(pdu) => pdu.validate(),
+            Pdu::ReplaceSm(pdu) => pdu.validate(),
+            Pdu::SubmitMulti(pdu) => pdu.validate(),
+            Pdu::SubmitMultiResp(pdu) => pdu.validate(),
+            Pdu::BroadcastSm(pdu) => pdu.validate(),
+            Pdu::BroadcastSmResp(pdu) => pdu.validate(),
+            Pdu::QueryBroadcastSm(pdu) => pdu.validate(),
+            Pdu::QueryBroadcastSmResp(pdu) => pdu.validate(),
+            Pdu::CancelBroadcastSm(pdu) => pdu.validate(),
+            Pdu::Unbind
+            | Pdu::UnbindResp
+            | Pdu::EnquireLink
+            | Pdu::EnquireLinkResp
+            | Pdu::GenericNack
+            | Pdu::CancelSmResp
+            | Pdu::ReplaceSmResp
+            | Pdu::CancelBroadcastSmResp
+            | Pdu::Other { .. } => Ok(()),
+        }
+    }
+}
+
+impl Validate for Command {
+    /// Validates the [`Pdu`] of this [`Command`].
+    ///
+    /// Commands without a [`Pdu`] are valid.
+    fn validate(&self) -> Result<(), CommandStatus> {
+        self.pdu().map_or(Ok(()), Validate::validate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use crate::{
+        decode::owned::DecodeWithLength,
+        encode::{Encode, Length},
+        tlvs::owned::{MessageDeliveryRequestTlvValue, TlvValue},
+        types::owned::{AnyOctetString, COctetString},
+        values::{DataCoding, owned::*},
+    };
+
+    use super::*;
+
+    /// Encodes the PDU followed by the extra TLVs and decodes the result.
+    fn with_extra_tlvs<T: Encode + DecodeWithLength>(pdu: T, tlvs: &[Tlv]) -> T {
+        let length = pdu.length() + tlvs.iter().map(Length::length).sum::<usize>();
+        let mut buf = alloc::vec![0; length];
+
+        let mut size = pdu.encode(&mut buf);
+
+        for tlv in tlvs {
+            size += tlv.encode(&mut buf[size..]);
+        }
+
+        T::decode(&buf, size).unwrap().0
+    }
+
+    #[test]
+    fn valid() {
+        let submit_sm = SubmitSm::builder()
+            .short_message(OctetString::new(b"Hello").unwrap())
+            .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(1))
+            .build();
+
+        assert_eq!(submit_sm.validate(), Ok(()));
+        assert_eq!(Pdu::from(submit_sm).validate(), Ok(()));
+        assert_eq!(Pdu::EnquireLink.validate(), Ok(()));
+        assert_eq!(Command::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn message_payload_with_short_message() {
+        let submit_sm = with_extra_tlvs(
+            SubmitSm::builder()
+                .short_message(OctetString::new(b"Hello").unwrap())
+                .build(),
+            &[Tlv::new(TlvValue::MessagePayload(MessagePayload::new(
+                AnyOctetString::new(b"Hello"),
+            )))],
+        );
+
+        assert_eq!(submit_sm.validate(), Err(CommandStatus::EsmeRinvmsglen));
+    }
+
+    #[test]
+    fn malformed_user_data_header() {
+        let submit_sm = SubmitSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .short_message(OctetString::new(b"\x05\x00\x03").unwrap())
+            .build();
+
+        assert_eq!(submit_sm.validate(), Err(CommandStatus::EsmeRinvesmclass));
+
+        let submit_sm = SubmitSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .short_message(OctetString::new(b"\x05\x00\x03\x01\x02\x01Hello").unwrap())
+            .build();
+
+        assert_eq!(submit_sm.validate(), Ok(()));
+    }
+
+    #[test]
+    fn tlv_not_allowed() {
+        let submit_sm = with_extra_tlvs(
+            SubmitSm::default(),
+            &[Tlv::new(TlvValue::ReceiptedMessageId(
+                COctetString::from_str("id").unwrap(),
+            ))],
+        );
+
+        assert_eq!(submit_sm.validate(), Err(CommandStatus::EsmeRtlvnotallwd));
+
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(
+                COctetString::from_str("id").unwrap(),
+            ))
+            .build();
+
+        assert_eq!(deliver_sm.validate(), Ok(()));
+
+        // Vendor-specific TLVs are allowed.
+        let submit_sm = with_extra_tlvs(
+            SubmitSm::default(),
+            &[Tlv::new(TlvValue::Other {
+                tag: TlvTag::Other(0x1400),
+                value: AnyOctetString::new(b"vendor"),
+            })],
+        );
+
+        assert_eq!(submit_sm.validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_number_of_dests() {
+        assert_eq!(
+            SubmitMulti::default().validate(),
+            Err(CommandStatus::EsmeRinvnumdests)
+        );
+
+        let submit_multi = SubmitMulti::builder()
+            .push_dest_address(DestAddress::DistributionListName(
+                DistributionListName::new(COctetString::from_str("list").unwrap()),
+            ))
+            .build();
+
+        assert_eq!(submit_multi.validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_time() {
+        let submit_sm = SubmitSm::builder()
+            .validity_period(EmptyOrFullCOctetString::from_str("2023-10-01T12:00").unwrap())
+            .build();
+
+        assert_eq!(submit_sm.validate(), Err(CommandStatus::EsmeRinvexpiry));
+
+        let submit_sm = SubmitSm::builder()
+            .schedule_delivery_time(EmptyOrFullCOctetString::from_str("2023-10-01T12:00").unwrap())
+            .build();
+
+        assert_eq!(submit_sm.validate(), Err(CommandStatus::EsmeRinvsched));
+
+        let submit_sm = SubmitSm::builder()
+            .validity_period(EmptyOrFullCOctetString::from_str("000001000000000R").unwrap())
+            .build();
+
+        assert_eq!(submit_sm.validate(), Ok(()));
+    }
+
+    #[test]
+    fn sm_length_above_140_octets() {
+        for data_coding in [
+            DataCoding::McSpecific,
+            DataCoding::Ia5,
+            DataCoding::GsmMwiControl,
+            DataCoding::Ucs2,
+            DataCoding::Latin1,
+            DataCoding::Cyrillic,
+            DataCoding::LatinHebrew,
+        ] {
+            let submit_sm = SubmitSm::builder()
+                .data_coding(data_coding)
+                .short_message(OctetString::new([b'a'; 141]).unwrap())
+                .build();
+
+            assert_eq!(submit_sm.validate(), Err(CommandStatus::EsmeRinvmsglen));
+
+            let submit_sm = SubmitSm::builder()
+                .data_coding(data_coding)
+                .short_message(OctetString::new([b'a'; 140]).unwrap())
+                .build();
+
+            assert_eq!(submit_sm.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn missing_broadcast_tlvs() {
+        assert_eq!(
+            BroadcastSm::default().validate(),
+            Err(CommandStatus::EsmeRmissingtlv)
+        );
+    }
+}
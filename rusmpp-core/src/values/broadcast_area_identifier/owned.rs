@@ -1,6 +1,6 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::types::owned::AnyOctetString;
+use crate::{types::owned::AnyOctetString, values::borrowed};
 
 use super::BroadcastAreaFormat;
 
@@ -9,7 +9,10 @@ use super::BroadcastAreaFormat;
 #[rusmpp(decode = owned)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct BroadcastAreaIdentifier {
     pub format: BroadcastAreaFormat,
     #[rusmpp(length = "unchecked")]
@@ -22,6 +25,32 @@ impl BroadcastAreaIdentifier {
     }
 }
 
+impl From<borrowed::BroadcastAreaIdentifier<'_>> for BroadcastAreaIdentifier {
+    fn from(value: borrowed::BroadcastAreaIdentifier<'_>) -> Self {
+        Self {
+            format: value.format,
+            area: value.area.into(),
+        }
+    }
+}
+
+impl borrowed::BroadcastAreaIdentifier<'_> {
+    /// Converts this borrowed [`BroadcastAreaIdentifier`](borrowed::BroadcastAreaIdentifier) into an owned [`BroadcastAreaIdentifier`].
+    pub fn into_owned(self) -> BroadcastAreaIdentifier {
+        self.into()
+    }
+}
+
+impl BroadcastAreaIdentifier {
+    /// Returns a borrowed [`BroadcastAreaIdentifier`](borrowed::BroadcastAreaIdentifier) view of this [`BroadcastAreaIdentifier`].
+    pub fn as_borrowed(&self) -> borrowed::BroadcastAreaIdentifier<'_> {
+        borrowed::BroadcastAreaIdentifier {
+            format: self.format,
+            area: self.area.as_borrowed(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,582 @@
+use super::{
+    data_coding::DataCoding,
+    ms_msg_wait_facilities::{Indicator, TypeOfMessage},
+};
+
+/// The [`DataCoding`] octet decoded into its coding group, see 3GPP TS 23.038.
+///
+/// `SMPP` redefines the `0000xxxx` coding group of 3GPP TS 23.038 with its own alphabets, see [`DataCodingScheme::Smpp`].
+/// The remaining coding groups follow 3GPP TS 23.038.
+///
+/// Converting an octet into a [`DataCodingScheme`] and back always yields the same octet.
+/// Octets that set reserved bits are kept as [`DataCodingScheme::Reserved`].
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::values::{
+///     DataCoding, DataCodingAlphabet, DataCodingScheme, GsmAlphabet, MessageClass,
+/// };
+///
+/// // Flash SMS using UCS2.
+/// let scheme = DataCodingScheme::from(DataCoding::from(0x18));
+///
+/// assert_eq!(
+///     scheme,
+///     DataCodingScheme::General {
+///         alphabet: GsmAlphabet::Ucs2,
+///         message_class: Some(MessageClass::Class0),
+///         compressed: false,
+///         automatic_deletion: false,
+///     }
+/// );
+///
+/// assert_eq!(scheme.alphabet(), DataCodingAlphabet::Ucs2);
+/// assert_eq!(scheme.message_class(), Some(MessageClass::Class0));
+///
+/// assert_eq!(DataCoding::from(scheme), DataCoding::from(0x18));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
+pub enum DataCodingScheme {
+    /// `0000xxxx`: `SMPP` specific alphabets.
+    Smpp(DataCoding),
+    /// `00xxxxxx` and `01xxxxxx`: General data coding indication.
+    General {
+        /// The alphabet of the short message.
+        alphabet: GsmAlphabet,
+        /// The message class, if any.
+        message_class: Option<MessageClass>,
+        /// The text is compressed.
+        compressed: bool,
+        /// The message is marked for automatic deletion after reading, `01xxxxxx`.
+        automatic_deletion: bool,
+    },
+    /// `1100xxxx`, `1101xxxx` and `1110xxxx`: Message waiting indication.
+    MessageWaiting {
+        /// Whether the message is discarded or stored, and its alphabet.
+        group: MessageWaitingGroup,
+        /// Sets or clears the indication.
+        indicator: Indicator,
+        /// The type of the waiting message.
+        type_of_message: TypeOfMessage,
+    },
+    /// `1111xxxx`: Data coding and message class.
+    MessageClass {
+        /// The alphabet of the short message.
+        ///
+        /// [`GsmAlphabet::Ucs2`] is not defined in this coding group and is encoded as [`DataCodingScheme::General`].
+        alphabet: GsmAlphabet,
+        /// The message class.
+        message_class: MessageClass,
+    },
+    /// Reserved coding groups `1000xxxx` to `1011xxxx`, a reserved alphabet or reserved bits.
+    Reserved(u8),
+}
+
+/// Alphabets of the 3GPP TS 23.038 coding groups.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
+pub enum GsmAlphabet {
+    /// GSM 7-bit default alphabet.
+    #[default]
+    Gsm7Bit = 0b00,
+    /// 8-bit data.
+    Octet = 0b01,
+    /// UCS2.
+    Ucs2 = 0b10,
+}
+
+/// Message class of the 3GPP TS 23.038 coding groups.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
+pub enum MessageClass {
+    /// Class 0, displayed immediately and not stored (flash SMS).
+    #[default]
+    Class0 = 0b00,
+    /// Class 1, mobile equipment specific.
+    Class1 = 0b01,
+    /// Class 2, SIM specific.
+    Class2 = 0b10,
+    /// Class 3, terminal equipment specific.
+    Class3 = 0b11,
+}
+
+/// Message waiting indication coding groups.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
+pub enum MessageWaitingGroup {
+    /// `1100xxxx`: Discard the message, GSM 7-bit default alphabet.
+    #[default]
+    Discard = 0b1100,
+    /// `1101xxxx`: Store the message, GSM 7-bit default alphabet.
+    Store = 0b1101,
+    /// `1110xxxx`: Store the message, UCS2.
+    StoreUcs2 = 0b1110,
+}
+
+/// The alphabet of a [`DataCodingScheme`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DataCodingAlphabet {
+    /// GSM 7-bit default alphabet.
+    Gsm7Bit,
+    /// 8-bit data.
+    Octet,
+    /// UCS2.
+    Ucs2,
+    /// Latin 1 (ISO-8859-1).
+    Latin1,
+    /// IA5 (CCITT T.50)/ASCII (ANSI X3.4).
+    Ia5,
+    /// Any other `SMPP` specific alphabet, e.g. [`DataCoding::Jis`].
+    Other,
+}
+
+impl DataCodingScheme {
+    /// Returns the alphabet of the short message.
+    ///
+    /// [`DataCoding::McSpecific`] and reserved coding groups use the GSM 7-bit default alphabet.
+    pub fn alphabet(&self) -> DataCodingAlphabet {
+        match self {
+            Self::Smpp(data_coding) => match data_coding {
+                DataCoding::McSpecific => DataCodingAlphabet::Gsm7Bit,
+                DataCoding::Ia5 => DataCodingAlphabet::Ia5,
+                DataCoding::OctetUnspecified | DataCoding::OctetUnspecified2 => {
+                    DataCodingAlphabet::Octet
+                }
+                DataCoding::Latin1 => DataCodingAlphabet::Latin1,
+                DataCoding::Ucs2 => DataCodingAlphabet::Ucs2,
+                _ => DataCodingAlphabet::Other,
+            },
+            Self::General { alphabet, .. } | Self::MessageClass { alphabet, .. } => {
+                (*alphabet).into()
+            }
+            Self::MessageWaiting { group, .. } => match group {
+                MessageWaitingGroup::Discard | MessageWaitingGroup::Store => {
+                    DataCodingAlphabet::Gsm7Bit
+                }
+                MessageWaitingGroup::StoreUcs2 => DataCodingAlphabet::Ucs2,
+            },
+            Self::Reserved(_) => DataCodingAlphabet::Gsm7Bit,
+        }
+    }
+
+    /// Returns the message class, if any.
+    pub const fn message_class(&self) -> Option<MessageClass> {
+        match self {
+            Self::General { message_class, .. } => *message_class,
+            Self::MessageClass { message_class, .. } => Some(*message_class),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the text is compressed.
+    pub const fn is_compressed(&self) -> bool {
+        matches!(
+            self,
+            Self::General {
+                compressed: true,
+                ..
+            }
+        )
+    }
+}
+
+impl From<GsmAlphabet> for DataCodingAlphabet {
+    fn from(value: GsmAlphabet) -> Self {
+        match value {
+            GsmAlphabet::Gsm7Bit => Self::Gsm7Bit,
+            GsmAlphabet::Octet => Self::Octet,
+            GsmAlphabet::Ucs2 => Self::Ucs2,
+        }
+    }
+}
+
+impl From<u8> for DataCodingScheme {
+    fn from(value: u8) -> Self {
+        let group = value >> 4;
+
+        match group {
+            0b0000 => Self::Smpp(DataCoding::from(value)),
+            0b0001..=0b0111 => {
+                let alphabet = match (value >> 2) & 0b11 {
+                    0b00 => GsmAlphabet::Gsm7Bit,
+                    0b01 => GsmAlphabet::Octet,
+                    0b10 => GsmAlphabet::Ucs2,
+                    _ => return Self::Reserved(value),
+                };
+
+                let message_class = match value & 0b0001_0000 {
+                    0 if value & 0b11 != 0 => return Self::Reserved(value),
+                    0 => None,
+                    _ => Some(MessageClass::from_bits(value)),
+                };
+
+                Self::General {
+                    alphabet,
+                    message_class,
+                    compressed: value & 0b0010_0000 != 0,
+                    automatic_deletion: value & 0b0100_0000 != 0,
+                }
+            }
+            0b1100..=0b1110 => {
+                if value & 0b0100 != 0 {
+                    return Self::Reserved(value);
+                }
+
+                let group = match group {
+                    0b1100 => MessageWaitingGroup::Discard,
+                    0b1101 => MessageWaitingGroup::Store,
+                    _ => MessageWaitingGroup::StoreUcs2,
+                };
+
+                let indicator = match value & 0b1000 {
+                    0 => Indicator::Inactive,
+                    _ => Indicator::Active,
+                };
+
+                Self::MessageWaiting {
+                    group,
+                    indicator,
+                    type_of_message: TypeOfMessage::from(value & 0b11),
+                }
+            }
+            0b1111 => {
+                if value & 0b1000 != 0 {
+                    return Self::Reserved(value);
+                }
+
+                let alphabet = match value & 0b0100 {
+                    0 => GsmAlphabet::Gsm7Bit,
+                    _ => GsmAlphabet::Octet,
+                };
+
+                Self::MessageClass {
+                    alphabet,
+                    message_class: MessageClass::from_bits(value),
+                }
+            }
+            _ => Self::Reserved(value),
+        }
+    }
+}
+
+impl From<DataCodingScheme> for u8 {
+    fn from(value: DataCodingScheme) -> Self {
+        match value {
+            DataCodingScheme::Smpp(data_coding) => data_coding.into(),
+            DataCodingScheme::General {
+                alphabet,
+                message_class,
+                compressed,
+                automatic_deletion,
+            } => {
+                let class = match message_class {
+                    Some(message_class) => 0b0001_0000 | message_class as u8,
+                    None => 0,
+                };
+
+                ((automatic_deletion as u8) << 6)
+                    | ((compressed as u8) << 5)
+                    | ((alphabet as u8) << 2)
+                    | class
+            }
+            DataCodingScheme::MessageWaiting {
+                group,
+                indicator,
+                type_of_message,
+            } => {
+                let indicator = match indicator {
+                    Indicator::Active => 0b1000,
+                    _ => 0,
+                };
+
+                ((group as u8) << 4) | indicator | (u8::from(type_of_message) & 0b11)
+            }
+            DataCodingScheme::MessageClass {
+                alphabet: GsmAlphabet::Ucs2,
+                message_class,
+            } => 0b0001_1000 | message_class as u8,
+            DataCodingScheme::MessageClass {
+                alphabet,
+                message_class,
+            } => 0b1111_0000 | ((alphabet as u8) << 2) | message_class as u8,
+            DataCodingScheme::Reserved(value) => value,
+        }
+    }
+}
+
+impl From<DataCoding> for DataCodingScheme {
+    fn from(value: DataCoding) -> Self {
+        Self::from(u8::from(value))
+    }
+}
+
+impl From<DataCodingScheme> for DataCoding {
+    fn from(value: DataCodingScheme) -> Self {
+        Self::from(u8::from(value))
+    }
+}
+
+#[cfg(feature = "serde-deserialize")]
+impl<'de> ::serde::Deserialize<'de> for DataCodingScheme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        #[derive(::serde::Deserialize)]
+        #[serde(rename = "DataCodingScheme")]
+        enum DataCodingSchemeParts {
+            Smpp(DataCoding),
+            General {
+                alphabet: GsmAlphabet,
+                message_class: Option<MessageClass>,
+                compressed: bool,
+                automatic_deletion: bool,
+            },
+            MessageWaiting {
+                group: MessageWaitingGroup,
+                indicator: Indicator,
+                type_of_message: TypeOfMessage,
+            },
+            MessageClass {
+                alphabet: GsmAlphabet,
+                message_class: MessageClass,
+            },
+            Reserved(u8),
+        }
+
+        let value =
+            match <DataCodingSchemeParts as ::serde::Deserialize>::deserialize(deserializer)? {
+                DataCodingSchemeParts::Smpp(data_coding) => Self::Smpp(data_coding),
+                DataCodingSchemeParts::General {
+                    alphabet,
+                    message_class,
+                    compressed,
+                    automatic_deletion,
+                } => Self::General {
+                    alphabet,
+                    message_class,
+                    compressed,
+                    automatic_deletion,
+                },
+                DataCodingSchemeParts::MessageWaiting {
+                    group,
+                    indicator,
+                    type_of_message,
+                } => Self::MessageWaiting {
+                    group,
+                    indicator,
+                    type_of_message,
+                },
+                DataCodingSchemeParts::MessageClass {
+                    alphabet,
+                    message_class,
+                } => Self::MessageClass {
+                    alphabet,
+                    message_class,
+                },
+                DataCodingSchemeParts::Reserved(value) => Self::Reserved(value),
+            };
+
+        crate::deserialize::Check::check(&value).map_err(::serde::de::Error::custom)?;
+
+        Ok(value)
+    }
+}
+
+impl DataCoding {
+    /// Decodes the [`DataCoding`] into a [`DataCodingScheme`].
+    pub fn scheme(self) -> DataCodingScheme {
+        self.into()
+    }
+}
+
+impl MessageClass {
+    /// Decodes the message class from the two least significant bits of `value`.
+    const fn from_bits(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::Class0,
+            0b01 => Self::Class1,
+            0b10 => Self::Class2,
+            _ => Self::Class3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for value in 0..=u8::MAX {
+            let scheme = DataCodingScheme::from(value);
+
+            assert_eq!(u8::from(scheme), value, "{scheme:?}");
+            assert_eq!(DataCoding::from(scheme), DataCoding::from(value));
+        }
+    }
+
+    #[test]
+    fn smpp() {
+        assert_eq!(
+            DataCodingScheme::from(0x03),
+            DataCodingScheme::Smpp(DataCoding::Latin1)
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0x00).alphabet(),
+            DataCodingAlphabet::Gsm7Bit
+        );
+        assert_eq!(
+            DataCodingScheme::from(0x04).alphabet(),
+            DataCodingAlphabet::Octet
+        );
+        assert_eq!(
+            DataCodingScheme::from(0x08).alphabet(),
+            DataCodingAlphabet::Ucs2
+        );
+        assert_eq!(
+            DataCodingScheme::from(0x05).alphabet(),
+            DataCodingAlphabet::Other
+        );
+    }
+
+    #[test]
+    fn general() {
+        assert_eq!(
+            DataCodingScheme::from(0x10),
+            DataCodingScheme::General {
+                alphabet: GsmAlphabet::Gsm7Bit,
+                message_class: Some(MessageClass::Class0),
+                compressed: false,
+                automatic_deletion: false,
+            }
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0x76),
+            DataCodingScheme::General {
+                alphabet: GsmAlphabet::Octet,
+                message_class: Some(MessageClass::Class2),
+                compressed: true,
+                automatic_deletion: true,
+            }
+        );
+
+        assert!(DataCodingScheme::from(0x24).is_compressed());
+
+        // Reserved alphabet.
+        assert_eq!(
+            DataCodingScheme::from(0x1C),
+            DataCodingScheme::Reserved(0x1C)
+        );
+        // Message class bits without the message class flag.
+        assert_eq!(
+            DataCodingScheme::from(0x21),
+            DataCodingScheme::Reserved(0x21)
+        );
+    }
+
+    #[test]
+    fn message_waiting() {
+        assert_eq!(
+            DataCodingScheme::from(0xC8),
+            DataCodingScheme::MessageWaiting {
+                group: MessageWaitingGroup::Discard,
+                indicator: Indicator::Active,
+                type_of_message: TypeOfMessage::VoicemailMessageWaiting,
+            }
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0xE2),
+            DataCodingScheme::MessageWaiting {
+                group: MessageWaitingGroup::StoreUcs2,
+                indicator: Indicator::Inactive,
+                type_of_message: TypeOfMessage::ElectronicMailMessageWaiting,
+            }
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0xE2).alphabet(),
+            DataCodingAlphabet::Ucs2
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0xD4),
+            DataCodingScheme::Reserved(0xD4)
+        );
+    }
+
+    #[test]
+    fn message_class() {
+        assert_eq!(
+            DataCodingScheme::from(0xF0),
+            DataCodingScheme::MessageClass {
+                alphabet: GsmAlphabet::Gsm7Bit,
+                message_class: MessageClass::Class0,
+            }
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0xF6),
+            DataCodingScheme::MessageClass {
+                alphabet: GsmAlphabet::Octet,
+                message_class: MessageClass::Class2,
+            }
+        );
+
+        assert_eq!(
+            u8::from(DataCodingScheme::MessageClass {
+                alphabet: GsmAlphabet::Ucs2,
+                message_class: MessageClass::Class1,
+            }),
+            0x19
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0xF8),
+            DataCodingScheme::Reserved(0xF8)
+        );
+    }
+
+    #[test]
+    fn reserved() {
+        assert_eq!(
+            DataCodingScheme::from(0x80),
+            DataCodingScheme::Reserved(0x80)
+        );
+        assert_eq!(
+            DataCodingScheme::from(0xB3).alphabet(),
+            DataCodingAlphabet::Gsm7Bit
+        );
+    }
+}
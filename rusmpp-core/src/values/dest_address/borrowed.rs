@@ -38,6 +38,18 @@ impl Encode for DestAddress<'_> {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for DestAddress<'_> {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        match self {
+            Self::SmeAddress(sa) => crate::encode::EncodeBytesMut::encode_buf_mut(sa, dst),
+            Self::DistributionListName(dlm) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(dlm, dst)
+            }
+        }
+    }
+}
+
 impl<'a> Decode<'a> for DestAddress<'a> {
     fn decode(src: &'a [u8]) -> Result<(Self, usize), DecodeError> {
         let size = 0;
@@ -66,7 +78,7 @@ pub struct SmeAddress<'a> {
     ///
     /// Can't and shouldn't be updated
     #[rusmpp(skip_decode)]
-    dest_flag: DestFlag,
+    pub(crate) dest_flag: DestFlag,
     /// Type of Number for destination.
     pub dest_addr_ton: Ton,
     /// Numbering Plan Indicator for destination.
@@ -106,7 +118,7 @@ pub struct DistributionListName<'a> {
     ///
     /// Can't and shouldn't be updated.
     #[rusmpp(skip_decode)]
-    dest_flag: DestFlag,
+    pub(crate) dest_flag: DestFlag,
     /// Name of Distribution List.
     pub dl_name: COctetString<'a, 1, 21>,
 }
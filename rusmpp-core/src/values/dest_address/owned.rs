@@ -7,13 +7,16 @@ use crate::{
     },
     encode::{Encode, Length},
     types::owned::COctetString,
-    values::{dest_address::DestFlag, npi::Npi, ton::Ton},
+    values::{borrowed, dest_address::DestFlag, npi::Npi, ton::Ton},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum DestAddress {
     /// SME Format Destination Address.
     SmeAddress(SmeAddress),
@@ -39,6 +42,18 @@ impl Encode for DestAddress {
     }
 }
 
+#[cfg(feature = "tokio-codec")]
+impl crate::encode::EncodeBytesMut for DestAddress {
+    fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+        match self {
+            Self::SmeAddress(sa) => crate::encode::EncodeBytesMut::encode_buf_mut(sa, dst),
+            Self::DistributionListName(dlm) => {
+                crate::encode::EncodeBytesMut::encode_buf_mut(dlm, dst)
+            }
+        }
+    }
+}
+
 impl Decode for DestAddress {
     fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
         let size = 0;
@@ -59,10 +74,16 @@ impl Decode for DestAddress {
 
 /// SME Format Destination Address.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct SmeAddress {
     /// 0x01 (SME Address).
     ///
@@ -100,10 +121,16 @@ impl SmeAddress {
 
 /// Distribution List Format Destination Address.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
-#[rusmpp(decode = owned, test = skip)]
+#[rusmpp(decode = owned, test = skip, deserialize = checked)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
 pub struct DistributionListName {
     /// 0x02 (Distribution List).
     ///
@@ -127,6 +154,92 @@ impl DistributionListName {
     }
 }
 
+impl From<borrowed::SmeAddress<'_>> for SmeAddress {
+    fn from(value: borrowed::SmeAddress<'_>) -> Self {
+        Self {
+            dest_flag: value.dest_flag,
+            dest_addr_ton: value.dest_addr_ton,
+            dest_addr_npi: value.dest_addr_npi,
+            destination_addr: value.destination_addr.into(),
+        }
+    }
+}
+
+impl borrowed::SmeAddress<'_> {
+    /// Converts this borrowed [`SmeAddress`](borrowed::SmeAddress) into an owned [`SmeAddress`].
+    pub fn into_owned(self) -> SmeAddress {
+        self.into()
+    }
+}
+
+impl SmeAddress {
+    /// Returns a borrowed [`SmeAddress`](borrowed::SmeAddress) view of this [`SmeAddress`].
+    pub fn as_borrowed(&self) -> borrowed::SmeAddress<'_> {
+        borrowed::SmeAddress {
+            dest_flag: self.dest_flag,
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.as_borrowed(),
+        }
+    }
+}
+
+impl From<borrowed::DistributionListName<'_>> for DistributionListName {
+    fn from(value: borrowed::DistributionListName<'_>) -> Self {
+        Self {
+            dest_flag: value.dest_flag,
+            dl_name: value.dl_name.into(),
+        }
+    }
+}
+
+impl borrowed::DistributionListName<'_> {
+    /// Converts this borrowed [`DistributionListName`](borrowed::DistributionListName) into an owned [`DistributionListName`].
+    pub fn into_owned(self) -> DistributionListName {
+        self.into()
+    }
+}
+
+impl DistributionListName {
+    /// Returns a borrowed [`DistributionListName`](borrowed::DistributionListName) view of this [`DistributionListName`].
+    pub fn as_borrowed(&self) -> borrowed::DistributionListName<'_> {
+        borrowed::DistributionListName {
+            dest_flag: self.dest_flag,
+            dl_name: self.dl_name.as_borrowed(),
+        }
+    }
+}
+
+impl From<borrowed::DestAddress<'_>> for DestAddress {
+    fn from(value: borrowed::DestAddress<'_>) -> Self {
+        match value {
+            borrowed::DestAddress::SmeAddress(value) => Self::SmeAddress(value.into()),
+            borrowed::DestAddress::DistributionListName(value) => {
+                Self::DistributionListName(value.into())
+            }
+        }
+    }
+}
+
+impl borrowed::DestAddress<'_> {
+    /// Converts this borrowed [`DestAddress`](borrowed::DestAddress) into an owned [`DestAddress`].
+    pub fn into_owned(self) -> DestAddress {
+        self.into()
+    }
+}
+
+impl DestAddress {
+    /// Returns a borrowed [`DestAddress`](borrowed::DestAddress) view of this [`DestAddress`].
+    pub fn as_borrowed(&self) -> borrowed::DestAddress<'_> {
+        match self {
+            Self::SmeAddress(value) => borrowed::DestAddress::SmeAddress(value.as_borrowed()),
+            Self::DistributionListName(value) => {
+                borrowed::DestAddress::DistributionListName(value.as_borrowed())
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     //! See tests in [borrowed](super::super::borrowed) for more details.
@@ -11,7 +11,10 @@ use rusmpp_macros::Rusmpp;
 #[rusmpp(repr = "u8", test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct EsmClass {
     /// Messaging Mode (bits 1-0).
     pub messaging_mode: MessagingMode,
@@ -38,6 +41,16 @@ impl EsmClass {
         }
     }
 
+    /// Sets the Message Type field.
+    pub const fn with_message_type(self, message_type: MessageType) -> Self {
+        Self {
+            messaging_mode: self.messaging_mode,
+            message_type,
+            ansi41_specific: self.ansi41_specific,
+            gsm_features: self.gsm_features,
+        }
+    }
+
     /// Sets the UDH Indicator bit in the GSM Features field.
     pub const fn with_udhi_indicator(self) -> Self {
         Self {
@@ -47,6 +60,28 @@ impl EsmClass {
             gsm_features: GsmFeatures::UdhiIndicator,
         }
     }
+
+    /// Clears the UDH Indicator bit in the GSM Features field, keeping the Reply Path bit.
+    pub const fn without_udhi_indicator(self) -> Self {
+        let gsm_features = match self.gsm_features {
+            GsmFeatures::UdhiIndicator => GsmFeatures::NotSelected,
+            GsmFeatures::SetUdhiAndReplyPath => GsmFeatures::SetReplyPath,
+            GsmFeatures::Other(value) => GsmFeatures::Other(value & !0b01_00_00_00),
+            gsm_features => gsm_features,
+        };
+
+        Self {
+            messaging_mode: self.messaging_mode,
+            message_type: self.message_type,
+            ansi41_specific: self.ansi41_specific,
+            gsm_features,
+        }
+    }
+
+    /// Returns `true` if the UDH Indicator bit is set in the GSM Features field.
+    pub fn has_udhi_indicator(&self) -> bool {
+        u8::from(self.gsm_features) & 0b01_00_00_00 != 0
+    }
 }
 
 impl From<u8> for EsmClass {
@@ -74,7 +109,10 @@ impl From<EsmClass> for u8 {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum MessagingMode {
     /// Default MC Mode (e.g. Store and Forward).
     #[default]
@@ -96,7 +134,10 @@ pub enum MessagingMode {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum MessageType {
     /// Default message Type (i.e. normal message).
     #[default]
@@ -113,7 +154,10 @@ pub enum MessageType {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum Ansi41Specific {
     /// Short Message contains Delivery Acknowledgement.
     #[default]
@@ -130,7 +174,10 @@ pub enum Ansi41Specific {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum GsmFeatures {
     /// No specific features selected.
     #[default]
@@ -184,4 +231,30 @@ mod tests {
         crate::tests::owned::encode_decode_test_instances::<Ansi41Specific>();
         crate::tests::borrowed::encode_decode_test_instances::<GsmFeatures>();
     }
+
+    #[test]
+    fn has_udhi_indicator() {
+        assert!(!EsmClass::default().has_udhi_indicator());
+        assert!(
+            EsmClass::default()
+                .with_udhi_indicator()
+                .has_udhi_indicator()
+        );
+        assert!(EsmClass::from(0b11_00_00_00).has_udhi_indicator());
+        assert!(!EsmClass::from(0b10_00_00_00).has_udhi_indicator());
+    }
+
+    #[test]
+    fn without_udhi_indicator() {
+        assert_eq!(
+            EsmClass::default()
+                .with_udhi_indicator()
+                .without_udhi_indicator(),
+            EsmClass::default()
+        );
+        assert_eq!(
+            u8::from(EsmClass::from(0b11_00_00_11).without_udhi_indicator()),
+            0b10_00_00_11
+        );
+    }
 }
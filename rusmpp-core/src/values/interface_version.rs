@@ -5,7 +5,10 @@ use rusmpp_macros::Rusmpp;
 #[rusmpp(from_into = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum InterfaceVersion {
     Smpp3_3OrEarlier(u8),
     Smpp3_4 = 0x34,
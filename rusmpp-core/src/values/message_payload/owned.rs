@@ -1,12 +1,15 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::types::owned::AnyOctetString;
+use crate::{types::owned::AnyOctetString, values::borrowed};
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
 #[rusmpp(decode = owned)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct MessagePayload {
     #[rusmpp(length = "unchecked")]
     pub value: AnyOctetString,
@@ -30,6 +33,30 @@ impl From<MessagePayload> for AnyOctetString {
     }
 }
 
+impl From<borrowed::MessagePayload<'_>> for MessagePayload {
+    fn from(value: borrowed::MessagePayload<'_>) -> Self {
+        Self {
+            value: value.value.into(),
+        }
+    }
+}
+
+impl borrowed::MessagePayload<'_> {
+    /// Converts this borrowed [`MessagePayload`](borrowed::MessagePayload) into an owned [`MessagePayload`].
+    pub fn into_owned(self) -> MessagePayload {
+        self.into()
+    }
+}
+
+impl MessagePayload {
+    /// Returns a borrowed [`MessagePayload`](borrowed::MessagePayload) view of this [`MessagePayload`].
+    pub fn as_borrowed(&self) -> borrowed::MessagePayload<'_> {
+        borrowed::MessagePayload {
+            value: self.value.as_borrowed(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
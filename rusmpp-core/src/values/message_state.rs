@@ -6,7 +6,10 @@ use rusmpp_macros::Rusmpp;
 #[repr(u8)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub enum MessageState {
     /// The message is scheduled. Delivery has not
     /// yet been initiated.
@@ -100,6 +103,55 @@ pub enum MessageState {
     Other(u8),
 }
 
+impl MessageState {
+    /// Maps the `stat` field of a delivery receipt text onto a [`MessageState`].
+    ///
+    /// Accepts the abbreviations of the `SMPP` specification, e.g. `DELIVRD`, and the full state names, e.g. `DELIVERED`.
+    /// The comparison is case-insensitive.
+    pub fn from_receipt_stat(stat: &str) -> Option<Self> {
+        const STATS: [(&str, &str, MessageState); 10] = [
+            ("SCHEDUL", "SCHEDULED", MessageState::Scheduled),
+            ("ENROUTE", "ENROUTE", MessageState::Enroute),
+            ("DELIVRD", "DELIVERED", MessageState::Delivered),
+            ("EXPIRED", "EXPIRED", MessageState::Expired),
+            ("DELETED", "DELETED", MessageState::Deleted),
+            ("UNDELIV", "UNDELIVERABLE", MessageState::Undeliverable),
+            ("ACCEPTD", "ACCEPTED", MessageState::Accepted),
+            ("UNKNOWN", "UNKNOWN", MessageState::Unknown),
+            ("REJECTD", "REJECTED", MessageState::Rejected),
+            ("SKIPPED", "SKIPPED", MessageState::Skipped),
+        ];
+
+        let stat = stat.trim();
+
+        STATS
+            .iter()
+            .find(|(short, long, _)| {
+                stat.eq_ignore_ascii_case(short) || stat.eq_ignore_ascii_case(long)
+            })
+            .map(|(_, _, state)| *state)
+    }
+
+    /// Returns the `stat` abbreviation used in delivery receipt texts, e.g. `DELIVRD`.
+    ///
+    /// Returns `None` for [`MessageState::Other`].
+    pub const fn receipt_stat(&self) -> Option<&'static str> {
+        match self {
+            Self::Scheduled => Some("SCHEDUL"),
+            Self::Enroute => Some("ENROUTE"),
+            Self::Delivered => Some("DELIVRD"),
+            Self::Expired => Some("EXPIRED"),
+            Self::Deleted => Some("DELETED"),
+            Self::Undeliverable => Some("UNDELIV"),
+            Self::Accepted => Some("ACCEPTD"),
+            Self::Unknown => Some("UNKNOWN"),
+            Self::Rejected => Some("REJECTD"),
+            Self::Skipped => Some("SKIPPED"),
+            Self::Other(_) => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,4 +161,27 @@ mod tests {
         crate::tests::owned::encode_decode_test_instances::<MessageState>();
         crate::tests::borrowed::encode_decode_test_instances::<MessageState>();
     }
+
+    #[test]
+    fn receipt_stat() {
+        assert_eq!(
+            MessageState::from_receipt_stat("DELIVRD"),
+            Some(MessageState::Delivered)
+        );
+        assert_eq!(
+            MessageState::from_receipt_stat(" undeliverable "),
+            Some(MessageState::Undeliverable)
+        );
+        assert_eq!(MessageState::from_receipt_stat("FOO"), None);
+
+        for value in 0..=9 {
+            let state = MessageState::from(value);
+
+            let stat = state.receipt_stat().unwrap();
+
+            assert_eq!(MessageState::from_receipt_stat(stat), Some(state));
+        }
+
+        assert_eq!(MessageState::Other(42).receipt_stat(), None);
+    }
 }
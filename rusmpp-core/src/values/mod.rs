@@ -51,6 +51,11 @@ pub use congestion_state::CongestionState;
 mod data_coding;
 pub use data_coding::DataCoding;
 
+mod data_coding_scheme;
+pub use data_coding_scheme::{
+    DataCodingAlphabet, DataCodingScheme, GsmAlphabet, MessageClass, MessageWaitingGroup,
+};
+
 mod delivery_failure_reason;
 pub use delivery_failure_reason::DeliveryFailureReason;
 
@@ -131,6 +136,12 @@ pub use service_type::GenericServiceType;
 mod set_dpf;
 pub use set_dpf::SetDpf;
 
+mod smpp_time;
+pub use smpp_time::{SmppDateTime, SmppTime, SmppTimeError};
+
+mod smpp_address;
+pub use smpp_address::SmppAddressError;
+
 mod sub_address;
 pub use sub_address::SubaddressTag;
 
@@ -190,6 +201,7 @@ pub mod owned {
     pub use super::dest_address::owned::{DestAddress, DistributionListName, SmeAddress};
     pub use super::message_payload::owned::MessagePayload;
     pub use super::service_type::owned::ServiceType;
+    pub use super::smpp_address::owned::SmppAddress;
     pub use super::sub_address::owned::Subaddress;
     pub use super::unsuccess_sme::owned::UnsuccessSme;
 }
@@ -55,7 +55,7 @@ impl<'a> From<GenericServiceType> for ServiceType<'a> {
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub struct ServiceType<'a> {
-    value: COctetString<'a, 1, 6>,
+    pub(crate) value: COctetString<'a, 1, 6>,
 }
 
 impl<'a> ServiceType<'a> {
@@ -1,6 +1,6 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::types::owned::COctetString;
+use crate::{types::owned::COctetString, values::borrowed};
 
 use super::GenericServiceType;
 
@@ -54,7 +54,10 @@ impl From<GenericServiceType> for ServiceType {
 #[rusmpp(decode = owned)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct ServiceType {
     value: COctetString<1, 6>,
 }
@@ -76,6 +79,30 @@ impl ServiceType {
     }
 }
 
+impl From<borrowed::ServiceType<'_>> for ServiceType {
+    fn from(value: borrowed::ServiceType<'_>) -> Self {
+        Self {
+            value: value.value.into(),
+        }
+    }
+}
+
+impl borrowed::ServiceType<'_> {
+    /// Converts this borrowed [`ServiceType`](borrowed::ServiceType) into an owned [`ServiceType`].
+    pub fn into_owned(self) -> ServiceType {
+        self.into()
+    }
+}
+
+impl ServiceType {
+    /// Returns a borrowed [`ServiceType`](borrowed::ServiceType) view of this [`ServiceType`].
+    pub fn as_borrowed(&self) -> borrowed::ServiceType<'_> {
+        borrowed::ServiceType {
+            value: self.value.as_borrowed(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
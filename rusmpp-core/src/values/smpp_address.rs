@@ -0,0 +1,55 @@
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod owned;
+
+use crate::values::{npi::Npi, ton::Ton};
+
+/// Errors that can occur when creating an [`SmppAddress`](owned::SmppAddress).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SmppAddressError {
+    /// The address is empty.
+    Empty,
+    /// The address is too long.
+    TooLong {
+        /// The length of the address.
+        ///
+        /// Counted in septets for alphanumeric addresses.
+        length: usize,
+        /// The maximum length of the address.
+        max: usize,
+    },
+    /// The address contains a character that is not allowed.
+    InvalidCharacter {
+        /// The character.
+        character: char,
+        /// The index of the character.
+        index: usize,
+    },
+    /// The [`Ton`] and [`Npi`] can not be used together or with the address.
+    InvalidCombination {
+        /// The Type of Number.
+        ton: Ton,
+        /// The Numbering Plan Indicator.
+        npi: Npi,
+    },
+}
+
+impl core::fmt::Display for SmppAddressError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Empty address"),
+            Self::TooLong { length, max } => {
+                write!(f, "Address too long. actual: {length}, max: {max}")
+            }
+            Self::InvalidCharacter { character, index } => {
+                write!(f, "Invalid character {character:?} at index {index}")
+            }
+            Self::InvalidCombination { ton, npi } => {
+                write!(f, "Invalid combination. ton: {ton:?}, npi: {npi:?}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SmppAddressError {}
@@ -0,0 +1,541 @@
+use crate::{
+    encoding::gsm7bit::{Gsm7Bit, Gsm7BitEncodeError},
+    types::owned::{COctetString, OctetString},
+    values::{
+        npi::Npi,
+        owned::{DestAddress, SmeAddress},
+        smpp_address::SmppAddressError,
+        ton::Ton,
+    },
+};
+
+/// Maximum number of characters of an address, excluding the NULL octet.
+const MAX_ADDRESS_LENGTH: usize = 20;
+
+/// Maximum number of digits of an international E.164 number.
+const MAX_INTERNATIONAL_LENGTH: usize = 15;
+
+/// Maximum number of GSM 7-bit characters of an alphanumeric address.
+const MAX_ALPHANUMERIC_LENGTH: usize = 11;
+
+/// Maximum number of digits of a short code.
+const MAX_SHORT_CODE_LENGTH: usize = 8;
+
+/// Digit mode indicator of a `callback_num` encoded as ASCII.
+const CALLBACK_NUM_ASCII_DIGIT_MODE: u8 = 0x01;
+
+/// Maximum number of digits of a `callback_num`.
+const MAX_CALLBACK_NUM_LENGTH: usize = 16;
+
+/// Checks that `address` contains only digits and is at most `max` characters long.
+const fn validate_digits(address: &str, max: usize) -> Result<(), SmppAddressError> {
+    let bytes = address.as_bytes();
+
+    if bytes.is_empty() {
+        return Err(SmppAddressError::Empty);
+    }
+
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            return Err(SmppAddressError::InvalidCharacter {
+                character: bytes[index] as char,
+                index,
+            });
+        }
+
+        index += 1;
+    }
+
+    if bytes.len() > max {
+        return Err(SmppAddressError::TooLong {
+            length: bytes.len(),
+            max,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that `address` is a non-empty printable ASCII string.
+fn validate_printable(address: &str) -> Result<(), SmppAddressError> {
+    if address.is_empty() {
+        return Err(SmppAddressError::Empty);
+    }
+
+    match address
+        .char_indices()
+        .find(|(_, character)| !character.is_ascii() || character.is_ascii_control())
+    {
+        Some((index, character)) => Err(SmppAddressError::InvalidCharacter { character, index }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `address` is an ASCII string the GSM 7-bit default alphabet can encode in at most `11` septets.
+fn validate_alphanumeric(address: &str) -> Result<(), SmppAddressError> {
+    validate_printable(address)?;
+
+    let length = Gsm7Bit::unpacked()
+        .septet_count(address)
+        .map_err(|err| match err {
+            Gsm7BitEncodeError::UnencodableCharacter { character, index } => {
+                SmppAddressError::InvalidCharacter { character, index }
+            }
+        })?;
+
+    if length > MAX_ALPHANUMERIC_LENGTH {
+        return Err(SmppAddressError::TooLong {
+            length,
+            max: MAX_ALPHANUMERIC_LENGTH,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates the combination of `ton`, `npi` and `address`.
+///
+/// - [`Ton::International`] requires [`Npi::Isdn`] and at most `15` digits.
+/// - [`Ton::Alphanumeric`] requires [`Npi::Unknown`] and at most `11` GSM 7-bit characters.
+/// - [`Npi::Isdn`] requires digits.
+/// - Any other address must be a printable ASCII string of at most `20` characters.
+fn validate(ton: Ton, npi: Npi, address: &str) -> Result<(), SmppAddressError> {
+    match (ton, npi) {
+        (Ton::International, Npi::Isdn) => validate_digits(address, MAX_INTERNATIONAL_LENGTH),
+        (Ton::International, _) => Err(SmppAddressError::InvalidCombination { ton, npi }),
+        (Ton::Alphanumeric, Npi::Unknown) => validate_alphanumeric(address),
+        (Ton::Alphanumeric, _) => Err(SmppAddressError::InvalidCombination { ton, npi }),
+        (_, Npi::Isdn) => validate_digits(address, MAX_ADDRESS_LENGTH),
+        _ => {
+            validate_printable(address)?;
+
+            if address.len() > MAX_ADDRESS_LENGTH {
+                return Err(SmppAddressError::TooLong {
+                    length: address.len(),
+                    max: MAX_ADDRESS_LENGTH,
+                });
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Infers the [`Ton`] and [`Npi`] of `input` and returns them with the address to encode.
+///
+/// - `+` followed by digits is an international number.
+/// - Digits with a leading `0` are a national number.
+/// - Up to `8` digits are a short code.
+/// - More digits are a number of unknown type, e.g. a national number without a trunk prefix.
+/// - Anything else is alphanumeric.
+fn infer(input: &str) -> (Ton, Npi, &str) {
+    if let Some(digits) = input.strip_prefix('+') {
+        return (Ton::International, Npi::Isdn, digits);
+    }
+
+    if !input.is_empty() && input.bytes().all(|byte| byte.is_ascii_digit()) {
+        // A trunk prefix is never part of an international number.
+        if input.starts_with('0') {
+            return (Ton::National, Npi::Isdn, input);
+        }
+
+        if input.len() <= MAX_SHORT_CODE_LENGTH {
+            return (Ton::NetworkSpecific, Npi::Unknown, input);
+        }
+
+        return (Ton::Unknown, Npi::Isdn, input);
+    }
+
+    (Ton::Alphanumeric, Npi::Unknown, input)
+}
+
+/// An `SMPP` address: a [`Ton`], an [`Npi`] and the address itself.
+///
+/// `SMPP` PDUs carry addresses as three separate fields, e.g. `source_addr_ton`, `source_addr_npi` and `source_addr`.
+/// An [`SmppAddress`] bundles them and checks that they fit together, so that the MC does not reject the PDU with
+/// [`EsmeRinvsrcton`](crate::CommandStatus::EsmeRinvsrcton) or a similar status.
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::values::{Npi, Ton, owned::SmppAddress};
+///
+/// let address: SmppAddress = "+491701234567".parse().unwrap();
+///
+/// assert_eq!(address.ton(), Ton::International);
+/// assert_eq!(address.npi(), Npi::Isdn);
+/// assert_eq!(address.as_str(), "491701234567");
+///
+/// let address: SmppAddress = "ACME".parse().unwrap();
+///
+/// assert_eq!(address.ton(), Ton::Alphanumeric);
+/// assert_eq!(address.npi(), Npi::Unknown);
+///
+/// let address: SmppAddress = "12345".parse().unwrap();
+///
+/// assert_eq!(address.ton(), Ton::NetworkSpecific);
+/// assert_eq!(address.npi(), Npi::Unknown);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SmppAddress {
+    ton: Ton,
+    npi: Npi,
+    addr: COctetString<1, 21>,
+}
+
+impl SmppAddress {
+    /// Creates a new [`SmppAddress`].
+    ///
+    /// - [`Ton::International`] requires [`Npi::Isdn`] and at most `15` digits.
+    /// - [`Ton::Alphanumeric`] requires [`Npi::Unknown`] and at most `11` GSM 7-bit characters.
+    /// - [`Npi::Isdn`] requires digits.
+    /// - Any other address must be a printable ASCII string of at most `20` characters.
+    pub fn new(ton: Ton, npi: Npi, addr: &str) -> Result<Self, SmppAddressError> {
+        validate(ton, npi, addr)?;
+
+        Ok(Self::new_unchecked(ton, npi, addr))
+    }
+
+    /// Creates a new international E.164 [`SmppAddress`] with [`Ton::International`] and [`Npi::Isdn`].
+    ///
+    /// A leading `+` is removed.
+    pub fn international(digits: &str) -> Result<Self, SmppAddressError> {
+        let digits = digits.strip_prefix('+').unwrap_or(digits);
+
+        validate_digits(digits, MAX_INTERNATIONAL_LENGTH)?;
+
+        Ok(Self::new_unchecked(Ton::International, Npi::Isdn, digits))
+    }
+
+    /// Creates a new alphanumeric [`SmppAddress`] with [`Ton::Alphanumeric`] and [`Npi::Unknown`].
+    ///
+    /// `text` must be encodable in at most `11` septets of the GSM 7-bit default alphabet.
+    pub fn alphanumeric(text: &str) -> Result<Self, SmppAddressError> {
+        validate_alphanumeric(text)?;
+
+        Ok(Self::new_unchecked(Ton::Alphanumeric, Npi::Unknown, text))
+    }
+
+    /// Creates a new short code [`SmppAddress`] with [`Ton::NetworkSpecific`] and [`Npi::Unknown`].
+    ///
+    /// `digits` must be at most `8` digits.
+    pub fn short_code(digits: &str) -> Result<Self, SmppAddressError> {
+        validate_digits(digits, MAX_SHORT_CODE_LENGTH)?;
+
+        Ok(Self::new_unchecked(
+            Ton::NetworkSpecific,
+            Npi::Unknown,
+            digits,
+        ))
+    }
+
+    /// Creates a new [`SmppAddress`], inferring the [`Ton`] and [`Npi`] from `input`.
+    ///
+    /// - `+` followed by digits is an [international](SmppAddress::international) number.
+    /// - Digits with a leading `0` are a national number with [`Ton::National`] and [`Npi::Isdn`].
+    /// - Up to `8` digits are a [short code](SmppAddress::short_code).
+    /// - More digits are a number of unknown type with [`Ton::Unknown`] and [`Npi::Isdn`].
+    /// - Anything else is [alphanumeric](SmppAddress::alphanumeric).
+    pub fn parse(input: &str) -> Result<Self, SmppAddressError> {
+        let (ton, npi, addr) = infer(input);
+
+        Self::new(ton, npi, addr)
+    }
+
+    /// Validated addresses are printable ASCII strings of at most `20` characters.
+    fn new_unchecked(ton: Ton, npi: Npi, addr: &str) -> Self {
+        let mut bytes = alloc::vec::Vec::with_capacity(addr.len() + 1);
+
+        bytes.extend_from_slice(addr.as_bytes());
+        bytes.push(0);
+
+        Self {
+            ton,
+            npi,
+            addr: COctetString::new_unchecked(bytes),
+        }
+    }
+
+    /// Returns the Type of Number.
+    pub const fn ton(&self) -> Ton {
+        self.ton
+    }
+
+    /// Returns the Numbering Plan Indicator.
+    pub const fn npi(&self) -> Npi {
+        self.npi
+    }
+
+    /// Returns the address.
+    pub const fn addr(&self) -> &COctetString<1, 21> {
+        &self.addr
+    }
+
+    /// Returns the address as a &[`str`].
+    pub fn as_str(&self) -> &str {
+        self.addr.as_str()
+    }
+
+    /// Returns the [`Ton`], the [`Npi`] and the address.
+    pub fn into_parts(self) -> (Ton, Npi, COctetString<1, 21>) {
+        (self.ton, self.npi, self.addr)
+    }
+
+    /// Encodes the address as the value of a `callback_num` TLV.
+    ///
+    /// The digits are encoded as ASCII and preceded by the digit mode indicator, the [`Ton`] and the [`Npi`].
+    ///
+    /// Fails for alphanumeric addresses, addresses that are not digits only and addresses longer than `16` digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rusmpp_core::values::owned::SmppAddress;
+    ///
+    /// let address = SmppAddress::international("+4917012345").unwrap();
+    ///
+    /// let callback_num = address.to_callback_num().unwrap();
+    ///
+    /// assert_eq!(callback_num.bytes(), b"\x01\x01\x014917012345");
+    /// ```
+    pub fn to_callback_num(&self) -> Result<OctetString<4, 19>, SmppAddressError> {
+        if matches!(self.ton, Ton::Alphanumeric) {
+            return Err(SmppAddressError::InvalidCombination {
+                ton: self.ton,
+                npi: self.npi,
+            });
+        }
+
+        let digits = self.as_str();
+
+        validate_digits(digits, MAX_CALLBACK_NUM_LENGTH)?;
+
+        let mut bytes = alloc::vec::Vec::with_capacity(digits.len() + 3);
+
+        bytes.extend_from_slice(&[
+            CALLBACK_NUM_ASCII_DIGIT_MODE,
+            self.ton.into(),
+            self.npi.into(),
+        ]);
+        bytes.extend_from_slice(digits.as_bytes());
+
+        Ok(OctetString::new(bytes).expect("Callback numbers are 4 to 19 octets long"))
+    }
+}
+
+impl core::str::FromStr for SmppAddress {
+    type Err = SmppAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl core::fmt::Display for SmppAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.ton {
+            Ton::International => write!(f, "+{}", self.as_str()),
+            _ => f.write_str(self.as_str()),
+        }
+    }
+}
+
+impl From<SmppAddress> for SmeAddress {
+    fn from(value: SmppAddress) -> Self {
+        SmeAddress::new(value.ton, value.npi, value.addr)
+    }
+}
+
+impl From<SmppAddress> for DestAddress {
+    fn from(value: SmppAddress) -> Self {
+        DestAddress::SmeAddress(value.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn parse_infers_ton_and_npi() {
+        let cases = [
+            (
+                "+491701234567",
+                Ton::International,
+                Npi::Isdn,
+                "491701234567",
+            ),
+            ("491701234567", Ton::Unknown, Npi::Isdn, "491701234567"),
+            ("2025550123", Ton::Unknown, Npi::Isdn, "2025550123"),
+            ("12345", Ton::NetworkSpecific, Npi::Unknown, "12345"),
+            ("01701234567", Ton::National, Npi::Isdn, "01701234567"),
+            ("0800", Ton::National, Npi::Isdn, "0800"),
+            ("ACME", Ton::Alphanumeric, Npi::Unknown, "ACME"),
+            ("ACME Bank", Ton::Alphanumeric, Npi::Unknown, "ACME Bank"),
+        ];
+
+        for (input, ton, npi, addr) in cases {
+            let address = SmppAddress::parse(input).unwrap();
+
+            assert_eq!(address.ton(), ton, "{input}");
+            assert_eq!(address.npi(), npi, "{input}");
+            assert_eq!(address.as_str(), addr, "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_addresses() {
+        let cases = [
+            ("", SmppAddressError::Empty),
+            ("+", SmppAddressError::Empty),
+            (
+                "+49170123456789012",
+                SmppAddressError::TooLong {
+                    length: 17,
+                    max: 15,
+                },
+            ),
+            (
+                "+4917O",
+                SmppAddressError::InvalidCharacter {
+                    character: 'O',
+                    index: 4,
+                },
+            ),
+            (
+                "ACME Company",
+                SmppAddressError::TooLong {
+                    length: 12,
+                    max: 11,
+                },
+            ),
+            (
+                "ACME`",
+                SmppAddressError::InvalidCharacter {
+                    character: '`',
+                    index: 4,
+                },
+            ),
+            (
+                "ACMÉ",
+                SmppAddressError::InvalidCharacter {
+                    character: 'É',
+                    index: 3,
+                },
+            ),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(SmppAddress::parse(input), Err(error), "{input}");
+        }
+    }
+
+    #[test]
+    fn alphanumeric_counts_extension_characters_as_two_septets() {
+        assert!(SmppAddress::alphanumeric("ACME[1234]").is_err());
+        assert!(SmppAddress::alphanumeric("ACME[12]").is_ok());
+    }
+
+    #[test]
+    fn new_rejects_invalid_combinations() {
+        assert_eq!(
+            SmppAddress::new(Ton::International, Npi::Unknown, "491701234567"),
+            Err(SmppAddressError::InvalidCombination {
+                ton: Ton::International,
+                npi: Npi::Unknown,
+            })
+        );
+
+        assert_eq!(
+            SmppAddress::new(Ton::Alphanumeric, Npi::Isdn, "ACME"),
+            Err(SmppAddressError::InvalidCombination {
+                ton: Ton::Alphanumeric,
+                npi: Npi::Isdn,
+            })
+        );
+
+        assert_eq!(
+            SmppAddress::new(Ton::National, Npi::Isdn, "0170-123"),
+            Err(SmppAddressError::InvalidCharacter {
+                character: '-',
+                index: 4,
+            })
+        );
+
+        assert!(SmppAddress::new(Ton::National, Npi::Isdn, "01701234567").is_ok());
+        assert!(SmppAddress::new(Ton::Unknown, Npi::Unknown, "anything-goes").is_ok());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            SmppAddress::international("491701234567")
+                .unwrap()
+                .to_string(),
+            "+491701234567"
+        );
+
+        assert_eq!(
+            SmppAddress::short_code("12345").unwrap().to_string(),
+            "12345"
+        );
+    }
+
+    #[test]
+    fn into_dest_address() {
+        let address = SmppAddress::international("+491701234567").unwrap();
+
+        let dest_address = DestAddress::from(address);
+
+        assert_eq!(
+            dest_address,
+            DestAddress::SmeAddress(SmeAddress::new(
+                Ton::International,
+                Npi::Isdn,
+                COctetString::new(b"491701234567\0").unwrap(),
+            ))
+        );
+    }
+
+    #[test]
+    fn callback_num() {
+        assert!(
+            SmppAddress::alphanumeric("ACME")
+                .unwrap()
+                .to_callback_num()
+                .is_err()
+        );
+
+        assert_eq!(
+            SmppAddress::short_code("12345")
+                .unwrap()
+                .to_callback_num()
+                .unwrap()
+                .bytes(),
+            b"\x01\x03\x0012345"
+        );
+
+        assert_eq!(
+            SmppAddress::parse("01701234567")
+                .unwrap()
+                .to_callback_num()
+                .unwrap()
+                .bytes(),
+            b"\x01\x02\x0101701234567"
+        );
+
+        assert_eq!(
+            SmppAddress::new(Ton::Unknown, Npi::Unknown, "0170-123")
+                .unwrap()
+                .to_callback_num(),
+            Err(SmppAddressError::InvalidCharacter {
+                character: '-',
+                index: 4,
+            })
+        );
+    }
+}
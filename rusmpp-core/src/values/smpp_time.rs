@@ -0,0 +1,964 @@
+/// Errors that can occur when creating, parsing or converting an [`SmppTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SmppTimeError {
+    /// The input is not `16` characters long.
+    InvalidLength {
+        /// The length of the input.
+        length: usize,
+    },
+    /// A character that must be a digit is not.
+    InvalidDigit {
+        /// The index of the character.
+        index: usize,
+    },
+    /// The last character is not `+`, `-` or `R`.
+    InvalidDirection {
+        /// The last character.
+        direction: u8,
+    },
+    /// A field is out of range.
+    OutOfRange {
+        /// The name of the field.
+        field: &'static str,
+        /// The value of the field.
+        value: i32,
+    },
+    /// The date does not exist, e.g. `February 30`.
+    InvalidDate,
+    /// A relative time can not be converted to a date and time.
+    Relative,
+}
+
+impl core::fmt::Display for SmppTimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength { length } => {
+                write!(f, "Invalid length. actual: {length}, expected: 16")
+            }
+            Self::InvalidDigit { index } => write!(f, "Invalid digit at index {index}"),
+            Self::InvalidDirection { direction } => {
+                write!(f, "Invalid direction: 0x{direction:02X}")
+            }
+            Self::OutOfRange { field, value } => {
+                write!(f, "Field {field} is out of range: {value}")
+            }
+            Self::InvalidDate => write!(f, "Invalid date"),
+            Self::Relative => write!(f, "Relative time has no date"),
+        }
+    }
+}
+
+impl core::error::Error for SmppTimeError {}
+
+/// The date and time of an absolute [`SmppTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
+pub struct SmppDateTime {
+    year: u8,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    tenths: u8,
+}
+
+impl SmppDateTime {
+    /// Creates a new [`SmppDateTime`].
+    ///
+    /// `year` is the last two digits of the year.
+    ///
+    /// Fails if a field is out of range. The day is not checked against the month.
+    pub const fn new(
+        year: u8,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        tenths: u8,
+    ) -> Result<Self, SmppTimeError> {
+        let fields = [
+            ("year", year, 0, 99),
+            ("month", month, 1, 12),
+            ("day", day, 1, 31),
+            ("hour", hour, 0, 23),
+            ("minute", minute, 0, 59),
+            ("second", second, 0, 59),
+            ("tenths", tenths, 0, 9),
+        ];
+
+        let mut index = 0;
+
+        while index < fields.len() {
+            let (field, value, min, max) = fields[index];
+
+            if value < min || value > max {
+                return Err(SmppTimeError::OutOfRange {
+                    field,
+                    value: value as i32,
+                });
+            }
+
+            index += 1;
+        }
+
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            tenths,
+        })
+    }
+
+    /// Returns the last two digits of the year.
+    pub const fn year(&self) -> u8 {
+        self.year
+    }
+
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the tenths of second.
+    pub const fn tenths(&self) -> u8 {
+        self.tenths
+    }
+}
+
+/// An absolute or relative `SMPP` time.
+///
+/// The schedule_delivery_time, validity_period and final_date fields use the `16`
+/// character format `YYMMDDhhmmsstnnp`:
+///
+/// - `YY`: last two digits of the year (`00`-`99`).
+/// - `MM`: month (`01`-`12`).
+/// - `DD`: day (`01`-`31`).
+/// - `hh`: hour (`00`-`23`).
+/// - `mm`: minute (`00`-`59`).
+/// - `ss`: second (`00`-`59`).
+/// - `t`: tenths of second (`0`-`9`).
+/// - `nn`: time difference in quarter hours between local time and UTC (`00`-`48`).
+/// - `p`: `+` local time is ahead of UTC, `-` local time is behind UTC, `R` relative time.
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp_core::values::{SmppDateTime, SmppTime};
+///
+/// let time = SmppTime::absolute(SmppDateTime::new(25, 10, 18, 13, 42, 5, 0).unwrap(), -14).unwrap();
+///
+/// assert_eq!(time.to_bytes(), *b"251018134205014-");
+///
+/// let time: SmppTime = "000001020000000R".parse().unwrap();
+///
+/// assert_eq!(time, SmppTime::relative(0, 0, 1, 2, 0, 0).unwrap());
+/// ```
+///
+/// Values created with [`SmppTime::absolute`], [`SmppTime::relative`] or parsed from the wire form are valid.
+/// Values created from the variants directly can be checked with [`SmppTime::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(
+    all(
+        feature = "serde-deserialize-unchecked",
+        not(feature = "serde-deserialize")
+    ),
+    derive(::serde::Deserialize)
+)]
+pub enum SmppTime {
+    /// An absolute date and time.
+    Absolute {
+        /// The local date and time.
+        datetime: SmppDateTime,
+        /// The difference between local time and UTC in quarter hours (`-48`-`48`).
+        utc_offset_quarter_hours: i8,
+    },
+    /// A time relative to the current MC time.
+    Relative {
+        years: u8,
+        months: u8,
+        days: u8,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+    },
+}
+
+impl SmppTime {
+    /// The length of the wire form, without the null terminator.
+    pub const LENGTH: usize = 16;
+
+    /// Creates a new [`SmppTime::Absolute`].
+    ///
+    /// Fails if `utc_offset_quarter_hours` is not in `-48..=48`.
+    pub const fn absolute(
+        datetime: SmppDateTime,
+        utc_offset_quarter_hours: i8,
+    ) -> Result<Self, SmppTimeError> {
+        if utc_offset_quarter_hours < -48 || utc_offset_quarter_hours > 48 {
+            return Err(SmppTimeError::OutOfRange {
+                field: "utc_offset_quarter_hours",
+                value: utc_offset_quarter_hours as i32,
+            });
+        }
+
+        Ok(Self::Absolute {
+            datetime,
+            utc_offset_quarter_hours,
+        })
+    }
+
+    /// Creates a new [`SmppTime::Relative`].
+    ///
+    /// Fails if a field is greater than `99`.
+    pub const fn relative(
+        years: u8,
+        months: u8,
+        days: u8,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+    ) -> Result<Self, SmppTimeError> {
+        let fields = [
+            ("years", years),
+            ("months", months),
+            ("days", days),
+            ("hours", hours),
+            ("minutes", minutes),
+            ("seconds", seconds),
+        ];
+
+        let mut index = 0;
+
+        while index < fields.len() {
+            let (field, value) = fields[index];
+
+            if value > 99 {
+                return Err(SmppTimeError::OutOfRange {
+                    field,
+                    value: value as i32,
+                });
+            }
+
+            index += 1;
+        }
+
+        Ok(Self::Relative {
+            years,
+            months,
+            days,
+            hours,
+            minutes,
+            seconds,
+        })
+    }
+
+    /// Checks that all fields are in range.
+    pub const fn validate(&self) -> Result<(), SmppTimeError> {
+        let result = match *self {
+            Self::Absolute {
+                datetime,
+                utc_offset_quarter_hours,
+            } => match SmppDateTime::new(
+                datetime.year,
+                datetime.month,
+                datetime.day,
+                datetime.hour,
+                datetime.minute,
+                datetime.second,
+                datetime.tenths,
+            ) {
+                Ok(datetime) => Self::absolute(datetime, utc_offset_quarter_hours),
+                Err(err) => Err(err),
+            },
+            Self::Relative {
+                years,
+                months,
+                days,
+                hours,
+                minutes,
+                seconds,
+            } => Self::relative(years, months, days, hours, minutes, seconds),
+        };
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns `true` if this is a [`SmppTime::Relative`].
+    pub const fn is_relative(&self) -> bool {
+        matches!(self, Self::Relative { .. })
+    }
+
+    /// Parses the wire form, with or without the null terminator.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SmppTimeError> {
+        let bytes = match bytes {
+            [bytes @ .., 0] => bytes,
+            bytes => bytes,
+        };
+
+        if bytes.len() != Self::LENGTH {
+            return Err(SmppTimeError::InvalidLength {
+                length: bytes.len(),
+            });
+        }
+
+        if let Some(index) = bytes[..15].iter().position(|byte| !byte.is_ascii_digit()) {
+            return Err(SmppTimeError::InvalidDigit { index });
+        }
+
+        let field = |index: usize| (bytes[index] - b'0') * 10 + (bytes[index + 1] - b'0');
+
+        match bytes[15] {
+            b'R' => Self::relative(field(0), field(2), field(4), field(6), field(8), field(10)),
+            direction @ (b'+' | b'-') => {
+                let datetime = SmppDateTime::new(
+                    field(0),
+                    field(2),
+                    field(4),
+                    field(6),
+                    field(8),
+                    field(10),
+                    bytes[12] - b'0',
+                )?;
+
+                let offset = field(13);
+
+                if offset > 48 {
+                    return Err(SmppTimeError::OutOfRange {
+                        field: "utc_offset_quarter_hours",
+                        value: offset as i32,
+                    });
+                }
+
+                let offset = offset as i8;
+
+                Self::absolute(datetime, if direction == b'-' { -offset } else { offset })
+            }
+            direction => Err(SmppTimeError::InvalidDirection { direction }),
+        }
+    }
+
+    /// Returns the wire form, without the null terminator.
+    ///
+    /// Fields are written modulo `100`. See [`SmppTime::validate`].
+    pub const fn to_bytes(&self) -> [u8; Self::LENGTH] {
+        const fn digits(dst: &mut [u8; SmppTime::LENGTH], index: usize, value: u8) {
+            dst[index] = b'0' + (value % 100) / 10;
+            dst[index + 1] = b'0' + value % 10;
+        }
+
+        let mut bytes = [b'0'; Self::LENGTH];
+
+        match *self {
+            Self::Absolute {
+                datetime,
+                utc_offset_quarter_hours,
+            } => {
+                digits(&mut bytes, 0, datetime.year);
+                digits(&mut bytes, 2, datetime.month);
+                digits(&mut bytes, 4, datetime.day);
+                digits(&mut bytes, 6, datetime.hour);
+                digits(&mut bytes, 8, datetime.minute);
+                digits(&mut bytes, 10, datetime.second);
+
+                bytes[12] = b'0' + datetime.tenths % 10;
+
+                digits(&mut bytes, 13, utc_offset_quarter_hours.unsigned_abs());
+
+                bytes[15] = if utc_offset_quarter_hours < 0 {
+                    b'-'
+                } else {
+                    b'+'
+                };
+            }
+            Self::Relative {
+                years,
+                months,
+                days,
+                hours,
+                minutes,
+                seconds,
+            } => {
+                digits(&mut bytes, 0, years);
+                digits(&mut bytes, 2, months);
+                digits(&mut bytes, 4, days);
+                digits(&mut bytes, 6, hours);
+                digits(&mut bytes, 8, minutes);
+                digits(&mut bytes, 10, seconds);
+
+                bytes[15] = b'R';
+            }
+        }
+
+        bytes
+    }
+}
+
+impl core::str::FromStr for SmppTime {
+    type Err = SmppTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl core::fmt::Display for SmppTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bytes = self.to_bytes();
+
+        f.write_str(core::str::from_utf8(&bytes).expect("SmppTime is ascii by definition"))
+    }
+}
+
+#[cfg(feature = "serde-deserialize")]
+impl<'de> ::serde::Deserialize<'de> for SmppDateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        #[derive(::serde::Deserialize)]
+        #[serde(rename = "SmppDateTime")]
+        struct SmppDateTimeParts {
+            year: u8,
+            month: u8,
+            day: u8,
+            hour: u8,
+            minute: u8,
+            second: u8,
+            tenths: u8,
+        }
+
+        let parts = <SmppDateTimeParts as ::serde::Deserialize>::deserialize(deserializer)?;
+
+        let value = Self {
+            year: parts.year,
+            month: parts.month,
+            day: parts.day,
+            hour: parts.hour,
+            minute: parts.minute,
+            second: parts.second,
+            tenths: parts.tenths,
+        };
+
+        crate::deserialize::Check::check(&value).map_err(::serde::de::Error::custom)?;
+
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "serde-deserialize")]
+impl<'de> ::serde::Deserialize<'de> for SmppTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        #[derive(::serde::Deserialize)]
+        #[serde(rename = "SmppTime")]
+        enum SmppTimeParts {
+            Absolute {
+                datetime: SmppDateTime,
+                utc_offset_quarter_hours: i8,
+            },
+            Relative {
+                years: u8,
+                months: u8,
+                days: u8,
+                hours: u8,
+                minutes: u8,
+                seconds: u8,
+            },
+        }
+
+        let value = match <SmppTimeParts as ::serde::Deserialize>::deserialize(deserializer)? {
+            SmppTimeParts::Absolute {
+                datetime,
+                utc_offset_quarter_hours,
+            } => Self::Absolute {
+                datetime,
+                utc_offset_quarter_hours,
+            },
+            SmppTimeParts::Relative {
+                years,
+                months,
+                days,
+                hours,
+                minutes,
+                seconds,
+            } => Self::Relative {
+                years,
+                months,
+                days,
+                hours,
+                minutes,
+                seconds,
+            },
+        };
+
+        crate::deserialize::Check::check(&value).map_err(::serde::de::Error::custom)?;
+
+        Ok(value)
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<SmppTime> for crate::types::owned::EmptyOrFullCOctetString<17> {
+    fn from(value: SmppTime) -> Self {
+        let mut bytes = [0; 17];
+
+        bytes[..SmppTime::LENGTH].copy_from_slice(&value.to_bytes());
+
+        Self::new(bytes).expect("SmppTime is a valid full COctetString")
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl crate::types::owned::EmptyOrFullCOctetString<17> {
+    /// Parses the [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the string is empty.
+    pub fn to_smpp_time(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        SmppTime::from_bytes(self.bytes()).map(Some)
+    }
+}
+
+impl crate::types::borrowed::EmptyOrFullCOctetString<'_, 17> {
+    /// Parses the [`SmppTime`].
+    ///
+    /// Returns `Ok(None)` if the string is empty.
+    pub fn to_smpp_time(&self) -> Result<Option<SmppTime>, SmppTimeError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        SmppTime::from_bytes(self.bytes()).map(Some)
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Offset, TimeZone, Timelike};
+
+    use super::*;
+
+    /// Converts a date and time in the years `2000`-`2099` with a quarter hour UTC offset.
+    ///
+    /// Sub-second precision is truncated to tenths.
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    impl<Tz: TimeZone> TryFrom<&DateTime<Tz>> for SmppTime {
+        type Error = SmppTimeError;
+
+        fn try_from(value: &DateTime<Tz>) -> Result<Self, Self::Error> {
+            let offset = value.offset().fix().local_minus_utc();
+
+            if offset % (15 * 60) != 0 {
+                return Err(SmppTimeError::OutOfRange {
+                    field: "utc_offset",
+                    value: offset,
+                });
+            }
+
+            let local = value.naive_local();
+
+            if !(2000..=2099).contains(&local.year()) {
+                return Err(SmppTimeError::OutOfRange {
+                    field: "year",
+                    value: local.year(),
+                });
+            }
+
+            let datetime = SmppDateTime::new(
+                (local.year() - 2000) as u8,
+                local.month() as u8,
+                local.day() as u8,
+                local.hour() as u8,
+                local.minute() as u8,
+                // Leap seconds are represented as nanosecond overflow.
+                local.second() as u8,
+                (local.nanosecond() % 1_000_000_000 / 100_000_000) as u8,
+            )?;
+
+            Self::absolute(datetime, (offset / (15 * 60)) as i8)
+        }
+    }
+
+    /// Converts an absolute time, with the year in `2000`-`2099`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    impl TryFrom<SmppTime> for DateTime<FixedOffset> {
+        type Error = SmppTimeError;
+
+        fn try_from(value: SmppTime) -> Result<Self, Self::Error> {
+            let SmppTime::Absolute {
+                datetime,
+                utc_offset_quarter_hours,
+            } = value
+            else {
+                return Err(SmppTimeError::Relative);
+            };
+
+            let offset = FixedOffset::east_opt(utc_offset_quarter_hours as i32 * 15 * 60).ok_or(
+                SmppTimeError::OutOfRange {
+                    field: "utc_offset_quarter_hours",
+                    value: utc_offset_quarter_hours as i32,
+                },
+            )?;
+
+            NaiveDate::from_ymd_opt(
+                2000 + datetime.year as i32,
+                datetime.month as u32,
+                datetime.day as u32,
+            )
+            .and_then(|date| {
+                date.and_hms_milli_opt(
+                    datetime.hour as u32,
+                    datetime.minute as u32,
+                    datetime.second as u32,
+                    datetime.tenths as u32 * 100,
+                )
+            })
+            .and_then(|local| offset.from_local_datetime(&local).single())
+            .ok_or(SmppTimeError::InvalidDate)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    use super::*;
+
+    /// Converts a date and time in the years `2000`-`2099` with a quarter hour UTC offset.
+    ///
+    /// Sub-second precision is truncated to tenths.
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    impl TryFrom<&OffsetDateTime> for SmppTime {
+        type Error = SmppTimeError;
+
+        fn try_from(value: &OffsetDateTime) -> Result<Self, Self::Error> {
+            let offset = value.offset().whole_seconds();
+
+            if offset % (15 * 60) != 0 {
+                return Err(SmppTimeError::OutOfRange {
+                    field: "utc_offset",
+                    value: offset,
+                });
+            }
+
+            if !(2000..=2099).contains(&value.year()) {
+                return Err(SmppTimeError::OutOfRange {
+                    field: "year",
+                    value: value.year(),
+                });
+            }
+
+            let datetime = SmppDateTime::new(
+                (value.year() - 2000) as u8,
+                u8::from(value.month()),
+                value.day(),
+                value.hour(),
+                value.minute(),
+                value.second(),
+                (value.millisecond() / 100) as u8,
+            )?;
+
+            Self::absolute(datetime, (offset / (15 * 60)) as i8)
+        }
+    }
+
+    /// Converts an absolute time, with the year in `2000`-`2099`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    impl TryFrom<SmppTime> for OffsetDateTime {
+        type Error = SmppTimeError;
+
+        fn try_from(value: SmppTime) -> Result<Self, Self::Error> {
+            let SmppTime::Absolute {
+                datetime,
+                utc_offset_quarter_hours,
+            } = value
+            else {
+                return Err(SmppTimeError::Relative);
+            };
+
+            let offset = UtcOffset::from_whole_seconds(utc_offset_quarter_hours as i32 * 15 * 60)
+                .map_err(|_| SmppTimeError::OutOfRange {
+                field: "utc_offset_quarter_hours",
+                value: utc_offset_quarter_hours as i32,
+            })?;
+
+            let month = Month::try_from(datetime.month).map_err(|_| SmppTimeError::InvalidDate)?;
+
+            let date = Date::from_calendar_date(2000 + datetime.year as i32, month, datetime.day)
+                .map_err(|_| SmppTimeError::InvalidDate)?;
+
+            let time = Time::from_hms_milli(
+                datetime.hour,
+                datetime.minute,
+                datetime.second,
+                datetime.tenths as u16 * 100,
+            )
+            .map_err(|_| SmppTimeError::InvalidDate)?;
+
+            Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::types::owned::EmptyOrFullCOctetString;
+
+    use super::*;
+
+    #[test]
+    fn absolute() {
+        let datetime = SmppDateTime::new(25, 10, 18, 13, 42, 5, 7).unwrap();
+
+        let time = SmppTime::absolute(datetime, 8).unwrap();
+
+        assert_eq!(&time.to_bytes(), b"251018134205708+");
+        assert_eq!(SmppTime::from_bytes(b"251018134205708+").unwrap(), time);
+
+        let time = SmppTime::absolute(datetime, -14).unwrap();
+
+        assert_eq!(time.to_string(), "251018134205714-");
+        assert_eq!("251018134205714-".parse::<SmppTime>().unwrap(), time);
+
+        // Zero offset is written as ahead of UTC
+        let time = SmppTime::absolute(datetime, 0).unwrap();
+
+        assert_eq!(time.to_string(), "251018134205700+");
+        assert_eq!("251018134205700-".parse::<SmppTime>().unwrap(), time);
+    }
+
+    #[test]
+    fn relative() {
+        let time = SmppTime::relative(0, 1, 2, 3, 4, 5).unwrap();
+
+        assert!(time.is_relative());
+        assert_eq!(time.to_string(), "000102030405000R");
+        assert_eq!("000102030405000R".parse::<SmppTime>().unwrap(), time);
+    }
+
+    #[test]
+    fn null_terminated() {
+        assert_eq!(
+            SmppTime::from_bytes(b"000102030405000R\0").unwrap(),
+            SmppTime::relative(0, 1, 2, 3, 4, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            SmppTime::from_bytes(b"0001020304050R"),
+            Err(SmppTimeError::InvalidLength { length: 14 })
+        );
+        assert_eq!(
+            SmppTime::from_bytes(b"00010203040500xR"),
+            Err(SmppTimeError::InvalidDigit { index: 14 })
+        );
+        assert_eq!(
+            SmppTime::from_bytes(b"000102030405000X"),
+            Err(SmppTimeError::InvalidDirection { direction: b'X' })
+        );
+        assert_eq!(
+            SmppTime::from_bytes(b"251318134205700+"),
+            Err(SmppTimeError::OutOfRange {
+                field: "month",
+                value: 13
+            })
+        );
+        assert_eq!(
+            SmppTime::from_bytes(b"251018134205749+"),
+            Err(SmppTimeError::OutOfRange {
+                field: "utc_offset_quarter_hours",
+                value: 49
+            })
+        );
+        assert_eq!(
+            SmppTime::relative(100, 0, 0, 0, 0, 0),
+            Err(SmppTimeError::OutOfRange {
+                field: "years",
+                value: 100
+            })
+        );
+        assert_eq!(
+            SmppDateTime::new(25, 10, 18, 24, 0, 0, 0),
+            Err(SmppTimeError::OutOfRange {
+                field: "hour",
+                value: 24
+            })
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let time = SmppTime::Absolute {
+            datetime: SmppDateTime::new(25, 10, 18, 13, 42, 5, 0).unwrap(),
+            utc_offset_quarter_hours: 49,
+        };
+
+        assert_eq!(
+            time.validate(),
+            Err(SmppTimeError::OutOfRange {
+                field: "utc_offset_quarter_hours",
+                value: 49
+            })
+        );
+
+        let time = SmppTime::Relative {
+            years: 0,
+            months: 0,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 100,
+        };
+
+        assert_eq!(
+            time.validate(),
+            Err(SmppTimeError::OutOfRange {
+                field: "seconds",
+                value: 100
+            })
+        );
+    }
+
+    #[test]
+    fn empty_or_full_c_octet_string() {
+        let time = SmppTime::relative(0, 0, 1, 0, 0, 0).unwrap();
+
+        let string = EmptyOrFullCOctetString::<17>::from(time);
+
+        assert_eq!(string.bytes(), b"000001000000000R\0");
+        assert_eq!(string.to_smpp_time(), Ok(Some(time)));
+
+        assert_eq!(
+            EmptyOrFullCOctetString::<17>::empty().to_smpp_time(),
+            Ok(None)
+        );
+
+        let string =
+            crate::types::borrowed::EmptyOrFullCOctetString::<17>::new(b"000001000000000R\0")
+                .unwrap();
+
+        assert_eq!(string.to_smpp_time(), Ok(Some(time)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use chrono::{DateTime, FixedOffset, TimeZone};
+
+        let offset = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+
+        let datetime = offset
+            .with_ymd_and_hms(2025, 10, 18, 13, 42, 5)
+            .unwrap()
+            .checked_add_signed(chrono::TimeDelta::milliseconds(750))
+            .unwrap();
+
+        let time = SmppTime::try_from(&datetime).unwrap();
+
+        assert_eq!(time.to_string(), "251018134205714-");
+
+        let converted = DateTime::<FixedOffset>::try_from(time).unwrap();
+
+        assert_eq!(converted, datetime - chrono::TimeDelta::milliseconds(50));
+
+        // Not a quarter hour offset
+        let datetime = FixedOffset::east_opt(60)
+            .unwrap()
+            .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+            .unwrap();
+
+        assert!(SmppTime::try_from(&datetime).is_err());
+
+        // Year out of range
+        let datetime = offset.with_ymd_and_hms(1999, 1, 1, 0, 0, 0).unwrap();
+
+        assert!(SmppTime::try_from(&datetime).is_err());
+
+        // Invalid date
+        let time = SmppTime::from_bytes(b"250230000000000+").unwrap();
+
+        assert_eq!(
+            DateTime::<FixedOffset>::try_from(time),
+            Err(SmppTimeError::InvalidDate)
+        );
+
+        let time = SmppTime::relative(0, 0, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            DateTime::<FixedOffset>::try_from(time),
+            Err(SmppTimeError::Relative)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        use time::{Date, Month, OffsetDateTime, Time, UtcOffset};
+
+        let datetime = Date::from_calendar_date(2025, Month::October, 18)
+            .unwrap()
+            .with_time(Time::from_hms_milli(13, 42, 5, 750).unwrap())
+            .assume_offset(UtcOffset::from_hms(5, 45, 0).unwrap());
+
+        let time = SmppTime::try_from(&datetime).unwrap();
+
+        assert_eq!(time.to_string(), "251018134205723+");
+
+        let converted = OffsetDateTime::try_from(time).unwrap();
+
+        assert_eq!(converted, datetime - time::Duration::milliseconds(50));
+
+        let time = SmppTime::from_bytes(b"250230000000000+").unwrap();
+
+        assert_eq!(
+            OffsetDateTime::try_from(time),
+            Err(SmppTimeError::InvalidDate)
+        );
+    }
+}
@@ -1,13 +1,19 @@
 use rusmpp_macros::Rusmpp;
 
-use crate::{types::owned::OctetString, values::sub_address::SubaddressTag};
+use crate::{
+    types::owned::OctetString,
+    values::{borrowed, sub_address::SubaddressTag},
+};
 
 // https://smpp.org/SMPP_v5.pdf#page=165
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
 #[rusmpp(decode = owned)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct Subaddress {
     pub tag: SubaddressTag,
     // addr can not be empty, because the whole source_subaddress tlv value is between 2 and 23 bytes long, and the tag is 1 byte long
@@ -21,6 +27,32 @@ impl Subaddress {
     }
 }
 
+impl From<borrowed::Subaddress<'_>> for Subaddress {
+    fn from(value: borrowed::Subaddress<'_>) -> Self {
+        Self {
+            tag: value.tag,
+            addr: value.addr.into(),
+        }
+    }
+}
+
+impl borrowed::Subaddress<'_> {
+    /// Converts this borrowed [`Subaddress`](borrowed::Subaddress) into an owned [`Subaddress`].
+    pub fn into_owned(self) -> Subaddress {
+        self.into()
+    }
+}
+
+impl Subaddress {
+    /// Returns a borrowed [`Subaddress`](borrowed::Subaddress) view of this [`Subaddress`].
+    pub fn as_borrowed(&self) -> borrowed::Subaddress<'_> {
+        borrowed::Subaddress {
+            tag: self.tag,
+            addr: self.addr.as_borrowed(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
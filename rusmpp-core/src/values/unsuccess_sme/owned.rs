@@ -3,14 +3,17 @@ use rusmpp_macros::Rusmpp;
 use crate::{
     CommandStatus,
     types::owned::COctetString,
-    values::{npi::Npi, ton::Ton},
+    values::{borrowed, npi::Npi, ton::Ton},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
 #[rusmpp(decode = owned)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
-#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+#[cfg_attr(
+    any(feature = "serde-deserialize", feature = "serde-deserialize-unchecked"),
+    derive(::serde::Deserialize)
+)]
 pub struct UnsuccessSme {
     /// Type of number for destination.
     pub dest_addr_ton: Ton,
@@ -49,6 +52,36 @@ impl UnsuccessSme {
     }
 }
 
+impl From<borrowed::UnsuccessSme<'_>> for UnsuccessSme {
+    fn from(value: borrowed::UnsuccessSme<'_>) -> Self {
+        Self {
+            dest_addr_ton: value.dest_addr_ton,
+            dest_addr_npi: value.dest_addr_npi,
+            destination_addr: value.destination_addr.into(),
+            error_status_code: value.error_status_code,
+        }
+    }
+}
+
+impl borrowed::UnsuccessSme<'_> {
+    /// Converts this borrowed [`UnsuccessSme`](borrowed::UnsuccessSme) into an owned [`UnsuccessSme`].
+    pub fn into_owned(self) -> UnsuccessSme {
+        self.into()
+    }
+}
+
+impl UnsuccessSme {
+    /// Returns a borrowed [`UnsuccessSme`](borrowed::UnsuccessSme) view of this [`UnsuccessSme`].
+    pub fn as_borrowed(&self) -> borrowed::UnsuccessSme<'_> {
+        borrowed::UnsuccessSme {
+            dest_addr_ton: self.dest_addr_ton,
+            dest_addr_npi: self.dest_addr_npi,
+            destination_addr: self.destination_addr.as_borrowed(),
+            error_status_code: self.error_status_code,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,3 +82,29 @@ impl FromIntoAttributes {
         matches!(self, Self::Implement)
     }
 }
+
+/// `#[rusmpp(deserialize = checked)]`
+#[derive(Default)]
+pub enum DeserializeAttributes {
+    #[default]
+    Skip,
+    Checked,
+}
+
+impl DeserializeAttributes {
+    pub fn extract(meta: syn::meta::ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let ident: Ident = meta.value()?.parse()?;
+
+        match ident.to_string().as_str() {
+            "checked" => Ok(Self::Checked),
+            other => Err(meta.error(format!(
+                "unknown deserialize attribute: {}, expected checked",
+                other
+            ))),
+        }
+    }
+
+    pub const fn is_checked(&self) -> bool {
+        matches!(self, Self::Checked)
+    }
+}
@@ -96,6 +96,7 @@ pub fn derive_tlv_value_for_enum(
     // Collect match arms
     let mut tag_arms = Vec::new();
     let mut value_arms = Vec::new();
+    let mut allowed_tags = Vec::new();
     let mut has_other_variant = false;
 
     for variant in &data_enum.variants {
@@ -107,6 +108,8 @@ pub fn derive_tlv_value_for_enum(
                     #ident::#v_ident(_) => TlvTag::#v_ident,
                 });
 
+                allowed_tags.push(quote! { TlvTag::#v_ident });
+
                 value_arms.push(quote! {
                     #ident::#v_ident(value) => TlvValue::#v_ident(value),
                 });
@@ -151,6 +154,8 @@ pub fn derive_tlv_value_for_enum(
                         #ident::Other { tag, .. } => *tag,
                     });
 
+                    allowed_tags.push(quote! { TlvTag::Other(_) });
+
                     value_arms.push(quote! {
                         #ident::Other { tag, value } => TlvValue::Other { tag, value },
                     });
@@ -172,6 +177,11 @@ pub fn derive_tlv_value_for_enum(
                     #(#tag_arms)*
                 }
             }
+
+            /// Returns `true` if a TLV with the given [`TlvTag`] is allowed in this TLV set.
+            pub const fn allows(tag: TlvTag) -> bool {
+                matches!(tag, #(#allowed_tags)|*)
+            }
         }
 
         impl #impl_generics From<#ident #ty_generics> for TlvValue #ty_generics #where_clause {
@@ -12,7 +12,8 @@ mod parts;
 mod repr;
 mod structs;
 
-/// Implements `Length`, `Encode`, `Decode` and `TestInstance` with one default value for structs and enums.
+/// Implements `Length`, `Encode`, `Decode` and `TestInstance` with one default value for structs and enums,
+/// and `Dissect` for structs with an owned `Decode` implementation.
 /// And creates parts structs for structs with `new` and `raw` methods and adds `into_parts` method to the original struct.
 ///
 /// # Enums
@@ -31,6 +32,7 @@ mod structs;
 /// - `#[rusmpp(repr = "u8")]`: Use the `From<u8>`/`Into<u8>` representation for decoding.
 /// - `#[rusmpp(decode = skip|owned|borrowed|all)]`: Control which `Decode` implementations to generate. Default is `all`.
 /// - `#[rusmpp(test = skip)]`: Skip impl `TestInstance` for the struct.
+/// - `#[rusmpp(deserialize = checked)]`: Implement serde's `Deserialize` for the struct through the parts struct and `crate::deserialize::Check`, if the `serde-deserialize` feature is enabled.
 ///
 /// ## Field attributes
 ///
@@ -54,7 +56,7 @@ pub fn rusmpp(input: TokenStream) -> TokenStream {
         .into()
 }
 
-/// Creates a `TlvValue`-like and implements `Into<TlvValue>` and `Into<Tlv>`.
+/// Creates a `TlvValue`-like, implements `Into<TlvValue>` and `Into<Tlv>` and adds the `tag` and `allows` methods.
 #[proc_macro_derive(TlvValue)]
 pub fn tlv_value(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -2,7 +2,13 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, FieldsNamed, Ident};
 
-pub fn quote_parts(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStream {
+use crate::container_attributes::DeserializeAttributes;
+
+pub fn quote_parts(
+    input: &DeriveInput,
+    fields_named: &FieldsNamed,
+    deserialize_attrs: &DeserializeAttributes,
+) -> TokenStream {
     let name = &input.ident;
     let generics = &input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -36,8 +42,22 @@ pub fn quote_parts(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStre
         .clone()
         .map(|ident| quote! { #ident: self.#ident });
 
+    // The checked `Deserialize` implementation of the original struct deserializes the parts first.
+    let deserialize = match deserialize_attrs {
+        DeserializeAttributes::Skip => quote! {},
+        DeserializeAttributes::Checked => {
+            let name = name.to_string();
+
+            quote! {
+                #[cfg_attr(feature = "serde-deserialize", derive(::serde::Deserialize))]
+                #[cfg_attr(feature = "serde-deserialize", serde(rename = #name))]
+            }
+        }
+    };
+
     quote! {
         #[derive(Debug)]
+        #deserialize
         pub struct #parts_struct_name #generics {
             #(#parts_struct_fields),*
         }
@@ -56,6 +56,13 @@ impl Repr {
                     #repr_ident::from(*self).encode(dst)
                 }
             }
+
+            #[cfg(feature = "tokio-codec")]
+            impl crate::encode::EncodeBytesMut for #name {
+                fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+                    crate::encode::EncodeBytesMut::encode_buf_mut(&#repr_ident::from(*self), dst)
+                }
+            }
         }
     }
 
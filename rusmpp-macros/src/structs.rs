@@ -4,7 +4,8 @@ use syn::{DeriveInput, Field, FieldsNamed, Ident, Lit};
 
 use crate::{
     container_attributes::{
-        DecodeAttributes, DecodeImplementation, FromIntoAttributes, TestAttributes,
+        DecodeAttributes, DecodeImplementation, DeserializeAttributes, FromIntoAttributes,
+        TestAttributes,
     },
     parts,
     repr::{Repr, ReprType},
@@ -16,7 +17,7 @@ pub fn derive_rusmpp_for_struct(
 ) -> syn::Result<TokenStream> {
     let struct_attrs = StructAttributes::extract(input)?;
 
-    let parts = parts::quote_parts(input, fields_named);
+    let parts = parts::quote_parts(input, fields_named, &struct_attrs.deserialize_attrs);
 
     if let Some(repr) = struct_attrs.repr {
         let repr_expanded = repr.quote_rusmpp(
@@ -38,6 +39,8 @@ pub fn derive_rusmpp_for_struct(
     let encode = quote_encode(input, fields_named);
     let decode = quote_decode(input, fields_named, &struct_attrs.decode_attrs)?;
     let test = quote_test(input, &struct_attrs.test_attrs);
+    let deserialize =
+        quote_checked_deserialize(input, fields_named, &struct_attrs.deserialize_attrs);
 
     let expanded = quote! {
         #parts
@@ -45,6 +48,7 @@ pub fn derive_rusmpp_for_struct(
         #encode
         #decode
         #test
+        #deserialize
     };
 
     Ok(expanded)
@@ -79,7 +83,8 @@ fn quote_encode(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStream
     let field_idents = fields_named
         .named
         .iter()
-        .map(|f| f.ident.as_ref().expect("Named fields must have idents"));
+        .map(|f| f.ident.as_ref().expect("Named fields must have idents"))
+        .collect::<Vec<_>>();
 
     quote! {
         impl #impl_generics crate::encode::Encode for #name #ty_generics #where_clause {
@@ -91,6 +96,17 @@ fn quote_encode(input: &DeriveInput, fields_named: &FieldsNamed) -> TokenStream
                 size
             }
         }
+
+        #[cfg(feature = "tokio-codec")]
+        impl #impl_generics crate::encode::EncodeBytesMut for #name #ty_generics #where_clause {
+            fn encode_buf_mut(&self, dst: &mut tokio_util::bytes::BytesMut) -> usize {
+                let size = 0;
+                #(
+                    let size = size + crate::encode::EncodeBytesMut::encode_buf_mut(&self.#field_idents, dst);
+                )*
+                size
+            }
+        }
     }
 }
 
@@ -117,39 +133,60 @@ fn quote_decode(
             let decode_type = fields.decode_type();
 
             match impl_type {
-                DecodeImplementation::Owned => match decode_type {
-                    DecodeType::Decode => Ok(quote_owned_decode(input, &fields)),
-                    DecodeType::DecodeWithLength => {
-                        Ok(quote_owned_decode_with_length(input, &fields))
-                    }
-                },
+                DecodeImplementation::Owned => {
+                    let quote_owned_decode = match decode_type {
+                        DecodeType::Decode => quote_owned_decode(input, &fields),
+                        DecodeType::DecodeWithLength => {
+                            quote_owned_decode_with_length(input, &fields)
+                        }
+                    };
+                    // Owned types only exist with `alloc`.
+                    let quote_owned_dissect = quote_owned_dissect(input, &fields, quote! {});
+
+                    Ok(quote! {
+                        #quote_owned_decode
+                        #quote_owned_dissect
+                    })
+                }
                 DecodeImplementation::Borrowed => match decode_type {
                     DecodeType::Decode => Ok(quote_borrowed_decode(input, &fields)),
                     DecodeType::DecodeWithLength => {
                         Ok(quote_borrowed_decode_with_length(input, &fields))
                     }
                 },
-                DecodeImplementation::All => match decode_type {
-                    DecodeType::Decode => {
-                        let quote_borrowed_decode = quote_borrowed_decode(input, &fields);
-                        let quote_owned_decode = quote_owned_decode(input, &fields);
-
-                        Ok(quote! {
-                            #quote_borrowed_decode
-                            #quote_owned_decode
-                        })
-                    }
-                    DecodeType::DecodeWithLength => {
-                        let quote_borrowed_decode =
-                            quote_borrowed_decode_with_length(input, &fields);
-                        let quote_owned_decode = quote_owned_decode_with_length(input, &fields);
-
-                        Ok(quote! {
-                            #quote_borrowed_decode
-                            #quote_owned_decode
-                        })
-                    }
-                },
+                DecodeImplementation::All => {
+                    let quote_decode = match decode_type {
+                        DecodeType::Decode => {
+                            let quote_borrowed_decode = quote_borrowed_decode(input, &fields);
+                            let quote_owned_decode = quote_owned_decode(input, &fields);
+
+                            quote! {
+                                #quote_borrowed_decode
+                                #quote_owned_decode
+                            }
+                        }
+                        DecodeType::DecodeWithLength => {
+                            let quote_borrowed_decode =
+                                quote_borrowed_decode_with_length(input, &fields);
+                            let quote_owned_decode = quote_owned_decode_with_length(input, &fields);
+
+                            quote! {
+                                #quote_borrowed_decode
+                                #quote_owned_decode
+                            }
+                        }
+                    };
+                    let quote_owned_dissect = quote_owned_dissect(
+                        input,
+                        &fields,
+                        quote! { #[cfg(any(test, feature = "alloc"))] },
+                    );
+
+                    Ok(quote! {
+                        #quote_decode
+                        #quote_owned_dissect
+                    })
+                }
             }
         }
     }
@@ -332,11 +369,80 @@ fn quote_owned_decode_with_length(input: &DeriveInput, fields: &ValidFields) ->
     }
 }
 
+/// Dissects the fields with the owned decode implementations, see `rusmpp_core::dissect`.
+///
+/// `cfg` gates the implementation for types that are also available without `alloc`.
+fn quote_owned_dissect(input: &DeriveInput, fields: &ValidFields, cfg: TokenStream) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
+
+    let fields = fields.fields.iter().map(|f| f.quote_owned_dissect());
+
+    quote! {
+        #cfg
+        impl #impl_generics crate::dissect::Dissect for #name #ty_generics #where_clause {
+            fn dissect(
+                src: &[u8],
+                length: usize,
+                dissector: &mut crate::dissect::Dissector<'_>,
+            ) -> Result<usize, crate::decode::DecodeError> {
+                let _ = length;
+                let size = 0;
+                #(
+                    #fields
+                )*
+
+                Ok(size)
+            }
+        }
+    }
+}
+
+/// Deserializes the parts struct, builds the struct from the parts and checks it with `crate::deserialize::Check`.
+fn quote_checked_deserialize(
+    input: &DeriveInput,
+    fields_named: &FieldsNamed,
+    deserialize_attrs: &DeserializeAttributes,
+) -> TokenStream {
+    if !deserialize_attrs.is_checked() {
+        return quote! {};
+    }
+
+    let name = &input.ident;
+    let parts_struct_name = Ident::new(&format!("{}Parts", name), name.span());
+
+    let field_names = fields_named
+        .named
+        .iter()
+        .map(|f| f.ident.as_ref().expect("Named fields must have idents"));
+
+    quote! {
+        #[cfg(feature = "serde-deserialize")]
+        impl<'de> ::serde::Deserialize<'de> for #name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let parts = <#parts_struct_name as ::serde::Deserialize>::deserialize(deserializer)?;
+
+                let value = Self {
+                    #(#field_names: parts.#field_names),*
+                };
+
+                crate::deserialize::Check::check(&value).map_err(::serde::de::Error::custom)?;
+
+                Ok(value)
+            }
+        }
+    }
+}
+
 struct StructAttributes {
     /// `#[rusmpp(repr = "u8")]`
     repr: Option<Repr>,
     decode_attrs: DecodeAttributes,
     test_attrs: TestAttributes,
+    deserialize_attrs: DeserializeAttributes,
 }
 
 impl StructAttributes {
@@ -344,6 +450,7 @@ impl StructAttributes {
         let mut repr: Option<Repr> = None;
         let mut decode_attrs = DecodeAttributes::default();
         let mut test_attrs = TestAttributes::default();
+        let mut deserialize_attrs = DeserializeAttributes::default();
 
         for attr in &input.attrs {
             if !attr.path().is_ident("rusmpp") {
@@ -374,6 +481,8 @@ impl StructAttributes {
                     decode_attrs = DecodeAttributes::extract(meta)?;
                 } else if meta.path.is_ident("test") {
                     test_attrs = TestAttributes::extract(meta)?;
+                } else if meta.path.is_ident("deserialize") {
+                    deserialize_attrs = DeserializeAttributes::extract(meta)?;
                 }
 
                 Ok(())
@@ -384,6 +493,7 @@ impl StructAttributes {
             repr,
             decode_attrs,
             test_attrs,
+            deserialize_attrs,
         })
     }
 }
@@ -668,6 +778,96 @@ impl ValidField<'_> {
     }
 }
 
+impl ValidField<'_> {
+    /// The owned decode expression of the field, and whether it decodes an `Option`.
+    fn quote_owned_decode_expr(&self) -> Option<(TokenStream, bool)> {
+        match &self.attrs {
+            ValidFieldAttributes::None => Some((
+                quote! { crate::decode::owned::DecodeExt::decode_move(src, size) },
+                false,
+            )),
+            ValidFieldAttributes::SkipDecode => None,
+            ValidFieldAttributes::LengthUnchecked => Some((
+                quote! { crate::decode::owned::DecodeWithLengthExt::decode_move(src, length.saturating_sub(size), size) },
+                false,
+            )),
+            ValidFieldAttributes::LengthChecked => Some((
+                quote! { crate::decode::owned::DecodeExt::length_checked_decode_move(src, length.saturating_sub(size), size) },
+                true,
+            )),
+            ValidFieldAttributes::LengthIdent { length_ident } => Some((
+                quote! { crate::decode::owned::DecodeWithLengthExt::decode_move(src, #length_ident as usize, size) },
+                false,
+            )),
+            ValidFieldAttributes::KeyLengthUnchecked { key_ident } => Some((
+                quote! { crate::decode::owned::DecodeWithKeyOptionalExt::decode_move(#key_ident, src, length.saturating_sub(size), size) },
+                true,
+            )),
+            ValidFieldAttributes::KeyLengthIdent {
+                key_ident,
+                length_ident,
+            } => Some((
+                quote! { crate::decode::owned::DecodeWithKeyExt::optional_length_checked_decode_move(#key_ident, src, #length_ident as usize, size) },
+                true,
+            )),
+            ValidFieldAttributes::Count { count_ident } => Some((
+                quote! { crate::decode::owned::DecodeExt::counted_move(src, #count_ident as usize, size) },
+                false,
+            )),
+        }
+    }
+
+    fn quote_owned_dissect(&self) -> TokenStream {
+        let name = self
+            .field
+            .ident
+            .as_ref()
+            .expect("Named fields must have idents");
+
+        let ty = &self.field.ty;
+
+        let Some((expr, optional)) = self.quote_owned_decode_expr() else {
+            return quote! {};
+        };
+
+        let decoded = if optional {
+            quote! {
+                decoded
+                    .map(|(this, size)| (Some(this), size))
+                    .unwrap_or((None, size))
+            }
+        } else {
+            quote! { decoded }
+        };
+
+        let field = if optional {
+            quote! {
+                if let Some(value) = &#name {
+                    dissector.field(crate::fields::SmppField::#name, start, size, value);
+                }
+            }
+        } else {
+            quote! {
+                dissector.field(crate::fields::SmppField::#name, start, size, &#name);
+            }
+        };
+
+        quote! {
+            let start = size;
+            let (#name, size): (#ty, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+                #expr,
+                crate::fields::SmppField::#name,
+            ) {
+                Ok(decoded) => #decoded,
+                Err(error) => {
+                    return Err(dissector.error(crate::fields::SmppField::#name, start, error));
+                }
+            };
+            #field
+        }
+    }
+}
+
 struct ValidFields<'a> {
     fields: Vec<ValidField<'a>>,
 }
@@ -120,6 +120,57 @@ impl crate::decode::owned::Decode for CancelSm {
         ))
     }
 }
+impl crate::dissect::Dissect for CancelSm {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (service_type, size): (ServiceType, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::service_type,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::service_type, start, error),
+                );
+            }
+        };
+        dissector
+            .field(crate::fields::SmppField::service_type, start, size, &service_type);
+        let start = size;
+        let (message_id, size): (COctetString<1, 65>, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::message_id,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::message_id, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::message_id, start, size, &message_id);
+        let start = size;
+        let (other, size): (u8, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::other,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::other, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::other, start, size, &other);
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
@@ -135,6 +135,68 @@ impl crate::decode::owned::DecodeWithLength for SubmitMulti {
         ))
     }
 }
+impl crate::dissect::Dissect for SubmitMulti {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (other, size): (u8, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::other,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::other, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::other, start, size, &other);
+        let start = size;
+        let (number_of_dests, size): (u8, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::number_of_dests,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector
+                        .error(crate::fields::SmppField::number_of_dests, start, error),
+                );
+            }
+        };
+        dissector
+            .field(
+                crate::fields::SmppField::number_of_dests,
+                start,
+                size,
+                &number_of_dests,
+            );
+        let start = size;
+        let (dest_address, size): (::alloc::vec::Vec<DestAddress>, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::counted_move(
+                src,
+                number_of_dests as usize,
+                size,
+            ),
+            crate::fields::SmppField::dest_address,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::dest_address, start, error),
+                );
+            }
+        };
+        dissector
+            .field(crate::fields::SmppField::dest_address, start, size, &dest_address);
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
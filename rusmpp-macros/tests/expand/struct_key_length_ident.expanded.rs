@@ -116,6 +116,64 @@ impl crate::decode::owned::Decode for Tlv {
         Ok((Self { tag, value_length, value }, size))
     }
 }
+impl crate::dissect::Dissect for Tlv {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (tag, size): (TlvTag, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::tag,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(dissector.error(crate::fields::SmppField::tag, start, error));
+            }
+        };
+        dissector.field(crate::fields::SmppField::tag, start, size, &tag);
+        let start = size;
+        let (value_length, size): (u16, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::value_length,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::value_length, start, error),
+                );
+            }
+        };
+        dissector
+            .field(crate::fields::SmppField::value_length, start, size, &value_length);
+        let start = size;
+        let (value, size): (Option<TlvValue>, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeWithKeyExt::optional_length_checked_decode_move(
+                tag,
+                src,
+                value_length as usize,
+                size,
+            ),
+            crate::fields::SmppField::value,
+        ) {
+            Ok(decoded) => {
+                decoded.map(|(this, size)| (Some(this), size)).unwrap_or((None, size))
+            }
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::value, start, error),
+                );
+            }
+        };
+        if let Some(value) = &value {
+            dissector.field(crate::fields::SmppField::value, start, size, value);
+        }
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
@@ -158,6 +158,88 @@ impl crate::decode::owned::DecodeWithLength for Command {
         ))
     }
 }
+impl crate::dissect::Dissect for Command {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (id, size): (CommandId, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::id,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(dissector.error(crate::fields::SmppField::id, start, error));
+            }
+        };
+        dissector.field(crate::fields::SmppField::id, start, size, &id);
+        let start = size;
+        let (command_status, size): (CommandStatus, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::command_status,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector
+                        .error(crate::fields::SmppField::command_status, start, error),
+                );
+            }
+        };
+        dissector
+            .field(
+                crate::fields::SmppField::command_status,
+                start,
+                size,
+                &command_status,
+            );
+        let start = size;
+        let (sequence_number, size): (u32, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::sequence_number,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector
+                        .error(crate::fields::SmppField::sequence_number, start, error),
+                );
+            }
+        };
+        dissector
+            .field(
+                crate::fields::SmppField::sequence_number,
+                start,
+                size,
+                &sequence_number,
+            );
+        let start = size;
+        let (pdu, size): (Option<Pdu>, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeWithKeyOptionalExt::decode_move(
+                id,
+                src,
+                length.saturating_sub(size),
+                size,
+            ),
+            crate::fields::SmppField::pdu,
+        ) {
+            Ok(decoded) => {
+                decoded.map(|(this, size)| (Some(this), size)).unwrap_or((None, size))
+            }
+            Err(error) => {
+                return Err(dissector.error(crate::fields::SmppField::pdu, start, error));
+            }
+        };
+        if let Some(value) = &pdu {
+            dissector.field(crate::fields::SmppField::pdu, start, size, value);
+        }
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
@@ -131,6 +131,62 @@ impl crate::decode::owned::DecodeWithLength for SubmitSm {
         ))
     }
 }
+impl crate::dissect::Dissect for SubmitSm {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (other, size): (u8, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::other,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::other, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::other, start, size, &other);
+        let start = size;
+        let (sm_length, size): (u8, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::sm_length,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::sm_length, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::sm_length, start, size, &sm_length);
+        let start = size;
+        let (short_message, size): (OctetString<0, 255>, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeWithLengthExt::decode_move(
+                src,
+                sm_length as usize,
+                size,
+            ),
+            crate::fields::SmppField::short_message,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector
+                        .error(crate::fields::SmppField::short_message, start, error),
+                );
+            }
+        };
+        dissector
+            .field(crate::fields::SmppField::short_message, start, size, &short_message);
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
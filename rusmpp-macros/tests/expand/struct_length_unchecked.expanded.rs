@@ -103,6 +103,47 @@ impl crate::decode::owned::DecodeWithLength for BroadcastAreaIdentifier {
         Ok((Self { format, area }, size))
     }
 }
+impl crate::dissect::Dissect for BroadcastAreaIdentifier {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (format, size): (BroadcastAreaFormat, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::format,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::format, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::format, start, size, &format);
+        let start = size;
+        let (area, size): (AnyOctetString, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeWithLengthExt::decode_move(
+                src,
+                length.saturating_sub(size),
+                size,
+            ),
+            crate::fields::SmppField::area,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::area, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::area, start, size, &area);
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
@@ -89,6 +89,30 @@ impl crate::decode::owned::Decode for DistributionListName {
         Ok((Self::new(dl_name), size))
     }
 }
+impl crate::dissect::Dissect for DistributionListName {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (dl_name, size): (COctetString<1, 21>, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::dl_name,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::dl_name, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::dl_name, start, size, &dl_name);
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
@@ -79,6 +79,30 @@ impl crate::decode::owned::Decode for SubmitSmResp {
         Ok((Self { message_id }, size))
     }
 }
+impl crate::dissect::Dissect for SubmitSmResp {
+    fn dissect(
+        src: &[u8],
+        length: usize,
+        dissector: &mut crate::dissect::Dissector<'_>,
+    ) -> Result<usize, crate::decode::DecodeError> {
+        let _ = length;
+        let size = 0;
+        let start = size;
+        let (message_id, size): (COctetString<1, 65>, usize) = match crate::decode::DecodeErrorExt::map_as_source(
+            crate::decode::owned::DecodeExt::decode_move(src, size),
+            crate::fields::SmppField::message_id,
+        ) {
+            Ok(decoded) => decoded,
+            Err(error) => {
+                return Err(
+                    dissector.error(crate::fields::SmppField::message_id, start, error),
+                );
+            }
+        };
+        dissector.field(crate::fields::SmppField::message_id, start, size, &message_id);
+        Ok(size)
+    }
+}
 /// Docs
 ///
 /// More docs
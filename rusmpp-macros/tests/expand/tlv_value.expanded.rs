@@ -229,6 +229,35 @@ impl BroadcastRequestTlvValue {
             }
         }
     }
+    /// Returns `true` if a TLV with the given [`TlvTag`] is allowed in this TLV set.
+    pub const fn allows(tag: TlvTag) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match tag {
+            TlvTag::AlertOnMessageDelivery
+            | TlvTag::BroadcastChannelIndicator
+            | TlvTag::BroadcastContentTypeInfo
+            | TlvTag::BroadcastMessageClass
+            | TlvTag::BroadcastServiceGroup
+            | TlvTag::CallbackNum
+            | TlvTag::CallbackNumAtag
+            | TlvTag::CallbackNumPresInd
+            | TlvTag::DestAddrSubunit
+            | TlvTag::DestSubaddress
+            | TlvTag::DestPort
+            | TlvTag::DisplayTime
+            | TlvTag::LanguageIndicator
+            | TlvTag::MessagePayload
+            | TlvTag::MsValidity
+            | TlvTag::PayloadType
+            | TlvTag::PrivacyIndicator
+            | TlvTag::SmsSignal
+            | TlvTag::SourceAddrSubunit
+            | TlvTag::SourcePort
+            | TlvTag::SourceSubaddress
+            | TlvTag::UserMessageReference => true,
+            _ => false,
+        }
+    }
 }
 impl From<BroadcastRequestTlvValue> for TlvValue {
     fn from(value: BroadcastRequestTlvValue) -> Self {
@@ -563,6 +592,39 @@ impl<'a> BroadcastRequestTlvValue<'a> {
             }
         }
     }
+    /// Returns `true` if a TLV with the given [`TlvTag`] is allowed in this TLV set.
+    pub const fn allows(tag: TlvTag) -> bool {
+        #[allow(non_exhaustive_omitted_patterns)]
+        match tag {
+            TlvTag::BroadcastAreaIdentifier
+            | TlvTag::BroadcastContentType
+            | TlvTag::BroadcastFrequencyInterval
+            | TlvTag::BroadcastRepNum
+            | TlvTag::AlertOnMessageDelivery
+            | TlvTag::BroadcastChannelIndicator
+            | TlvTag::BroadcastContentTypeInfo
+            | TlvTag::BroadcastMessageClass
+            | TlvTag::BroadcastServiceGroup
+            | TlvTag::CallbackNum
+            | TlvTag::CallbackNumAtag
+            | TlvTag::CallbackNumPresInd
+            | TlvTag::DestAddrSubunit
+            | TlvTag::DestSubaddress
+            | TlvTag::DestPort
+            | TlvTag::DisplayTime
+            | TlvTag::LanguageIndicator
+            | TlvTag::MessagePayload
+            | TlvTag::MsValidity
+            | TlvTag::PayloadType
+            | TlvTag::PrivacyIndicator
+            | TlvTag::SmsSignal
+            | TlvTag::SourceAddrSubunit
+            | TlvTag::SourcePort
+            | TlvTag::SourceSubaddress
+            | TlvTag::UserMessageReference => true,
+            _ => false,
+        }
+    }
 }
 impl<'a> From<BroadcastRequestTlvValue<'a>> for TlvValue<'a> {
     fn from(value: BroadcastRequestTlvValue<'a>) -> Self {
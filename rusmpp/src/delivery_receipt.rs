@@ -0,0 +1,3 @@
+//! MC delivery receipts.
+
+pub use rusmpp_core::delivery_receipt::*;
@@ -0,0 +1,3 @@
+//! Checked `serde` deserialization of `SMPP` types.
+
+pub use rusmpp_core::deserialize::*;
@@ -0,0 +1,3 @@
+//! Annotated dissection of `SMPP` commands.
+
+pub use rusmpp_core::dissect::*;
@@ -0,0 +1,3 @@
+//! Short message text encodings.
+
+pub use rusmpp_core::encoding::*;
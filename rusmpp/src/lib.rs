@@ -88,6 +88,9 @@
 //! - `verbose`: Enables verbose error reports.
 //! - `serde`: Implements [`Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html) trait for all SMPP types.
 //! - `serde-deserialize-unchecked`: Implements [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) trait for all SMPP types, but does not check the validity of the data. Use with caution.
+//! - `serde-deserialize`: Implements [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) trait for all SMPP types and checks the deserialized values like decoded values. Takes precedence over `serde-deserialize-unchecked`. See [`deserialize`].
+//! - `chrono`: Implements conversions between [`SmppTime`](values::SmppTime) and [`chrono`](https://docs.rs/chrono/latest/chrono/)'s `DateTime`.
+//! - `time`: Implements conversions between [`SmppTime`](values::SmppTime) and [`time`](https://docs.rs/time/latest/time/)'s `OffsetDateTime`.
 //! - `tracing`: Enables logging using [`tracing`](https://docs.rs/tracing/latest/tracing/).
 //! - `pretty-hex-fmt`: Logs byte slices like `[0x00, 0x00, 0x00, 0x6F]` instead of `[00, 00, 00, 6F]`, if `tracing` feature is enabled.
 //! - `char-fmt`: Logs byte slices as characters, if `tracing` feature is enabled.
@@ -108,7 +111,10 @@ pub mod types;
 pub mod decode;
 pub mod encode;
 
-pub use rusmpp_core::{CommandId, CommandStatus, command::owned::Command, pdus::owned::Pdu};
+pub use rusmpp_core::{
+    CommandId, CommandStatus, CommandStatusCategory, RetryHint, command::owned::Command,
+    pdus::owned::Pdu,
+};
 
 pub mod command;
 
@@ -120,4 +126,20 @@ pub mod pdus;
 
 pub mod fields;
 
+pub mod encoding;
+
+pub mod udhs;
+
+pub mod multipart;
+
 pub mod session;
+
+pub mod delivery_receipt;
+
+pub mod validate;
+
+pub mod dissect;
+
+#[cfg(feature = "serde-deserialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde-deserialize")))]
+pub mod deserialize;
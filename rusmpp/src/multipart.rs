@@ -0,0 +1,3 @@
+//! Multipart (concatenated) short messages.
+
+pub use rusmpp_core::multipart::*;
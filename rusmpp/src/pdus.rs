@@ -9,9 +9,9 @@ pub mod parts {
 }
 
 pub use rusmpp_core::pdus::owned::{
-    AlertNotification, BindReceiver, BindReceiverResp, BindTransceiver, BindTransceiverResp,
-    BindTransmitter, BindTransmitterResp, BroadcastSm, BroadcastSmResp, CancelBroadcastSm,
-    CancelSm, DataSm, DataSmResp, DeliverSm, DeliverSmResp, Outbind, QueryBroadcastSm,
-    QueryBroadcastSmResp, QuerySm, QuerySmResp, ReplaceSm, SubmitMulti, SubmitMultiResp, SubmitSm,
-    SubmitSmResp,
+    AlertNotification, AsBorrowedError, BindReceiver, BindReceiverResp, BindTransceiver,
+    BindTransceiverResp, BindTransmitter, BindTransmitterResp, BroadcastSm, BroadcastSmResp,
+    CancelBroadcastSm, CancelSm, DataSm, DataSmResp, DeliverSm, DeliverSmResp, MessageKind,
+    Outbind, QueryBroadcastSm, QueryBroadcastSmResp, QuerySm, QuerySmResp, ReplaceSm, SubmitMulti,
+    SubmitMultiResp, SubmitSm, SubmitSmResp, UserMessage, VendorPdu, VendorPduBody,
 };
@@ -0,0 +1,3 @@
+//! User Data Headers (UDHs).
+
+pub use rusmpp_core::udhs::{UdhId, errors, owned::*};
@@ -0,0 +1,3 @@
+//! Semantic validation of `SMPP` PDUs.
+
+pub use rusmpp_core::validate::*;
@@ -17,18 +17,20 @@ pub use rusmpp_core::values::{
     AddrSubunit, AlertOnMessageDelivery, Ansi41Cbs, Ansi41Specific, Ansi136, BearerType,
     BroadcastAreaFormat, BroadcastAreaSuccess, BroadcastChannelIndicator, BroadcastContentType,
     BroadcastFrequencyInterval, BroadcastMessageClass, BroadcastRepNum, CallbackNumPresInd,
-    CongestionState, DataCoding, DeliveryFailureReason, DestAddrNpResolution, DestFlag,
-    DisplayTime, DpfResult, EncodingContentType, ErrorCodeNetworkType, EsmClass,
-    GenericServiceType, GsmCbs, GsmFeatures, GsmSms, Indicator, InterfaceVersion,
-    IntermediateNotification, Is95, ItsReplyType, ItsSessionInfo, LanguageIndicator,
-    MCDeliveryReceipt, MessageState, MessageType, MessagingMode, MoreMessagesToSend,
-    MsAvailabilityStatus, MsMsgWaitFacilities, MsValidity, MsValidityBehavior,
-    MsValidityInformation, NetworkErrorCode, NetworkType, Npi, NumberOfMessages, PayloadType,
-    Presentation, PriorityFlag, PriorityFlagType, PrivacyIndicator, RegisteredDelivery,
-    ReplaceIfPresentFlag, Screening, SetDpf, SmeOriginatedAcknowledgement, SubaddressTag, Ton,
-    TypeOfMessage, TypeOfNetwork, UnitOfTime, UnitsOfTime, UserMessageReference, UssdServiceOp,
+    CongestionState, DataCoding, DataCodingAlphabet, DataCodingScheme, DeliveryFailureReason,
+    DestAddrNpResolution, DestFlag, DisplayTime, DpfResult, EncodingContentType,
+    ErrorCodeNetworkType, EsmClass, GenericServiceType, GsmAlphabet, GsmCbs, GsmFeatures, GsmSms,
+    Indicator, InterfaceVersion, IntermediateNotification, Is95, ItsReplyType, ItsSessionInfo,
+    LanguageIndicator, MCDeliveryReceipt, MessageClass, MessageState, MessageType,
+    MessageWaitingGroup, MessagingMode, MoreMessagesToSend, MsAvailabilityStatus,
+    MsMsgWaitFacilities, MsValidity, MsValidityBehavior, MsValidityInformation, NetworkErrorCode,
+    NetworkType, Npi, NumberOfMessages, PayloadType, Presentation, PriorityFlag, PriorityFlagType,
+    PrivacyIndicator, RegisteredDelivery, ReplaceIfPresentFlag, Screening, SetDpf,
+    SmeOriginatedAcknowledgement, SmppAddressError, SmppDateTime, SmppTime, SmppTimeError,
+    SubaddressTag, Ton, TypeOfMessage, TypeOfNetwork, UnitOfTime, UnitsOfTime,
+    UserMessageReference, UssdServiceOp,
     owned::{
-        BroadcastAreaIdentifier, MessagePayload, ServiceType, Subaddress, UnsuccessSme,
-        {DestAddress, DistributionListName, SmeAddress},
+        BroadcastAreaIdentifier, MessagePayload, ServiceType, SmppAddress, Subaddress,
+        UnsuccessSme, {DestAddress, DistributionListName, SmeAddress},
     },
 };
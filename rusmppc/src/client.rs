@@ -11,7 +11,7 @@ use rusmpp::{
     command::CommandParts,
     pdus::{
         BindReceiver, BindReceiverResp, BindTransceiver, BindTransceiverResp, BindTransmitter,
-        BindTransmitterResp, DeliverSmResp, SubmitSm, SubmitSmResp,
+        BindTransmitterResp, DeliverSmResp, SubmitSm, SubmitSmResp, VendorPdu, VendorPduBody,
     },
     values::InterfaceVersion,
 };
@@ -135,6 +135,25 @@ impl Client {
             .await
     }
 
+    /// Sends a vendor-specific command to the server and waits for a successful vendor-specific response `R`.
+    pub async fn vendor_request<T: VendorPduBody, R: VendorPduBody>(
+        &self,
+        body: T,
+    ) -> Result<R, Error> {
+        self.registered_request().vendor_request(body).await
+    }
+
+    /// Sends a vendor-specific response command to the server.
+    pub async fn vendor_response<T: VendorPduBody>(
+        &self,
+        sequence_number: u32,
+        body: T,
+    ) -> Result<(), Error> {
+        self.unregistered_request()
+            .vendor_response(sequence_number, body)
+            .await
+    }
+
     /// Closes the connection.
     ///
     /// This method completes, when the connection has registered the close request.
@@ -370,6 +389,16 @@ impl<'a> UnregisteredRequestBuilder<'a> {
             .await
     }
 
+    /// Sends a vendor-specific response command to the server.
+    pub async fn vendor_response<T: VendorPduBody>(
+        self,
+        sequence_number: u32,
+        body: T,
+    ) -> Result<(), Error> {
+        self.unregistered_request(VendorPdu(body), sequence_number)
+            .await
+    }
+
     /// Sends a [`BindTransmitter`] command to the server and waits for a successful [`BindTransmitterResp`].
     pub async fn bind_transmitter(
         &self,
@@ -408,6 +437,14 @@ impl<'a> UnregisteredRequestBuilder<'a> {
     pub async fn enquire_link(&self) -> Result<(), Error> {
         self.registered_request().enquire_link().await
     }
+
+    /// Sends a vendor-specific command to the server and waits for a successful vendor-specific response `R`.
+    pub async fn vendor_request<T: VendorPduBody, R: VendorPduBody>(
+        &self,
+        body: T,
+    ) -> Result<R, Error> {
+        self.registered_request().vendor_request(body).await
+    }
 }
 
 #[derive(Debug)]
@@ -596,6 +633,28 @@ impl<'a> RegisteredRequestBuilder<'a> {
             .map(|_| ())
             .map_err(Error::unexpected_response)
     }
+
+    /// Sends a vendor-specific command to the server and waits for a successful vendor-specific response `R`.
+    ///
+    /// Returns [`Error::VendorBody`] if the successful response is not `R` or its body can not be decoded.
+    ///
+    /// See [`VendorPduBody`].
+    pub async fn vendor_request<T: VendorPduBody, R: VendorPduBody>(
+        &self,
+        body: T,
+    ) -> Result<R, Error> {
+        let response = self
+            .request(VendorPdu(body))
+            .await?
+            .ok()
+            .map_err(Error::unexpected_response)?;
+
+        match response.vendor_body::<R>() {
+            Some(Ok(body)) => Ok(body),
+            Some(Err(error)) => Err(Error::vendor_body(response, Some(error))),
+            None => Err(Error::vendor_body(response, None)),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -650,4 +709,15 @@ impl<'a> NoWaitRequestBuilder<'a> {
 
         Ok(sequence_number)
     }
+
+    /// Sends a vendor-specific command to the server without waiting for the response.
+    pub async fn vendor_request<T: VendorPduBody>(&self, body: T) -> Result<u32, Error> {
+        let sequence_number = self.client.inner.next_sequence_number();
+
+        self.unregistered_request()
+            .unregistered_request(VendorPdu(body), sequence_number)
+            .await?;
+
+        Ok(sequence_number)
+    }
 }
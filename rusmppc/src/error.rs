@@ -3,7 +3,7 @@
 use std::time::Duration;
 
 use rusmpp::{
-    Command,
+    Command, CommandStatus, CommandStatusCategory, RetryHint,
     tokio_codec::{DecodeError, EncodeError},
     values::InterfaceVersion,
 };
@@ -87,11 +87,26 @@ pub enum Error {
     /// Error responses are responses with the status code other than [`EsmeRok`](rusmpp::CommandStatus::EsmeRok).
     ///
     /// This error is returned by methods that send commands and wait for a response, such as [`bind_transceiver`](crate::client::Client::bind_transceiver) and [`submit_sm`](crate::client::Client::submit_sm).
+    ///
+    /// See [`Error::status`], [`Error::category`] and [`Error::retry_hint`] to decide how to react to the error.
     #[error("Unexpected response from the server: response: {response:?}")]
     UnexpectedResponse {
         /// The response that was received from the server.
         response: Box<Command>,
     },
+    /// The server responded to a vendor-specific request with a successful response that is not the expected vendor-specific response.
+    ///
+    /// This error is returned by [`vendor_request`](crate::client::Client::vendor_request).
+    #[error("Unexpected vendor-specific response from the server: response: {response:?}")]
+    VendorBody {
+        /// The response that was received from the server.
+        response: Box<Command>,
+        /// The error that occurred while decoding the response body.
+        ///
+        /// `None` if the response is not the expected vendor-specific response.
+        #[source]
+        error: Option<rusmpp::decode::DecodeError>,
+    },
     /// The client used an interface version that is not supported by the library.
     ///
     /// The library supports only `SMPP v5.0`.
@@ -107,12 +122,40 @@ pub enum Error {
 }
 
 impl Error {
+    /// Returns the [`CommandStatus`] of the error response, if this is an [`Error::UnexpectedResponse`].
+    pub fn status(&self) -> Option<CommandStatus> {
+        match self {
+            Self::UnexpectedResponse { response } => Some(response.status()),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`CommandStatusCategory`] of the error response, if this is an [`Error::UnexpectedResponse`].
+    pub fn category(&self) -> Option<CommandStatusCategory> {
+        self.status().map(CommandStatus::category)
+    }
+
+    /// Returns the [`RetryHint`] of the error response, if this is an [`Error::UnexpectedResponse`].
+    pub fn retry_hint(&self) -> Option<RetryHint> {
+        self.status().map(CommandStatus::retry_hint)
+    }
+
     pub(crate) fn unexpected_response(response: impl Into<Box<Command>>) -> Self {
         Self::UnexpectedResponse {
             response: response.into(),
         }
     }
 
+    pub(crate) fn vendor_body(
+        response: impl Into<Box<Command>>,
+        error: Option<rusmpp::decode::DecodeError>,
+    ) -> Self {
+        Self::VendorBody {
+            response: response.into(),
+            error,
+        }
+    }
+
     pub(crate) const fn unsupported_interface_version(version: InterfaceVersion) -> Self {
         Self::UnsupportedInterfaceVersion {
             version,
@@ -8,10 +8,12 @@ use std::time::{Duration, Instant};
 
 use futures::{SinkExt, StreamExt};
 use rusmpp::{
-    Command, CommandId, CommandStatus, Pdu,
+    Command, CommandId, CommandStatus, CommandStatusCategory, Pdu, RetryHint,
+    decode::{Decode, DecodeError},
+    encode::{Encode, Length},
     pdus::{
         AlertNotification, BindReceiverResp, BindTransceiverResp, BindTransmitterResp, SubmitSm,
-        SubmitSmResp,
+        SubmitSmResp, VendorPdu, VendorPduBody,
     },
     tokio_codec::CommandCodec,
 };
@@ -383,6 +385,45 @@ async fn request_after_closing_connection_should_fail() {
     let _ = events.count().await;
 }
 
+#[tokio::test]
+async fn error_response_should_expose_status_category_and_retry_hint() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        while let Some(Ok(command)) = framed.next().await {
+            if command.id() != CommandId::SubmitSm {
+                continue;
+            }
+
+            let response = Command::builder()
+                .status(CommandStatus::EsmeRthrottled)
+                .sequence_number(command.sequence_number())
+                .pdu(SubmitSmResp::default());
+
+            framed
+                .send(response)
+                .await
+                .expect("Failed to send response");
+        }
+    });
+
+    let (client, _events) = ConnectionBuilder::new().connected(client);
+
+    let error = client.submit_sm(SubmitSm::default()).await.unwrap_err();
+
+    assert_eq!(error.status(), Some(CommandStatus::EsmeRthrottled));
+    assert_eq!(error.category(), Some(CommandStatusCategory::Transient));
+    assert_eq!(error.retry_hint(), Some(RetryHint::Throttle));
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+}
+
 #[tokio::test]
 async fn close_connection_twice_should_fail() {
     init_tracing();
@@ -801,3 +842,275 @@ async fn disabled_auto_enquire_link_response_should_pipe_enquire_link_through_ev
 
     assert!(matches!(command.id(), CommandId::EnquireLink));
 }
+
+#[derive(Debug, PartialEq)]
+struct Balance {
+    account: u32,
+}
+
+impl Length for Balance {
+    fn length(&self) -> usize {
+        self.account.length()
+    }
+}
+
+impl Encode for Balance {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        self.account.encode(dst)
+    }
+}
+
+impl Decode for Balance {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        u32::decode(src).map(|(account, size)| (Self { account }, size))
+    }
+}
+
+impl VendorPduBody for Balance {
+    const COMMAND_ID: u32 = 0x00010201;
+}
+
+#[derive(Debug, PartialEq)]
+struct BalanceResp {
+    balance: u32,
+}
+
+impl Length for BalanceResp {
+    fn length(&self) -> usize {
+        self.balance.length()
+    }
+}
+
+impl Encode for BalanceResp {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        self.balance.encode(dst)
+    }
+}
+
+impl Decode for BalanceResp {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        u32::decode(src).map(|(balance, size)| (Self { balance }, size))
+    }
+}
+
+impl VendorPduBody for BalanceResp {
+    const COMMAND_ID: u32 = 0x80010201;
+}
+
+#[tokio::test]
+async fn vendor_request_should_match_vendor_response() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        while let Some(Ok(command)) = framed.next().await {
+            let Some(Ok(balance)) = command.pdu().and_then(Pdu::vendor_body::<Balance>) else {
+                continue;
+            };
+
+            let response = Command::builder()
+                .status(CommandStatus::EsmeRok)
+                .sequence_number(command.sequence_number())
+                .pdu(VendorPdu(BalanceResp {
+                    balance: balance.account * 10,
+                }));
+
+            framed
+                .send(response)
+                .await
+                .expect("Failed to send response");
+        }
+    });
+
+    let (client, _events) = ConnectionBuilder::new().connected(client);
+
+    let response: BalanceResp = client
+        .vendor_request(Balance { account: 7 })
+        .await
+        .expect("Failed to send vendor request");
+
+    assert_eq!(response, BalanceResp { balance: 70 });
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+}
+
+#[derive(Debug, PartialEq)]
+struct Reset;
+
+impl Length for Reset {
+    fn length(&self) -> usize {
+        0
+    }
+}
+
+impl Encode for Reset {
+    fn encode(&self, _dst: &mut [u8]) -> usize {
+        0
+    }
+}
+
+impl Decode for Reset {
+    fn decode(_src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        Ok((Self, 0))
+    }
+}
+
+impl VendorPduBody for Reset {
+    const COMMAND_ID: u32 = 0x00010202;
+}
+
+#[derive(Debug, PartialEq)]
+struct ResetResp;
+
+impl Length for ResetResp {
+    fn length(&self) -> usize {
+        0
+    }
+}
+
+impl Encode for ResetResp {
+    fn encode(&self, _dst: &mut [u8]) -> usize {
+        0
+    }
+}
+
+impl Decode for ResetResp {
+    fn decode(_src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        Ok((Self, 0))
+    }
+}
+
+impl VendorPduBody for ResetResp {
+    const COMMAND_ID: u32 = 0x80010202;
+}
+
+#[tokio::test]
+async fn vendor_request_should_match_header_only_vendor_response() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        while let Some(Ok(command)) = framed.next().await {
+            if command.vendor_body::<Reset>().is_none() {
+                continue;
+            }
+
+            let response = Command::builder()
+                .status(CommandStatus::EsmeRok)
+                .sequence_number(command.sequence_number())
+                .pdu(VendorPdu(ResetResp));
+
+            framed
+                .send(response)
+                .await
+                .expect("Failed to send response");
+        }
+    });
+
+    let (client, _events) = ConnectionBuilder::new().connected(client);
+
+    let response: ResetResp = client
+        .vendor_request(Reset)
+        .await
+        .expect("Failed to send vendor request");
+
+    assert_eq!(response, ResetResp);
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+}
+
+/// A [`BalanceResp`] body that is too short to decode.
+#[derive(Debug, PartialEq)]
+struct TruncatedBalanceResp;
+
+impl Length for TruncatedBalanceResp {
+    fn length(&self) -> usize {
+        2
+    }
+}
+
+impl Encode for TruncatedBalanceResp {
+    fn encode(&self, dst: &mut [u8]) -> usize {
+        dst[..2].copy_from_slice(&[0x00, 0x07]);
+
+        2
+    }
+}
+
+impl VendorPduBody for TruncatedBalanceResp {
+    const COMMAND_ID: u32 = BalanceResp::COMMAND_ID;
+}
+
+impl Decode for TruncatedBalanceResp {
+    fn decode(src: &[u8]) -> Result<(Self, usize), DecodeError> {
+        Ok((Self, src.len()))
+    }
+}
+
+#[tokio::test]
+async fn vendor_request_should_fail_with_unexpected_vendor_body() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        while let Some(Ok(command)) = framed.next().await {
+            let response = Command::builder()
+                .status(CommandStatus::EsmeRok)
+                .sequence_number(command.sequence_number());
+
+            // Answer a balance request with a truncated body and a reset request with the wrong response.
+            let response = match command.vendor_body::<Balance>() {
+                Some(_) => response.pdu(VendorPdu(TruncatedBalanceResp)),
+                None => response.pdu(VendorPdu(BalanceResp { balance: 0 })),
+            };
+
+            framed
+                .send(response)
+                .await
+                .expect("Failed to send response");
+        }
+    });
+
+    let (client, _events) = ConnectionBuilder::new().connected(client);
+
+    let error = client
+        .vendor_request::<_, BalanceResp>(Balance { account: 7 })
+        .await
+        .expect_err("Expected a vendor body error");
+
+    assert!(matches!(error, Error::VendorBody { error: Some(_), .. }));
+    assert_eq!(error.category(), None);
+
+    let error = client
+        .vendor_request::<_, ResetResp>(Reset)
+        .await
+        .expect_err("Expected a vendor body error");
+
+    let Error::VendorBody {
+        response,
+        error: None,
+    } = &error
+    else {
+        panic!("Expected a vendor body error without a decode error, got: {error:?}");
+    };
+
+    assert_eq!(response.status(), CommandStatus::EsmeRok);
+    assert_eq!(error.category(), None);
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+}